@@ -0,0 +1,445 @@
+//! Regression Benchmark Harness
+//!
+//! This promotes the one-off `WebNLG` evaluation loop into a reusable
+//! benchmark: a JSON "workload" file describes a (model, config, dataset)
+//! combination and a repeat count, `bench` runs it N times per case to
+//! smooth out end-to-end timing noise, and writes a machine-readable results
+//! file recording per-case wall-clock time, a `retries_exhausted` flag (set
+//! when extraction failed; the number of retries is not recorded), and
+//! precision/recall/F1. Passing `--baseline <results.json>` additionally
+//! diffs the new run against a stored baseline and flags statistically
+//! meaningful regressions in average F1 or latency.
+//!
+//! ## Workload file format
+//!
+//! ```json
+//! {
+//!   "name": "webnlg-claude-sonnet",
+//!   "model": "claude-3-5-sonnet",
+//!   "dataset_path": "tests/fixtures/test_cases.json",
+//!   "repeats": 3
+//! }
+//! ```
+//!
+//! ## Running This Example
+//!
+//! ```bash
+//! export GENAI_API_KEY="your-key"
+//! cargo run --example bench -- workload.json --out results.json
+//! cargo run --example bench -- workload.json --out results.json --baseline baseline.json
+//! ```
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashSet;
+use std::env;
+use std::fs;
+use std::time::Instant;
+use text_to_rdf::normalize::normalize_predicate;
+use text_to_rdf::{ExtractionConfig, GenAiExtractor, RdfExtractor};
+
+/// A workload file: the (model, config, dataset) combination to benchmark
+#[derive(Debug, Deserialize)]
+struct Workload {
+    name: String,
+    model: String,
+    dataset_path: String,
+    /// Number of times to repeat the whole dataset, to average out noise
+    #[serde(default = "default_repeats")]
+    repeats: usize,
+}
+
+const fn default_repeats() -> usize {
+    3
+}
+
+#[derive(Debug, Deserialize)]
+struct TestCase {
+    id: String,
+    raw_text: String,
+    expected_triples: Vec<Triple>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq, Hash)]
+struct Triple {
+    subject: String,
+    predicate: String,
+    object: String,
+}
+
+/// Metrics for a single extraction attempt
+#[derive(Debug, Clone)]
+struct EvaluationMetrics {
+    precision: f64,
+    recall: f64,
+    f1_score: f64,
+}
+
+impl EvaluationMetrics {
+    fn new(predicted: &HashSet<Triple>, expected: &HashSet<Triple>) -> Self {
+        let true_positives = predicted.intersection(expected).count();
+
+        let precision = if predicted.is_empty() {
+            0.0
+        } else {
+            true_positives as f64 / predicted.len() as f64
+        };
+
+        let recall = if expected.is_empty() {
+            0.0
+        } else {
+            true_positives as f64 / expected.len() as f64
+        };
+
+        let f1_score = if precision + recall == 0.0 {
+            0.0
+        } else {
+            2.0 * (precision * recall) / (precision + recall)
+        };
+
+        Self {
+            precision,
+            recall,
+            f1_score,
+        }
+    }
+}
+
+/// One repeat of one test case: timing plus the resulting metrics
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CaseRun {
+    case_id: String,
+    repeat: usize,
+    latency_ms: f64,
+    retries_exhausted: bool,
+    precision: f64,
+    recall: f64,
+    f1_score: f64,
+}
+
+/// Mean and sample standard deviation of a metric across repeats
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+struct Stat {
+    mean: f64,
+    stddev: f64,
+}
+
+impl Stat {
+    fn from_samples(samples: &[f64]) -> Self {
+        let n = samples.len() as f64;
+        if samples.is_empty() {
+            return Self {
+                mean: 0.0,
+                stddev: 0.0,
+            };
+        }
+        let mean = samples.iter().sum::<f64>() / n;
+        let variance = if samples.len() < 2 {
+            0.0
+        } else {
+            samples.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / (n - 1.0)
+        };
+        Self {
+            mean,
+            stddev: variance.sqrt(),
+        }
+    }
+}
+
+/// Full benchmark result for a workload, ready to serialize to a results file
+#[derive(Debug, Serialize, Deserialize)]
+struct BenchResult {
+    workload_name: String,
+    model: String,
+    total_runs: usize,
+    f1: Stat,
+    precision: Stat,
+    recall: Stat,
+    latency_ms: Stat,
+    runs: Vec<CaseRun>,
+}
+
+impl BenchResult {
+    fn from_runs(workload: &Workload, runs: Vec<CaseRun>) -> Self {
+        let f1_samples: Vec<f64> = runs.iter().map(|r| r.f1_score).collect();
+        let precision_samples: Vec<f64> = runs.iter().map(|r| r.precision).collect();
+        let recall_samples: Vec<f64> = runs.iter().map(|r| r.recall).collect();
+        let latency_samples: Vec<f64> = runs.iter().map(|r| r.latency_ms).collect();
+
+        Self {
+            workload_name: workload.name.clone(),
+            model: workload.model.clone(),
+            total_runs: runs.len(),
+            f1: Stat::from_samples(&f1_samples),
+            precision: Stat::from_samples(&precision_samples),
+            recall: Stat::from_samples(&recall_samples),
+            latency_ms: Stat::from_samples(&latency_samples),
+            runs,
+        }
+    }
+}
+
+/// A flagged regression between a baseline run and the current run
+#[derive(Debug)]
+struct Regression {
+    metric: &'static str,
+    baseline: f64,
+    current: f64,
+    delta: f64,
+}
+
+/// Flag a regression when the current mean drops by more than twice the
+/// combined standard error versus the baseline mean - a cheap stand-in for a
+/// two-sample significance test that avoids false alarms from run-to-run
+/// noise alone.
+fn is_significant_drop(baseline: Stat, current: Stat, baseline_n: usize, current_n: usize) -> bool {
+    let drop = baseline.mean - current.mean;
+    if drop <= 0.0 {
+        return false;
+    }
+    let baseline_se = baseline.stddev / (baseline_n.max(1) as f64).sqrt();
+    let current_se = current.stddev / (current_n.max(1) as f64).sqrt();
+    let combined_se = (baseline_se.powi(2) + current_se.powi(2)).sqrt();
+    drop > 2.0 * combined_se.max(f64::EPSILON)
+}
+
+fn diff_against_baseline(baseline: &BenchResult, current: &BenchResult) -> Vec<Regression> {
+    let mut regressions = Vec::new();
+
+    if is_significant_drop(
+        baseline.f1,
+        current.f1,
+        baseline.total_runs,
+        current.total_runs,
+    ) {
+        regressions.push(Regression {
+            metric: "avg F1",
+            baseline: baseline.f1.mean,
+            current: current.f1.mean,
+            delta: current.f1.mean - baseline.f1.mean,
+        });
+    }
+
+    // Latency regresses when it goes *up*, so compare with signs flipped
+    let baseline_latency = Stat {
+        mean: -baseline.latency_ms.mean,
+        stddev: baseline.latency_ms.stddev,
+    };
+    let current_latency = Stat {
+        mean: -current.latency_ms.mean,
+        stddev: current.latency_ms.stddev,
+    };
+    if is_significant_drop(
+        baseline_latency,
+        current_latency,
+        baseline.total_runs,
+        current.total_runs,
+    ) {
+        regressions.push(Regression {
+            metric: "avg latency (ms)",
+            baseline: baseline.latency_ms.mean,
+            current: current.latency_ms.mean,
+            delta: current.latency_ms.mean - baseline.latency_ms.mean,
+        });
+    }
+
+    regressions
+}
+
+fn extract_triples_from_jsonld(jsonld: &Value) -> HashSet<Triple> {
+    let mut triples = HashSet::new();
+
+    if let Some(obj) = jsonld.as_object() {
+        let subject = obj
+            .get("name")
+            .and_then(|v| v.as_str())
+            .unwrap_or("unknown")
+            .to_string();
+
+        for (key, value) in obj {
+            if key.starts_with('@') || key == "name" {
+                continue;
+            }
+
+            match value {
+                Value::String(s) => {
+                    triples.insert(Triple {
+                        subject: subject.clone(),
+                        predicate: normalize_predicate(key),
+                        object: s.clone(),
+                    });
+                }
+                Value::Object(_) => {
+                    if let Some(nested_name) = value.get("name").and_then(|v| v.as_str()) {
+                        triples.insert(Triple {
+                            subject: subject.clone(),
+                            predicate: normalize_predicate(key),
+                            object: nested_name.to_string(),
+                        });
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    triples
+}
+
+/// Very small CLI argument parser: `<workload.json> [--out <path>] [--baseline <path>]`
+struct BenchArgs {
+    workload_path: String,
+    out_path: String,
+    baseline_path: Option<String>,
+}
+
+fn parse_args() -> BenchArgs {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let workload_path = args
+        .first()
+        .cloned()
+        .unwrap_or_else(|| "workload.json".to_string());
+
+    let mut out_path = "bench_results.json".to_string();
+    let mut baseline_path = None;
+
+    let mut i = 1;
+    while i < args.len() {
+        match args[i].as_str() {
+            "--out" => {
+                if let Some(v) = args.get(i + 1) {
+                    out_path = v.clone();
+                    i += 1;
+                }
+            }
+            "--baseline" => {
+                if let Some(v) = args.get(i + 1) {
+                    baseline_path = Some(v.clone());
+                    i += 1;
+                }
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+
+    BenchArgs {
+        workload_path,
+        out_path,
+        baseline_path,
+    }
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    dotenvy::dotenv().ok();
+
+    let args = parse_args();
+
+    println!("📂 Loading workload from: {}", args.workload_path);
+    let workload_contents = fs::read_to_string(&args.workload_path)?;
+    let workload: Workload = serde_json::from_str(&workload_contents)?;
+
+    println!(
+        "▶ Workload '{}' - model={}, repeats={}",
+        workload.name, workload.model, workload.repeats
+    );
+
+    let contents = fs::read_to_string(&workload.dataset_path)?;
+    let test_cases: Vec<TestCase> = serde_json::from_str(&contents)?;
+    println!(
+        "✓ Loaded {} test cases from {}",
+        test_cases.len(),
+        workload.dataset_path
+    );
+
+    let config = ExtractionConfig::from_env()?.with_model(workload.model.clone());
+    let extractor = GenAiExtractor::new(config.clone())?;
+
+    let mut runs = Vec::new();
+
+    for repeat in 0..workload.repeats {
+        for test_case in &test_cases {
+            let start = Instant::now();
+            let result = extractor.extract(&test_case.raw_text).await;
+            let latency_ms = start.elapsed().as_secs_f64() * 1000.0;
+
+            let (metrics, retries_exhausted) = match result {
+                Ok(doc) => {
+                    let predicted = extract_triples_from_jsonld(&doc.data);
+                    let expected: HashSet<Triple> =
+                        test_case.expected_triples.iter().cloned().collect();
+                    (EvaluationMetrics::new(&predicted, &expected), false)
+                }
+                Err(e) => {
+                    eprintln!("  ✗ {} (repeat {repeat}): {e}", test_case.id);
+                    (
+                        EvaluationMetrics {
+                            precision: 0.0,
+                            recall: 0.0,
+                            f1_score: 0.0,
+                        },
+                        true,
+                    )
+                }
+            };
+
+            println!(
+                "  [{repeat}] {} - F1 {:.1}% in {:.0}ms",
+                test_case.id,
+                metrics.f1_score * 100.0,
+                latency_ms
+            );
+
+            runs.push(CaseRun {
+                case_id: test_case.id.clone(),
+                repeat,
+                latency_ms,
+                retries_exhausted,
+                precision: metrics.precision,
+                recall: metrics.recall,
+                f1_score: metrics.f1_score,
+            });
+        }
+    }
+
+    let current = BenchResult::from_runs(&workload, runs);
+
+    println!("\n📊 Summary ({} runs):", current.total_runs);
+    println!(
+        "  F1:       {:.2}% ± {:.2}",
+        current.f1.mean * 100.0,
+        current.f1.stddev * 100.0
+    );
+    println!(
+        "  Latency:  {:.0}ms ± {:.0}ms",
+        current.latency_ms.mean, current.latency_ms.stddev
+    );
+
+    let results_json = serde_json::to_string_pretty(&current)?;
+    fs::write(&args.out_path, &results_json)?;
+    println!("\n💾 Results written to {}", args.out_path);
+
+    if let Some(baseline_path) = &args.baseline_path {
+        let baseline_contents = fs::read_to_string(baseline_path)?;
+        let baseline: BenchResult = serde_json::from_str(&baseline_contents)?;
+
+        let regressions = diff_against_baseline(&baseline, &current);
+
+        println!("\n🔍 Baseline diff ({baseline_path}):");
+        if regressions.is_empty() {
+            println!("  ✓ No statistically meaningful regressions detected");
+        } else {
+            println!("  ✗ {} regression(s) detected:", regressions.len());
+            for r in &regressions {
+                println!(
+                    "    {} : {:.3} -> {:.3} ({:+.3})",
+                    r.metric, r.baseline, r.current, r.delta
+                );
+            }
+            std::process::exit(1);
+        }
+    }
+
+    Ok(())
+}
@@ -33,11 +33,17 @@
 
 use serde::Deserialize;
 use serde_json::Value;
+use std::collections::HashMap;
 use std::collections::HashSet;
 use std::env;
 use std::fs;
+use text_to_rdf::embedding::{best_fuzzy_alias_match, levenshtein_distance, normalize_for_fuzzy_match};
 use text_to_rdf::normalize::normalize_predicate;
-use text_to_rdf::{ExtractionConfig, GenAiExtractor};
+use text_to_rdf::triple_filter::{FilterAction, FilterRule, TripleFilter, TriplePredicate};
+use text_to_rdf::{
+    self_consistency_confidence, Atom, ConfidentTriple, DocumentResult, EntailedTriple, Evaluator,
+    ExtractionConfig, GenAiExtractor, Reasoner, Rule,
+};
 
 /// A document from the `DocRED` dataset
 #[derive(Debug, Deserialize)]
@@ -96,8 +102,7 @@ struct Relation {
     #[serde(rename = "r")]
     relation_type: String,
 
-    /// Evidence sentence IDs
-    #[allow(dead_code)]
+    /// Evidence sentence IDs (flattened index into `doc.sentences`)
     evidence: Vec<usize>,
 }
 
@@ -124,6 +129,11 @@ struct DocumentMetrics {
     total_entities: usize,
     #[allow(dead_code)]
     cross_sentence_relations: usize,
+
+    /// Evidence-localization precision/recall (see [`evidence_precision_recall`]),
+    /// `None` until [`Self::with_evidence_metrics`] attaches it
+    evidence_precision: Option<f64>,
+    evidence_recall: Option<f64>,
 }
 
 impl DocumentMetrics {
@@ -165,8 +175,82 @@ impl DocumentMetrics {
             total_sentences,
             total_entities,
             cross_sentence_relations: 0, // Will be calculated separately
+            evidence_precision: None,
+            evidence_recall: None,
         }
     }
+
+    /// Coreference- and alias-aware fuzzy variant of [`DocumentMetrics::new`]
+    ///
+    /// Instead of exact `HashSet` intersection, matches predicted triples
+    /// against `expected` via [`fuzzy_match_triples`]: a predicted
+    /// triple/gold triple pair counts as a match when predicates are
+    /// identical and both subject and object land within the edit-distance
+    /// budget of some mention in the gold entity's coreference cluster. This
+    /// gives a fairer score for document-level extraction, where "united
+    /// states" vs "the United States" or a pronoun-resolved mention
+    /// shouldn't count as a miss.
+    fn new_fuzzy(
+        predicted: &HashSet<Triple>,
+        expected: &[ExpectedTripleWithAliases],
+        total_sentences: usize,
+        total_entities: usize,
+    ) -> Self {
+        let predicted: Vec<Triple> = predicted.iter().cloned().collect();
+        let matches = fuzzy_match_triples(&predicted, expected);
+
+        let true_positives = matches.len();
+        let false_positives = predicted.len() - true_positives;
+        let false_negatives = expected.len() - true_positives;
+
+        let precision = if predicted.is_empty() {
+            0.0
+        } else {
+            true_positives as f64 / predicted.len() as f64
+        };
+
+        let recall = if expected.is_empty() {
+            0.0
+        } else {
+            true_positives as f64 / expected.len() as f64
+        };
+
+        let f1_score = if precision + recall == 0.0 {
+            0.0
+        } else {
+            2.0 * (precision * recall) / (precision + recall)
+        };
+
+        Self {
+            precision,
+            recall,
+            f1_score,
+            true_positives,
+            false_positives,
+            false_negatives,
+            total_sentences,
+            total_entities,
+            cross_sentence_relations: 0,
+            evidence_precision: None,
+            evidence_recall: None,
+        }
+    }
+
+    /// Attach evidence-localization precision/recall (see
+    /// [`evidence_precision_recall`]) computed against `gold_evidence`
+    #[must_use]
+    fn with_evidence_metrics(
+        mut self,
+        predicted: &HashSet<Triple>,
+        expected: &HashSet<Triple>,
+        gold_evidence: &HashMap<Triple, HashSet<usize>>,
+        doc: &DocREDDocument,
+    ) -> Self {
+        let (precision, recall) = evidence_precision_recall(predicted, expected, gold_evidence, doc);
+        self.evidence_precision = Some(precision);
+        self.evidence_recall = Some(recall);
+        self
+    }
 }
 
 impl DocREDDocument {
@@ -182,18 +266,31 @@ impl DocREDDocument {
         paragraphs.join("\n\n")
     }
 
-    /// Get the canonical name for an entity (first mention)
-    fn get_entity_name(&self, entity_idx: usize) -> Option<String> {
+    /// Get every known surface form (coreference mentions) for an entity
+    ///
+    /// `DocRED`'s `vertexSet` records each place an entity is mentioned, so a
+    /// pronoun-resolved or alias mention ("the company", "IBM") sits
+    /// alongside the canonical name in the same cluster.
+    fn get_entity_name(&self, entity_idx: usize) -> Option<Vec<String>> {
         self.entities
             .get(entity_idx)
-            .and_then(|mentions| mentions.first())
-            .map(|mention| mention.name.clone())
+            .map(|mentions| mentions.iter().map(|m| m.name.clone()).collect())
     }
 
     /// Count total sentences in document
     fn sentence_count(&self) -> usize {
         self.sentences.iter().map(std::vec::Vec::len).sum()
     }
+
+    /// Flattened (paragraph-unaware) sentence lookup, matching the indexing
+    /// `EntityMention::sent_id` and gold relation `evidence` IDs use
+    fn sentence_at(&self, sentence_id: usize) -> Option<&str> {
+        self.sentences
+            .iter()
+            .flatten()
+            .nth(sentence_id)
+            .map(String::as_str)
+    }
 }
 
 /// Map Wikidata property IDs to Schema.org properties
@@ -222,23 +319,158 @@ fn map_wikidata_to_schema(property_id: &str) -> Option<&'static str> {
 }
 
 /// Convert `DocRED` relations to normalized triples
+///
+/// Uses each entity's first recorded mention as the canonical subject/object
+/// name. See [`docred_to_triples_with_aliases`] for a variant that keeps the
+/// full coreference cluster around for fuzzy matching.
 fn docred_to_triples(doc: &DocREDDocument) -> HashSet<Triple> {
     let mut triples = HashSet::new();
 
     for relation in &doc.labels {
-        if let (Some(subject), Some(object)) = (
+        if let (Some(subject_mentions), Some(object_mentions)) = (
             doc.get_entity_name(relation.head),
             doc.get_entity_name(relation.tail),
         ) {
-            if let Some(schema_property) = map_wikidata_to_schema(&relation.relation_type) {
-                // Normalize: lowercase and replace spaces with underscores
-                // Preserve trailing punctuation like periods in "Inc.", "Ltd.", etc.
-                let normalized_subject = subject.to_lowercase().replace(' ', "_");
+            if let (Some(subject), Some(object)) =
+                (subject_mentions.first(), object_mentions.first())
+            {
+                if let Some(schema_property) = map_wikidata_to_schema(&relation.relation_type) {
+                    // Normalize: lowercase and replace spaces with underscores
+                    // Preserve trailing punctuation like periods in "Inc.", "Ltd.", etc.
+                    let normalized_subject = subject.to_lowercase().replace(' ', "_");
 
-                triples.insert(Triple {
-                    subject: normalized_subject,
+                    triples.insert(Triple {
+                        subject: normalized_subject,
+                        predicate: normalize_predicate(schema_property),
+                        object: object.clone(),
+                    });
+                }
+            }
+        }
+    }
+
+    triples
+}
+
+/// Gold evidence sentence IDs for each expected triple, keyed the same way
+/// [`docred_to_triples`] builds its triples
+fn docred_evidence_by_triple(doc: &DocREDDocument) -> HashMap<Triple, HashSet<usize>> {
+    let mut evidence: HashMap<Triple, HashSet<usize>> = HashMap::new();
+
+    for relation in &doc.labels {
+        if let (Some(subject_mentions), Some(object_mentions)) = (
+            doc.get_entity_name(relation.head),
+            doc.get_entity_name(relation.tail),
+        ) {
+            if let (Some(subject), Some(object)) =
+                (subject_mentions.first(), object_mentions.first())
+            {
+                if let Some(schema_property) = map_wikidata_to_schema(&relation.relation_type) {
+                    let normalized_subject = subject.to_lowercase().replace(' ', "_");
+                    let triple = Triple {
+                        subject: normalized_subject,
+                        predicate: normalize_predicate(schema_property),
+                        object: object.clone(),
+                    };
+                    evidence
+                        .entry(triple)
+                        .or_default()
+                        .extend(relation.evidence.iter().copied());
+                }
+            }
+        }
+    }
+
+    evidence
+}
+
+/// Find which sentences plausibly support `triple`, by substring-matching
+/// its subject/object against every sentence in `doc`
+///
+/// This is a heuristic stand-in for the per-property evidence spans
+/// [`text_to_rdf::types::Provenance`] tracks for live extractions - `DocRED`'s
+/// gold relations don't carry character offsets, only sentence IDs, so the
+/// comparison has to work at sentence granularity.
+fn locate_evidence_sentences(triple: &Triple, doc: &DocREDDocument) -> HashSet<usize> {
+    let subject_needle = triple.subject.replace('_', " ").to_lowercase();
+    let object_needle = triple.object.to_lowercase();
+
+    let mut matches = HashSet::new();
+    let mut idx = 0;
+    while let Some(sentence) = doc.sentence_at(idx) {
+        let lower = sentence.to_lowercase();
+        if lower.contains(&subject_needle) || lower.contains(&object_needle) {
+            matches.insert(idx);
+        }
+        idx += 1;
+    }
+    matches
+}
+
+/// Micro-averaged evidence-localization precision/recall: for every
+/// predicted triple that's also expected, compare the sentences
+/// [`locate_evidence_sentences`] derives against `gold_evidence`'s sentence
+/// IDs for that triple, summing overlap/predicted/gold counts across all
+/// true positives before dividing (micro, not macro, averaging)
+fn evidence_precision_recall(
+    predicted: &HashSet<Triple>,
+    expected: &HashSet<Triple>,
+    gold_evidence: &HashMap<Triple, HashSet<usize>>,
+    doc: &DocREDDocument,
+) -> (f64, f64) {
+    let mut total_predicted = 0usize;
+    let mut total_gold = 0usize;
+    let mut total_overlap = 0usize;
+
+    for triple in predicted.intersection(expected) {
+        let Some(gold) = gold_evidence.get(triple) else {
+            continue;
+        };
+        let predicted_evidence = locate_evidence_sentences(triple, doc);
+        total_overlap += predicted_evidence.intersection(gold).count();
+        total_predicted += predicted_evidence.len();
+        total_gold += gold.len();
+    }
+
+    let precision = if total_predicted == 0 {
+        0.0
+    } else {
+        total_overlap as f64 / total_predicted as f64
+    };
+    let recall = if total_gold == 0 {
+        0.0
+    } else {
+        total_overlap as f64 / total_gold as f64
+    };
+
+    (precision, recall)
+}
+
+/// A gold triple paired with every coreferent surface form `DocRED` records
+/// for its subject and object, so fuzzy matching can accept any mention in
+/// the cluster rather than only the first-listed name
+#[derive(Debug, Clone)]
+struct ExpectedTripleWithAliases {
+    predicate: String,
+    subject_aliases: Vec<String>,
+    object_aliases: Vec<String>,
+}
+
+/// Convert `DocRED` relations to gold triples, keeping each entity's full
+/// coreference cluster instead of collapsing it to a single canonical name
+fn docred_to_triples_with_aliases(doc: &DocREDDocument) -> Vec<ExpectedTripleWithAliases> {
+    let mut triples = Vec::new();
+
+    for relation in &doc.labels {
+        if let (Some(subject_aliases), Some(object_aliases)) = (
+            doc.get_entity_name(relation.head),
+            doc.get_entity_name(relation.tail),
+        ) {
+            if let Some(schema_property) = map_wikidata_to_schema(&relation.relation_type) {
+                triples.push(ExpectedTripleWithAliases {
                     predicate: normalize_predicate(schema_property),
-                    object,
+                    subject_aliases,
+                    object_aliases,
                 });
             }
         }
@@ -247,6 +479,165 @@ fn docred_to_triples(doc: &DocREDDocument) -> HashSet<Triple> {
     triples
 }
 
+/// Greedily align predicted triples to gold triples: predicates must match
+/// exactly, while subject and object are accepted if they're within the
+/// Levenshtein budget of *any* surface form in the gold entity's
+/// coreference cluster. Each gold triple is consumed at most once, with the
+/// best (lowest combined edit distance) candidate pairs matched first.
+fn fuzzy_match_triples(
+    predicted: &[Triple],
+    expected: &[ExpectedTripleWithAliases],
+) -> Vec<(usize, usize)> {
+    let mut candidates = Vec::new();
+
+    for (pi, pred) in predicted.iter().enumerate() {
+        for (ei, exp) in expected.iter().enumerate() {
+            if pred.predicate != exp.predicate {
+                continue;
+            }
+
+            if let (Some(subject_index), Some(object_index)) = (
+                best_fuzzy_alias_match(&pred.subject, &exp.subject_aliases),
+                best_fuzzy_alias_match(&pred.object, &exp.object_aliases),
+            ) {
+                let distance = |name: &str, alias: &str| {
+                    levenshtein_distance(&normalize_for_fuzzy_match(name), &normalize_for_fuzzy_match(alias))
+                };
+                let subject_distance = distance(&pred.subject, &exp.subject_aliases[subject_index]);
+                let object_distance = distance(&pred.object, &exp.object_aliases[object_index]);
+                candidates.push((pi, ei, subject_distance + object_distance));
+            }
+        }
+    }
+
+    candidates.sort_by_key(|(_, _, score)| *score);
+
+    let mut used_predicted = HashSet::new();
+    let mut used_expected = HashSet::new();
+    let mut matches = Vec::new();
+
+    for (pi, ei, _) in candidates {
+        if used_predicted.contains(&pi) || used_expected.contains(&ei) {
+            continue;
+        }
+        used_predicted.insert(pi);
+        used_expected.insert(ei);
+        matches.push((pi, ei));
+    }
+
+    matches
+}
+
+/// Inference rules for deriving cross-sentence/cross-paragraph relations
+/// that a single extracted triple can't capture on its own, via
+/// [`Reasoner::saturate_rules`]
+fn docred_inference_rules() -> Vec<Rule> {
+    vec![
+        // containedInPlace(?x, ?z) :- containedInPlace(?x, ?y), containedInPlace(?y, ?z)
+        Rule::new(
+            Atom::new("?x", "containedInPlace", "?z"),
+            vec![
+                Atom::new("?x", "containedInPlace", "?y"),
+                Atom::new("?y", "containedInPlace", "?z"),
+            ],
+        ),
+        // addressCountry(?x, ?c) :- location(?x, ?y), addressCountry(?y, ?c)
+        Rule::new(
+            Atom::new("?x", "addressCountry", "?c"),
+            vec![
+                Atom::new("?x", "location", "?y"),
+                Atom::new("?y", "addressCountry", "?c"),
+            ],
+        ),
+    ]
+}
+
+/// A single point on a precision-recall curve, scored at a given confidence threshold
+#[derive(Debug, Clone, Copy)]
+struct PrPoint {
+    threshold: f64,
+    precision: f64,
+    recall: f64,
+    f1: f64,
+}
+
+/// Sweep every distinct confidence value in `predicted` as a threshold,
+/// keeping only triples at or above it, and score the remainder against
+/// `expected` at each point - tracing the full precision/recall curve
+/// instead of a single fixed operating point.
+fn precision_recall_curve(predicted: &[ConfidentTriple], expected: &HashSet<Triple>) -> Vec<PrPoint> {
+    let mut thresholds: Vec<f64> = predicted.iter().map(|t| t.confidence).collect();
+    thresholds.push(0.0);
+    thresholds.sort_by(|a, b| b.partial_cmp(a).unwrap());
+    thresholds.dedup_by(|a, b| (*a - *b).abs() < f64::EPSILON);
+
+    thresholds
+        .into_iter()
+        .map(|threshold| {
+            let kept: HashSet<Triple> = predicted
+                .iter()
+                .filter(|t| t.confidence >= threshold)
+                .map(|t| Triple {
+                    subject: t.subject.clone(),
+                    predicate: t.predicate.clone(),
+                    object: t.object.clone(),
+                })
+                .collect();
+            let metrics = DocumentMetrics::new(&kept, expected, 0, 0);
+            PrPoint {
+                threshold,
+                precision: metrics.precision,
+                recall: metrics.recall,
+                f1: metrics.f1_score,
+            }
+        })
+        .collect()
+}
+
+/// The operating point on `curve` with the highest F1 score
+fn best_f1_operating_point(curve: &[PrPoint]) -> Option<PrPoint> {
+    curve
+        .iter()
+        .copied()
+        .max_by(|a, b| a.f1.partial_cmp(&b.f1).unwrap())
+}
+
+/// Area under the precision-recall curve, via the trapezoidal rule over
+/// recall-sorted points - a threshold-free summary of how well confidence
+/// ranks correct triples above incorrect ones
+fn average_precision(curve: &[PrPoint]) -> f64 {
+    let mut points: Vec<(f64, f64)> = curve.iter().map(|p| (p.recall, p.precision)).collect();
+    points.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+    points.dedup_by(|a, b| (a.0 - b.0).abs() < f64::EPSILON);
+
+    points
+        .windows(2)
+        .map(|w| {
+            let (r0, p0) = w[0];
+            let (r1, p1) = w[1];
+            (r1 - r0) * (p0 + p1) / 2.0
+        })
+        .sum()
+}
+
+fn to_entailed_triples(triples: &HashSet<Triple>) -> HashSet<EntailedTriple> {
+    triples
+        .iter()
+        .map(|t| EntailedTriple::new(t.subject.clone(), t.predicate.clone(), t.object.clone()))
+        .collect()
+}
+
+fn from_entailed_triples(triples: HashSet<EntailedTriple>) -> HashSet<Triple> {
+    triples
+        .into_iter()
+        .map(|t| Triple {
+            subject: t.subject,
+            predicate: t.predicate,
+            object: t.object,
+        })
+        .collect()
+}
+
 /// Extract triples from JSON-LD output
 fn extract_triples_from_jsonld(jsonld: &Value) -> HashSet<Triple> {
     let mut triples = HashSet::new();
@@ -326,33 +717,55 @@ fn extract_triples_from_jsonld(jsonld: &Value) -> HashSet<Triple> {
     triples
 }
 
-/// Filter out likely incorrect triples based on heuristics
-fn filter_likely_incorrect_triples(triples: HashSet<Triple>) -> HashSet<Triple> {
-    triples
-        .into_iter()
-        .filter(|triple| {
-            let predicate = &triple.predicate;
-
-            // Only filter out clearly wrong properties that shouldn't exist
-            // Be very conservative - only remove obvious mistakes
-
+/// Declarative rules for dropping likely-incorrect triples, expressed via
+/// `text_to_rdf`'s [`TripleFilter`] DSL instead of hardcoded `if` statements.
+/// Only the clearly-wrong properties below are removed - stay conservative.
+fn docred_triple_filter() -> TripleFilter {
+    TripleFilter::new(
+        vec![
             // Filter founder/funder - we expect worksFor instead
-            if predicate.contains("founder") || predicate.contains("funder") {
-                return false;
-            }
-
+            FilterRule {
+                when: TriplePredicate::AnyOf(vec![
+                    TriplePredicate::PredicateContains("founder".to_string()),
+                    TriplePredicate::PredicateContains("funder".to_string()),
+                ]),
+                action: FilterAction::Drop,
+            },
             // Filter currentceo/ceo - not in our Schema.org mapping
-            if predicate.contains("currentceo") || predicate == "ceo" {
-                return false;
-            }
-
+            FilterRule {
+                when: TriplePredicate::AnyOf(vec![
+                    TriplePredicate::PredicateContains("currentceo".to_string()),
+                    TriplePredicate::PredicateEquals("ceo".to_string()),
+                ]),
+                action: FilterAction::Drop,
+            },
             // Filter alumni (reverse) - we expect alumniOf instead
-            if predicate.contains("alumni") && !predicate.contains("alumniof") {
-                return false;
-            }
+            FilterRule {
+                when: TriplePredicate::AllOf(vec![
+                    TriplePredicate::PredicateContains("alumni".to_string()),
+                    TriplePredicate::Not(Box::new(TriplePredicate::PredicateContains(
+                        "alumniof".to_string(),
+                    ))),
+                ]),
+                action: FilterAction::Drop,
+            },
+        ],
+        FilterAction::Keep,
+    )
+}
 
-            // Everything else passes through
-            true
+/// Filter out likely incorrect triples using [`docred_triple_filter`]
+fn filter_likely_incorrect_triples(triples: HashSet<Triple>) -> HashSet<Triple> {
+    let filter = docred_triple_filter();
+    triples
+        .into_iter()
+        .filter(|triple| {
+            let core_triple = EntailedTriple::new(
+                triple.subject.clone(),
+                triple.predicate.clone(),
+                triple.object.clone(),
+            );
+            filter.keep(&core_triple)
         })
         .collect()
 }
@@ -425,6 +838,16 @@ fn print_evaluation_report(
     );
     println!("  F1 Score:         {:.2}%", metrics.f1_score * 100.0);
 
+    if let (Some(evidence_precision), Some(evidence_recall)) =
+        (metrics.evidence_precision, metrics.evidence_recall)
+    {
+        println!(
+            "  Evidence P/R:     {:.2}% / {:.2}%",
+            evidence_precision * 100.0,
+            evidence_recall * 100.0
+        );
+    }
+
     if !predicted
         .intersection(expected)
         .collect::<Vec<_>>()
@@ -546,6 +969,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Evaluate each document
     let mut all_metrics = Vec::new();
+    let mut document_results = Vec::new();
 
     for (_idx, doc) in documents.iter().enumerate().take(3) {
         // Limit to 3 for demo
@@ -581,20 +1005,87 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         // Apply heuristic filtering to reduce false positives
         let predicted_triples = filter_likely_incorrect_triples(predicted_triples);
 
+        // Derive cross-sentence relations (e.g. transitive containedInPlace,
+        // or addressCountry chained through an intermediate location) that
+        // the extractor only stated indirectly, to recover recall on
+        // document-level, inter-paragraph relations.
+        let predicted_triples = from_entailed_triples(Reasoner::saturate_rules(
+            to_entailed_triples(&predicted_triples),
+            &docred_inference_rules(),
+        ));
+
         let expected_triples = docred_to_triples(doc);
 
+        // Self-consistency: resample the document-level extraction a few
+        // times and derive per-triple confidence from how often each triple
+        // recurs, then sweep a confidence threshold to find the best-F1
+        // operating point and the area under the precision-recall curve -
+        // a calibration signal a single extraction can't provide.
+        const SELF_CONSISTENCY_SAMPLES: usize = 3;
+        match extractor.extract_samples(&text, SELF_CONSISTENCY_SAMPLES).await {
+            Ok(sample_docs) => {
+                let sample_triple_sets: Vec<HashSet<EntailedTriple>> = sample_docs
+                    .iter()
+                    .map(|sample| {
+                        to_entailed_triples(&filter_likely_incorrect_triples(
+                            extract_triples_from_jsonld(&sample.data),
+                        ))
+                    })
+                    .collect();
+                let confident_triples = self_consistency_confidence(&sample_triple_sets);
+                let curve = precision_recall_curve(&confident_triples, &expected_triples);
+
+                if let Some(best) = best_f1_operating_point(&curve) {
+                    println!(
+                        "\n🎲 Self-consistency ({SELF_CONSISTENCY_SAMPLES} samples): best F1 {:.2}% at confidence ≥ {:.2} (P {:.2}%, R {:.2}%), AUC-PR {:.3}",
+                        best.f1 * 100.0,
+                        best.threshold,
+                        best.precision * 100.0,
+                        best.recall * 100.0,
+                        average_precision(&curve)
+                    );
+                }
+            }
+            Err(e) => {
+                eprintln!("  ⚠️  Self-consistency sampling failed: {e}");
+            }
+        }
+
         // Calculate metrics
+        let gold_evidence = docred_evidence_by_triple(doc);
         let metrics = DocumentMetrics::new(
             &predicted_triples,
             &expected_triples,
             doc.sentence_count(),
             doc.entities.len(),
-        );
+        )
+        .with_evidence_metrics(&predicted_triples, &expected_triples, &gold_evidence, doc);
 
         all_metrics.push((metrics.precision, metrics.recall, metrics.f1_score));
+        document_results.push(DocumentResult::new(
+            to_entailed_triples(&predicted_triples),
+            to_entailed_triples(&expected_triples),
+        ));
 
         // Print report
         print_evaluation_report(doc, &predicted_triples, &expected_triples, &metrics);
+
+        // Coreference- and alias-aware fuzzy matching: accept any DocRED
+        // mention of an entity and tolerate small spelling differences,
+        // which the strict HashSet intersection above counts as total misses
+        let expected_triples_with_aliases = docred_to_triples_with_aliases(doc);
+        let fuzzy_metrics = DocumentMetrics::new_fuzzy(
+            &predicted_triples,
+            &expected_triples_with_aliases,
+            doc.sentence_count(),
+            doc.entities.len(),
+        );
+        println!(
+            "\n🔎 Fuzzy match (coreference-aware, edit-distance budget): F1 {:.2}% | P {:.2}% | R {:.2}%",
+            fuzzy_metrics.f1_score * 100.0,
+            fuzzy_metrics.precision * 100.0,
+            fuzzy_metrics.recall * 100.0
+        );
     }
 
     // Print aggregate stats
@@ -631,6 +1122,49 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         println!("\n═══════════════════════════════════════════════════════════════\n");
     }
 
+    // Micro/macro-averaged metrics, a per-predicate breakdown, and a
+    // bootstrap confidence interval on micro F1 - a sample of 3 documents is
+    // too small for a single point estimate to mean much on its own.
+    if !document_results.is_empty() {
+        let report = Evaluator::new().evaluate(&document_results);
+
+        println!("═══════════════════════════════════════════════════════════════");
+        println!("              📊 CORPUS-LEVEL METRICS REPORT");
+        println!("═══════════════════════════════════════════════════════════════");
+        println!(
+            "\n  Micro F1:  {:.2}% (P {:.2}%, R {:.2}%), 95% CI [{:.2}%, {:.2}%]",
+            report.micro.f1 * 100.0,
+            report.micro.precision * 100.0,
+            report.micro.recall * 100.0,
+            report.micro_f1_ci.lower * 100.0,
+            report.micro_f1_ci.upper * 100.0
+        );
+        println!(
+            "  Macro F1:  {:.2}% (P {:.2}%, R {:.2}%)",
+            report.macro_averaged.f1 * 100.0,
+            report.macro_averaged.precision * 100.0,
+            report.macro_averaged.recall * 100.0
+        );
+
+        let mut by_predicate: Vec<_> = report.per_predicate.iter().collect();
+        by_predicate.sort_by(|a, b| b.1.support.cmp(&a.1.support));
+
+        println!("\n  Per-predicate breakdown:");
+        println!("  {:<20} {:>8} {:>8} {:>8} {:>8}", "predicate", "P", "R", "F1", "support");
+        for (predicate, breakdown) in by_predicate {
+            println!(
+                "  {:<20} {:>7.1}% {:>7.1}% {:>7.1}% {:>8}",
+                predicate,
+                breakdown.metrics.precision * 100.0,
+                breakdown.metrics.recall * 100.0,
+                breakdown.metrics.f1 * 100.0,
+                breakdown.support
+            );
+        }
+
+        println!("\n═══════════════════════════════════════════════════════════════\n");
+    }
+
     println!("✓ Document-level evaluation complete!\n");
 
     Ok(())
@@ -49,6 +49,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 }
                 Ok(None) => {
                     println!("✗ No entity link found (confidence too low or service unavailable)");
+                    result.mint_canonical_id();
+                    println!("  Falling back to content-addressed id: {}", result.get_id().unwrap_or_default());
                 }
                 Err(e) => {
                     println!("✗ Entity linking failed: {}", e);
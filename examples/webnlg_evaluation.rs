@@ -28,12 +28,18 @@
 
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::env;
 use std::fs;
+use text_to_rdf::confidence::{resolve_disjunctions, ConfidentTriple};
+use text_to_rdf::embedding::{greedy_max_weight_match, HashingEmbedder};
 use text_to_rdf::normalize::normalize_predicate;
 use text_to_rdf::{ExtractionConfig, GenAiExtractor, RdfExtractor};
 
+/// Similarity threshold above which a predicted/gold triple pair counts as a
+/// fuzzy match rather than a total miss
+const FUZZY_MATCH_THRESHOLD: f32 = 0.5;
+
 /// A test case from the `WebNLG` dataset
 #[derive(Debug, Deserialize)]
 struct TestCase {
@@ -53,7 +59,7 @@ struct Triple {
 }
 
 /// Evaluation metrics for comparing predicted vs expected triples
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 struct EvaluationMetrics {
     precision: f64,
     recall: f64,
@@ -61,6 +67,11 @@ struct EvaluationMetrics {
     true_positives: usize,
     false_positives: usize,
     false_negatives: usize,
+    /// Calibration-aware variant of F1: a matched triple contributes its
+    /// predicted confidence instead of a flat 1.0 (see `soft_f1`)
+    soft_precision: f64,
+    soft_recall: f64,
+    soft_f1: f64,
 }
 
 impl EvaluationMetrics {
@@ -87,6 +98,13 @@ impl EvaluationMetrics {
             2.0 * (precision * recall) / (precision + recall)
         };
 
+        // Without per-triple confidences the soft metrics degenerate to the
+        // strict ones (every predicted triple counts with weight 1.0)
+        let confidences: HashMap<&Triple, f64> =
+            predicted.iter().map(|t| (t, 1.0)).collect();
+        let (soft_precision, soft_recall, soft_f1) =
+            soft_f1(predicted, expected, &confidences);
+
         Self {
             precision,
             recall,
@@ -94,10 +112,120 @@ impl EvaluationMetrics {
             true_positives,
             false_positives,
             false_negatives,
+            soft_precision,
+            soft_recall,
+            soft_f1,
         }
     }
 }
 
+/// Confidence-weighted precision/recall/F1
+///
+/// A matched triple contributes its predicted confidence weight rather than
+/// a flat 1.0: `precision = sum(confidence of matched) / sum(confidence of all predicted)`,
+/// and `recall` weights each matched gold triple by its predicted confidence.
+/// This rewards calibrated extractors - a low-confidence correct guess scores
+/// less than a confident one - alongside the existing exact-set metrics.
+fn soft_f1(
+    predicted: &HashSet<Triple>,
+    expected: &HashSet<Triple>,
+    confidences: &HashMap<&Triple, f64>,
+) -> (f64, f64, f64) {
+    let total_predicted_weight: f64 = predicted.iter().map(|t| confidences.get(t).copied().unwrap_or(1.0)).sum();
+    let matched_weight: f64 = predicted
+        .intersection(expected)
+        .map(|t| confidences.get(t).copied().unwrap_or(1.0))
+        .sum();
+
+    let soft_precision = if total_predicted_weight == 0.0 {
+        0.0
+    } else {
+        matched_weight / total_predicted_weight
+    };
+
+    let soft_recall = if expected.is_empty() {
+        0.0
+    } else {
+        matched_weight / expected.len() as f64
+    };
+
+    let soft_f1 = if soft_precision + soft_recall == 0.0 {
+        0.0
+    } else {
+        2.0 * (soft_precision * soft_recall) / (soft_precision + soft_recall)
+    };
+
+    (soft_precision, soft_recall, soft_f1)
+}
+
+/// A predicted/gold triple pair matched by embedding similarity rather than
+/// an exact string match, along with the score that earned the match
+#[derive(Debug)]
+struct NearMiss {
+    predicted: Triple,
+    gold: Triple,
+    similarity: f32,
+}
+
+/// Fuzzy precision/recall/F1 via embedding-based bipartite matching
+///
+/// Flattens each triple to a single `"subject predicate object"` string,
+/// embeds it with `embedder`, and greedily aligns predicted triples to gold
+/// triples by cosine similarity above `FUZZY_MATCH_THRESHOLD`. This catches
+/// semantically-equivalent but surface-different triples (e.g. "UK" vs
+/// "United Kingdom") that the strict `HashSet` intersection misses entirely.
+fn fuzzy_metrics(
+    predicted: &HashSet<Triple>,
+    expected: &HashSet<Triple>,
+) -> (f64, f64, f64, Vec<NearMiss>) {
+    let predicted: Vec<Triple> = predicted.iter().cloned().collect();
+    let expected: Vec<Triple> = expected.iter().cloned().collect();
+
+    let predicted_strings: Vec<String> = predicted.iter().map(triple_to_string).collect();
+    let expected_strings: Vec<String> = expected.iter().map(triple_to_string).collect();
+
+    let embedder = HashingEmbedder::default();
+    let matches = greedy_max_weight_match(
+        &predicted_strings,
+        &expected_strings,
+        &embedder,
+        FUZZY_MATCH_THRESHOLD,
+    );
+
+    let near_misses: Vec<NearMiss> = matches
+        .iter()
+        .filter(|(pi, gi, _)| predicted[*pi] != expected[*gi])
+        .map(|(pi, gi, similarity)| NearMiss {
+            predicted: predicted[*pi].clone(),
+            gold: expected[*gi].clone(),
+            similarity: *similarity,
+        })
+        .collect();
+
+    let matched = matches.len();
+    let precision = if predicted.is_empty() {
+        0.0
+    } else {
+        matched as f64 / predicted.len() as f64
+    };
+    let recall = if expected.is_empty() {
+        0.0
+    } else {
+        matched as f64 / expected.len() as f64
+    };
+    let f1 = if precision + recall == 0.0 {
+        0.0
+    } else {
+        2.0 * (precision * recall) / (precision + recall)
+    };
+
+    (precision, recall, f1, near_misses)
+}
+
+fn triple_to_string(triple: &Triple) -> String {
+    format!("{} {} {}", triple.subject, triple.predicate, triple.object)
+}
+
 /// Aggregate metrics across multiple test cases
 #[derive(Debug)]
 struct AggregateMetrics {
@@ -105,6 +233,7 @@ struct AggregateMetrics {
     avg_precision: f64,
     avg_recall: f64,
     avg_f1_score: f64,
+    avg_soft_f1_score: f64,
     total_tp: usize,
     total_fp: usize,
     total_fn: usize,
@@ -116,6 +245,8 @@ impl AggregateMetrics {
         let avg_precision = metrics.iter().map(|m| m.precision).sum::<f64>() / total_cases as f64;
         let avg_recall = metrics.iter().map(|m| m.recall).sum::<f64>() / total_cases as f64;
         let avg_f1_score = metrics.iter().map(|m| m.f1_score).sum::<f64>() / total_cases as f64;
+        let avg_soft_f1_score =
+            metrics.iter().map(|m| m.soft_f1).sum::<f64>() / total_cases as f64;
         let total_tp = metrics.iter().map(|m| m.true_positives).sum();
         let total_fp = metrics.iter().map(|m| m.false_positives).sum();
         let total_fn = metrics.iter().map(|m| m.false_negatives).sum();
@@ -125,6 +256,7 @@ impl AggregateMetrics {
             avg_precision,
             avg_recall,
             avg_f1_score,
+            avg_soft_f1_score,
             total_tp,
             total_fp,
             total_fn,
@@ -175,6 +307,29 @@ fn extract_triples_from_jsonld(jsonld: &Value) -> HashSet<Triple> {
     triples
 }
 
+/// Resolve mutually-exclusive (subject, predicate) disjunctions among the
+/// predicted triples before scoring, so that e.g. two competing `birthPlace`
+/// guesses don't both count as false positives against a single gold triple.
+/// This example doesn't yet have per-triple confidences from the extractor,
+/// so every candidate starts at confidence 1.0 and the pass only breaks ties
+/// among functional predicates; it still must run before comparison per the
+/// evaluation contract.
+fn normalize_via_disjunctions(triples: HashSet<Triple>) -> HashSet<Triple> {
+    let confident: Vec<ConfidentTriple> = triples
+        .into_iter()
+        .map(|t| ConfidentTriple::new(t.subject, t.predicate, t.object, 1.0))
+        .collect();
+
+    resolve_disjunctions(confident, 0.0)
+        .into_iter()
+        .map(|t| Triple {
+            subject: t.subject,
+            predicate: t.predicate,
+            object: t.object,
+        })
+        .collect()
+}
+
 /// Check if Ollama is available on localhost:11434
 fn is_ollama_available() -> bool {
     use std::net::TcpStream;
@@ -227,6 +382,12 @@ fn print_test_case_report(
         expected.len()
     );
     println!("  F1 Score:         {:.2}%", metrics.f1_score * 100.0);
+    println!(
+        "  Soft F1 Score:    {:.2}% (P {:.2}% / R {:.2}%)",
+        metrics.soft_f1 * 100.0,
+        metrics.soft_precision * 100.0,
+        metrics.soft_recall * 100.0
+    );
 
     // True Positives
     let true_positives: Vec<_> = predicted.intersection(expected).collect();
@@ -268,6 +429,10 @@ fn print_summary_report(aggregate: &AggregateMetrics) {
     println!("  Average Precision:   {:.2}%", aggregate.avg_precision * 100.0);
     println!("  Average Recall:      {:.2}%", aggregate.avg_recall * 100.0);
     println!("  Average F1 Score:    {:.2}%", aggregate.avg_f1_score * 100.0);
+    println!(
+        "  Average Soft F1:     {:.2}%",
+        aggregate.avg_soft_f1_score * 100.0
+    );
 
     println!("\n🎯 Triple Statistics:");
     println!("  True Positives:      {}", aggregate.total_tp);
@@ -370,8 +535,9 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             println!("{}", serde_json::to_string_pretty(&result.data).unwrap_or_default());
         }
 
-        // Extract triples from predicted and expected JSON-LD
-        let predicted_triples = extract_triples_from_jsonld(&result.data);
+        // Extract triples from predicted and expected JSON-LD, resolving
+        // mutually-exclusive disjunctions before scoring
+        let predicted_triples = normalize_via_disjunctions(extract_triples_from_jsonld(&result.data));
         let expected_triples: HashSet<Triple> = test_case.expected_triples.iter().cloned().collect();
 
         // Calculate metrics
@@ -385,17 +551,37 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         );
 
         // Store for aggregate report
-        all_metrics.push(EvaluationMetrics {
-            precision: metrics.precision,
-            recall: metrics.recall,
-            f1_score: metrics.f1_score,
-            true_positives: metrics.true_positives,
-            false_positives: metrics.false_positives,
-            false_negatives: metrics.false_negatives,
-        });
+        all_metrics.push(metrics.clone());
 
         // Print detailed report for this test case
         print_test_case_report(test_case, &predicted_triples, &expected_triples, &metrics);
+
+        // Embedding-based fuzzy matching: how much of the gap between strict
+        // precision/recall and 100% is surface-form variation vs real misses?
+        let (fuzzy_precision, fuzzy_recall, fuzzy_f1, near_misses) =
+            fuzzy_metrics(&predicted_triples, &expected_triples);
+        println!(
+            "\n🔎 Fuzzy match (embedding similarity ≥ {:.2}): F1 {:.2}% | P {:.2}% | R {:.2}%",
+            FUZZY_MATCH_THRESHOLD,
+            fuzzy_f1 * 100.0,
+            fuzzy_precision * 100.0,
+            fuzzy_recall * 100.0
+        );
+        if !near_misses.is_empty() {
+            println!("  Near misses (matched but not identical):");
+            for miss in &near_misses {
+                println!(
+                    "    ({}, {}, {}) ~ ({}, {}, {})  sim={:.2}",
+                    miss.predicted.subject,
+                    miss.predicted.predicate,
+                    miss.predicted.object,
+                    miss.gold.subject,
+                    miss.gold.predicate,
+                    miss.gold.object,
+                    miss.similarity
+                );
+            }
+        }
     }
 
     // Print aggregate summary
@@ -0,0 +1,348 @@
+//! Columnar Arrow export and Flight streaming of extracted triples
+//!
+//! [`reasoning::Triple`](crate::reasoning::Triple) and the
+//! `ConfidentTriple`/`EntailedTriple` family it feeds only ever live in
+//! `HashSet`s used for test/metric comparison - there's no way to hand a
+//! corpus of extracted triples to DuckDB/Polars without re-parsing every
+//! document's JSON-LD. This module mirrors
+//! [`gliner_extractor`](crate::gliner_extractor)'s entity-level Arrow export
+//! (see `GlinerExtractor::extract_to_batch`) one level up the pipeline, for
+//! triples rather than entities: a three-`Utf8`-column schema
+//! (`subject`/`predicate`/`object`) plus the `subject_uri`/`object_uri`
+//! columns entity linking fills in when available, an
+//! [`ArrowTripleWriter`] that accumulates triples across many `extract()`
+//! calls and flushes them to Parquet, and - behind the separate
+//! `arrow-flight` feature, since it pulls in a gRPC server stack most
+//! callers won't want - a [`FlightServer`] that serves the same accumulated
+//! batches to a streaming Arrow Flight client as documents are processed.
+
+#[cfg(feature = "arrow")]
+use crate::error::{Error, Result};
+#[cfg(feature = "arrow")]
+use crate::reasoning::Triple;
+#[cfg(feature = "arrow")]
+use arrow::array::StringArray;
+#[cfg(feature = "arrow")]
+use arrow::datatypes::{DataType, Field, Schema};
+#[cfg(feature = "arrow")]
+use arrow::record_batch::RecordBatch;
+#[cfg(feature = "arrow")]
+use parquet::arrow::ArrowWriter;
+#[cfg(feature = "arrow")]
+use std::fs::File;
+#[cfg(feature = "arrow")]
+use std::path::Path;
+#[cfg(feature = "arrow")]
+use std::sync::{Arc, Mutex};
+
+#[cfg(feature = "arrow-flight")]
+use arrow_flight::flight_service_server::FlightService;
+#[cfg(feature = "arrow-flight")]
+use arrow_flight::{
+    Action, ActionType, Criteria, Empty, FlightData, FlightDescriptor, FlightInfo,
+    HandshakeRequest, HandshakeResponse, PollInfo, PutResult, SchemaResult, Ticket,
+};
+#[cfg(feature = "arrow-flight")]
+use futures::stream::{self, BoxStream, StreamExt};
+#[cfg(feature = "arrow-flight")]
+use tonic::{Request, Response, Status, Streaming};
+
+/// A [`Triple`] plus the canonical URIs entity linking resolved for its
+/// subject/object, when available
+#[cfg(feature = "arrow")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LinkedTriple {
+    pub triple: Triple,
+    pub subject_uri: Option<String>,
+    pub object_uri: Option<String>,
+}
+
+#[cfg(feature = "arrow")]
+impl LinkedTriple {
+    #[must_use]
+    pub const fn new(triple: Triple, subject_uri: Option<String>, object_uri: Option<String>) -> Self {
+        Self { triple, subject_uri, object_uri }
+    }
+}
+
+#[cfg(feature = "arrow")]
+impl From<Triple> for LinkedTriple {
+    fn from(triple: Triple) -> Self {
+        Self { triple, subject_uri: None, object_uri: None }
+    }
+}
+
+/// Arrow schema shared by every [`triples_to_batch`] batch and by
+/// [`FlightServer`]'s advertised schema
+#[cfg(feature = "arrow")]
+fn triple_batch_schema() -> Schema {
+    Schema::new(vec![
+        Field::new("subject", DataType::Utf8, false),
+        Field::new("predicate", DataType::Utf8, false),
+        Field::new("object", DataType::Utf8, false),
+        Field::new("subject_uri", DataType::Utf8, true),
+        Field::new("object_uri", DataType::Utf8, true),
+    ])
+}
+
+/// Serialize `triples` into a single Arrow `RecordBatch` matching
+/// [`triple_batch_schema`]
+///
+/// # Errors
+///
+/// Returns an error if the batch cannot be assembled
+#[cfg(feature = "arrow")]
+pub fn triples_to_batch(triples: &[LinkedTriple]) -> Result<RecordBatch> {
+    let subjects: Vec<&str> = triples.iter().map(|t| t.triple.subject.as_str()).collect();
+    let predicates: Vec<&str> = triples.iter().map(|t| t.triple.predicate.as_str()).collect();
+    let objects: Vec<&str> = triples.iter().map(|t| t.triple.object.as_str()).collect();
+    let subject_uris: Vec<Option<&str>> =
+        triples.iter().map(|t| t.subject_uri.as_deref()).collect();
+    let object_uris: Vec<Option<&str>> = triples.iter().map(|t| t.object_uri.as_deref()).collect();
+
+    RecordBatch::try_new(
+        Arc::new(triple_batch_schema()),
+        vec![
+            Arc::new(StringArray::from(subjects)),
+            Arc::new(StringArray::from(predicates)),
+            Arc::new(StringArray::from(objects)),
+            Arc::new(StringArray::from(subject_uris)),
+            Arc::new(StringArray::from(object_uris)),
+        ],
+    )
+    .map_err(|e| Error::Extraction(format!("Failed to build Arrow RecordBatch: {}", e)))
+}
+
+/// Accumulates [`LinkedTriple`]s extracted across many `extract()` calls and
+/// flushes them to a Parquet file. The accumulated buffer is shared
+/// (`Arc<Mutex<...>>`) so a [`FlightServer`] can stream the same
+/// in-progress batches to a client without a separate copy of the data.
+#[cfg(feature = "arrow")]
+pub struct ArrowTripleWriter {
+    buffer: Arc<Mutex<Vec<LinkedTriple>>>,
+}
+
+#[cfg(feature = "arrow")]
+impl ArrowTripleWriter {
+    #[must_use]
+    pub fn new() -> Self {
+        Self { buffer: Arc::new(Mutex::new(Vec::new())) }
+    }
+
+    /// Append triples extracted from one document
+    pub fn push(&self, triples: impl IntoIterator<Item = LinkedTriple>) {
+        let mut buffer = self.buffer.lock().unwrap_or_else(std::sync::PoisonError::into_inner);
+        buffer.extend(triples);
+    }
+
+    /// Snapshot the accumulated triples as a single `RecordBatch` without
+    /// clearing the buffer
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the batch cannot be assembled
+    pub fn to_batch(&self) -> Result<RecordBatch> {
+        let buffer = self.buffer.lock().unwrap_or_else(std::sync::PoisonError::into_inner);
+        triples_to_batch(&buffer)
+    }
+
+    /// Write every accumulated triple to a single-row-group Parquet file at
+    /// `path`, then clear the buffer
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the batch cannot be assembled or the file cannot
+    /// be written
+    pub fn flush_to_parquet(&self, path: impl AsRef<Path>) -> Result<()> {
+        let batch = self.to_batch()?;
+
+        let file = File::create(path)?;
+        let mut writer = ArrowWriter::try_new(file, Arc::new(triple_batch_schema()), None)
+            .map_err(|e| Error::Extraction(format!("Failed to create Parquet writer: {}", e)))?;
+        writer
+            .write(&batch)
+            .map_err(|e| Error::Extraction(format!("Failed to write Parquet row group: {}", e)))?;
+        writer
+            .close()
+            .map_err(|e| Error::Extraction(format!("Failed to finalize Parquet file: {}", e)))?;
+
+        self.buffer.lock().unwrap_or_else(std::sync::PoisonError::into_inner).clear();
+        Ok(())
+    }
+
+    /// A handle to this writer's shared buffer, for constructing a
+    /// [`FlightServer`] (behind `--features arrow-flight`) that serves the
+    /// same triples being accumulated here
+    #[must_use]
+    pub fn shared_buffer(&self) -> Arc<Mutex<Vec<LinkedTriple>>> {
+        Arc::clone(&self.buffer)
+    }
+}
+
+#[cfg(feature = "arrow")]
+impl Default for ArrowTripleWriter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Arrow Flight service that serves an [`ArrowTripleWriter`]'s accumulated
+/// triples as a single batch per `do_get` call, so a client can pull
+/// extracted triples while a corpus is still being processed
+///
+/// Only `do_get` (returning the current snapshot under the `"triples"`
+/// ticket) and `get_flight_info`/`get_schema` are implemented;
+/// `list_flights`/`list_actions` return empty streams, and the other
+/// `FlightService` methods return `Status::unimplemented` since this server
+/// only exists to stream triples out, not to accept uploads or custom
+/// actions.
+#[cfg(feature = "arrow-flight")]
+pub struct FlightServer {
+    buffer: Arc<Mutex<Vec<LinkedTriple>>>,
+}
+
+#[cfg(feature = "arrow-flight")]
+impl FlightServer {
+    #[must_use]
+    pub const fn new(buffer: Arc<Mutex<Vec<LinkedTriple>>>) -> Self {
+        Self { buffer }
+    }
+}
+
+#[cfg(feature = "arrow-flight")]
+#[tonic::async_trait]
+impl FlightService for FlightServer {
+    type HandshakeStream = BoxStream<'static, std::result::Result<HandshakeResponse, Status>>;
+    type ListFlightsStream = BoxStream<'static, std::result::Result<FlightInfo, Status>>;
+    type DoGetStream = BoxStream<'static, std::result::Result<FlightData, Status>>;
+    type DoPutStream = BoxStream<'static, std::result::Result<PutResult, Status>>;
+    type DoActionStream = BoxStream<'static, std::result::Result<arrow_flight::Result, Status>>;
+    type ListActionsStream = BoxStream<'static, std::result::Result<ActionType, Status>>;
+    type DoExchangeStream = BoxStream<'static, std::result::Result<FlightData, Status>>;
+
+    async fn handshake(
+        &self,
+        _request: Request<Streaming<HandshakeRequest>>,
+    ) -> std::result::Result<Response<Self::HandshakeStream>, Status> {
+        Err(Status::unimplemented("handshake is not required by this server"))
+    }
+
+    async fn list_flights(
+        &self,
+        _request: Request<Criteria>,
+    ) -> std::result::Result<Response<Self::ListFlightsStream>, Status> {
+        Ok(Response::new(Box::pin(stream::empty())))
+    }
+
+    async fn get_flight_info(
+        &self,
+        _request: Request<FlightDescriptor>,
+    ) -> std::result::Result<Response<FlightInfo>, Status> {
+        let schema = triple_batch_schema();
+        let info = FlightInfo::new()
+            .try_with_schema(&schema)
+            .map_err(|e| Status::internal(format!("failed to encode schema: {e}")))?;
+        Ok(Response::new(info))
+    }
+
+    async fn poll_flight_info(
+        &self,
+        _request: Request<FlightDescriptor>,
+    ) -> std::result::Result<Response<PollInfo>, Status> {
+        Err(Status::unimplemented("polling long-running flights is not supported"))
+    }
+
+    async fn get_schema(
+        &self,
+        _request: Request<FlightDescriptor>,
+    ) -> std::result::Result<Response<SchemaResult>, Status> {
+        let schema = triple_batch_schema();
+        SchemaResult::try_from(&schema)
+            .map(Response::new)
+            .map_err(|e| Status::internal(format!("failed to encode schema: {e}")))
+    }
+
+    /// Stream the writer's current buffer, as of the moment of the call, as
+    /// a single `RecordBatch` under the `"triples"` ticket
+    async fn do_get(
+        &self,
+        request: Request<Ticket>,
+    ) -> std::result::Result<Response<Self::DoGetStream>, Status> {
+        let ticket = request.into_inner();
+        if ticket.ticket.as_ref() != b"triples" {
+            return Err(Status::not_found("unknown ticket, expected \"triples\""));
+        }
+
+        let triples = self.buffer.lock().unwrap_or_else(std::sync::PoisonError::into_inner).clone();
+        let batch = triples_to_batch(&triples)
+            .map_err(|e| Status::internal(format!("failed to build batch: {e}")))?;
+
+        let batch_stream = stream::once(async move { Ok(batch) });
+        let flight_data_stream = arrow_flight::encode::FlightDataEncoderBuilder::new()
+            .build(batch_stream)
+            .map(|result| result.map_err(|e| Status::internal(e.to_string())));
+
+        Ok(Response::new(Box::pin(flight_data_stream)))
+    }
+
+    async fn do_put(
+        &self,
+        _request: Request<Streaming<FlightData>>,
+    ) -> std::result::Result<Response<Self::DoPutStream>, Status> {
+        Err(Status::unimplemented("this server only streams triples out, it does not accept uploads"))
+    }
+
+    async fn do_exchange(
+        &self,
+        _request: Request<Streaming<FlightData>>,
+    ) -> std::result::Result<Response<Self::DoExchangeStream>, Status> {
+        Err(Status::unimplemented("bidirectional exchange is not supported"))
+    }
+
+    async fn do_action(
+        &self,
+        _request: Request<Action>,
+    ) -> std::result::Result<Response<Self::DoActionStream>, Status> {
+        Err(Status::unimplemented("no custom actions are supported"))
+    }
+
+    async fn list_actions(
+        &self,
+        _request: Request<Empty>,
+    ) -> std::result::Result<Response<Self::ListActionsStream>, Status> {
+        Ok(Response::new(Box::pin(stream::empty())))
+    }
+}
+
+#[cfg(all(test, feature = "arrow"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_triple_batch_schema_columns() {
+        let schema = triple_batch_schema();
+        let names: Vec<&str> = schema.fields().iter().map(|f| f.name().as_str()).collect();
+        assert_eq!(names, vec!["subject", "predicate", "object", "subject_uri", "object_uri"]);
+    }
+
+    #[test]
+    fn test_linked_triple_from_triple_has_no_uris() {
+        let linked: LinkedTriple = Triple::new("Alice", "worksFor", "Acme").into();
+        assert_eq!(linked.subject_uri, None);
+        assert_eq!(linked.object_uri, None);
+    }
+
+    #[test]
+    fn test_arrow_triple_writer_accumulates_across_pushes() {
+        let writer = ArrowTripleWriter::new();
+        writer.push([LinkedTriple::from(Triple::new("Alice", "worksFor", "Acme"))]);
+        writer.push([LinkedTriple::new(
+            Triple::new("Bob", "worksFor", "Acme"),
+            Some("https://example.org/bob".to_string()),
+            Some("https://example.org/acme".to_string()),
+        )]);
+
+        let batch = writer.to_batch().expect("batch should build");
+        assert_eq!(batch.num_rows(), 2);
+    }
+}
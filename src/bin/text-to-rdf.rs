@@ -0,0 +1,406 @@
+//! `text-to-rdf` CLI - extract / link / validate / batch subcommands
+//!
+//! The library crate previously exposed everything only through ad-hoc
+//! `examples/`. This binary wraps the same pipeline (`ExtractionConfig`,
+//! `build_extractor`, `RdfValidator`, `EntityLinker`) behind subcommands so
+//! it can be used directly in shell pipelines, reading text from a file or
+//! stdin and writing JSON-LD or N-Triples to stdout.
+
+use serde_json::Value;
+use std::collections::HashSet;
+use std::env;
+use std::fs;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use text_to_rdf::{
+    build_extractor, EntityLinker, ExtractionConfig, RdfDocument, RdfExtractor, RdfValidator,
+    Severity,
+};
+
+/// Output format for a document written to stdout or a file
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum OutputFormat {
+    PrettyJsonLd,
+    CompactJsonLd,
+    NTriples,
+}
+
+/// Flag overrides shared by every subcommand
+#[derive(Debug, Default)]
+struct ConfigOverrides {
+    model: Option<String>,
+    temperature: Option<f32>,
+    ontologies: Vec<String>,
+    max_retries: Option<u32>,
+    strict: Option<bool>,
+}
+
+impl ConfigOverrides {
+    fn apply(self, mut config: ExtractionConfig) -> ExtractionConfig {
+        if let Some(model) = self.model {
+            config = config.with_model(model);
+        }
+        if let Some(temperature) = self.temperature {
+            config = config.with_temperature(temperature);
+        }
+        for ontology in self.ontologies {
+            config = config.with_ontology(ontology);
+        }
+        if let Some(max_retries) = self.max_retries {
+            config = config.with_max_retries(max_retries);
+        }
+        if let Some(strict) = self.strict {
+            config = config.with_strict_validation(strict);
+        }
+        config
+    }
+}
+
+/// Parse `--model`, `--temperature`, `--ontology` (repeatable), `--max-retries`,
+/// `--strict`/`--no-strict` and the output format flags (`--pretty`,
+/// `--compact`, `--ntriples`) out of the remaining args, leaving
+/// the positional arguments (subcommand input paths) untouched
+fn parse_flags(args: &[String]) -> (Vec<String>, ConfigOverrides, OutputFormat) {
+    let mut positional = Vec::new();
+    let mut overrides = ConfigOverrides::default();
+    let mut format = OutputFormat::PrettyJsonLd;
+
+    let mut i = 0;
+    while i < args.len() {
+        match args[i].as_str() {
+            "--model" => {
+                if let Some(v) = args.get(i + 1) {
+                    overrides.model = Some(v.clone());
+                    i += 1;
+                }
+            }
+            "--temperature" => {
+                if let Some(v) = args.get(i + 1).and_then(|v| v.parse::<f32>().ok()) {
+                    overrides.temperature = Some(v);
+                    i += 1;
+                }
+            }
+            "--ontology" => {
+                if let Some(v) = args.get(i + 1) {
+                    overrides.ontologies.push(v.clone());
+                    i += 1;
+                }
+            }
+            "--max-retries" => {
+                if let Some(v) = args.get(i + 1).and_then(|v| v.parse::<u32>().ok()) {
+                    overrides.max_retries = Some(v);
+                    i += 1;
+                }
+            }
+            "--strict" => overrides.strict = Some(true),
+            "--no-strict" => overrides.strict = Some(false),
+            "--compact" => format = OutputFormat::CompactJsonLd,
+            "--ntriples" => format = OutputFormat::NTriples,
+            "--pretty" => format = OutputFormat::PrettyJsonLd,
+            other => positional.push(other.to_string()),
+        }
+        i += 1;
+    }
+
+    (positional, overrides, format)
+}
+
+/// Read `path`'s contents, or stdin when `path == "-"`
+fn read_input(path: &str) -> std::io::Result<String> {
+    if path == "-" {
+        let mut buf = String::new();
+        std::io::stdin().read_to_string(&mut buf)?;
+        Ok(buf)
+    } else {
+        fs::read_to_string(path)
+    }
+}
+
+/// Render a document in the requested output format
+fn render(doc: &RdfDocument, format: OutputFormat) -> Result<String, Box<dyn std::error::Error>> {
+    match format {
+        OutputFormat::PrettyJsonLd => Ok(doc.to_json()?),
+        OutputFormat::CompactJsonLd => Ok(serde_json::to_string(doc)?),
+        OutputFormat::NTriples => Ok(to_ntriples(doc)),
+    }
+}
+
+/// Flatten the document into sorted N-Triples lines: each entity's `@id`
+/// (or a `_:` blank node built from its `name` when it has no `@id`) is the
+/// subject, its properties other than `@context`/`@type`/`@id` are
+/// `https://schema.org/` predicates, and string values become literals while
+/// nested entities become object references
+fn to_ntriples(doc: &RdfDocument) -> String {
+    let mut triples = HashSet::new();
+    collect_ntriples(&doc.data, &mut triples);
+    let mut lines: Vec<String> = triples.into_iter().collect();
+    lines.sort();
+    lines.join("\n")
+}
+
+fn subject_ref(value: &Value) -> Option<String> {
+    let obj = value.as_object()?;
+    if let Some(id) = obj.get("@id").and_then(Value::as_str) {
+        return Some(format!("<{id}>"));
+    }
+    let name = obj.get("name").and_then(Value::as_str)?;
+    Some(format!("_:{}", name.replace(char::is_whitespace, "_")))
+}
+
+fn collect_ntriples(value: &Value, triples: &mut HashSet<String>) {
+    let Some(obj) = value.as_object() else {
+        if let Some(arr) = value.as_array() {
+            for item in arr {
+                collect_ntriples(item, triples);
+            }
+        }
+        return;
+    };
+
+    if let Some(subject) = subject_ref(value) {
+        for (key, val) in obj {
+            if matches!(key.as_str(), "@context" | "@type" | "@id") {
+                continue;
+            }
+            let predicate = format!("<https://schema.org/{key}>");
+            match val {
+                Value::String(s) => {
+                    triples.insert(format!(
+                        "{subject} {predicate} \"{}\" .",
+                        s.replace('"', "\\\"")
+                    ));
+                }
+                Value::Object(_) => {
+                    if let Some(object_ref) = subject_ref(val) {
+                        triples.insert(format!("{subject} {predicate} {object_ref} ."));
+                    }
+                }
+                Value::Array(items) => {
+                    for item in items {
+                        if let Some(s) = item.as_str() {
+                            triples.insert(format!(
+                                "{subject} {predicate} \"{}\" .",
+                                s.replace('"', "\\\"")
+                            ));
+                        } else if let Some(object_ref) = subject_ref(item) {
+                            triples.insert(format!("{subject} {predicate} {object_ref} ."));
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    for val in obj.values() {
+        collect_ntriples(val, triples);
+    }
+}
+
+/// Collect every entity `name` found in a JSON-LD value, depth-first
+fn collect_entity_names(value: &Value, names: &mut Vec<String>) {
+    match value {
+        Value::Object(obj) => {
+            if let Some(name) = obj.get("name").and_then(Value::as_str) {
+                names.push(name.to_string());
+            }
+            for val in obj.values() {
+                collect_entity_names(val, names);
+            }
+        }
+        Value::Array(arr) => {
+            for item in arr {
+                collect_entity_names(item, names);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Set `@id` on every entity whose `name` matches `entity_name` (entities
+/// nested inside a matched entity are not visited)
+fn enrich_entity_with_uri(value: &mut Value, entity_name: &str, uri: &str) {
+    match value {
+        Value::Object(obj) => {
+            if obj.get("name").and_then(Value::as_str) == Some(entity_name) {
+                obj.insert("@id".to_string(), Value::String(uri.to_string()));
+                return;
+            }
+            for val in obj.values_mut() {
+                enrich_entity_with_uri(val, entity_name, uri);
+            }
+        }
+        Value::Array(arr) => {
+            for item in arr {
+                enrich_entity_with_uri(item, entity_name, uri);
+            }
+        }
+        _ => {}
+    }
+}
+
+async fn cmd_extract(
+    input: &str,
+    config: ExtractionConfig,
+    format: OutputFormat,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let text = read_input(input)?;
+    let extractor = build_extractor(config)?;
+    let doc = extractor.extract_and_validate(&text).await?;
+    println!("{}", render(&doc, format)?);
+    Ok(())
+}
+
+async fn cmd_validate(input: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let json = read_input(input)?;
+    let doc = RdfDocument::from_json(&json)?;
+    let result = RdfValidator::default().validate(&doc);
+
+    if result.valid {
+        println!("✅ Valid (confidence {:.2})", result.confidence);
+    } else {
+        println!("❌ Invalid (confidence {:.2})", result.confidence);
+    }
+
+    for violation in &result.violations {
+        let icon = match violation.severity {
+            Severity::Error => "🔴",
+            Severity::Warning => "🟡",
+        };
+        let property = violation
+            .property
+            .as_deref()
+            .map_or_else(String::new, |p| format!(" [{p}]"));
+        println!(
+            "{icon} {:?} {}{}: {}",
+            violation.severity, violation.rule, property, violation.message
+        );
+    }
+
+    if !result.valid {
+        std::process::exit(1);
+    }
+    Ok(())
+}
+
+async fn cmd_link(
+    input: &str,
+    config: ExtractionConfig,
+    format: OutputFormat,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let json = read_input(input)?;
+    let mut doc = RdfDocument::from_json(&json)?;
+
+    let linker = EntityLinker::new(config.entity_linker.clone())?;
+
+    let mut names = Vec::new();
+    collect_entity_names(&doc.data, &mut names);
+    names.sort();
+    names.dedup();
+
+    let linked = linker.link_entities(&json, &names).await?;
+    for (name, maybe_linked) in names.iter().zip(linked.iter()) {
+        if let Some(entity) = maybe_linked {
+            enrich_entity_with_uri(&mut doc.data, name, &entity.uri);
+        }
+    }
+
+    println!("{}", render(&doc, format)?);
+    Ok(())
+}
+
+async fn cmd_batch(
+    dir: &str,
+    config: ExtractionConfig,
+    format: OutputFormat,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let extractor = build_extractor(config)?;
+    let mut merged = Vec::new();
+
+    let mut entries: Vec<PathBuf> = fs::read_dir(dir)?
+        .filter_map(|e| e.ok())
+        .map(|e| e.path())
+        .filter(|p| p.extension().and_then(|e| e.to_str()) == Some("txt"))
+        .collect();
+    entries.sort();
+
+    for path in entries {
+        println!("📄 Processing {}", path.display());
+        let text = fs::read_to_string(&path)?;
+        let doc = extractor.extract_and_validate(&text).await?;
+
+        let out_path = path.with_extension(output_extension(format));
+        fs::write(&out_path, render(&doc, format)?)?;
+        println!("   -> {}", out_path.display());
+
+        merged.push(doc.data.clone());
+    }
+
+    let merged_doc = RdfDocument {
+        context: serde_json::json!("https://schema.org/"),
+        data: Value::Array(merged),
+        provenance: None,
+        prov: None,
+        validity: None,
+    };
+    let merged_path = Path::new(dir).join(format!("merged.{}", output_extension(format)));
+    fs::write(&merged_path, render(&merged_doc, format)?)?;
+    println!("🔗 Merged graph -> {}", merged_path.display());
+
+    Ok(())
+}
+
+const fn output_extension(format: OutputFormat) -> &'static str {
+    match format {
+        OutputFormat::PrettyJsonLd | OutputFormat::CompactJsonLd => "jsonld",
+        OutputFormat::NTriples => "nt",
+    }
+}
+
+fn print_usage() {
+    eprintln!(
+        "Usage: text-to-rdf <extract|validate|link|batch> <file|dir|-> [options]\n\n\
+        Options:\n\
+        \x20 --model <name>         Override the extraction model\n\
+        \x20 --temperature <f32>    Override the sampling temperature\n\
+        \x20 --ontology <uri>       Add a target ontology (repeatable)\n\
+        \x20 --max-retries <u32>    Override the retry budget\n\
+        \x20 --strict/--no-strict   Toggle strict schema validation\n\
+        \x20 --pretty/--compact/--ntriples   Output format (default: pretty JSON-LD)"
+    );
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    dotenvy::dotenv().ok();
+
+    let args: Vec<String> = env::args().skip(1).collect();
+    if args.is_empty() {
+        print_usage();
+        std::process::exit(2);
+    }
+
+    let subcommand = args[0].clone();
+    let (positional, overrides, format) = parse_flags(&args[1..]);
+
+    let Some(input) = positional.first() else {
+        print_usage();
+        std::process::exit(2);
+    };
+
+    let config = overrides.apply(ExtractionConfig::from_env()?);
+
+    match subcommand.as_str() {
+        "extract" => cmd_extract(input, config, format).await?,
+        "validate" => cmd_validate(input).await?,
+        "link" => cmd_link(input, config, format).await?,
+        "batch" => cmd_batch(input, config, format).await?,
+        other => {
+            eprintln!("Unknown subcommand: {other}");
+            print_usage();
+            std::process::exit(2);
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,149 @@
+//! A BK-tree (Burkhard-Keller tree): an index over strings queryable by
+//! bounded edit distance in better-than-linear time
+//!
+//! A plain linear scan rescoring every entry (as the old
+//! [`EntityLinker`](crate::entity_linker::EntityLinker) fuzzy search did via
+//! a SPARQL `CONTAINS` query) either pays an O(n) cost per query or, worse,
+//! caps results with an arbitrary `LIMIT` that silently drops candidates on
+//! large knowledge bases. A BK-tree stores one key per node; each edge is
+//! labeled with the edit distance between parent and child. To find every
+//! key within distance `d` of a query, compute `dist` from the query to the
+//! current node, emit it if `dist <= d`, and recurse only into children whose
+//! edge weight lies in `[dist - d, dist + d]` - the triangle inequality
+//! guarantees no matching key can be reached through a pruned child.
+
+use crate::embedding::levenshtein_distance;
+use std::collections::HashMap;
+
+struct BkNode<T> {
+    key: String,
+    values: Vec<T>,
+    children: HashMap<usize, Box<BkNode<T>>>,
+}
+
+/// An edit-distance index over `(key, value)` pairs, searchable for all
+/// entries within a bounded Levenshtein distance of a query string
+#[derive(Default)]
+pub struct BkTree<T> {
+    root: Option<Box<BkNode<T>>>,
+}
+
+impl<T> BkTree<T> {
+    /// Create an empty tree
+    #[must_use]
+    pub fn new() -> Self {
+        Self { root: None }
+    }
+
+    /// Insert `value` under `key`. Keys that already exist in the tree
+    /// (edit distance 0) accumulate multiple values rather than overwriting.
+    pub fn insert(&mut self, key: impl Into<String>, value: T) {
+        let key = key.into();
+        match &mut self.root {
+            None => {
+                self.root = Some(Box::new(BkNode {
+                    key,
+                    values: vec![value],
+                    children: HashMap::new(),
+                }));
+            }
+            Some(root) => Self::insert_into(root, key, value),
+        }
+    }
+
+    fn insert_into(node: &mut BkNode<T>, key: String, value: T) {
+        let distance = levenshtein_distance(&node.key, &key);
+        if distance == 0 {
+            node.values.push(value);
+            return;
+        }
+
+        match node.children.get_mut(&distance) {
+            Some(child) => Self::insert_into(child, key, value),
+            None => {
+                node.children.insert(
+                    distance,
+                    Box::new(BkNode { key, values: vec![value], children: HashMap::new() }),
+                );
+            }
+        }
+    }
+
+    /// Every `(key, value, distance)` entry within `max_distance` edits of
+    /// `query`, unordered
+    #[must_use]
+    pub fn search(&self, query: &str, max_distance: usize) -> Vec<(&str, &T, usize)> {
+        let mut results = Vec::new();
+        if let Some(root) = &self.root {
+            Self::search_node(root, query, max_distance, &mut results);
+        }
+        results
+    }
+
+    fn search_node<'a>(
+        node: &'a BkNode<T>,
+        query: &str,
+        max_distance: usize,
+        results: &mut Vec<(&'a str, &'a T, usize)>,
+    ) {
+        let distance = levenshtein_distance(&node.key, query);
+        if distance <= max_distance {
+            results.extend(node.values.iter().map(|value| (node.key.as_str(), value, distance)));
+        }
+
+        let lower = distance.saturating_sub(max_distance);
+        let upper = distance + max_distance;
+        for (edge, child) in &node.children {
+            if *edge >= lower && *edge <= upper {
+                Self::search_node(child, query, max_distance, results);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_search_finds_exact_match_at_distance_zero() {
+        let mut tree = BkTree::new();
+        tree.insert("Einstein", "Q937");
+
+        let matches = tree.search("Einstein", 0);
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0], ("Einstein", &"Q937", 0));
+    }
+
+    #[test]
+    fn test_search_tolerates_typo_within_budget() {
+        let mut tree = BkTree::new();
+        tree.insert("Einstein", "Q937");
+        tree.insert("Eisenstein", "Q152428");
+
+        let matches = tree.search("Einstien", 2);
+        let keys: Vec<&str> = matches.iter().map(|(key, _, _)| *key).collect();
+        assert!(keys.contains(&"Einstein"));
+    }
+
+    #[test]
+    fn test_search_excludes_entries_beyond_max_distance() {
+        let mut tree = BkTree::new();
+        tree.insert("Einstein", "Q937");
+        tree.insert("banana", "irrelevant");
+
+        let matches = tree.search("Einstien", 2);
+        let keys: Vec<&str> = matches.iter().map(|(key, _, _)| *key).collect();
+        assert!(!keys.contains(&"banana"));
+    }
+
+    #[test]
+    fn test_insert_accumulates_values_for_duplicate_keys() {
+        let mut tree = BkTree::new();
+        tree.insert("Paris", "Q90");
+        tree.insert("Paris", "Q830149");
+
+        let matches = tree.search("Paris", 0);
+        assert_eq!(matches.len(), 2);
+    }
+}
@@ -0,0 +1,196 @@
+//! Content-addressed cache for extraction results
+//!
+//! LLM calls are the dominant cost of extraction, yet re-running the same
+//! document or chunk re-invokes the provider every time. This module keys
+//! each request by a hash of the inputs that actually affect the output
+//! (normalized chunk text, model, temperature, system prompt, ontologies)
+//! and persists the resulting [`RdfDocument`] to `<cache_dir>/<key>.rkyv`.
+//!
+//! The on-disk format is an `rkyv` archive so a cache hit validates and
+//! memory-maps the file instead of re-parsing JSON - the `@context`/`data`
+//! fields are read straight out of the archive (no copy) and only handed to
+//! `serde_json` once, to rebuild the `Value`s `RdfDocument` is built on.
+//! This matters for large batch jobs where most chunks are cache hits.
+
+use std::fs::{self, File};
+use std::path::PathBuf;
+
+use rkyv::{Archive, Deserialize as RkyvDeserialize, Serialize as RkyvSerialize};
+use sha2::{Digest, Sha256};
+
+use crate::error::{Error, Result};
+use crate::types::RdfDocument;
+
+/// Archived, on-disk representation of a cached [`RdfDocument`]
+///
+/// Stores `@context` and `data` as pre-serialized JSON strings rather than
+/// deriving `Archive` for `serde_json::Value` directly, so the archive stays
+/// a flat, `rkyv`-friendly shape.
+#[derive(Archive, RkyvSerialize, RkyvDeserialize, Debug)]
+#[archive(check_bytes)]
+struct CacheEntry {
+    context_json: String,
+    data_json: String,
+}
+
+/// Content-addressed, `rkyv`-backed cache of extraction results
+#[derive(Debug, Clone)]
+pub struct ExtractionCache {
+    dir: PathBuf,
+}
+
+impl ExtractionCache {
+    /// Open (creating if necessary) a cache rooted at `dir`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `dir` cannot be created
+    pub fn open(dir: impl Into<PathBuf>) -> Result<Self> {
+        let dir = dir.into();
+        fs::create_dir_all(&dir)?;
+        Ok(Self { dir })
+    }
+
+    /// Compute the content-addressed cache key for a request
+    ///
+    /// Hashes the normalized chunk text together with everything else that
+    /// can change the extracted output: model, temperature, system prompt
+    /// and target ontologies.
+    #[must_use]
+    pub fn key(
+        text: &str,
+        model: &str,
+        temperature: Option<f32>,
+        system_prompt: &str,
+        ontologies: &[String],
+    ) -> String {
+        let mut hasher = Sha256::new();
+        hasher.update(text.trim().as_bytes());
+        hasher.update(b"\0model=");
+        hasher.update(model.as_bytes());
+        hasher.update(b"\0temperature=");
+        hasher.update(temperature.map_or_else(|| "none".to_string(), |t| t.to_string()));
+        hasher.update(b"\0system_prompt=");
+        hasher.update(system_prompt.as_bytes());
+        hasher.update(b"\0ontologies=");
+        hasher.update(ontologies.join(",").as_bytes());
+        hasher
+            .finalize()
+            .iter()
+            .map(|byte| format!("{byte:02x}"))
+            .collect()
+    }
+
+    fn path_for(&self, key: &str) -> PathBuf {
+        self.dir.join(format!("{key}.rkyv"))
+    }
+
+    /// Look up a previously cached document for `key`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the cache entry exists but is corrupt (fails
+    /// `rkyv` validation or holds invalid JSON)
+    pub fn get(&self, key: &str) -> Result<Option<RdfDocument>> {
+        let path = self.path_for(key);
+        if !path.exists() {
+            return Ok(None);
+        }
+
+        let file = File::open(&path)?;
+        // SAFETY: the mapped file is only ever written atomically by `put`
+        // below and not concurrently mutated while mapped.
+        let mmap = unsafe { memmap2::Mmap::map(&file)? };
+
+        let archived = rkyv::check_archived_root::<CacheEntry>(&mmap)
+            .map_err(|e| Error::Config(format!("Corrupt extraction cache entry {key}: {e:?}")))?;
+
+        let context = serde_json::from_str(&archived.context_json)?;
+        let data = serde_json::from_str(&archived.data_json)?;
+
+        Ok(Some(RdfDocument {
+            context,
+            data,
+            provenance: None,
+            prov: None,
+            validity: None,
+        }))
+    }
+
+    /// Write `doc` to the cache under `key`, replacing any existing entry
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if serialization or the filesystem write fails
+    pub fn put(&self, key: &str, doc: &RdfDocument) -> Result<()> {
+        let entry = CacheEntry {
+            context_json: serde_json::to_string(&doc.context)?,
+            data_json: serde_json::to_string(&doc.data)?,
+        };
+
+        let bytes = rkyv::to_bytes::<_, 4096>(&entry)
+            .map_err(|e| Error::Config(format!("Failed to serialize cache entry: {e:?}")))?;
+
+        // Write to a temp file then rename, so a concurrent `get` never
+        // observes a partially-written archive.
+        let final_path = self.path_for(key);
+        let tmp_path = self.dir.join(format!("{key}.rkyv.tmp"));
+        fs::write(&tmp_path, &bytes)?;
+        fs::rename(&tmp_path, &final_path)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_cache() -> (ExtractionCache, PathBuf) {
+        let dir = std::env::temp_dir().join(format!(
+            "text_to_rdf_cache_test_{}",
+            std::process::id()
+        ));
+        let _ = fs::remove_dir_all(&dir);
+        (ExtractionCache::open(&dir).unwrap(), dir)
+    }
+
+    #[test]
+    fn test_miss_then_hit_roundtrip() {
+        let (cache, dir) = temp_cache();
+        let key = ExtractionCache::key("Hello world", "claude-3-5-sonnet", Some(0.3), "sys", &[]);
+
+        assert!(cache.get(&key).unwrap().is_none());
+
+        let doc = RdfDocument {
+            context: serde_json::json!("https://schema.org/"),
+            data: serde_json::json!({"@type": "Person", "name": "Ada"}),
+            provenance: None,
+            prov: None,
+            validity: None,
+        };
+        cache.put(&key, &doc).unwrap();
+
+        let hit = cache.get(&key).unwrap().expect("cache hit");
+        assert_eq!(hit.data, doc.data);
+        assert_eq!(hit.context, doc.context);
+
+        fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn test_key_changes_with_model_and_temperature() {
+        let a = ExtractionCache::key("text", "model-a", Some(0.3), "sys", &[]);
+        let b = ExtractionCache::key("text", "model-b", Some(0.3), "sys", &[]);
+        let c = ExtractionCache::key("text", "model-a", Some(0.7), "sys", &[]);
+        assert_ne!(a, b);
+        assert_ne!(a, c);
+    }
+
+    #[test]
+    fn test_key_ignores_surrounding_whitespace() {
+        let a = ExtractionCache::key("Hello world", "m", None, "sys", &[]);
+        let b = ExtractionCache::key("  Hello world  ", "m", None, "sys", &[]);
+        assert_eq!(a, b);
+    }
+}
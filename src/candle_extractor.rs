@@ -0,0 +1,371 @@
+//! In-process LLM extraction via `candle` - no external service required
+//!
+//! This extractor runs a quantized Llama/Gemma model fully in-process using
+//! `candle`/`candle-transformers`, loading GGUF weights and `tokenizer.json`
+//! from a local directory. Hugging Face hub repo ids are not resolved or
+//! downloaded; the files must already be on disk. It is intended for environments
+//! where spinning up Ollama or paying for a cloud API is undesirable (e.g.
+//! CI, offline evaluation harnesses like `webnlg_evaluation`), at the cost of
+//! slower generation and a larger binary/download footprint.
+
+#[cfg(feature = "candle")]
+use crate::error::{Error, Result};
+#[cfg(feature = "candle")]
+use crate::extractor::DEFAULT_SYSTEM_PROMPT;
+#[cfg(feature = "candle")]
+use crate::types::RdfDocument;
+#[cfg(feature = "candle")]
+use crate::RdfExtractor;
+#[cfg(feature = "candle")]
+use async_trait::async_trait;
+#[cfg(feature = "candle")]
+use candle_core::{DType, Device, Tensor};
+#[cfg(feature = "candle")]
+use candle_transformers::generation::LogitsProcessor;
+#[cfg(feature = "candle")]
+use candle_transformers::models::quantized_llama::ModelWeights;
+#[cfg(feature = "candle")]
+use std::path::PathBuf;
+#[cfg(feature = "candle")]
+use std::sync::Mutex;
+#[cfg(feature = "candle")]
+use tokenizers::Tokenizer;
+
+/// Compute device used for local inference
+#[cfg(feature = "candle")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CandleDevice {
+    /// CPU inference (always available, slowest)
+    Cpu,
+    /// CUDA GPU inference, with the device ordinal
+    Cuda(usize),
+    /// Apple Metal GPU inference
+    Metal,
+}
+
+#[cfg(feature = "candle")]
+impl CandleDevice {
+    fn to_device(self) -> Result<Device> {
+        match self {
+            Self::Cpu => Ok(Device::Cpu),
+            Self::Cuda(ordinal) => Device::new_cuda(ordinal)
+                .map_err(|e| Error::Config(format!("Failed to initialize CUDA device: {e}"))),
+            Self::Metal => Device::new_metal(0)
+                .map_err(|e| Error::Config(format!("Failed to initialize Metal device: {e}"))),
+        }
+    }
+}
+
+/// Weight quantization level for the GGUF model file
+///
+/// Lower-bit quantizations trade accuracy for smaller memory footprint and
+/// faster inference; `Q4K` is a reasonable default for CPU inference.
+#[cfg(feature = "candle")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QuantizationLevel {
+    Q4_0,
+    Q4K,
+    Q5K,
+    Q8_0,
+    F16,
+}
+
+/// Configuration for the in-process `candle` extractor
+#[cfg(feature = "candle")]
+#[derive(Debug, Clone)]
+pub struct CandleConfig {
+    /// Local directory containing the GGUF weights and `tokenizer.json`; it
+    /// is read as a filesystem path, so a Hugging Face hub repo id is not
+    /// downloaded
+    pub model_dir: PathBuf,
+
+    /// GGUF file name within `model_dir` (e.g. `"llama-2-7b-chat.Q4_K_M.gguf"`)
+    pub weights_file: String,
+
+    /// Compute device to run inference on
+    pub device: CandleDevice,
+
+    /// Quantization level of the weights file (informational - the actual
+    /// quantization is baked into the GGUF file itself)
+    pub quantization: QuantizationLevel,
+
+    /// Sampling temperature
+    pub temperature: f64,
+
+    /// Maximum number of tokens to generate
+    pub max_tokens: usize,
+}
+
+#[cfg(feature = "candle")]
+impl Default for CandleConfig {
+    fn default() -> Self {
+        Self {
+            model_dir: PathBuf::from("models/llama-2-7b-chat-gguf"),
+            weights_file: "llama-2-7b-chat.Q4_K_M.gguf".to_string(),
+            device: CandleDevice::Cpu,
+            quantization: QuantizationLevel::Q4K,
+            temperature: 0.3,
+            max_tokens: 4096,
+        }
+    }
+}
+
+#[cfg(feature = "candle")]
+impl CandleConfig {
+    /// Load configuration from environment variables
+    ///
+    /// Supported environment variables:
+    /// - `CANDLE_MODEL_DIR`: Local model directory
+    /// - `CANDLE_WEIGHTS_FILE`: GGUF file name within `CANDLE_MODEL_DIR`
+    /// - `CANDLE_DEVICE`: `"cpu"`, `"cuda"`, `"cuda:N"`, or `"metal"`
+    /// - `CANDLE_QUANTIZATION`: `"q4_0"`, `"q4_k_m"`, `"q5_k_m"`, `"q8_0"`, or `"f16"`
+    pub fn from_env() -> Result<Self> {
+        dotenvy::dotenv().ok();
+
+        let model_dir = std::env::var("CANDLE_MODEL_DIR")
+            .map(PathBuf::from)
+            .unwrap_or_else(|_| Self::default().model_dir);
+
+        let weights_file = std::env::var("CANDLE_WEIGHTS_FILE")
+            .unwrap_or_else(|_| Self::default().weights_file);
+
+        let device = match std::env::var("CANDLE_DEVICE")
+            .unwrap_or_else(|_| "cpu".to_string())
+            .to_lowercase()
+            .as_str()
+        {
+            "metal" => CandleDevice::Metal,
+            s if s == "cuda" => CandleDevice::Cuda(0),
+            s if s.starts_with("cuda:") => {
+                let ordinal = s[5..].parse::<usize>().unwrap_or(0);
+                CandleDevice::Cuda(ordinal)
+            }
+            _ => CandleDevice::Cpu,
+        };
+
+        let quantization = match std::env::var("CANDLE_QUANTIZATION")
+            .unwrap_or_else(|_| "q4_k_m".to_string())
+            .to_lowercase()
+            .as_str()
+        {
+            "q4_0" => QuantizationLevel::Q4_0,
+            "q5_k_m" | "q5_k" => QuantizationLevel::Q5K,
+            "q8_0" => QuantizationLevel::Q8_0,
+            "f16" => QuantizationLevel::F16,
+            _ => QuantizationLevel::Q4K,
+        };
+
+        Ok(Self {
+            model_dir,
+            weights_file,
+            device,
+            quantization,
+            ..Self::default()
+        })
+    }
+}
+
+/// In-process RDF extractor backed by a quantized `candle` model
+///
+/// Unlike [`GenAiExtractor`](crate::GenAiExtractor), this extractor has no
+/// external dependency: no API key, and no Ollama server to probe for. It
+/// loads weights once and keeps them resident for the lifetime of the
+/// extractor.
+#[cfg(feature = "candle")]
+pub struct CandleExtractor {
+    model: Mutex<ModelWeights>,
+    tokenizer: Tokenizer,
+    device: Device,
+    config: CandleConfig,
+}
+
+#[cfg(feature = "candle")]
+impl CandleExtractor {
+    /// Create a new `candle`-backed extractor, loading weights from `model_dir`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the tokenizer or GGUF weights cannot be found or
+    /// fail to load, or if the requested device cannot be initialized.
+    pub fn new(config: CandleConfig) -> Result<Self> {
+        let device = config.device.to_device()?;
+
+        let tokenizer_path = config.model_dir.join("tokenizer.json");
+        let tokenizer = Tokenizer::from_file(&tokenizer_path).map_err(|e| {
+            Error::Config(format!(
+                "Failed to load tokenizer from {tokenizer_path:?}: {e}"
+            ))
+        })?;
+
+        let weights_path = config.model_dir.join(&config.weights_file);
+        if !weights_path.exists() {
+            return Err(Error::Config(format!(
+                "GGUF weights not found at {weights_path:?}. Download a quantized model, e.g.:\n\
+                 huggingface-cli download TheBloke/Llama-2-7B-Chat-GGUF {} --local-dir {:?}",
+                config.weights_file, config.model_dir
+            )));
+        }
+
+        let mut file = std::fs::File::open(&weights_path)
+            .map_err(|e| Error::Config(format!("Failed to open {weights_path:?}: {e}")))?;
+        let gguf_content = candle_core::quantized::gguf_file::Content::read(&mut file)
+            .map_err(|e| Error::Config(format!("Failed to parse GGUF header: {e}")))?;
+        let model = ModelWeights::from_gguf(gguf_content, &mut file, &device)
+            .map_err(|e| Error::Config(format!("Failed to load GGUF weights: {e}")))?;
+
+        Ok(Self {
+            model: Mutex::new(model),
+            tokenizer,
+            device,
+            config,
+        })
+    }
+
+    /// Build the full prompt fed to the model, reusing the same extraction
+    /// instructions as [`GenAiExtractor`](crate::GenAiExtractor) so both
+    /// backends aim for the same JSON-LD shape.
+    fn build_prompt(text: &str) -> String {
+        format!(
+            "{DEFAULT_SYSTEM_PROMPT}\n\nExtract RDF entities and relations from the \
+             following text. Return only valid JSON-LD:\n\n{text}"
+        )
+    }
+
+    /// Greedily/temperature-sample tokens until an end-of-sequence token or
+    /// `max_tokens` is reached, returning the decoded completion
+    fn generate(&self, prompt: &str) -> Result<String> {
+        let encoding = self
+            .tokenizer
+            .encode(prompt, true)
+            .map_err(|e| Error::Extraction(format!("Tokenization failed: {e}")))?;
+
+        let mut tokens = encoding.get_ids().to_vec();
+        let mut logits_processor = LogitsProcessor::new(299_792_458, Some(self.config.temperature), None);
+        let mut model = self
+            .model
+            .lock()
+            .map_err(|_| Error::Extraction("Candle model mutex poisoned".to_string()))?;
+
+        let mut generated = Vec::new();
+        let eos_token = self
+            .tokenizer
+            .token_to_id("</s>")
+            .or_else(|| self.tokenizer.token_to_id("<|eot_id|>"))
+            .unwrap_or(2);
+
+        for index in 0..self.config.max_tokens {
+            let context_size = if index == 0 { tokens.len() } else { 1 };
+            let start = tokens.len().saturating_sub(context_size);
+            let input = Tensor::new(&tokens[start..], &self.device)
+                .and_then(|t| t.unsqueeze(0))
+                .map_err(|e| Error::Extraction(format!("Failed to build input tensor: {e}")))?;
+
+            let logits = model
+                .forward(&input, start)
+                .map_err(|e| Error::Extraction(format!("Forward pass failed: {e}")))?;
+            let logits = logits
+                .squeeze(0)
+                .and_then(|l| l.squeeze(0))
+                .and_then(|l| l.to_dtype(DType::F32))
+                .map_err(|e| Error::Extraction(format!("Failed to read logits: {e}")))?;
+
+            let next_token = logits_processor
+                .sample(&logits)
+                .map_err(|e| Error::Extraction(format!("Sampling failed: {e}")))?;
+
+            if next_token == eos_token {
+                break;
+            }
+
+            tokens.push(next_token);
+            generated.push(next_token);
+        }
+
+        self.tokenizer
+            .decode(&generated, true)
+            .map_err(|e| Error::Extraction(format!("Detokenization failed: {e}")))
+    }
+
+    /// Extract the JSON-LD content from the model's completion, mirroring
+    /// [`GenAiExtractor::extract_json_from_response`](crate::extractor::GenAiExtractor)
+    fn extract_json_from_response(response: &str) -> String {
+        if let Some(start) = response.find("```json") {
+            let after_fence = start + 7;
+            if let Some(end_offset) = response[after_fence..].find("```") {
+                let json_end = after_fence + end_offset;
+                return response[after_fence..json_end].trim().to_string();
+            }
+        }
+
+        if let Some(start) = response.find('{') {
+            if let Some(end) = response.rfind('}') {
+                return response[start..=end].trim().to_string();
+            }
+        }
+
+        response.trim().to_string()
+    }
+}
+
+#[cfg(feature = "candle")]
+#[async_trait]
+impl RdfExtractor for CandleExtractor {
+    /// Extract RDF entities from text by running inference entirely in-process
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if tokenization, the forward pass, or JSON-LD parsing
+    /// of the model's completion fails
+    async fn extract(&self, text: &str) -> Result<RdfDocument> {
+        let prompt = Self::build_prompt(text);
+        let completion = self.generate(&prompt)?;
+        let json = Self::extract_json_from_response(&completion);
+        RdfDocument::from_json(&json)
+    }
+}
+
+// Stub implementations when the feature is disabled, mirroring gliner_extractor's pattern
+#[cfg(not(feature = "candle"))]
+pub struct CandleConfig;
+
+#[cfg(not(feature = "candle"))]
+pub struct CandleExtractor;
+
+#[cfg(not(feature = "candle"))]
+impl CandleExtractor {
+    pub fn new(_config: CandleConfig) -> Result<Self, crate::error::Error> {
+        Err(crate::error::Error::Config(
+            "candle feature not enabled. Rebuild with --features candle".to_string(),
+        ))
+    }
+}
+
+#[cfg(all(test, feature = "candle"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_config_default() {
+        let config = CandleConfig::default();
+        assert_eq!(config.device, CandleDevice::Cpu);
+        assert_eq!(config.quantization, QuantizationLevel::Q4K);
+    }
+
+    #[test]
+    fn test_extract_json_from_response_fenced() {
+        let response = "Here you go:\n```json\n{\"@type\": \"Person\"}\n```\nDone.";
+        assert_eq!(
+            CandleExtractor::extract_json_from_response(response),
+            "{\"@type\": \"Person\"}"
+        );
+    }
+
+    #[test]
+    fn test_extract_json_from_response_raw() {
+        let response = "{\"@type\": \"Person\"}";
+        assert_eq!(
+            CandleExtractor::extract_json_from_response(response),
+            "{\"@type\": \"Person\"}"
+        );
+    }
+}
@@ -1,5 +1,15 @@
 use text_splitter::TextSplitter;
 
+use crate::tokenizer::TokenCounter;
+
+/// Representative English prose used to estimate characters-per-token when a
+/// chunker is sized from a token budget rather than a character budget. Any
+/// reasonably sized natural-language sample works here; this just needs to be
+/// long enough to avoid quantization noise.
+const CHARS_PER_TOKEN_SAMPLE: &str = "The quick brown fox jumps over the lazy dog. \
+    Entities and relations extracted from unstructured text are mapped to \
+    Schema.org types and serialized as JSON-LD for downstream RDF tooling.";
+
 /// A chunk of text with metadata for document-level extraction
 #[derive(Debug, Clone)]
 pub struct DocumentChunk {
@@ -39,6 +49,21 @@ impl SemanticChunker {
         }
     }
 
+    /// Create a chunker sized from a token budget rather than a character
+    /// count
+    ///
+    /// Converts `max_tokens` to a character budget using `counter`'s
+    /// observed characters-per-token ratio, so chunk boundaries respect the
+    /// caller's actual token budget (e.g. `max_context_tokens - max_tokens -
+    /// system_prompt_tokens`) instead of the crude `text.len() / 4` estimate.
+    #[must_use]
+    pub fn for_token_budget(max_tokens: usize, overlap_chars: usize, counter: &TokenCounter) -> Self {
+        let chars_per_token = counter.chars_per_token(CHARS_PER_TOKEN_SAMPLE);
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let max_chunk_size = (max_tokens as f64 * chars_per_token) as usize;
+        Self::new(max_chunk_size.max(1), overlap_chars)
+    }
+
     /// Split text into semantic chunks
     ///
     /// This uses sentence boundaries to avoid splitting mid-sentence,
@@ -105,6 +130,207 @@ impl Default for SemanticChunker {
     }
 }
 
+/// Chunker that budgets by exact token counts instead of
+/// [`SemanticChunker::for_token_budget`]'s chars-per-token approximation
+///
+/// `for_token_budget` converts a token budget into a single character budget
+/// up front, using the average chars-per-token ratio of an English sample.
+/// That average silently breaks down for any one chunk that's more
+/// token-dense than the sample - code, CJK text, URLs, heavy punctuation -
+/// so a chunk can still overflow the model's context window even though it
+/// looked fine by character count. `TokenAwareChunker` instead walks
+/// sentence/paragraph boundaries and measures each candidate unit with
+/// [`TokenCounter::count`], so the budget it enforces is exact rather than
+/// estimated. A single unit that alone exceeds the budget (e.g. one
+/// enormous sentence) is hard-split mid-sentence rather than emitted
+/// oversized.
+pub struct TokenAwareChunker<'a> {
+    token_budget: usize,
+    overlap_tokens: usize,
+    counter: &'a TokenCounter,
+}
+
+impl<'a> TokenAwareChunker<'a> {
+    /// Create a chunker that keeps every chunk under `token_budget` tokens
+    /// (as measured by `counter`) with roughly `overlap_tokens` of trailing
+    /// context carried into the next chunk
+    ///
+    /// # Arguments
+    /// * `token_budget` - Maximum tokens per chunk. Callers typically derive
+    ///   this as `model_context - prompt_overhead - expected_output` rather
+    ///   than passing the raw context window.
+    /// * `overlap_tokens` - Approximate number of trailing tokens repeated at
+    ///   the start of the next chunk, so coreference context carries across
+    ///   chunk boundaries
+    /// * `counter` - Tokenizer used to measure each candidate unit
+    #[must_use]
+    pub const fn new(token_budget: usize, overlap_tokens: usize, counter: &'a TokenCounter) -> Self {
+        Self {
+            token_budget: if token_budget == 0 { 1 } else { token_budget },
+            overlap_tokens,
+            counter,
+        }
+    }
+
+    /// Split `text` into token-budgeted chunks
+    ///
+    /// Each [`DocumentChunk`]'s `start_offset`/`end_offset` are exact byte
+    /// offsets into `text`, so callers like
+    /// [`Provenance::with_text_span`](crate::types::Provenance::with_text_span)
+    /// keep working unchanged.
+    #[must_use]
+    pub fn chunk(&self, text: &str) -> Vec<DocumentChunk> {
+        if text.is_empty() {
+            return Vec::new();
+        }
+
+        let mut chunks = Vec::new();
+        let mut chunk_start = 0usize;
+        let mut chunk_end = 0usize;
+        let mut chunk_tokens = 0usize;
+
+        for (unit_start, unit_end) in Self::split_units(text) {
+            let unit_tokens = self.counter.count(&text[unit_start..unit_end]);
+
+            if unit_tokens > self.token_budget {
+                if chunk_end > chunk_start {
+                    chunks.push(Self::build_chunk(chunks.len(), text, chunk_start, chunk_end));
+                }
+                for (split_start, split_end) in self.hard_split(text, unit_start, unit_end) {
+                    chunks.push(Self::build_chunk(chunks.len(), text, split_start, split_end));
+                }
+                chunk_start = unit_end;
+                chunk_end = unit_end;
+                chunk_tokens = 0;
+                continue;
+            }
+
+            if chunk_tokens + unit_tokens > self.token_budget && chunk_end > chunk_start {
+                chunks.push(Self::build_chunk(chunks.len(), text, chunk_start, chunk_end));
+                chunk_start = self.overlap_start(text, chunk_start, chunk_end);
+                chunk_tokens = self.counter.count(&text[chunk_start..chunk_end]);
+                // Shorten the carried-over overlap until the next unit fits
+                while chunk_start < chunk_end && chunk_tokens + unit_tokens > self.token_budget {
+                    chunk_start += 1;
+                    while chunk_start < chunk_end && !text.is_char_boundary(chunk_start) {
+                        chunk_start += 1;
+                    }
+                    chunk_tokens = self.counter.count(&text[chunk_start..chunk_end]);
+                }
+            }
+
+            chunk_end = unit_end;
+            chunk_tokens += unit_tokens;
+        }
+
+        if chunk_end > chunk_start {
+            chunks.push(Self::build_chunk(chunks.len(), text, chunk_start, chunk_end));
+        }
+
+        chunks
+    }
+
+    fn build_chunk(id: usize, text: &str, start: usize, end: usize) -> DocumentChunk {
+        DocumentChunk {
+            id,
+            text: text[start..end].to_string(),
+            start_offset: start,
+            end_offset: end,
+            entities_mentioned: vec![],
+        }
+    }
+
+    /// Step the next chunk's start back from `chunk_end` toward `chunk_start`
+    /// by roughly `overlap_tokens` worth of characters (estimated from the
+    /// flushed chunk's own chars-per-token ratio), snapped to a char boundary
+    fn overlap_start(&self, text: &str, chunk_start: usize, chunk_end: usize) -> usize {
+        if self.overlap_tokens == 0 {
+            return chunk_end;
+        }
+
+        let chars_per_token = self.counter.chars_per_token(&text[chunk_start..chunk_end]);
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let overlap_chars = (self.overlap_tokens as f64 * chars_per_token) as usize;
+
+        let mut start = chunk_end.saturating_sub(overlap_chars).max(chunk_start);
+        while start < chunk_end && !text.is_char_boundary(start) {
+            start += 1;
+        }
+        start
+    }
+
+    /// Hard-split a single unit that alone exceeds the token budget, by
+    /// bisecting at a char boundary until every piece fits
+    fn hard_split(&self, text: &str, start: usize, end: usize) -> Vec<(usize, usize)> {
+        if end <= start {
+            return Vec::new();
+        }
+        if end - start <= 1 || self.counter.count(&text[start..end]) <= self.token_budget {
+            return vec![(start, end)];
+        }
+
+        let mut mid = start + (end - start) / 2;
+        while mid < end && !text.is_char_boundary(mid) {
+            mid += 1;
+        }
+        if mid <= start || mid >= end {
+            return vec![(start, end)];
+        }
+
+        let mut result = self.hard_split(text, start, mid);
+        result.extend(self.hard_split(text, mid, end));
+        result
+    }
+
+    /// Split `text` into sentence/paragraph-sized units with byte ranges,
+    /// breaking after `.`/`!`/`?` followed by whitespace and after blank
+    /// lines - good enough for token budgeting without a full NLP sentence
+    /// segmenter
+    fn split_units(text: &str) -> Vec<(usize, usize)> {
+        let chars: Vec<(usize, char)> = text.char_indices().collect();
+        let mut units = Vec::new();
+        let mut start = 0usize;
+        let mut i = 0usize;
+
+        while i < chars.len() {
+            let (_, ch) = chars[i];
+            let is_terminator = matches!(ch, '.' | '!' | '?');
+            let is_blank_line = ch == '\n' && chars.get(i + 1).is_some_and(|(_, c)| *c == '\n');
+
+            if is_terminator {
+                let mut j = i + 1;
+                while chars.get(j).is_some_and(|(_, c)| c.is_whitespace()) {
+                    j += 1;
+                }
+                let end = chars.get(j).map_or(text.len(), |(b, _)| *b);
+                units.push((start, end));
+                start = end;
+                i = j;
+                continue;
+            }
+
+            if is_blank_line {
+                let mut j = i;
+                while chars.get(j).is_some_and(|(_, c)| *c == '\n') {
+                    j += 1;
+                }
+                let end = chars.get(j).map_or(text.len(), |(b, _)| *b);
+                units.push((start, end));
+                start = end;
+                i = j;
+                continue;
+            }
+
+            i += 1;
+        }
+
+        if start < text.len() {
+            units.push((start, text.len()));
+        }
+        units
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -155,6 +381,15 @@ mod tests {
         assert!(chunker.needs_chunking(&"x".repeat(200)));
     }
 
+    #[test]
+    fn test_for_token_budget_scales_with_tokens() {
+        let counter = TokenCounter::for_model("claude-3-5-sonnet");
+        let small = SemanticChunker::for_token_budget(100, 20, &counter);
+        let large = SemanticChunker::for_token_budget(1000, 20, &counter);
+
+        assert!(large.max_chunk_size > small.max_chunk_size);
+    }
+
     #[test]
     fn test_estimate_chunk_count() {
         let chunker = SemanticChunker::new(100, 20);
@@ -163,4 +398,94 @@ mod tests {
         assert_eq!(chunker.estimate_chunk_count(&"x".repeat(100)), 1);
         assert_eq!(chunker.estimate_chunk_count(&"x".repeat(200)), 3);
     }
+
+    #[test]
+    fn test_token_aware_chunker_short_text_is_one_chunk() {
+        let counter = TokenCounter::for_model("claude-3-5-sonnet");
+        let chunker = TokenAwareChunker::new(1000, 20, &counter);
+        let text = "This is a short document. It should not be split.";
+
+        let chunks = chunker.chunk(text);
+
+        assert_eq!(chunks.len(), 1);
+        assert_eq!(chunks[0].text, text);
+        assert_eq!(chunks[0].start_offset, 0);
+        assert_eq!(chunks[0].end_offset, text.len());
+    }
+
+    #[test]
+    fn test_token_aware_chunker_splits_at_exact_token_budget() {
+        let counter = TokenCounter::for_model("claude-3-5-sonnet");
+        let text = "This is sentence one. This is sentence two. This is sentence three. \
+            This is sentence four. This is sentence five. This is sentence six.";
+        let budget = counter.count(text) / 3;
+        let chunker = TokenAwareChunker::new(budget, 0, &counter);
+
+        let chunks = chunker.chunk(text);
+
+        assert!(chunks.len() > 1, "text should be split into multiple chunks");
+        for chunk in &chunks {
+            assert!(
+                counter.count(&chunk.text) <= budget,
+                "chunk exceeded the token budget: {} tokens",
+                counter.count(&chunk.text)
+            );
+        }
+    }
+
+    #[test]
+    fn test_token_aware_chunker_preserves_byte_offsets() {
+        let counter = TokenCounter::for_model("claude-3-5-sonnet");
+        let text = "Sentence one here. Sentence two here. Sentence three here. Sentence four here.";
+        let chunker = TokenAwareChunker::new(8, 0, &counter);
+
+        let chunks = chunker.chunk(text);
+
+        for chunk in &chunks {
+            assert_eq!(&text[chunk.start_offset..chunk.end_offset], chunk.text);
+        }
+    }
+
+    #[test]
+    fn test_token_aware_chunker_hard_splits_oversized_sentence() {
+        let counter = TokenCounter::for_model("claude-3-5-sonnet");
+        // A single "sentence" (no terminators) far larger than the budget
+        let text = format!("{} ", "word".repeat(500));
+        let chunker = TokenAwareChunker::new(10, 0, &counter);
+
+        let chunks = chunker.chunk(&text);
+
+        assert!(chunks.len() > 1, "oversized sentence should be hard-split");
+        for chunk in &chunks {
+            assert!(counter.count(&chunk.text) <= 10);
+            assert_eq!(&text[chunk.start_offset..chunk.end_offset], chunk.text);
+        }
+    }
+
+    #[test]
+    fn test_token_aware_chunker_overlap_carries_context_forward() {
+        let counter = TokenCounter::for_model("claude-3-5-sonnet");
+        let text = "Sentence one here. Sentence two here. Sentence three here. Sentence four here. \
+            Sentence five here. Sentence six here.";
+        let budget = counter.count(text) / 3;
+        let chunker = TokenAwareChunker::new(budget, 4, &counter);
+
+        let chunks = chunker.chunk(text);
+
+        assert!(chunks.len() > 1);
+        for i in 1..chunks.len() {
+            assert!(
+                chunks[i].start_offset < chunks[i - 1].end_offset,
+                "chunk {i} should overlap with the previous chunk's tail"
+            );
+        }
+    }
+
+    #[test]
+    fn test_token_aware_chunker_empty_text_yields_no_chunks() {
+        let counter = TokenCounter::for_model("claude-3-5-sonnet");
+        let chunker = TokenAwareChunker::new(100, 20, &counter);
+
+        assert!(chunker.chunk("").is_empty());
+    }
 }
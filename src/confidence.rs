@@ -0,0 +1,189 @@
+//! Confidence-weighted triples and mutually-exclusive disjunction resolution
+//!
+//! Extractors that attach a confidence to each fact (e.g. `GlinerExtractor`'s
+//! `_confidence` metadata) can produce multiple competing candidates for the
+//! same (subject, predicate) pair - for instance two different `birthPlace`
+//! guesses from overlapping spans. This module groups such candidates into a
+//! "disjunction" (at most one member can be true) and resolves each group to
+//! its highest-confidence member above a threshold, while multi-valued
+//! ("open") predicates like `knows` keep every candidate above the threshold.
+//!
+//! When an extractor doesn't report a confidence at all,
+//! [`self_consistency_confidence`] derives one by sampling the same
+//! extraction repeatedly and counting how often each triple recurs.
+
+use crate::reasoning::Triple;
+use std::collections::HashMap;
+
+/// A single RDF fact with an extraction confidence in `[0, 1]`
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConfidentTriple {
+    pub subject: String,
+    pub predicate: String,
+    pub object: String,
+    pub confidence: f64,
+}
+
+impl ConfidentTriple {
+    #[must_use]
+    pub fn new(
+        subject: impl Into<String>,
+        predicate: impl Into<String>,
+        object: impl Into<String>,
+        confidence: f64,
+    ) -> Self {
+        Self {
+            subject: subject.into(),
+            predicate: predicate.into(),
+            object: object.into(),
+            confidence: confidence.clamp(0.0, 1.0),
+        }
+    }
+}
+
+/// Schema.org properties that are single-valued in practice: at most one
+/// object can hold per subject, so competing candidates form a true
+/// disjunction rather than independent facts.
+const FUNCTIONAL_PREDICATES: &[&str] = &[
+    "birthDate",
+    "deathDate",
+    "birthPlace",
+    "deathPlace",
+    "alumniOf",
+    "foundingDate",
+    "addressCountry",
+    "gender",
+];
+
+fn is_functional_predicate(predicate: &str) -> bool {
+    FUNCTIONAL_PREDICATES
+        .iter()
+        .any(|p| p.eq_ignore_ascii_case(predicate))
+}
+
+/// Resolve mutually-exclusive disjunctions among candidate triples
+///
+/// Triples are grouped by `(subject, predicate)`. For functional predicates
+/// (see [`FUNCTIONAL_PREDICATES`]), only the highest-confidence member of
+/// each group survives, and only if its confidence meets `threshold`. For
+/// open (multi-valued) predicates, every candidate above `threshold` is kept,
+/// since several objects can legitimately hold at once.
+#[must_use]
+pub fn resolve_disjunctions(triples: Vec<ConfidentTriple>, threshold: f64) -> Vec<ConfidentTriple> {
+    let mut groups: HashMap<(String, String), Vec<ConfidentTriple>> = HashMap::new();
+    for triple in triples {
+        groups
+            .entry((triple.subject.clone(), triple.predicate.clone()))
+            .or_default()
+            .push(triple);
+    }
+
+    let mut resolved = Vec::new();
+    for ((_, predicate), mut candidates) in groups {
+        if is_functional_predicate(&predicate) {
+            candidates.sort_by(|a, b| b.confidence.partial_cmp(&a.confidence).unwrap());
+            if let Some(best) = candidates.into_iter().next() {
+                if best.confidence >= threshold {
+                    resolved.push(best);
+                }
+            }
+        } else {
+            resolved.extend(candidates.into_iter().filter(|c| c.confidence >= threshold));
+        }
+    }
+
+    resolved
+}
+
+/// Derive per-triple confidence from self-consistency across repeated
+/// extraction samples of the same input: `confidence = count / samples.len()`,
+/// where `count` is how many samples produced that exact triple.
+///
+/// This gives a calibrated-ish confidence for extractors (or models) that
+/// don't report one directly, on the assumption that a triple the model
+/// extracts consistently across resampled attempts is more likely correct
+/// than one it only produces once.
+#[must_use]
+pub fn self_consistency_confidence(samples: &[std::collections::HashSet<Triple>]) -> Vec<ConfidentTriple> {
+    let mut counts: HashMap<Triple, usize> = HashMap::new();
+    for sample in samples {
+        for triple in sample {
+            *counts.entry(triple.clone()).or_insert(0) += 1;
+        }
+    }
+
+    let total = samples.len().max(1) as f64;
+    counts
+        .into_iter()
+        .map(|(triple, count)| {
+            ConfidentTriple::new(triple.subject, triple.predicate, triple.object, count as f64 / total)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_functional_predicate_keeps_only_max_confidence() {
+        let triples = vec![
+            ConfidentTriple::new("Alan Bean", "birthPlace", "Wheeler", 0.4),
+            ConfidentTriple::new("Alan Bean", "birthPlace", "Texas", 0.9),
+        ];
+
+        let resolved = resolve_disjunctions(triples, 0.3);
+
+        assert_eq!(resolved.len(), 1);
+        assert_eq!(resolved[0].object, "Texas");
+    }
+
+    #[test]
+    fn test_below_threshold_is_dropped() {
+        let triples = vec![ConfidentTriple::new("Alan Bean", "birthPlace", "Texas", 0.2)];
+
+        let resolved = resolve_disjunctions(triples, 0.5);
+
+        assert!(resolved.is_empty());
+    }
+
+    #[test]
+    fn test_open_predicate_keeps_all_above_threshold() {
+        let triples = vec![
+            ConfidentTriple::new("Apollo 12", "crewMember", "Alan Bean", 0.9),
+            ConfidentTriple::new("Apollo 12", "crewMember", "Pete Conrad", 0.8),
+            ConfidentTriple::new("Apollo 12", "crewMember", "Dick Gordon", 0.1),
+        ];
+
+        let resolved = resolve_disjunctions(triples, 0.5);
+
+        assert_eq!(resolved.len(), 2);
+    }
+
+    #[test]
+    fn test_self_consistency_confidence_counts_recurrence() {
+        let consistent = Triple::new("Alan Bean", "birthPlace", "Wheeler");
+        let one_off = Triple::new("Alan Bean", "birthPlace", "Fort Worth");
+        let samples = vec![
+            std::collections::HashSet::from([consistent.clone()]),
+            std::collections::HashSet::from([consistent.clone()]),
+            std::collections::HashSet::from([consistent.clone(), one_off.clone()]),
+        ];
+
+        let confident = self_consistency_confidence(&samples);
+
+        let consistent_confidence = confident
+            .iter()
+            .find(|c| c.object == "Wheeler")
+            .unwrap()
+            .confidence;
+        let one_off_confidence = confident
+            .iter()
+            .find(|c| c.object == "Fort Worth")
+            .unwrap()
+            .confidence;
+
+        assert!((consistent_confidence - 1.0).abs() < 1e-9);
+        assert!((one_off_confidence - (1.0 / 3.0)).abs() < 1e-9);
+    }
+}
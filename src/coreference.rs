@@ -39,10 +39,12 @@
 //! Stage 4: Validation (SHACL)
 //! ```
 
+use crate::chunking::SemanticChunker;
 use crate::error::{Error, Result};
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
 
 /// A coreference cluster representing multiple mentions of the same entity
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -89,21 +91,105 @@ pub enum MentionType {
     Pronominal,
 }
 
+/// A record of what a resolver decided about a single mention, whether or
+/// not the decision cleared `confidence_threshold` - lets callers audit why
+/// a pronoun was (or wasn't) replaced, and by which strategy, without
+/// re-running resolution
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MentionDecision {
+    /// Original surface form of the mention ("he", "the CEO", ...)
+    pub surface_form: String,
+
+    /// Character offset of the mention in the original text
+    pub start: usize,
+
+    /// Canonical name the mention was matched to
+    pub canonical: String,
+
+    /// Score assigned to this match
+    pub score: f32,
+
+    /// Which strategy produced this decision
+    pub strategy: CoreferenceStrategy,
+
+    /// Whether `score` cleared `confidence_threshold` and the mention was
+    /// spliced into `resolved_text` - `false` means the original surface
+    /// form was left untouched rather than replaced with a dubious antecedent
+    pub replaced: bool,
+}
+
 /// Result of coreference resolution
 #[derive(Debug, Clone)]
 pub struct CoreferenceResult {
     /// Resolved text with pronouns replaced by canonical names
     pub resolved_text: String,
 
-    /// All detected coreference clusters
+    /// All detected coreference clusters that cleared `confidence_threshold`
     pub clusters: Vec<CoreferenceCluster>,
 
-    /// Mapping from original offset to canonical name
+    /// Mapping from original offset to canonical name, for mentions that
+    /// cleared `confidence_threshold`
     pub offset_to_canonical: HashMap<usize, String>,
+
+    /// Per-mention record of every resolution attempt, kept or replaced
+    pub mention_decisions: Vec<MentionDecision>,
+}
+
+impl CoreferenceResult {
+    /// Render the detected clusters as a GraphViz `digraph`: one node per
+    /// canonical entity, with an edge from every `Mention` node back to its
+    /// canonical, labeled with the mention's surface text and `MentionType`.
+    /// Mention nodes are color-coded by `MentionType` (Proper/Nominal/
+    /// Pronominal) so resolution quality can be inspected visually - e.g.
+    /// with `dot -Tpng` - before the resolved text feeds into the downstream
+    /// RDF extraction stages.
+    #[must_use]
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph coreference {\n    rankdir=LR;\n    node [shape=box, style=filled];\n");
+
+        for (cluster_idx, cluster) in self.clusters.iter().enumerate() {
+            let canonical_id = format!("canonical_{cluster_idx}");
+            dot.push_str(&format!(
+                "    {canonical_id} [label=\"{}\", fillcolor=\"lightgrey\"];\n",
+                escape_dot_label(&cluster.canonical)
+            ));
+
+            for (mention_idx, mention) in cluster.mentions.iter().enumerate() {
+                let mention_id = format!("mention_{cluster_idx}_{mention_idx}");
+                let label = format!("{} [{:?}]", mention.text, mention.mention_type);
+                dot.push_str(&format!(
+                    "    {mention_id} [label=\"{}\", fillcolor=\"{}\"];\n",
+                    escape_dot_label(&label),
+                    mention_type_color(mention.mention_type)
+                ));
+                dot.push_str(&format!(
+                    "    {mention_id} -> {canonical_id} [label=\"conf={:.2}\"];\n",
+                    cluster.confidence
+                ));
+            }
+        }
+
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+/// GraphViz `fillcolor` used for a mention node of the given `MentionType`
+const fn mention_type_color(mention_type: MentionType) -> &'static str {
+    match mention_type {
+        MentionType::Proper => "lightblue",
+        MentionType::Nominal => "lightyellow",
+        MentionType::Pronominal => "lightpink",
+    }
+}
+
+/// Escape a string for safe use inside a GraphViz quoted label
+fn escape_dot_label(label: &str) -> String {
+    label.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n")
 }
 
 /// Strategy for coreference resolution
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum CoreferenceStrategy {
     /// No coreference resolution
     None,
@@ -116,6 +202,85 @@ pub enum CoreferenceStrategy {
 
     /// LLM-based resolution (accurate but expensive)
     Llm,
+
+    /// Run `RuleBased` first and escalate only the mentions it left
+    /// unresolved to `CoreferenceConfig::escalation_strategy` (must be
+    /// `PythonSidecar` or `Llm`) - cheap resolution handles the easy cases,
+    /// the expensive path is reserved for the hard ones
+    Chained,
+}
+
+/// A user-configurable alias/synonym dictionary mapping canonical entity
+/// names to the nominal phrases that refer to them (e.g. `"Dan Shalev" =>
+/// ["the CEO", "the founder"]`). Several aliases may collapse to the same
+/// canonical (many-to-one), and lookup works in both directions - from a
+/// canonical to its aliases, or from an alias phrase back to its canonical -
+/// mirroring how synonym sets work in a search engine.
+#[derive(Debug, Clone, Default)]
+pub struct AliasTable {
+    canonical_to_aliases: HashMap<String, Vec<String>>,
+    /// lowercased alias phrase -> canonical, built eagerly so resolution
+    /// doesn't re-lowercase the whole table on every lookup
+    alias_to_canonical: HashMap<String, String>,
+}
+
+impl AliasTable {
+    /// Build a table from a canonical-name -> alias-phrases map
+    #[must_use]
+    pub fn new(canonical_to_aliases: HashMap<String, Vec<String>>) -> Self {
+        let mut alias_to_canonical = HashMap::new();
+        for (canonical, aliases) in &canonical_to_aliases {
+            for alias in aliases {
+                alias_to_canonical.insert(alias.to_lowercase(), canonical.clone());
+            }
+        }
+
+        Self { canonical_to_aliases, alias_to_canonical }
+    }
+
+    /// Load a table from a JSON file mapping canonical names to alias phrase
+    /// lists, e.g. `{"Dan Shalev": ["the CEO", "the founder"]}`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or does not contain a
+    /// valid canonical-to-aliases JSON object
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self> {
+        let contents = std::fs::read_to_string(path.as_ref())
+            .map_err(|e| Error::Config(format!("Failed to read alias file: {e}")))?;
+        let canonical_to_aliases: HashMap<String, Vec<String>> =
+            serde_json::from_str(&contents).map_err(Error::JsonParse)?;
+        Ok(Self::new(canonical_to_aliases))
+    }
+
+    /// The canonical entity name `phrase` refers to, matched
+    /// case-insensitively, if any
+    #[must_use]
+    pub fn resolve(&self, phrase: &str) -> Option<&str> {
+        self.alias_to_canonical.get(&phrase.to_lowercase()).map(String::as_str)
+    }
+
+    /// The alias phrases registered for `canonical` (matched exactly), if any
+    #[must_use]
+    pub fn aliases_of(&self, canonical: &str) -> Option<&[String]> {
+        self.canonical_to_aliases.get(canonical).map(Vec::as_slice)
+    }
+
+    /// All `(alias, canonical)` pairs in the table
+    fn entries(&self) -> Vec<(&str, &str)> {
+        self.canonical_to_aliases
+            .iter()
+            .flat_map(|(canonical, aliases)| {
+                aliases.iter().map(move |alias| (alias.as_str(), canonical.as_str()))
+            })
+            .collect()
+    }
+
+    /// Whether the table has no configured aliases
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.canonical_to_aliases.is_empty()
+    }
 }
 
 /// Configuration for coreference resolution
@@ -135,6 +300,19 @@ pub struct CoreferenceConfig {
 
     /// Whether to preserve original offsets in metadata
     pub preserve_offsets: bool,
+
+    /// Canonical-name -> alias-phrase dictionary for resolving `Nominal`
+    /// references (e.g. "the CEO") that a pronoun/proper-noun scan alone
+    /// can't connect to an entity
+    pub aliases: AliasTable,
+
+    /// Model used by the `Llm` strategy (see [`LlmResolver`])
+    pub llm_model: String,
+
+    /// Secondary resolver escalated to by the `Chained` strategy for
+    /// mentions `RuleBased` couldn't confidently resolve. Must be
+    /// `PythonSidecar` or `Llm`; ignored by every other strategy.
+    pub escalation_strategy: Option<CoreferenceStrategy>,
 }
 
 impl Default for CoreferenceConfig {
@@ -145,6 +323,9 @@ impl Default for CoreferenceConfig {
             confidence_threshold: 0.7,
             max_mention_distance: 5000, // ~1-2 paragraphs
             preserve_offsets: true,
+            aliases: AliasTable::default(),
+            llm_model: "claude-3-5-sonnet".to_string(),
+            escalation_strategy: None,
         }
     }
 }
@@ -153,10 +334,15 @@ impl CoreferenceConfig {
     /// Load configuration from environment variables
     ///
     /// Supported environment variables:
-    /// - `COREF_STRATEGY`: Strategy (`none`, `rule_based`, `sidecar`, `llm`)
+    /// - `COREF_STRATEGY`: Strategy (`none`, `rule_based`, `sidecar`, `llm`, `chained`)
     /// - `COREF_SIDECAR_URL`: URL for sidecar service (default: `http://localhost:8001`)
     /// - `COREF_CONFIDENCE`: Confidence threshold (0.0-1.0)
     /// - `COREF_MAX_DISTANCE`: Maximum mention distance in characters
+    /// - `COREF_ALIASES_FILE`: Path to a JSON file of canonical name -> alias
+    ///   phrase lists (see [`AliasTable::from_file`])
+    /// - `COREF_LLM_MODEL`: Model used by the `Llm` strategy (default: `claude-3-5-sonnet`)
+    /// - `COREF_ESCALATION_STRATEGY`: Secondary strategy (`sidecar`, `python`, or `llm`)
+    ///   the `Chained` strategy escalates unresolved mentions to
     pub fn from_env() -> Result<Self> {
         dotenvy::dotenv().ok();
 
@@ -167,10 +353,19 @@ impl CoreferenceConfig {
                 "rule_based" | "rule-based" => Some(CoreferenceStrategy::RuleBased),
                 "sidecar" | "python" => Some(CoreferenceStrategy::PythonSidecar),
                 "llm" => Some(CoreferenceStrategy::Llm),
+                "chained" => Some(CoreferenceStrategy::Chained),
                 _ => None,
             })
             .unwrap_or(CoreferenceStrategy::None);
 
+        let escalation_strategy = std::env::var("COREF_ESCALATION_STRATEGY")
+            .ok()
+            .and_then(|s| match s.to_lowercase().as_str() {
+                "sidecar" | "python" => Some(CoreferenceStrategy::PythonSidecar),
+                "llm" => Some(CoreferenceStrategy::Llm),
+                _ => None,
+            });
+
         let sidecar_url = std::env::var("COREF_SIDECAR_URL")
             .ok()
             .or_else(|| {
@@ -191,12 +386,24 @@ impl CoreferenceConfig {
             .and_then(|v| v.parse::<usize>().ok())
             .unwrap_or(5000);
 
+        let aliases = std::env::var("COREF_ALIASES_FILE")
+            .ok()
+            .map(AliasTable::from_file)
+            .transpose()?
+            .unwrap_or_default();
+
+        let llm_model =
+            std::env::var("COREF_LLM_MODEL").unwrap_or_else(|_| "claude-3-5-sonnet".to_string());
+
         Ok(Self {
             strategy,
             sidecar_url,
             confidence_threshold,
             max_mention_distance,
             preserve_offsets: true,
+            aliases,
+            llm_model,
+            escalation_strategy,
         })
     }
 }
@@ -243,11 +450,8 @@ impl CoreferenceEngine {
             CoreferenceStrategy::PythonSidecar => {
                 Box::new(SidecarResolver::new(config.clone())?)
             }
-            CoreferenceStrategy::Llm => {
-                return Err(Error::Config(
-                    "LLM-based coreference resolution not yet implemented".to_string(),
-                ));
-            }
+            CoreferenceStrategy::Llm => Box::new(LlmResolver::new(config.clone())),
+            CoreferenceStrategy::Chained => Box::new(ChainedResolver::new(config.clone())?),
         };
 
         Ok(Self { config, resolver })
@@ -269,16 +473,143 @@ impl CoreferenceResolver for NoopResolver {
             resolved_text: text.to_string(),
             clusters: Vec::new(),
             offset_to_canonical: HashMap::new(),
+            mention_decisions: Vec::new(),
         })
     }
 }
 
+/// Coarse gender/number category used to score grammatical agreement
+/// between a pronoun and a candidate antecedent
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum GrammaticalClass {
+    MasculineSingular,
+    FeminineSingular,
+    NeuterSingular,
+    Plural,
+    /// Unknown gender/number (most proper names, without a name gazetteer) -
+    /// treated as compatible with any pronoun, at reduced (0.85) credit
+    Unknown,
+}
+
+impl GrammaticalClass {
+    fn from_pronoun(word: &str) -> Self {
+        match word.to_lowercase().as_str() {
+            "he" | "him" | "his" => Self::MasculineSingular,
+            "she" | "her" | "hers" => Self::FeminineSingular,
+            "it" | "its" => Self::NeuterSingular,
+            "they" | "them" | "their" | "theirs" => Self::Plural,
+            _ => Self::Unknown,
+        }
+    }
+
+    /// Classify a candidate mention from a small gendered-title/common-noun
+    /// lexicon, falling back to a plural heuristic for `Nominal` mentions and
+    /// `Unknown` otherwise (e.g. most bare proper names)
+    fn from_candidate(candidate: &Mention) -> Self {
+        const MASCULINE_WORDS: &[&str] =
+            &["mr", "mr.", "king", "prince", "father", "husband", "actor", "chairman", "he"];
+        const FEMININE_WORDS: &[&str] = &[
+            "mrs", "mrs.", "ms", "ms.", "miss", "queen", "princess", "mother", "wife", "actress",
+            "chairwoman", "she",
+        ];
+        const NEUTER_WORDS: &[&str] = &[
+            "company", "organization", "corporation", "product", "technology", "system",
+            "country", "city", "team", "agency", "platform",
+        ];
+
+        let lower = candidate.text.to_lowercase();
+        let words: Vec<&str> =
+            lower.split_whitespace().map(|w| w.trim_matches(|c: char| !c.is_alphanumeric())).collect();
+
+        if words.iter().any(|w| MASCULINE_WORDS.contains(w)) {
+            return Self::MasculineSingular;
+        }
+        if words.iter().any(|w| FEMININE_WORDS.contains(w)) {
+            return Self::FeminineSingular;
+        }
+        if words.iter().any(|w| NEUTER_WORDS.contains(w)) {
+            return Self::NeuterSingular;
+        }
+        if candidate.mention_type == MentionType::Nominal
+            && words.last().is_some_and(|w| w.ends_with('s') && !w.ends_with("ss"))
+        {
+            return Self::Plural;
+        }
+
+        Self::Unknown
+    }
+}
+
+/// Score a pronoun-candidate pair's grammatical agreement: full credit on a
+/// matching class, high-but-not-full credit whenever either side is
+/// `Unknown` (most proper names, absent a name gazetteer, can't be ruled
+/// out), zero on a confirmed mismatch (e.g. "she" against a `Neuter`
+/// candidate like "the company")
+fn grammatical_agreement(pronoun: &str, candidate: &Mention) -> f32 {
+    let pronoun_class = GrammaticalClass::from_pronoun(pronoun);
+    let candidate_class = GrammaticalClass::from_candidate(candidate);
+
+    match (pronoun_class, candidate_class) {
+        (GrammaticalClass::Unknown, _) | (_, GrammaticalClass::Unknown) => 0.85,
+        (p, c) if p == c => 1.0,
+        _ => 0.0,
+    }
+}
+
+/// Common verbs used to approximate syntactic salience: a candidate
+/// immediately followed by one of these reads as the sentence's subject
+const SALIENCE_VERBS: &[&str] = &[
+    "said", "is", "was", "are", "were", "announced", "stated", "noted", "added", "founded",
+    "launched", "reported", "led", "became", "joined", "resigned", "replied", "explained",
+];
+
+/// Approximate syntactic prominence: half credit for starting a sentence,
+/// half credit for immediately preceding a verb - a cheap proxy for subject
+/// position without a real parse tree
+fn syntactic_salience(text: &str, candidate: &Mention) -> f32 {
+    let mut score = 0.0;
+
+    let before = text[..candidate.start].trim_end();
+    let starts_sentence = before.is_empty() || before.ends_with(['.', '!', '?']);
+    if starts_sentence {
+        score += 0.5;
+    }
+
+    let after = text[candidate.end..].trim_start();
+    let next_word = after
+        .split_whitespace()
+        .next()
+        .unwrap_or("")
+        .trim_matches(|c: char| !c.is_alphanumeric())
+        .to_lowercase();
+    if SALIENCE_VERBS.contains(&next_word.as_str()) {
+        score += 0.5;
+    }
+
+    score
+}
+
+/// Weight given to recency (`1 / (1 + character_distance)`) in
+/// [`RuleBasedResolver::score_candidate`]'s weighted sum - small, since most
+/// antecedents sit many characters before their pronoun and this term would
+/// otherwise swamp the other two for only the very closest candidates
+const RECENCY_WEIGHT: f32 = 0.1;
+/// Weight given to [`grammatical_agreement`] in the candidate score
+const AGREEMENT_WEIGHT: f32 = 0.5;
+/// Weight given to [`syntactic_salience`] in the candidate score
+const SALIENCE_WEIGHT: f32 = 0.4;
+
 /// Rule-based coreference resolver
 ///
-/// Uses simple heuristics to resolve common patterns:
-/// - Gender pronouns → nearest proper noun of matching gender
-/// - "It" → nearest nominal/proper noun
-/// - Definite descriptions ("the CEO") → nearest title match
+/// Resolves each pronoun by a scored backward scan over every `Proper` and
+/// `Nominal` mention occurring before it, within `config.max_mention_distance`
+/// characters: candidates are ranked by a weighted sum of recency,
+/// grammatical agreement ([`grammatical_agreement`]), and syntactic salience
+/// ([`syntactic_salience`]), and the top-scoring candidate above
+/// `confidence_threshold` is selected (see
+/// [`RuleBasedResolver::find_antecedent`]). Mentions resolving to the same
+/// canonical text are merged into one [`CoreferenceCluster`] instead of one
+/// cluster per pronoun.
 struct RuleBasedResolver {
     config: CoreferenceConfig,
 }
@@ -288,7 +619,7 @@ impl RuleBasedResolver {
         Self { config }
     }
 
-    /// Detect mentions in text (simple pattern matching)
+    /// Detect pronoun mentions in text (simple pattern matching)
     fn detect_mentions(&self, text: &str) -> Vec<Mention> {
         let mut mentions = Vec::new();
 
@@ -323,25 +654,186 @@ impl RuleBasedResolver {
         mentions
     }
 
-    /// Find the nearest proper noun before a pronoun
-    fn find_antecedent(&self, text: &str, pronoun_offset: usize) -> Option<String> {
-        // Look backwards for capitalized words (simple heuristic)
-        let before_pronoun = &text[..pronoun_offset];
+    /// Detect `Proper` (consecutive capitalized words) and `Nominal`
+    /// (`the`/`a`/`an` + a lowercase noun, e.g. "the CEO") candidate
+    /// antecedent mentions in text
+    fn detect_candidate_mentions(&self, text: &str) -> Vec<Mention> {
+        let mut mentions = Vec::new();
+
+        let words: Vec<(usize, &str)> = text
+            .split_whitespace()
+            .scan(0, |offset, word| {
+                let start = *offset;
+                *offset += word.len() + 1;
+                Some((start, word))
+            })
+            .collect();
+
+        let mut i = 0;
+        while i < words.len() {
+            let (start, word) = words[i];
+            let trimmed = word.trim_matches(|c: char| !c.is_alphanumeric());
+
+            if trimmed.len() > 2
+                && trimmed.chars().next().map_or(false, char::is_uppercase)
+                && !["The", "A", "An", "This", "That"].contains(&trimmed)
+            {
+                // Consume a run of consecutive capitalized words as one
+                // multi-word proper noun (e.g. "Dan Shalev").
+                let mut end_idx = i;
+                while end_idx + 1 < words.len() {
+                    let next_trimmed =
+                        words[end_idx + 1].1.trim_matches(|c: char| !c.is_alphanumeric());
+                    if next_trimmed.chars().next().map_or(false, char::is_uppercase) {
+                        end_idx += 1;
+                    } else {
+                        break;
+                    }
+                }
 
-        // Find last capitalized word that's not at sentence start
-        let words: Vec<&str> = before_pronoun.split_whitespace().collect();
+                let (last_start, last_word) = words[end_idx];
+                let end = last_start + last_word.len();
+                mentions.push(Mention {
+                    text: text[start..end].to_string(),
+                    start,
+                    end,
+                    mention_type: MentionType::Proper,
+                });
+                i = end_idx + 1;
+                continue;
+            }
 
-        for word in words.iter().rev() {
-            // Skip single letters and common words
-            if word.len() > 2
-                && word.chars().next().map_or(false, |c| c.is_uppercase())
-                && !["The", "A", "An", "This"].contains(word)
+            if (word.eq_ignore_ascii_case("the")
+                || word.eq_ignore_ascii_case("a")
+                || word.eq_ignore_ascii_case("an"))
+                && i + 1 < words.len()
             {
-                return Some(word.to_string());
+                let (next_start, next_word) = words[i + 1];
+                let next_trimmed = next_word.trim_matches(|c: char| !c.is_alphanumeric());
+                if next_trimmed.chars().next().map_or(false, char::is_lowercase) {
+                    let end = next_start + next_word.len();
+                    mentions.push(Mention {
+                        text: text[start..end].to_string(),
+                        start,
+                        end,
+                        mention_type: MentionType::Nominal,
+                    });
+                    i += 2;
+                    continue;
+                }
+            }
+
+            i += 1;
+        }
+
+        mentions
+    }
+
+    /// Score a candidate antecedent for `pronoun` as a weighted sum of
+    /// recency, grammatical agreement, and syntactic salience
+    fn score_candidate(&self, text: &str, candidate: &Mention, pronoun: &Mention) -> f32 {
+        let distance = pronoun.start.saturating_sub(candidate.end) as f32;
+        let recency = 1.0 / (1.0 + distance);
+        let agreement = grammatical_agreement(&pronoun.text, candidate);
+        let salience = syntactic_salience(text, candidate);
+
+        RECENCY_WEIGHT * recency + AGREEMENT_WEIGHT * agreement + SALIENCE_WEIGHT * salience
+    }
+
+    /// Find the highest-scoring `Proper`/`Nominal` candidate occurring before
+    /// `pronoun` and within `config.max_mention_distance` characters,
+    /// regardless of `config.confidence_threshold` - callers decide whether
+    /// the score clears the threshold, since a sub-threshold guess still
+    /// needs to be recorded as a [`MentionDecision`]
+    fn find_antecedent(&self, text: &str, candidates: &[Mention], pronoun: &Mention) -> Option<(Mention, f32)> {
+        let mut best: Option<(Mention, f32)> = None;
+
+        for candidate in candidates {
+            if candidate.end > pronoun.start {
+                continue;
+            }
+
+            let distance = pronoun.start - candidate.end;
+            if distance > self.config.max_mention_distance {
+                continue;
+            }
+
+            let score = self.score_candidate(text, candidate, pronoun);
+            if best.as_ref().map_or(true, |(_, best_score)| score > *best_score) {
+                best = Some((candidate.clone(), score));
+            }
+        }
+
+        best
+    }
+
+    /// Find every non-overlapping occurrence of a `config.aliases` phrase in
+    /// `text` (whole-word, case-insensitive), paired with the canonical name
+    /// it resolves to. Longer aliases are matched first so a short alias
+    /// can't claim a span that belongs to a longer one it's a substring of.
+    fn detect_alias_mentions(&self, text: &str) -> Vec<(Mention, String)> {
+        if self.config.aliases.is_empty() {
+            return Vec::new();
+        }
+
+        let lower_text = text.to_lowercase();
+        let mut aliases = self.config.aliases.entries();
+        aliases.sort_by_key(|(alias, _)| std::cmp::Reverse(alias.len()));
+
+        let mut claimed: Vec<(usize, usize)> = Vec::new();
+        let mut found = Vec::new();
+
+        for (alias, canonical) in aliases {
+            let lower_alias = alias.to_lowercase();
+            if lower_alias.is_empty() {
+                continue;
+            }
+
+            for (start, _) in lower_text.match_indices(&lower_alias) {
+                let end = start + alias.len();
+
+                let before_ok =
+                    text[..start].chars().next_back().is_none_or(|c| !c.is_alphanumeric());
+                let after_ok = text[end..].chars().next().is_none_or(|c| !c.is_alphanumeric());
+                if !before_ok || !after_ok {
+                    continue;
+                }
+
+                if claimed.iter().any(|&(c_start, c_end)| start < c_end && end > c_start) {
+                    continue;
+                }
+
+                claimed.push((start, end));
+                found.push((
+                    Mention {
+                        text: text[start..end].to_string(),
+                        start,
+                        end,
+                        mention_type: MentionType::Nominal,
+                    },
+                    canonical.to_string(),
+                ));
             }
         }
 
-        None
+        found
+    }
+}
+
+/// One resolved mention awaiting a splice into `resolved_text`: either a
+/// pronoun matched to a scored antecedent, or a configured alias phrase
+/// matched to its canonical (confidence 1.0 - an exact, user-configured
+/// mapping rather than a heuristic guess)
+enum ResolvedMention {
+    Pronoun { mention: Mention, antecedent: Mention, score: f32 },
+    Alias { mention: Mention, canonical: String },
+}
+
+impl ResolvedMention {
+    fn start(&self) -> usize {
+        match self {
+            Self::Pronoun { mention, .. } | Self::Alias { mention, .. } => mention.start,
+        }
     }
 }
 
@@ -349,33 +841,109 @@ impl RuleBasedResolver {
 impl CoreferenceResolver for RuleBasedResolver {
     async fn resolve(&self, text: &str) -> Result<CoreferenceResult> {
         let mentions = self.detect_mentions(text);
+        let alias_mentions = self.detect_alias_mentions(text);
 
-        if mentions.is_empty() {
+        if mentions.is_empty() && alias_mentions.is_empty() {
             return Ok(CoreferenceResult {
                 resolved_text: text.to_string(),
                 clusters: Vec::new(),
                 offset_to_canonical: HashMap::new(),
+                mention_decisions: Vec::new(),
+            });
+        }
+
+        let candidates = self.detect_candidate_mentions(text);
+
+        let mut mention_decisions: Vec<MentionDecision> = Vec::new();
+
+        // Only mentions whose score clears `confidence_threshold` are
+        // spliced into `resolved_text`/folded into `clusters` - a
+        // sub-threshold guess is recorded via `mention_decisions` but the
+        // original surface form is left untouched.
+        let mut resolved: Vec<ResolvedMention> = Vec::new();
+
+        for mention in &mentions {
+            let Some((antecedent, score)) = self.find_antecedent(text, &candidates, mention)
+            else {
+                continue;
+            };
+
+            let replaced = score >= self.config.confidence_threshold;
+            mention_decisions.push(MentionDecision {
+                surface_form: mention.text.clone(),
+                start: mention.start,
+                canonical: antecedent.text.clone(),
+                score,
+                strategy: CoreferenceStrategy::RuleBased,
+                replaced,
+            });
+
+            if replaced {
+                resolved.push(ResolvedMention::Pronoun {
+                    mention: mention.clone(),
+                    antecedent,
+                    score,
+                });
+            }
+        }
+
+        for (mention, canonical) in alias_mentions {
+            mention_decisions.push(MentionDecision {
+                surface_form: mention.text.clone(),
+                start: mention.start,
+                canonical: canonical.clone(),
+                score: 1.0,
+                strategy: CoreferenceStrategy::RuleBased,
+                replaced: true,
             });
+            resolved.push(ResolvedMention::Alias { mention, canonical });
         }
 
+        // Splice in reverse offset order so earlier replacements don't shift
+        // the offsets of ones still to come.
+        resolved.sort_by_key(|r| std::cmp::Reverse(r.start()));
+        mention_decisions.sort_by_key(|d| d.start);
+
         let mut resolved_text = text.to_string();
         let mut offset_to_canonical = HashMap::new();
-        let mut clusters = Vec::new();
-
-        // Process pronouns in reverse order to preserve offsets
-        for mention in mentions.iter().rev() {
-            if let Some(antecedent) = self.find_antecedent(text, mention.start) {
-                // Replace pronoun with antecedent
-                resolved_text.replace_range(mention.start..mention.end, &antecedent);
-                offset_to_canonical.insert(mention.start, antecedent.clone());
-
-                // Create cluster
-                clusters.push(CoreferenceCluster {
-                    canonical: antecedent.clone(),
-                    canonical_offset: 0, // Would need proper tracking
-                    mentions: vec![mention.clone()],
-                    confidence: 0.6, // Rule-based confidence is lower
-                });
+        let mut clusters: Vec<CoreferenceCluster> = Vec::new();
+        let mut cluster_index_by_canonical: HashMap<String, usize> = HashMap::new();
+
+        for resolved_mention in resolved {
+            let (mention, canonical, score, seed_antecedent) = match resolved_mention {
+                ResolvedMention::Pronoun { mention, antecedent, score } => {
+                    (mention, antecedent.text.clone(), score, Some(antecedent))
+                }
+                ResolvedMention::Alias { mention, canonical } => (mention, canonical, 1.0, None),
+            };
+
+            resolved_text.replace_range(mention.start..mention.end, &canonical);
+            offset_to_canonical.insert(mention.start, canonical.clone());
+
+            // Merge into the existing cluster for this canonical, if any,
+            // instead of creating a new single-mention cluster per mention.
+            match cluster_index_by_canonical.get(&canonical) {
+                Some(&idx) => {
+                    clusters[idx].mentions.push(mention);
+                    clusters[idx].confidence = clusters[idx].confidence.max(score);
+                }
+                None => {
+                    let canonical_offset =
+                        seed_antecedent.as_ref().map_or(mention.start, |a| a.start);
+                    let mut cluster_mentions = Vec::new();
+                    if let Some(antecedent) = seed_antecedent {
+                        cluster_mentions.push(antecedent);
+                    }
+                    cluster_mentions.push(mention);
+
+                    cluster_index_by_canonical.insert(canonical.clone(), clusters.len());
+                    clusters.push(CoreferenceCluster {
+                        canonical,
+                        canonical_offset,
+                        mentions: cluster_mentions,
+                        confidence: score,
+                    });
+                }
             }
         }
 
@@ -383,6 +951,7 @@ impl CoreferenceResolver for RuleBasedResolver {
             resolved_text,
             clusters,
             offset_to_canonical,
+            mention_decisions,
         })
     }
 }
@@ -450,22 +1019,392 @@ impl CoreferenceResolver for SidecarResolver {
             .await
             .map_err(|e| Error::Extraction(format!("Failed to parse sidecar response: {}", e)))?;
 
-        // Build offset mapping
+        // Gate each cluster by its own confidence rather than trusting the
+        // sidecar's `resolved_text` verbatim - a cluster below
+        // `confidence_threshold` is recorded in `mention_decisions` but kept
+        // out of `clusters`/`offset_to_canonical` and never spliced in.
+        let mut mention_decisions = Vec::new();
+        let mut offset_to_canonical = HashMap::new();
+        let mut kept_clusters = Vec::new();
+        let mut replacements: Vec<(usize, usize, String)> = Vec::new();
+
+        for cluster in response.clusters {
+            let replaced = cluster.confidence >= self.config.confidence_threshold;
+
+            for mention in &cluster.mentions {
+                mention_decisions.push(MentionDecision {
+                    surface_form: mention.text.clone(),
+                    start: mention.start,
+                    canonical: cluster.canonical.clone(),
+                    score: cluster.confidence,
+                    strategy: CoreferenceStrategy::PythonSidecar,
+                    replaced,
+                });
+
+                if replaced {
+                    offset_to_canonical.insert(mention.start, cluster.canonical.clone());
+                    replacements.push((mention.start, mention.end, cluster.canonical.clone()));
+                }
+            }
+
+            if replaced {
+                kept_clusters.push(cluster);
+            }
+        }
+
+        mention_decisions.sort_by_key(|d| d.start);
+
+        // Splice in reverse offset order so earlier replacements don't shift
+        // the offsets of ones still to come.
+        replacements.sort_by_key(|(start, _, _)| std::cmp::Reverse(*start));
+        let mut resolved_text = text.to_string();
+        for (start, end, canonical) in replacements {
+            if end <= resolved_text.len() {
+                resolved_text.replace_range(start..end, &canonical);
+            }
+        }
+
+        Ok(CoreferenceResult {
+            resolved_text,
+            clusters: kept_clusters,
+            offset_to_canonical,
+            mention_decisions,
+        })
+    }
+}
+
+/// Character budget for a single `LlmResolver` chunk. The overlap between
+/// consecutive chunks is `config.max_mention_distance` (see
+/// [`LlmResolver::resolve`]), so an antecedent near a chunk boundary is still
+/// visible to the LLM call covering the pronoun that follows it.
+const LLM_COREF_CHUNK_CHARS: usize = 6000;
+
+/// One cluster as returned by the LLM, before its mention offsets are
+/// rebased from chunk-local to document-global
+#[derive(Deserialize)]
+struct LlmCluster {
+    canonical: String,
+    confidence: f32,
+    mentions: Vec<LlmMention>,
+}
+
+#[derive(Deserialize)]
+struct LlmMention {
+    text: String,
+    start: usize,
+    end: usize,
+    #[serde(default)]
+    kind: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct LlmClusterResponse {
+    clusters: Vec<LlmCluster>,
+}
+
+fn parse_mention_type(kind: Option<&str>) -> MentionType {
+    match kind.map(str::to_lowercase).as_deref() {
+        Some("nominal") => MentionType::Nominal,
+        Some("pronominal") => MentionType::Pronominal,
+        _ => MentionType::Proper,
+    }
+}
+
+/// LLM-based coreference resolver
+///
+/// Sends the document text to the crate's LLM client with a prompt asking
+/// for every coreference cluster as structured JSON, then parses the
+/// response back into [`CoreferenceCluster`]s. Long documents are split into
+/// overlapping chunks (see [`LLM_COREF_CHUNK_CHARS`]) so each LLM call fits a
+/// reasonable prompt size; the overlap is `config.max_mention_distance`
+/// characters, so an antecedent near the end of one chunk is still present
+/// in the next chunk's prompt and entities spanning the boundary stay
+/// linked. Clusters from each chunk are merged by canonical name, and
+/// `offset_to_canonical`/`resolved_text` are rebuilt from the merged
+/// clusters the same way [`SidecarResolver`] rebuilds them from its
+/// sidecar's response.
+struct LlmResolver {
+    config: CoreferenceConfig,
+    client: genai::Client,
+}
+
+impl LlmResolver {
+    fn new(config: CoreferenceConfig) -> Self {
+        Self { config, client: genai::Client::default() }
+    }
+
+    /// Resolve coreferences within a single chunk, returning clusters with
+    /// offsets local to `chunk_text`
+    async fn resolve_chunk(&self, chunk_text: &str) -> Result<Vec<CoreferenceCluster>> {
+        let prompt = format!(
+            r#"Identify every coreference cluster in the text below: group all mentions (pronouns, nominal references like "the CEO", and proper names) that refer to the same real-world entity.
+
+Text:
+"{chunk_text}"
+
+Respond with ONLY a JSON object of this form:
+{{"clusters": [{{"canonical": "<canonical entity name>", "confidence": <0.0-1.0>, "mentions": [{{"text": "<mention text>", "start": <0-based char offset into the text above>, "end": <char offset>, "kind": "proper"|"nominal"|"pronominal"}}]}}]}}
+
+Include the canonical mention itself as one of the entries in "mentions". Omit clusters with only one mention."#
+        );
+
+        let user_msg = genai::chat::ChatMessage::user(prompt);
+        let chat_req = genai::chat::ChatRequest::new(vec![user_msg]);
+
+        let response = self
+            .client
+            .exec_chat(&self.config.llm_model, chat_req, None)
+            .await
+            .map_err(|e| Error::Network(format!("LLM coreference resolution failed: {}", e)))?;
+
+        let response_text = response.first_text().unwrap_or("");
+        let parsed: LlmClusterResponse = serde_json::from_str(response_text.trim())
+            .map_err(|e| Error::Extraction(format!("Invalid LLM coreference response: {}", e)))?;
+
+        Ok(parsed
+            .clusters
+            .into_iter()
+            .map(|cluster| {
+                let mentions: Vec<Mention> = cluster
+                    .mentions
+                    .into_iter()
+                    .map(|m| Mention {
+                        mention_type: parse_mention_type(m.kind.as_deref()),
+                        text: m.text,
+                        start: m.start,
+                        end: m.end,
+                    })
+                    .collect();
+                let canonical_offset = mentions.first().map_or(0, |m| m.start);
+
+                CoreferenceCluster {
+                    canonical: cluster.canonical,
+                    canonical_offset,
+                    mentions,
+                    confidence: cluster.confidence,
+                }
+            })
+            .collect())
+    }
+}
+
+#[async_trait]
+impl CoreferenceResolver for LlmResolver {
+    async fn resolve(&self, text: &str) -> Result<CoreferenceResult> {
+        let chunker = SemanticChunker::new(LLM_COREF_CHUNK_CHARS, self.config.max_mention_distance);
+        let chunks = chunker.chunk(text);
+
+        let mut clusters: Vec<CoreferenceCluster> = Vec::new();
+        let mut cluster_index_by_canonical: HashMap<String, usize> = HashMap::new();
+
+        for chunk in &chunks {
+            let chunk_clusters = self.resolve_chunk(&chunk.text).await?;
+
+            for cluster in chunk_clusters {
+                // Rebase chunk-local offsets onto the full document.
+                let mentions: Vec<Mention> = cluster
+                    .mentions
+                    .into_iter()
+                    .map(|m| Mention {
+                        start: m.start + chunk.start_offset,
+                        end: m.end + chunk.start_offset,
+                        ..m
+                    })
+                    .collect();
+                let canonical_offset = cluster.canonical_offset + chunk.start_offset;
+
+                match cluster_index_by_canonical.get(&cluster.canonical) {
+                    Some(&idx) => {
+                        for mention in mentions {
+                            // Overlapping chunks see the same mention twice;
+                            // skip an exact repeat rather than double-count it.
+                            let already_present = clusters[idx]
+                                .mentions
+                                .iter()
+                                .any(|existing| existing.start == mention.start && existing.end == mention.end);
+                            if !already_present {
+                                clusters[idx].mentions.push(mention);
+                            }
+                        }
+                        clusters[idx].confidence = clusters[idx].confidence.max(cluster.confidence);
+                    }
+                    None => {
+                        cluster_index_by_canonical.insert(cluster.canonical.clone(), clusters.len());
+                        clusters.push(CoreferenceCluster {
+                            canonical: cluster.canonical,
+                            canonical_offset,
+                            mentions,
+                            confidence: cluster.confidence,
+                        });
+                    }
+                }
+            }
+        }
+
+        // Gate each merged cluster by its own confidence - a cluster below
+        // `confidence_threshold` is recorded in `mention_decisions` but kept
+        // out of the returned `clusters`/`offset_to_canonical` and never
+        // spliced into `resolved_text`.
+        let mut mention_decisions = Vec::new();
         let mut offset_to_canonical = HashMap::new();
-        for cluster in &response.clusters {
+        let mut final_clusters = Vec::new();
+        let mut replacements: Vec<(usize, usize, String)> = Vec::new();
+
+        for cluster in clusters {
+            let replaced = cluster.confidence >= self.config.confidence_threshold;
+
             for mention in &cluster.mentions {
-                offset_to_canonical.insert(mention.start, cluster.canonical.clone());
+                mention_decisions.push(MentionDecision {
+                    surface_form: mention.text.clone(),
+                    start: mention.start,
+                    canonical: cluster.canonical.clone(),
+                    score: cluster.confidence,
+                    strategy: CoreferenceStrategy::Llm,
+                    replaced,
+                });
+
+                if replaced {
+                    offset_to_canonical.insert(mention.start, cluster.canonical.clone());
+                    replacements.push((mention.start, mention.end, cluster.canonical.clone()));
+                }
+            }
+
+            if replaced {
+                final_clusters.push(cluster);
+            }
+        }
+
+        mention_decisions.sort_by_key(|d| d.start);
+
+        // Splice in reverse offset order so earlier replacements don't shift
+        // later offsets.
+        replacements.sort_by_key(|(start, _, _)| std::cmp::Reverse(*start));
+        let mut resolved_text = text.to_string();
+        for (start, end, canonical) in replacements {
+            if end <= resolved_text.len() {
+                resolved_text.replace_range(start..end, &canonical);
             }
         }
 
         Ok(CoreferenceResult {
-            resolved_text: response.resolved_text,
-            clusters: response.clusters,
+            resolved_text,
+            clusters: final_clusters,
             offset_to_canonical,
+            mention_decisions,
         })
     }
 }
 
+/// Two-tier coreference resolver: runs [`RuleBasedResolver`] first, then
+/// escalates only the mentions it left sub-threshold to `config
+/// .escalation_strategy`. Cheap resolution handles the mentions it's
+/// confident about; the secondary resolver is called only when at least one
+/// mention was left unresolved. It is given the whole document, and only its
+/// results for the unresolved mentions are adopted.
+struct ChainedResolver {
+    primary: RuleBasedResolver,
+    secondary: Box<dyn CoreferenceResolver>,
+    secondary_strategy: CoreferenceStrategy,
+}
+
+impl ChainedResolver {
+    fn new(config: CoreferenceConfig) -> Result<Self> {
+        let secondary_strategy = config.escalation_strategy.ok_or_else(|| {
+            Error::Config(
+                "Chained coreference strategy requires escalation_strategy to be set".to_string(),
+            )
+        })?;
+
+        let secondary: Box<dyn CoreferenceResolver> = match secondary_strategy {
+            CoreferenceStrategy::PythonSidecar => Box::new(SidecarResolver::new(config.clone())?),
+            CoreferenceStrategy::Llm => Box::new(LlmResolver::new(config.clone())),
+            other => {
+                return Err(Error::Config(format!(
+                    "Chained coreference strategy's escalation_strategy must be PythonSidecar or Llm, got {other:?}"
+                )))
+            }
+        };
+
+        Ok(Self { primary: RuleBasedResolver::new(config), secondary, secondary_strategy })
+    }
+}
+
+#[async_trait]
+impl CoreferenceResolver for ChainedResolver {
+    async fn resolve(&self, text: &str) -> Result<CoreferenceResult> {
+        let mut result = self.primary.resolve(text).await?;
+
+        let unresolved_starts: HashSet<usize> = result
+            .mention_decisions
+            .iter()
+            .filter(|d| !d.replaced)
+            .map(|d| d.start)
+            .collect();
+
+        if unresolved_starts.is_empty() {
+            return Ok(result);
+        }
+
+        let secondary_result = self.secondary.resolve(text).await?;
+
+        let mut cluster_index_by_canonical: HashMap<String, usize> = result
+            .clusters
+            .iter()
+            .enumerate()
+            .map(|(i, c)| (c.canonical.clone(), i))
+            .collect();
+
+        let mut escalated_mentions: Vec<(Mention, String)> = Vec::new();
+
+        for cluster in &secondary_result.clusters {
+            for mention in &cluster.mentions {
+                if !unresolved_starts.contains(&mention.start) {
+                    continue;
+                }
+
+                result.offset_to_canonical.insert(mention.start, cluster.canonical.clone());
+                escalated_mentions.push((mention.clone(), cluster.canonical.clone()));
+
+                match cluster_index_by_canonical.get(&cluster.canonical) {
+                    Some(&idx) => result.clusters[idx].mentions.push(mention.clone()),
+                    None => {
+                        cluster_index_by_canonical
+                            .insert(cluster.canonical.clone(), result.clusters.len());
+                        result.clusters.push(CoreferenceCluster {
+                            canonical: cluster.canonical.clone(),
+                            canonical_offset: mention.start,
+                            mentions: vec![mention.clone()],
+                            confidence: cluster.confidence,
+                        });
+                    }
+                }
+
+                if let Some(decision) = result
+                    .mention_decisions
+                    .iter_mut()
+                    .find(|d| d.start == mention.start && !d.replaced)
+                {
+                    decision.canonical = cluster.canonical.clone();
+                    decision.score = cluster.confidence;
+                    decision.strategy = self.secondary_strategy;
+                    decision.replaced = true;
+                }
+            }
+        }
+
+        // Splice in reverse offset order so earlier replacements don't shift
+        // the offsets of ones still to come.
+        escalated_mentions.sort_by_key(|(m, _)| std::cmp::Reverse(m.start));
+        for (mention, canonical) in escalated_mentions {
+            if mention.end <= result.resolved_text.len() {
+                result.resolved_text.replace_range(mention.start..mention.end, &canonical);
+            }
+        }
+
+        Ok(result)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -501,4 +1440,249 @@ mod tests {
         // Should detect "He" as a pronoun
         assert!(!result.clusters.is_empty());
     }
+
+    #[tokio::test]
+    async fn test_rule_based_resolver_sets_actual_canonical_offset() {
+        let config = CoreferenceConfig {
+            strategy: CoreferenceStrategy::RuleBased,
+            ..Default::default()
+        };
+
+        let resolver = RuleBasedResolver::new(config);
+        let text = "Dan Shalev founded the company. He is the CEO.";
+        let result = resolver.resolve(text).await.unwrap();
+
+        let cluster = result.clusters.first().expect("expected a resolved cluster");
+        assert_eq!(cluster.canonical, "Dan Shalev");
+        assert_eq!(cluster.canonical_offset, text.find("Dan Shalev").unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_rule_based_resolver_merges_repeated_pronouns_into_one_cluster() {
+        let config = CoreferenceConfig {
+            strategy: CoreferenceStrategy::RuleBased,
+            ..Default::default()
+        };
+
+        let resolver = RuleBasedResolver::new(config);
+        let text = "Dan Shalev founded the company. He led it well. He also hired the first engineer.";
+        let result = resolver.resolve(text).await.unwrap();
+
+        let dan_clusters: Vec<_> = result.clusters.iter().filter(|c| c.canonical == "Dan Shalev").collect();
+        assert_eq!(dan_clusters.len(), 1, "both 'He' mentions should merge into one cluster");
+        assert!(dan_clusters[0].mentions.len() >= 3); // antecedent + 2 pronouns
+    }
+
+    #[test]
+    fn test_grammatical_agreement_rejects_confirmed_gender_mismatch() {
+        let company = Mention {
+            text: "the company".to_string(),
+            start: 0,
+            end: 12,
+            mention_type: MentionType::Nominal,
+        };
+        assert_eq!(grammatical_agreement("she", &company), 0.0);
+        assert_eq!(grammatical_agreement("it", &company), 1.0);
+    }
+
+    #[test]
+    fn test_grammatical_agreement_gives_partial_credit_for_unknown_gender() {
+        let name = Mention {
+            text: "Dan Shalev".to_string(),
+            start: 0,
+            end: 10,
+            mention_type: MentionType::Proper,
+        };
+        assert!((grammatical_agreement("he", &name) - 0.85).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn test_alias_table_resolves_case_insensitively() {
+        let table = AliasTable::new(HashMap::from([(
+            "Dan Shalev".to_string(),
+            vec!["the CEO".to_string(), "the founder".to_string()],
+        )]));
+
+        assert_eq!(table.resolve("The CEO"), Some("Dan Shalev"));
+        assert_eq!(table.resolve("the founder"), Some("Dan Shalev"));
+        assert_eq!(table.resolve("the intern"), None);
+    }
+
+    #[tokio::test]
+    async fn test_rule_based_resolver_folds_alias_phrase_into_matching_cluster() {
+        let config = CoreferenceConfig {
+            strategy: CoreferenceStrategy::RuleBased,
+            aliases: AliasTable::new(HashMap::from([(
+                "Dan Shalev".to_string(),
+                vec!["the CEO".to_string()],
+            )])),
+            ..Default::default()
+        };
+
+        let resolver = RuleBasedResolver::new(config);
+        let text = "Dan Shalev founded Acme Inc. The CEO announced new funding.";
+        let result = resolver.resolve(text).await.unwrap();
+
+        assert!(result.resolved_text.contains("Dan Shalev announced new funding"));
+        let cluster =
+            result.clusters.iter().find(|c| c.canonical == "Dan Shalev").expect("expected a cluster");
+        assert!(cluster.mentions.iter().any(|m| m.text == "The CEO"));
+    }
+
+    #[tokio::test]
+    async fn test_rule_based_resolver_merges_many_aliases_into_one_cluster() {
+        let config = CoreferenceConfig {
+            strategy: CoreferenceStrategy::RuleBased,
+            aliases: AliasTable::new(HashMap::from([(
+                "Acme Inc.".to_string(),
+                vec!["the company".to_string(), "the firm".to_string()],
+            )])),
+            ..Default::default()
+        };
+
+        let resolver = RuleBasedResolver::new(config);
+        let text = "Acme Inc. was founded in 2020. The company grew rapidly and the firm went public.";
+        let result = resolver.resolve(text).await.unwrap();
+
+        let clusters: Vec<_> = result.clusters.iter().filter(|c| c.canonical == "Acme Inc.").collect();
+        assert_eq!(clusters.len(), 1, "both aliases should collapse to one cluster");
+        assert_eq!(clusters[0].mentions.len(), 2);
+    }
+
+    #[test]
+    fn test_parse_mention_type_maps_llm_kind_strings() {
+        assert_eq!(parse_mention_type(Some("Nominal")), MentionType::Nominal);
+        assert_eq!(parse_mention_type(Some("pronominal")), MentionType::Pronominal);
+        assert_eq!(parse_mention_type(Some("proper")), MentionType::Proper);
+        assert_eq!(parse_mention_type(None), MentionType::Proper);
+    }
+
+    #[tokio::test]
+    async fn test_to_dot_renders_one_node_per_canonical_and_mention() {
+        let config = CoreferenceConfig {
+            strategy: CoreferenceStrategy::RuleBased,
+            ..Default::default()
+        };
+
+        let resolver = RuleBasedResolver::new(config);
+        let text = "Dan Shalev founded the company. He is the CEO.";
+        let result = resolver.resolve(text).await.unwrap();
+
+        let dot = result.to_dot();
+
+        assert!(dot.starts_with("digraph coreference {"));
+        assert!(dot.trim_end().ends_with('}'));
+        assert!(dot.contains("canonical_0"));
+        assert!(dot.contains("\"Dan Shalev\""));
+        assert!(dot.contains("->"));
+    }
+
+    #[test]
+    fn test_escape_dot_label_escapes_quotes_and_backslashes() {
+        assert_eq!(escape_dot_label(r#"say "hi""#), r#"say \"hi\""#);
+        assert_eq!(escape_dot_label(r"a\b"), r"a\\b");
+    }
+
+    #[tokio::test]
+    async fn test_rule_based_resolver_leaves_low_confidence_mention_unreplaced() {
+        let config = CoreferenceConfig {
+            strategy: CoreferenceStrategy::RuleBased,
+            confidence_threshold: 1.1, // unreachable, so nothing should clear it
+            ..Default::default()
+        };
+
+        let resolver = RuleBasedResolver::new(config);
+        let text = "Dan Shalev founded the company. He is the CEO.";
+        let result = resolver.resolve(text).await.unwrap();
+
+        assert_eq!(result.resolved_text, text, "sub-threshold pronoun must stay unreplaced");
+        assert!(result.clusters.is_empty());
+
+        let decision = result
+            .mention_decisions
+            .iter()
+            .find(|d| d.surface_form == "He")
+            .expect("expected a recorded decision for 'He' even though it was rejected");
+        assert!(!decision.replaced);
+        assert_eq!(decision.canonical, "Dan Shalev");
+        assert_eq!(decision.strategy, CoreferenceStrategy::RuleBased);
+    }
+
+    /// Minimal stand-in for a secondary resolver that resolves every pronoun
+    /// it's given to a fixed canonical name, used to exercise
+    /// `ChainedResolver`'s escalation without a real sidecar/LLM call.
+    struct StubAlwaysResolver {
+        canonical: String,
+    }
+
+    #[async_trait]
+    impl CoreferenceResolver for StubAlwaysResolver {
+        async fn resolve(&self, text: &str) -> Result<CoreferenceResult> {
+            let mentions: Vec<Mention> = text
+                .split_whitespace()
+                .scan(0, |offset, word| {
+                    let start = *offset;
+                    *offset += word.len() + 1;
+                    Some((start, word))
+                })
+                .filter(|(_, word)| word.eq_ignore_ascii_case("he"))
+                .map(|(start, word)| Mention {
+                    text: word.to_string(),
+                    start,
+                    end: start + word.len(),
+                    mention_type: MentionType::Pronominal,
+                })
+                .collect();
+
+            let cluster = CoreferenceCluster {
+                canonical: self.canonical.clone(),
+                canonical_offset: mentions.first().map_or(0, |m| m.start),
+                mentions,
+                confidence: 0.99,
+            };
+
+            Ok(CoreferenceResult {
+                resolved_text: text.to_string(),
+                clusters: vec![cluster],
+                offset_to_canonical: HashMap::new(),
+                mention_decisions: Vec::new(),
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_chained_resolver_escalates_only_unresolved_mentions() {
+        let config = CoreferenceConfig {
+            strategy: CoreferenceStrategy::Chained,
+            confidence_threshold: 1.1, // forces RuleBased to leave "He" unresolved
+            escalation_strategy: Some(CoreferenceStrategy::Llm),
+            ..Default::default()
+        };
+
+        let resolver = ChainedResolver {
+            primary: RuleBasedResolver::new(config.clone()),
+            secondary: Box::new(StubAlwaysResolver { canonical: "Dan Shalev".to_string() }),
+            secondary_strategy: CoreferenceStrategy::Llm,
+        };
+
+        let text = "Dan Shalev founded the company. He is the CEO.";
+        let result = resolver.resolve(text).await.unwrap();
+
+        assert!(result.resolved_text.contains("Dan Shalev is the CEO"));
+
+        let decision = result
+            .mention_decisions
+            .iter()
+            .find(|d| d.surface_form == "He")
+            .expect("expected a decision for the escalated mention");
+        assert!(decision.replaced);
+        assert_eq!(decision.strategy, CoreferenceStrategy::Llm);
+
+        let cluster = result
+            .clusters
+            .iter()
+            .find(|c| c.canonical == "Dan Shalev")
+            .expect("expected the escalated mention folded into a cluster");
+        assert!(cluster.mentions.iter().any(|m| m.text == "He"));
+    }
 }
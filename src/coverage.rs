@@ -0,0 +1,159 @@
+//! Extraction coverage analysis derived from provenance spans
+//!
+//! [`Provenance::text_span`](crate::types::Provenance::text_span) already
+//! records which byte range of the source document each chunk's extraction
+//! covered, but nothing reads it back once chunks are merged into one
+//! [`RdfDocument`](crate::types::RdfDocument) - there's no way to tell
+//! whether the LLM silently skipped part of a long document. This module
+//! fills that gap: [`compute_coverage`] unions a set of `(start, end)` spans
+//! against the full source text and reports the covered percentage plus the
+//! concrete uncovered byte ranges (with their source-text snippets), so a
+//! caller can spot gaps and optionally re-run extraction targeted at just
+//! those spans.
+
+use serde::{Deserialize, Serialize};
+
+/// A byte range of the source document that no chunk's provenance covered
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UncoveredSpan {
+    /// Start byte offset in the source document
+    pub start: usize,
+    /// End byte offset in the source document
+    pub end: usize,
+    /// The uncovered source text itself, for quick inspection without
+    /// re-slicing the original document
+    pub text: String,
+}
+
+/// Result of unioning a document's chunk provenance spans against its full
+/// text, as produced by [`compute_coverage`]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CoverageReport {
+    /// Length of the source document, in bytes
+    pub total_len: usize,
+    /// Number of bytes covered by at least one provenance span
+    pub covered_len: usize,
+    /// `covered_len / total_len`, in `[0.0, 1.0]` (`1.0` for an empty document)
+    pub coverage_ratio: f64,
+    /// Gaps between the covered spans, in document order
+    pub uncovered_spans: Vec<UncoveredSpan>,
+}
+
+/// Union `covered_spans` against `source_text` and report the covered
+/// fraction plus the uncovered gaps between them
+///
+/// Spans may overlap or arrive out of order; out-of-range bounds are
+/// clamped to `source_text`'s length and empty spans (`start >= end`) are
+/// dropped. Uncovered spans are snapped inward to the nearest `char`
+/// boundary so the reported snippet is always valid UTF-8.
+#[must_use]
+pub fn compute_coverage(source_text: &str, covered_spans: &[(usize, usize)]) -> CoverageReport {
+    let total_len = source_text.len();
+
+    let mut spans: Vec<(usize, usize)> = covered_spans
+        .iter()
+        .map(|&(start, end)| (start.min(total_len), end.min(total_len)))
+        .filter(|&(start, end)| start < end)
+        .collect();
+    spans.sort_unstable();
+
+    let mut merged: Vec<(usize, usize)> = Vec::new();
+    for (start, end) in spans {
+        if let Some(last) = merged.last_mut() {
+            if start <= last.1 {
+                last.1 = last.1.max(end);
+                continue;
+            }
+        }
+        merged.push((start, end));
+    }
+
+    let covered_len: usize = merged.iter().map(|&(start, end)| end - start).sum();
+
+    let mut uncovered_spans = Vec::new();
+    let mut cursor = 0usize;
+    for &(start, end) in &merged {
+        if cursor < start {
+            push_uncovered_span(&mut uncovered_spans, source_text, cursor, start);
+        }
+        cursor = cursor.max(end);
+    }
+    if cursor < total_len {
+        push_uncovered_span(&mut uncovered_spans, source_text, cursor, total_len);
+    }
+
+    let coverage_ratio = if total_len == 0 { 1.0 } else { covered_len as f64 / total_len as f64 };
+
+    CoverageReport { total_len, covered_len, coverage_ratio, uncovered_spans }
+}
+
+/// Snap `[start, end)` inward to the nearest `char` boundaries and, if
+/// non-empty, push the corresponding [`UncoveredSpan`] onto `spans`
+fn push_uncovered_span(spans: &mut Vec<UncoveredSpan>, source_text: &str, mut start: usize, mut end: usize) {
+    while start < end && !source_text.is_char_boundary(start) {
+        start += 1;
+    }
+    while end > start && !source_text.is_char_boundary(end) {
+        end -= 1;
+    }
+    if start < end {
+        spans.push(UncoveredSpan { start, end, text: source_text[start..end].to_string() });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_full_coverage_yields_no_uncovered_spans() {
+        let text = "hello world";
+        let report = compute_coverage(text, &[(0, text.len())]);
+        assert_eq!(report.covered_len, text.len());
+        assert!((report.coverage_ratio - 1.0).abs() < f64::EPSILON);
+        assert!(report.uncovered_spans.is_empty());
+    }
+
+    #[test]
+    fn test_gap_between_spans_is_reported() {
+        let text = "ABCDEFGHIJ";
+        let report = compute_coverage(text, &[(0, 3), (7, 10)]);
+        assert_eq!(report.covered_len, 6);
+        assert_eq!(report.uncovered_spans.len(), 1);
+        assert_eq!(report.uncovered_spans[0], UncoveredSpan { start: 3, end: 7, text: "DEFG".to_string() });
+    }
+
+    #[test]
+    fn test_overlapping_spans_are_merged() {
+        let text = "0123456789";
+        let report = compute_coverage(text, &[(0, 5), (3, 10)]);
+        assert_eq!(report.covered_len, 10);
+        assert!(report.uncovered_spans.is_empty());
+    }
+
+    #[test]
+    fn test_no_spans_leaves_everything_uncovered() {
+        let text = "uncovered";
+        let report = compute_coverage(text, &[]);
+        assert_eq!(report.covered_len, 0);
+        assert!((report.coverage_ratio - 0.0).abs() < f64::EPSILON);
+        assert_eq!(report.uncovered_spans.len(), 1);
+        assert_eq!(report.uncovered_spans[0].text, text);
+    }
+
+    #[test]
+    fn test_empty_document_has_full_coverage_ratio() {
+        let report = compute_coverage("", &[]);
+        assert_eq!(report.total_len, 0);
+        assert!((report.coverage_ratio - 1.0).abs() < f64::EPSILON);
+        assert!(report.uncovered_spans.is_empty());
+    }
+
+    #[test]
+    fn test_out_of_range_span_is_clamped() {
+        let text = "short";
+        let report = compute_coverage(text, &[(0, 1000)]);
+        assert_eq!(report.covered_len, text.len());
+        assert!(report.uncovered_spans.is_empty());
+    }
+}
@@ -0,0 +1,415 @@
+//! XSD/ISO 8601 datatype validation
+//!
+//! `RdfValidator`'s old date check only verified dash positions at indices 4
+//! and 7, so a calendar-nonsense value like `2024-13-45` passed. This module
+//! replaces it with real datatype validation - calendar-correct
+//! `xsd:date`/`xsd:dateTime` (with timezone offset)/`xsd:gYear`, ISO 8601
+//! `xsd:duration`, bounded `xsd:decimal`/`xsd:integer`, `xsd:anyURI`, and
+//! `xsd:boolean` - driven by a property->datatype mapping that can vary per
+//! Schema.org type (e.g. `Person.birthDate` is `xsd:date` but
+//! `Event.duration` is `xsd:duration`).
+
+use std::collections::{HashMap, HashSet};
+
+use serde_json::Value;
+
+use crate::types::RdfDocument;
+use crate::validation::{Severity, Violation};
+
+/// An XSD (or XSD-adjacent ISO 8601) datatype a property value can be checked against
+#[derive(Debug, Clone, PartialEq)]
+pub enum XsdDatatype {
+    /// `YYYY-MM-DD`, calendar-correct (month 1-12, day valid for month/leap year)
+    Date,
+    /// `YYYY-MM-DDTHH:MM:SS`, optionally with fractional seconds and a `Z`
+    /// or `+HH:MM`/`-HH:MM` timezone offset
+    DateTime,
+    /// A 4+ digit year, optionally signed (`2024`, `-0044`)
+    GYear,
+    /// An ISO 8601 duration (`P3Y6M4DT12H`): `T` must precede any `H`/`M`/`S`
+    /// component, and at least one component is required
+    Duration,
+    /// A bounded floating-point value
+    Decimal { min: f64, max: f64 },
+    /// A bounded integer value
+    Integer { min: i64, max: i64 },
+    /// A URI reference
+    AnyUri,
+    Boolean,
+}
+
+impl XsdDatatype {
+    /// Validate `value` against this datatype, returning a human-readable
+    /// failure reason on mismatch
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` describing why `value` doesn't satisfy the datatype
+    pub fn validate(&self, value: &Value) -> Result<(), String> {
+        match self {
+            Self::Date => {
+                let raw = value.as_str().ok_or("expected a string for xsd:date")?;
+                if parse_date(raw).is_some() {
+                    Ok(())
+                } else {
+                    Err(format!("'{raw}' is not a valid xsd:date (YYYY-MM-DD)"))
+                }
+            }
+            Self::DateTime => {
+                let raw = value.as_str().ok_or("expected a string for xsd:dateTime")?;
+                if parse_date_time(raw).is_some() {
+                    Ok(())
+                } else {
+                    Err(format!("'{raw}' is not a valid xsd:dateTime"))
+                }
+            }
+            Self::GYear => {
+                let raw = value.as_str().ok_or("expected a string for xsd:gYear")?;
+                if parse_gyear(raw) {
+                    Ok(())
+                } else {
+                    Err(format!("'{raw}' is not a valid xsd:gYear"))
+                }
+            }
+            Self::Duration => {
+                let raw = value.as_str().ok_or("expected a string for xsd:duration")?;
+                if parse_duration(raw) {
+                    Ok(())
+                } else {
+                    Err(format!("'{raw}' is not a valid ISO 8601 duration"))
+                }
+            }
+            Self::Decimal { min, max } => {
+                let n = value.as_f64().ok_or("expected a number for xsd:decimal")?;
+                if n >= *min && n <= *max {
+                    Ok(())
+                } else {
+                    Err(format!("{n} is outside the xsd:decimal range [{min}, {max}]"))
+                }
+            }
+            Self::Integer { min, max } => {
+                let n = value.as_i64().ok_or("expected an integer for xsd:integer")?;
+                if n >= *min && n <= *max {
+                    Ok(())
+                } else {
+                    Err(format!("{n} is outside the xsd:integer range [{min}, {max}]"))
+                }
+            }
+            Self::AnyUri => {
+                let raw = value.as_str().ok_or("expected a string for xsd:anyURI")?;
+                if raw.is_empty() || raw.chars().any(char::is_whitespace) {
+                    Err(format!("'{raw}' is not a valid xsd:anyURI"))
+                } else {
+                    Ok(())
+                }
+            }
+            Self::Boolean => match value {
+                Value::Bool(_) => Ok(()),
+                Value::String(s) if matches!(s.as_str(), "true" | "false" | "1" | "0") => Ok(()),
+                other => Err(format!("{other} is not a valid xsd:boolean")),
+            },
+        }
+    }
+}
+
+fn is_leap_year(year: i64) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+fn days_in_month(year: i64, month: u32) -> u32 {
+    match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 => {
+            if is_leap_year(year) {
+                29
+            } else {
+                28
+            }
+        }
+        _ => 0,
+    }
+}
+
+/// Parse `YYYY-MM-DD`, checking month 1-12 and day validity for the given
+/// month/leap year rather than just dash positions
+fn parse_date(s: &str) -> Option<()> {
+    let mut fields = s.splitn(3, '-');
+    let year: i64 = fields.next()?.parse().ok()?;
+    let month: u32 = fields.next()?.parse().ok()?;
+    let day: u32 = fields.next()?.parse().ok()?;
+    if fields.next().is_some() || !(1..=12).contains(&month) || day == 0 || day > days_in_month(year, month) {
+        return None;
+    }
+    Some(())
+}
+
+fn parse_time(s: &str) -> Option<()> {
+    let mut fields = s.splitn(3, ':');
+    let hour: u32 = fields.next()?.parse().ok()?;
+    let minute: u32 = fields.next()?.parse().ok()?;
+    let second_str = fields.next()?;
+    let second: f64 = second_str.parse().ok()?;
+    if fields.next().is_some() || hour > 23 || minute > 59 || !(0.0..60.0).contains(&second) {
+        return None;
+    }
+    Some(())
+}
+
+/// `+HH:MM` / `-HH:MM` timezone offset (a bare `Z` is handled by the caller)
+fn is_valid_offset(s: &str) -> bool {
+    let Some(body) = s.strip_prefix('+').or_else(|| s.strip_prefix('-')) else { return false };
+    let mut fields = body.splitn(2, ':');
+    let (Some(hour), Some(minute)) = (fields.next(), fields.next()) else { return false };
+    matches!((hour.parse::<u32>(), minute.parse::<u32>()), (Ok(h), Ok(m)) if h <= 14 && m <= 59)
+}
+
+/// Parse `YYYY-MM-DDTHH:MM:SS`, optionally `Z`-suffixed or with a
+/// `+HH:MM`/`-HH:MM` timezone offset
+fn parse_date_time(s: &str) -> Option<()> {
+    let (date_part, rest) = s.split_once('T')?;
+    parse_date(date_part)?;
+
+    let time_part = if let Some(stripped) = rest.strip_suffix('Z') {
+        stripped
+    } else if let Some(offset_pos) = rest.rfind(['+', '-']) {
+        let (time, offset) = rest.split_at(offset_pos);
+        if !is_valid_offset(offset) {
+            return None;
+        }
+        time
+    } else {
+        rest
+    };
+
+    parse_time(time_part)
+}
+
+/// A 4+ digit year, optionally signed
+fn parse_gyear(s: &str) -> bool {
+    let digits = s.strip_prefix(['+', '-']).unwrap_or(s);
+    digits.len() >= 4 && digits.chars().all(|c| c.is_ascii_digit())
+}
+
+/// Scan `nUNIT` tokens out of `s`, each unit drawn from `order` and required
+/// to appear in that relative order (no duplicates, no out-of-order units).
+/// Returns the number of components matched, or `None` on malformed input.
+fn scan_duration_components(s: &str, order: &[char]) -> Option<usize> {
+    let mut remaining = s;
+    let mut next_allowed = 0;
+    let mut count = 0;
+    while !remaining.is_empty() {
+        let digit_len = remaining.find(|c: char| !c.is_ascii_digit() && c != '.').unwrap_or(remaining.len());
+        if digit_len == 0 {
+            return None;
+        }
+        let (magnitude, rest) = remaining.split_at(digit_len);
+        magnitude.parse::<f64>().ok()?;
+        let unit = rest.chars().next()?;
+        let offset = order[next_allowed..].iter().position(|&u| u == unit)?;
+        next_allowed += offset + 1;
+        count += 1;
+        remaining = &rest[unit.len_utf8()..];
+    }
+    Some(count)
+}
+
+/// Parse an ISO 8601 duration (`PnYnMnDTnHnMnS`): `T` must precede any
+/// `H`/`M`/`S` component, and at least one component is required
+fn parse_duration(s: &str) -> bool {
+    let Some(body) = s.strip_prefix('P') else { return false };
+    let (date_part, time_part) = match body.split_once('T') {
+        Some((date, time)) => (date, Some(time)),
+        None => (body, None),
+    };
+
+    let Some(date_components) = scan_duration_components(date_part, &['Y', 'M', 'D']) else { return false };
+    let time_components = match time_part {
+        Some(time) if time.is_empty() => return false,
+        Some(time) => match scan_duration_components(time, &['H', 'M', 'S']) {
+            Some(count) => count,
+            None => return false,
+        },
+        None => 0,
+    };
+
+    date_components + time_components > 0
+}
+
+/// Checks an `RdfDocument`'s properties against a configurable
+/// property->datatype mapping, optionally scoped per Schema.org type
+#[derive(Debug)]
+pub struct DatatypeValidator {
+    /// `(entity_type, property) -> datatype`
+    by_type: HashMap<(String, String), XsdDatatype>,
+    /// `property -> datatype`, applied to any entity type without a more
+    /// specific mapping for that property
+    defaults: HashMap<String, XsdDatatype>,
+}
+
+impl Default for DatatypeValidator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DatatypeValidator {
+    /// A validator with no mappings configured
+    #[must_use]
+    pub fn new() -> Self {
+        Self { by_type: HashMap::new(), defaults: HashMap::new() }
+    }
+
+    /// The Schema.org property->datatype mappings this repo extracts most often
+    #[must_use]
+    pub fn with_schema_org_defaults() -> Self {
+        Self::new()
+            .with_mapping("Person", "birthDate", XsdDatatype::Date)
+            .with_mapping("Person", "deathDate", XsdDatatype::Date)
+            .with_mapping("Event", "startDate", XsdDatatype::DateTime)
+            .with_mapping("Event", "endDate", XsdDatatype::DateTime)
+            .with_mapping("Event", "duration", XsdDatatype::Duration)
+            .with_default_mapping("datePublished", XsdDatatype::Date)
+            .with_default_mapping("dateCreated", XsdDatatype::Date)
+            .with_default_mapping("url", XsdDatatype::AnyUri)
+    }
+
+    /// Map `property` to `datatype` for documents of `entity_type`, taking
+    /// priority over any [`Self::with_default_mapping`] for the same property
+    #[must_use]
+    pub fn with_mapping(mut self, entity_type: impl Into<String>, property: impl Into<String>, datatype: XsdDatatype) -> Self {
+        self.by_type.insert((entity_type.into(), property.into()), datatype);
+        self
+    }
+
+    /// Map `property` to `datatype` regardless of entity type
+    #[must_use]
+    pub fn with_default_mapping(mut self, property: impl Into<String>, datatype: XsdDatatype) -> Self {
+        self.defaults.insert(property.into(), datatype);
+        self
+    }
+
+    /// Check every mapped property present on `document`, returning one
+    /// [`Violation`] per value that fails its datatype
+    #[must_use]
+    pub fn validate(&self, document: &RdfDocument) -> Vec<Violation> {
+        let entity_type = document.get_type();
+
+        let mut properties: HashSet<&str> = self.defaults.keys().map(String::as_str).collect();
+        if let Some(entity_type) = entity_type {
+            properties.extend(self.by_type.keys().filter(|(t, _)| t == entity_type).map(|(_, property)| property.as_str()));
+        }
+
+        let mut violations = Vec::new();
+        for property in properties {
+            let Some(value) = document.get(property) else { continue };
+            let datatype = entity_type
+                .and_then(|t| self.by_type.get(&(t.to_string(), property.to_string())))
+                .or_else(|| self.defaults.get(property));
+            let Some(datatype) = datatype else { continue };
+
+            if let Err(reason) = datatype.validate(value) {
+                violations.push(Violation {
+                    rule: "xsd_datatype".to_string(),
+                    message: format!("'{property}' {reason}"),
+                    severity: Severity::Warning,
+                    property: Some(property.to_string()),
+                    confidence_impact: -0.05,
+                    source: None,
+                });
+            }
+        }
+        violations
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_date_rejects_invalid_month_and_day() {
+        assert!(parse_date("2024-06-15").is_some());
+        assert!(parse_date("2024-13-45").is_none());
+        assert!(parse_date("2024-02-30").is_none());
+    }
+
+    #[test]
+    fn test_date_respects_leap_years() {
+        assert!(parse_date("2024-02-29").is_some());
+        assert!(parse_date("2023-02-29").is_none());
+    }
+
+    #[test]
+    fn test_date_time_with_timezone_offset() {
+        assert!(parse_date_time("2024-06-15T10:30:00Z").is_some());
+        assert!(parse_date_time("2024-06-15T10:30:00+05:30").is_some());
+        assert!(parse_date_time("2024-06-15T25:00:00Z").is_none());
+    }
+
+    #[test]
+    fn test_duration_requires_t_before_time_components() {
+        assert!(parse_duration("P3Y6M4DT12H"));
+        assert!(parse_duration("PT30M"));
+        assert!(!parse_duration("P3H"));
+        assert!(!parse_duration("P"));
+        assert!(!parse_duration("PT"));
+    }
+
+    #[test]
+    fn test_duration_rejects_out_of_order_components() {
+        assert!(!parse_duration("P4D6M3Y"));
+        assert!(!parse_duration("PT30S12H"));
+    }
+
+    #[test]
+    fn test_gyear_accepts_signed_years() {
+        assert!(parse_gyear("2024"));
+        assert!(parse_gyear("-0044"));
+        assert!(!parse_gyear("99"));
+    }
+
+    #[test]
+    fn test_validator_flags_calendar_nonsense_date() {
+        let doc = RdfDocument::from_value(json!({
+            "@context": "https://schema.org/",
+            "@type": "Person",
+            "birthDate": "2024-13-45"
+        }))
+        .unwrap();
+
+        let violations = DatatypeValidator::with_schema_org_defaults().validate(&doc);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].severity, Severity::Warning);
+        assert!(violations[0].message.contains("birthDate"));
+    }
+
+    #[test]
+    fn test_validator_applies_type_specific_mapping_over_default() {
+        let doc = RdfDocument::from_value(json!({
+            "@context": "https://schema.org/",
+            "@type": "Event",
+            "duration": "PT2H"
+        }))
+        .unwrap();
+
+        // A default mapping of `duration` to xsd:date would reject "PT2H";
+        // the Event-specific xsd:duration mapping takes priority over it
+        let validator = DatatypeValidator::with_schema_org_defaults().with_default_mapping("duration", XsdDatatype::Date);
+        assert!(validator.validate(&doc).is_empty());
+    }
+
+    #[test]
+    fn test_validator_passes_well_formed_values() {
+        let doc = RdfDocument::from_value(json!({
+            "@context": "https://schema.org/",
+            "@type": "Person",
+            "birthDate": "1990-01-01",
+            "deathDate": "2020-12-31"
+        }))
+        .unwrap();
+
+        assert!(DatatypeValidator::with_schema_org_defaults().validate(&doc).is_empty());
+    }
+}
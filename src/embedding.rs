@@ -0,0 +1,305 @@
+//! Lightweight text embeddings for fuzzy string/triple comparison
+//!
+//! Exact string comparison treats "United Kingdom" and "UK", or "bornIn" and
+//! "birthPlace", as total misses even though they mean the same thing. This
+//! module provides a small [`TextEmbedder`] trait so evaluation code can
+//! compute a cosine similarity between surface forms instead: a
+//! dependency-free [`HashingEmbedder`] that's always available. No learned
+//! sentence-embedding implementation is provided, with or without the
+//! `candle` feature; other embedders can be plugged in through the trait.
+
+use std::collections::HashMap;
+
+/// Something that can turn text into a fixed-size embedding vector
+pub trait TextEmbedder {
+    /// Embed `text` into a dense vector. Implementations should return
+    /// vectors of a consistent dimension across calls.
+    fn embed(&self, text: &str) -> Vec<f32>;
+}
+
+/// Cosine similarity between two equal-length vectors, in `[-1, 1]`
+///
+/// Returns `0.0` if either vector has zero magnitude.
+#[must_use]
+pub fn cosine_similarity(a: &[f32], b: &[f32]) -> f32 {
+    let dot: f32 = a.iter().zip(b).map(|(x, y)| x * y).sum();
+    let norm_a = a.iter().map(|x| x * x).sum::<f32>().sqrt();
+    let norm_b = b.iter().map(|x| x * x).sum::<f32>().sqrt();
+
+    if norm_a == 0.0 || norm_b == 0.0 {
+        0.0
+    } else {
+        dot / (norm_a * norm_b)
+    }
+}
+
+/// Dependency-free fallback embedder: a hashed character-trigram bag-of-words
+///
+/// This is not a learned embedding, but it's cheap, deterministic, and
+/// already captures surface-level similarity (shared substrings, common
+/// prefixes/suffixes) well enough to catch near-misses like "birthDate" vs
+/// "birthdat" (the stemmed form used elsewhere in this crate).
+#[derive(Debug, Clone, Copy, Default)]
+pub struct HashingEmbedder {
+    /// Dimensionality of the hashed embedding space
+    dims: usize,
+}
+
+impl HashingEmbedder {
+    const DEFAULT_DIMS: usize = 256;
+
+    #[must_use]
+    pub const fn new(dims: usize) -> Self {
+        Self { dims }
+    }
+
+    fn dims_or_default(self) -> usize {
+        if self.dims == 0 {
+            Self::DEFAULT_DIMS
+        } else {
+            self.dims
+        }
+    }
+}
+
+impl TextEmbedder for HashingEmbedder {
+    fn embed(&self, text: &str) -> Vec<f32> {
+        let dims = self.dims_or_default();
+        let mut vector = vec![0.0f32; dims];
+
+        let normalized = text.to_lowercase();
+        let chars: Vec<char> = normalized.chars().collect();
+        if chars.len() < 3 {
+            // Too short for trigrams; fall back to the whole string as one token
+            let bucket = simple_hash(&normalized) % dims;
+            vector[bucket] += 1.0;
+        } else {
+            for window in chars.windows(3) {
+                let trigram: String = window.iter().collect();
+                let bucket = simple_hash(&trigram) % dims;
+                vector[bucket] += 1.0;
+            }
+        }
+
+        let norm = vector.iter().map(|v| v * v).sum::<f32>().sqrt();
+        if norm > 0.0 {
+            for v in &mut vector {
+                *v /= norm;
+            }
+        }
+
+        vector
+    }
+}
+
+/// Levenshtein (edit) distance between two strings, counted in `char`s
+/// rather than bytes so multi-byte UTF-8 sequences count as one edit
+#[must_use]
+pub fn levenshtein_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+
+    let mut prev_row: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, ca) in a.iter().enumerate() {
+        let mut cur_row = vec![i + 1; b.len() + 1];
+        cur_row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let cost = usize::from(ca != cb);
+            cur_row[j + 1] = (cur_row[j] + 1)
+                .min(prev_row[j + 1] + 1)
+                .min(prev_row[j] + cost);
+        }
+        prev_row = cur_row;
+    }
+
+    prev_row[b.len()]
+}
+
+/// Normalize a name for fuzzy comparison: lowercase and treat spaces and
+/// underscores the same, so "United States" and "united_states" line up
+#[must_use]
+pub fn normalize_for_fuzzy_match(name: &str) -> String {
+    name.trim().to_lowercase().replace(' ', "_")
+}
+
+/// The edit-distance budget allowed for a fuzzy name match of the given
+/// (shorter) length: short names tolerate fewer typos before they become a
+/// different name, longer names can absorb a couple more edits
+#[must_use]
+fn edit_distance_budget(shorter_len: usize) -> usize {
+    if shorter_len <= 5 {
+        1
+    } else {
+        2
+    }
+}
+
+/// Whether two entity surface forms refer to the same entity: identical
+/// after normalization, or within a length-scaled Levenshtein budget of
+/// each other (catching residual spelling/typo differences)
+#[must_use]
+pub fn fuzzy_name_match(a: &str, b: &str) -> bool {
+    let a = normalize_for_fuzzy_match(a);
+    let b = normalize_for_fuzzy_match(b);
+    if a == b {
+        return true;
+    }
+    let shorter_len = a.chars().count().min(b.chars().count());
+    levenshtein_distance(&a, &b) <= edit_distance_budget(shorter_len)
+}
+
+/// Index into `aliases` of the surface form with the smallest edit distance
+/// to `name` (e.g. a coreference cluster's mention list), or `None` if none
+/// of them are within the fuzzy match budget
+#[must_use]
+pub fn best_fuzzy_alias_match(name: &str, aliases: &[String]) -> Option<usize> {
+    let normalized = normalize_for_fuzzy_match(name);
+    aliases
+        .iter()
+        .enumerate()
+        .filter_map(|(index, alias)| {
+            let alias = normalize_for_fuzzy_match(alias);
+            let distance = levenshtein_distance(&normalized, &alias);
+            let budget = edit_distance_budget(normalized.chars().count().min(alias.chars().count()));
+            (distance <= budget).then_some((index, distance))
+        })
+        .min_by_key(|&(_, distance)| distance)
+        .map(|(index, _)| index)
+}
+
+fn simple_hash(s: &str) -> usize {
+    // FNV-1a - simple, stable across runs, no extra dependency
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for byte in s.bytes() {
+        hash ^= u64::from(byte);
+        hash = hash.wrapping_mul(0x0100_0000_01b3);
+    }
+    hash as usize
+}
+
+/// Greedy maximum-weight bipartite match between two sets of labeled items
+///
+/// Computes the pairwise similarity of every (predicted, gold) pair via
+/// `embedder`, then greedily assigns the highest-similarity pairs first
+/// (each item used at most once), keeping only matches at or above
+/// `threshold`. Returns `(index_in_predicted, index_in_gold, similarity)`
+/// triples for every accepted match.
+#[must_use]
+pub fn greedy_max_weight_match(
+    predicted: &[String],
+    gold: &[String],
+    embedder: &dyn TextEmbedder,
+    threshold: f32,
+) -> Vec<(usize, usize, f32)> {
+    let predicted_embeddings: Vec<Vec<f32>> = predicted.iter().map(|p| embedder.embed(p)).collect();
+    let gold_embeddings: Vec<Vec<f32>> = gold.iter().map(|g| embedder.embed(g)).collect();
+
+    let mut candidates = Vec::new();
+    for (pi, pe) in predicted_embeddings.iter().enumerate() {
+        for (gi, ge) in gold_embeddings.iter().enumerate() {
+            let similarity = cosine_similarity(pe, ge);
+            if similarity >= threshold {
+                candidates.push((pi, gi, similarity));
+            }
+        }
+    }
+
+    candidates.sort_by(|a, b| b.2.partial_cmp(&a.2).unwrap());
+
+    let mut used_predicted: HashMap<usize, bool> = HashMap::new();
+    let mut used_gold: HashMap<usize, bool> = HashMap::new();
+    let mut matches = Vec::new();
+
+    for (pi, gi, similarity) in candidates {
+        if used_predicted.contains_key(&pi) || used_gold.contains_key(&gi) {
+            continue;
+        }
+        used_predicted.insert(pi, true);
+        used_gold.insert(gi, true);
+        matches.push((pi, gi, similarity));
+    }
+
+    matches
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cosine_similarity_identical() {
+        let a = vec![1.0, 2.0, 3.0];
+        assert!((cosine_similarity(&a, &a) - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_cosine_similarity_zero_vector() {
+        let a = vec![0.0, 0.0];
+        let b = vec![1.0, 1.0];
+        assert_eq!(cosine_similarity(&a, &b), 0.0);
+    }
+
+    #[test]
+    fn test_hashing_embedder_similar_strings() {
+        let embedder = HashingEmbedder::default();
+        let a = embedder.embed("United Kingdom");
+        let b = embedder.embed("United Kingdom of Great Britain");
+        let c = embedder.embed("banana split recipe");
+
+        let similar = cosine_similarity(&a, &b);
+        let dissimilar = cosine_similarity(&a, &c);
+
+        assert!(similar > dissimilar);
+    }
+
+    #[test]
+    fn test_levenshtein_distance() {
+        assert_eq!(levenshtein_distance("kitten", "sitting"), 3);
+        assert_eq!(levenshtein_distance("same", "same"), 0);
+        assert_eq!(levenshtein_distance("", "abc"), 3);
+    }
+
+    #[test]
+    fn test_fuzzy_name_match_underscore_and_case() {
+        assert!(fuzzy_name_match("United States", "UNITED_STATES"));
+        assert!(fuzzy_name_match("united states", "United_States"));
+    }
+
+    #[test]
+    fn test_fuzzy_name_match_within_typo_budget() {
+        assert!(fuzzy_name_match("Alen Bean", "Alan Bean"));
+        assert!(!fuzzy_name_match("Alan Bean", "Alan Shepard"));
+    }
+
+    #[test]
+    fn test_best_fuzzy_alias_match_picks_closest_coreferent_mention() {
+        // "the United States" is too distant a surface form, but the same
+        // coreference cluster also records the bare "United States" mention
+        let aliases = vec!["the United States".to_string(), "United States".to_string()];
+        assert_eq!(best_fuzzy_alias_match("united_states", &aliases), Some(1));
+        assert_eq!(best_fuzzy_alias_match("Germany", &aliases), None);
+    }
+
+    #[test]
+    fn test_best_fuzzy_alias_match_returns_winning_index_not_distance() {
+        // An exact match at index 1 (distance 0) must return Some(1), not
+        // the winning distance itself - the two only coincide when the
+        // winner happens to sit at index 0
+        let aliases = vec!["Germany".to_string(), "United States".to_string()];
+        assert_eq!(best_fuzzy_alias_match("united_states", &aliases), Some(1));
+    }
+
+    #[test]
+    fn test_greedy_max_weight_match() {
+        let predicted = vec!["United Kingdom".to_string(), "banana".to_string()];
+        let gold = vec!["United Kingdom of Great Britain".to_string()];
+        let embedder = HashingEmbedder::default();
+
+        let matches = greedy_max_weight_match(&predicted, &gold, &embedder, 0.3);
+
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].0, 0);
+        assert_eq!(matches[0].1, 0);
+    }
+}
@@ -9,16 +9,25 @@
 //! Supports both remote APIs (DBpedia, Wikidata) and local Rust-native linking
 //! via Oxigraph for production deployments.
 
+use crate::bk_tree::BkTree;
+use crate::embedding::{cosine_similarity, HashingEmbedder, TextEmbedder};
 use crate::error::{Error, Result};
+use crate::linking_cache::{IngestTracker, LinkingCache};
 use cached::proc_macro::cached;
+use futures::future::join_all;
+use oxigraph::io::RdfFormat;
 use oxigraph::model::Term;
 use oxigraph::sparql::QueryResults;
 use oxigraph::store::Store;
 use reqwest::Client;
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
 use std::path::PathBuf;
-use std::sync::Arc;
-use std::time::Duration;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+#[cfg(feature = "telemetry")]
+use opentelemetry::KeyValue;
 
 /// Configuration for entity linking
 #[derive(Debug, Clone)]
@@ -43,6 +52,43 @@ pub struct EntityLinkerConfig {
     pub use_llm_disambiguation: bool,
     /// Minimum number of candidates to trigger LLM disambiguation
     pub min_candidates_for_llm: usize,
+    /// Embedding model/endpoint used by the `Embedding` strategy's
+    /// cross-document entity resolution (see
+    /// [`KnowledgeBuffer`](crate::knowledge_buffer::KnowledgeBuffer) and
+    /// [`EmbeddingEntityIndex`](crate::knowledge_buffer::EmbeddingEntityIndex)).
+    /// `"hashing"` selects the dependency-free [`HashingEmbedder`](crate::embedding::HashingEmbedder); any
+    /// other value is reserved for a future real sentence-embedding backend.
+    pub embedding_model: String,
+    /// Minimum cosine similarity for the `Embedding` strategy to treat two
+    /// surface forms as the same entity
+    pub embedding_similarity_threshold: f64,
+    /// Path to persist the embedding-based entity index across runs, so
+    /// canonical IRIs stay stable across a corpus instead of resetting per
+    /// document
+    pub embedding_index_path: Option<PathBuf>,
+    /// For the `Local` strategy: also retrieve candidates by embedding
+    /// cosine similarity against a precomputed index of KB labels, catching
+    /// semantically equivalent but lexically different labels (e.g. "NYC"
+    /// vs "New York City") that exact SPARQL or BK-tree/Jaro-Winkler fuzzy
+    /// matching misses. Candidates from both retrieval methods compose before
+    /// confidence filtering and LLM disambiguation.
+    pub use_embeddings: bool,
+    /// Directory for the persistent `(strategy, surface_form, context)` ->
+    /// linking-decision cache (see [`crate::linking_cache::LinkingCache`]).
+    /// `None` disables the disk cache, so linking decisions are recomputed
+    /// on every process restart (remote strategies still get the in-memory
+    /// `#[cached]` layer regardless).
+    pub linking_cache_dir: Option<PathBuf>,
+    /// How long a cached linking decision stays valid before being
+    /// recomputed, in seconds
+    pub linking_cache_ttl_secs: u64,
+    /// Gzip-compress entries written to the linking cache, trading a little
+    /// CPU for smaller stored objects - most worthwhile for the
+    /// [`S3CacheBackend`](crate::object_cache::S3CacheBackend), where every
+    /// cached entry is a network round-trip. Only applies to the default
+    /// `linking_cache_dir`-backed cache; a cache installed via
+    /// [`EntityLinker::with_linking_cache`] controls its own compression.
+    pub linking_cache_compress: bool,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -53,6 +99,10 @@ pub enum LinkingStrategy {
     DbpediaSpotlight,
     /// Use Wikidata API
     Wikidata,
+    /// Resolve entities by embedding similarity against an in-memory index,
+    /// instead of exact name matching - catches surface-form variants like
+    /// "Marie Curie" vs "Marie Skłodowska Curie"
+    Embedding,
     /// Disable entity linking (use normalized names only)
     None,
 }
@@ -70,6 +120,13 @@ impl Default for EntityLinkerConfig {
             fuzzy_threshold: 0.8,
             use_llm_disambiguation: true,
             min_candidates_for_llm: 2,
+            embedding_model: "hashing".to_string(),
+            embedding_similarity_threshold: 0.85,
+            embedding_index_path: None,
+            use_embeddings: false,
+            linking_cache_dir: None,
+            linking_cache_ttl_secs: 86400,
+            linking_cache_compress: false,
         }
     }
 }
@@ -81,6 +138,22 @@ pub struct EntityLinker {
     store: Option<Arc<Store>>,
     /// GenAI client for LLM dis ambiguation
     llm_client: Option<genai::Client>,
+    /// Precomputed KB label embedding index, built when
+    /// `config.use_embeddings` is set (see [`EmbeddingCandidateIndex`])
+    embedding_index: Option<BruteForceEmbeddingIndex>,
+    /// BK-tree of KB labels for typo-tolerant fuzzy candidate retrieval,
+    /// built on construction for the `Local` strategy (see
+    /// [`build_label_index`])
+    label_index: Option<BkTree<LabelIndexEntry>>,
+    /// Persistent linking-decision cache, present when
+    /// `config.linking_cache_dir` is set
+    cache: Option<LinkingCache>,
+    /// Tracks which local-KB RDF sources have been ingested and their
+    /// checksums (see [`Self::ingest`]); present alongside `cache`
+    ingest_tracker: Option<IngestTracker>,
+    /// Accumulated telemetry across every `link_entity`/`link_entities` call
+    /// (see [`Self::metrics_snapshot`])
+    metrics: Arc<Mutex<LinkingMetrics>>,
 }
 
 impl std::fmt::Debug for EntityLinker {
@@ -89,10 +162,292 @@ impl std::fmt::Debug for EntityLinker {
             .field("config", &self.config)
             .field("store", &self.store.as_ref().map(|_| "Store"))
             .field("llm_client", &self.llm_client.as_ref().map(|_| "Client"))
+            .field("embedding_index", &self.embedding_index.as_ref().map(|i| i.entries.len()))
+            .field("label_index", &self.label_index.as_ref().map(|_| "BkTree"))
+            .field("cache", &self.cache.as_ref().map(|_| "LinkingCache"))
             .finish()
     }
 }
 
+/// Filename of the persisted [`IngestTracker`] state under
+/// `EntityLinkerConfig::linking_cache_dir`
+const INGEST_STATE_FILENAME: &str = "ingest_state.json";
+
+/// Hash the surrounding `text` a [`LinkedEntity`] was linked from, so
+/// [`LinkingCache`] keys the same surface form differently across distinct
+/// contexts instead of conflating them
+fn context_hash(text: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(text.trim().as_bytes());
+    hasher.finalize().iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+/// Retrieves entity-linking candidates by embedding cosine similarity
+/// against a precomputed index of KB labels
+///
+/// Abstracted behind a trait so the brute-force [`BruteForceEmbeddingIndex`]
+/// below - adequate for the small-to-medium KBs this linker targets - can
+/// later be swapped for an approximate index (e.g. HNSW) at scale, without
+/// changing `link_with_local`.
+trait EmbeddingCandidateIndex {
+    /// Candidates whose label embedding's cosine similarity to `query` meets
+    /// `threshold`, as [`LinkedEntity`] values with `confidence` set to that
+    /// similarity
+    fn search(&self, query: &[f32], threshold: f64) -> Vec<LinkedEntity>;
+}
+
+/// One precomputed `(uri, label, types, embedding)` entry in a
+/// [`BruteForceEmbeddingIndex`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct EmbeddingIndexEntry {
+    uri: String,
+    label: String,
+    types: Vec<String>,
+    vector: Vec<f32>,
+}
+
+/// A KB label embedding index searched by brute-force cosine similarity,
+/// persisted as JSON alongside the Oxigraph store at
+/// `EntityLinkerConfig::embedding_index_path`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct BruteForceEmbeddingIndex {
+    entries: Vec<EmbeddingIndexEntry>,
+}
+
+impl EmbeddingCandidateIndex for BruteForceEmbeddingIndex {
+    fn search(&self, query: &[f32], threshold: f64) -> Vec<LinkedEntity> {
+        let mut matches: Vec<LinkedEntity> = self
+            .entries
+            .iter()
+            .filter_map(|entry| {
+                let similarity = f64::from(cosine_similarity(query, &entry.vector));
+                (similarity >= threshold).then(|| LinkedEntity {
+                    surface_form: entry.label.clone(),
+                    uri: entry.uri.clone(),
+                    types: entry.types.clone(),
+                    confidence: similarity,
+                })
+            })
+            .collect();
+
+        matches.sort_by(|a, b| b.confidence.partial_cmp(&a.confidence).unwrap());
+        matches
+    }
+}
+
+/// Merge `additional` candidates into `candidates`, deduplicating by `uri`
+/// and keeping whichever confidence is higher - the point where the
+/// SPARQL/fuzzy and embedding retrieval methods compose into one candidate
+/// set before confidence filtering and LLM disambiguation
+fn merge_candidates(candidates: &mut Vec<LinkedEntity>, additional: Vec<LinkedEntity>) {
+    for candidate in additional {
+        match candidates.iter_mut().find(|c| c.uri == candidate.uri) {
+            Some(existing) if candidate.confidence > existing.confidence => *existing = candidate,
+            Some(_) => {}
+            None => candidates.push(candidate),
+        }
+    }
+}
+
+/// Query every `rdfs:label`/`schema:name` literal in `store`, grouped by
+/// entity URI into its (first) label and the set of `rdf:type` values found
+/// for it - the shared first step of [`build_embedding_index`] and
+/// [`build_label_index`]
+fn collect_labeled_entities(store: &Store) -> Result<HashMap<String, (String, Vec<String>)>> {
+    let query = r#"
+        PREFIX rdfs: <http://www.w3.org/2000/01/rdf-schema#>
+        PREFIX schema: <http://schema.org/>
+        PREFIX rdf: <http://www.w3.org/1999/02/22-rdf-syntax-ns#>
+
+        SELECT ?entity ?label ?type WHERE {
+            { ?entity rdfs:label ?label . }
+            UNION
+            { ?entity schema:name ?label . }
+            OPTIONAL { ?entity rdf:type ?type }
+        }
+    "#;
+
+    #[allow(deprecated)]
+    let results = store
+        .query(query)
+        .map_err(|e| Error::Extraction(format!("SPARQL query failed: {e}")))?;
+
+    let mut by_uri: HashMap<String, (String, Vec<String>)> = HashMap::new();
+
+    if let QueryResults::Solutions(solutions) = results {
+        for solution in solutions {
+            let solution = solution.map_err(|e| Error::Extraction(format!("Query solution error: {e}")))?;
+            let Some(Term::NamedNode(entity_node)) = solution.get("entity") else {
+                continue;
+            };
+
+            let label = solution.get("label").and_then(|t| match t {
+                Term::Literal(lit) => Some(lit.value().to_string()),
+                _ => None,
+            });
+            let entity_type = solution.get("type").and_then(|t| match t {
+                Term::NamedNode(node) => Some(node.as_str().to_string()),
+                _ => None,
+            });
+
+            let entry = by_uri
+                .entry(entity_node.as_str().to_string())
+                .or_insert_with(|| (String::new(), Vec::new()));
+            if entry.0.is_empty() {
+                if let Some(label) = label {
+                    entry.0 = label;
+                }
+            }
+            if let Some(entity_type) = entity_type {
+                if !entry.1.contains(&entity_type) {
+                    entry.1.push(entity_type);
+                }
+            }
+        }
+    }
+
+    Ok(by_uri)
+}
+
+/// Precompute an embedding for every `rdfs:label`/`schema:name` in `store`
+/// using the dependency-free [`HashingEmbedder`] (the only embedder wired up
+/// so far - see [`EntityLinkerConfig::embedding_model`]'s doc comment)
+fn build_embedding_index(store: &Store) -> Result<BruteForceEmbeddingIndex> {
+    let embedder = HashingEmbedder::default();
+
+    let entries = collect_labeled_entities(store)?
+        .into_iter()
+        .filter(|(_, (label, _))| !label.is_empty())
+        .map(|(uri, (label, types))| EmbeddingIndexEntry {
+            vector: embedder.embed(&label),
+            uri,
+            label,
+            types,
+        })
+        .collect();
+
+    Ok(BruteForceEmbeddingIndex { entries })
+}
+
+/// One KB entity indexed under its label in a [`BkTree`] for typo-tolerant
+/// fuzzy candidate retrieval (see [`build_label_index`])
+#[derive(Debug, Clone)]
+struct LabelIndexEntry {
+    uri: String,
+    types: Vec<String>,
+}
+
+/// Build a BK-tree over every KB label (`rdfs:label`/`schema:name`) in
+/// `store`, keyed by Levenshtein distance - replaces the old SPARQL
+/// `CONTAINS`+`LIMIT 50` candidate query in
+/// [`EntityLinker::fuzzy_search_candidates`], which missed transpositions
+/// like "Einstien" and silently dropped candidates past the limit on large
+/// KBs
+fn build_label_index(store: &Store) -> Result<BkTree<LabelIndexEntry>> {
+    let mut index = BkTree::new();
+
+    for (uri, (label, types)) in collect_labeled_entities(store)? {
+        if !label.is_empty() {
+            index.insert(label, LabelIndexEntry { uri, types });
+        }
+    }
+
+    Ok(index)
+}
+
+/// Edit-distance budget for a [`BkTree`] fuzzy search of `entity_name`,
+/// derived by scaling `fuzzy_threshold` (treated as the fraction of
+/// `entity_name`'s length that must match) by its length - longer names
+/// tolerate more edits before they become a different name, matching
+/// `fuzzy_threshold`'s existing 0.0-1.0 meaning
+fn fuzzy_match_budget(entity_name: &str, fuzzy_threshold: f64) -> usize {
+    let len = entity_name.chars().count().max(1) as f64;
+    (((1.0 - fuzzy_threshold) * len).round() as usize).max(1)
+}
+
+/// Load a persisted embedding index from `config.embedding_index_path` if
+/// present and valid, otherwise build one from `store` and persist it there
+fn load_or_build_embedding_index(store: &Store, config: &EntityLinkerConfig) -> Result<BruteForceEmbeddingIndex> {
+    if let Some(path) = &config.embedding_index_path {
+        if let Ok(bytes) = std::fs::read(path) {
+            if let Ok(index) = serde_json::from_slice::<BruteForceEmbeddingIndex>(&bytes) {
+                return Ok(index);
+            }
+        }
+    }
+
+    let index = build_embedding_index(store)?;
+
+    if let Some(path) = &config.embedding_index_path {
+        let bytes = serde_json::to_vec(&index).map_err(Error::from)?;
+        std::fs::write(path, bytes)?;
+    }
+
+    Ok(index)
+}
+
+/// Telemetry accumulated across every `link_entity`/`link_entities` call on
+/// an [`EntityLinker`], so callers can tune `confidence_threshold`,
+/// `fuzzy_threshold`, and `min_candidates_for_llm` from real data and detect
+/// a high NIL-linking rate instead of guessing. Snapshot with
+/// [`EntityLinker::metrics_snapshot`].
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+pub struct LinkingMetrics {
+    /// Total entities linked (across both `link_entity` and `link_entities`)
+    pub calls: u64,
+    /// Calls that resolved to no match
+    pub nil_links: u64,
+    /// Sum of candidate counts retrieved before confidence/fuzzy filtering
+    pub total_raw_candidates: u64,
+    /// Sum of candidate counts surviving confidence/fuzzy filtering
+    pub total_filtered_candidates: u64,
+    /// Calls where LLM disambiguation ran
+    pub llm_disambiguations: u64,
+    /// Persistent-cache ([`LinkingCache`]) hits
+    pub cache_hits: u64,
+    /// Persistent-cache misses, including calls made with no cache configured
+    pub cache_misses: u64,
+    /// Cumulative milliseconds spent retrieving candidates (exact SPARQL query
+    /// or BK-tree fuzzy search, or the equivalent remote API call for
+    /// non-`Local` strategies)
+    pub sparql_millis: u64,
+    /// Cumulative milliseconds spent on embedding/fuzzy candidate ranking
+    pub ranking_millis: u64,
+    /// Cumulative milliseconds spent in LLM disambiguation round-trips
+    pub llm_millis: u64,
+}
+
+/// Per-call telemetry returned alongside a [`LinkedEntity`] by
+/// [`EntityLinker::link_entity_with_report`]
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+pub struct LinkingReport {
+    /// Candidates retrieved before confidence/fuzzy filtering
+    pub raw_candidates: usize,
+    /// Candidates remaining after confidence/fuzzy filtering
+    pub filtered_candidates: usize,
+    /// Whether LLM disambiguation ran for this call
+    pub llm_disambiguation_used: bool,
+    /// The selected entity's confidence, or `None` if nothing was linked
+    pub selected_confidence: Option<f64>,
+    /// Whether this call was served from the persistent [`LinkingCache`]
+    pub cache_hit: bool,
+    /// Milliseconds spent retrieving candidates
+    pub sparql_millis: u64,
+    /// Milliseconds spent on embedding/fuzzy candidate ranking
+    pub ranking_millis: u64,
+    /// Milliseconds spent in an LLM disambiguation round-trip
+    pub llm_millis: u64,
+}
+
+/// Timing/count breakdown for one [`EntityLinker::retrieve_local_candidates`]
+/// call, folded into a [`LinkingReport`] or batch totals by its caller
+#[derive(Debug, Clone, Copy, Default)]
+struct CandidateRetrievalStats {
+    sparql_millis: u64,
+    ranking_millis: u64,
+    raw_count: usize,
+}
+
 /// Result of entity linking
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LinkedEntity {
@@ -124,6 +479,61 @@ struct DbpediaResource {
     confidence: f64,
 }
 
+/// Base URL for the Wikidata action API (`wbsearchentities`, `wbgetentities`)
+const WIKIDATA_API_URL: &str = "https://www.wikidata.org/w/api.php";
+
+/// Maximum number of `wbsearchentities` results requested per lookup
+const WIKIDATA_SEARCH_LIMIT: u32 = 10;
+
+#[derive(Debug, Deserialize)]
+struct WikidataSearchResponse {
+    search: Vec<WikidataSearchResult>,
+}
+
+#[derive(Debug, Deserialize)]
+struct WikidataSearchResult {
+    id: String,
+    label: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct WikidataEntitiesResponse {
+    entities: HashMap<String, WikidataEntity>,
+}
+
+#[derive(Debug, Deserialize)]
+struct WikidataEntity {
+    claims: Option<HashMap<String, Vec<WikidataClaim>>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct WikidataClaim {
+    mainsnak: WikidataMainSnak,
+}
+
+#[derive(Debug, Deserialize)]
+struct WikidataMainSnak {
+    datavalue: Option<WikidataDataValue>,
+}
+
+#[derive(Debug, Deserialize)]
+struct WikidataDataValue {
+    value: WikidataEntityIdValue,
+}
+
+#[derive(Debug, Deserialize)]
+struct WikidataEntityIdValue {
+    id: String,
+}
+
+/// One entity's selection in a batched LLM disambiguation response (see
+/// [`EntityLinker::disambiguate_batch_with_llm`])
+#[derive(Debug, Deserialize)]
+struct BatchDisambiguationSelection {
+    surface_form: String,
+    selected_index: usize,
+}
+
 impl EntityLinker {
     /// Create a new entity linker with the given configuration
     pub fn new(config: EntityLinkerConfig) -> Result<Self> {
@@ -149,31 +559,242 @@ impl EntityLinker {
             None
         };
 
-        Ok(Self { config, store, llm_client })
+        let embedding_index = if config.use_embeddings {
+            let store = store.as_ref().ok_or_else(|| {
+                Error::Config("use_embeddings requires the Local strategy".to_string())
+            })?;
+            Some(load_or_build_embedding_index(store, &config)?)
+        } else {
+            None
+        };
+
+        let label_index = store.as_ref().map(|store| build_label_index(store)).transpose()?;
+
+        let cache = config
+            .linking_cache_dir
+            .as_ref()
+            .map(|dir| {
+                let backend = Arc::new(crate::object_cache::FilesystemCacheBackend::open(dir)?);
+                Ok::<_, Error>(LinkingCache::with_backend(
+                    backend,
+                    config.linking_cache_ttl_secs,
+                    config.linking_cache_compress,
+                ))
+            })
+            .transpose()?;
+        let ingest_tracker = config
+            .linking_cache_dir
+            .as_ref()
+            .map(|dir| IngestTracker::load(&dir.join(INGEST_STATE_FILENAME)))
+            .transpose()?;
+
+        Ok(Self {
+            config,
+            store,
+            llm_client,
+            embedding_index,
+            label_index,
+            cache,
+            ingest_tracker,
+            metrics: Arc::new(Mutex::new(LinkingMetrics::default())),
+        })
+    }
+
+    /// Replace the persistent linking cache built from `linking_cache_dir`
+    /// with `cache`, e.g. one built on
+    /// [`S3CacheBackend`](crate::object_cache::S3CacheBackend) to share
+    /// cached links across machines instead of the default local `sled`
+    /// database. Building an `S3CacheBackend` is async (it dials the
+    /// object store), so it can't happen inside [`Self::new`] - build it
+    /// and wrap it in a [`LinkingCache`] first, then install it here.
+    #[must_use]
+    pub fn with_linking_cache(mut self, cache: LinkingCache) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
+    /// A snapshot of telemetry accumulated so far across every
+    /// `link_entity`/`link_entities` call
+    #[must_use]
+    pub fn metrics_snapshot(&self) -> LinkingMetrics {
+        *self.metrics.lock().unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+
+    /// Fold one call's [`LinkingReport`] into the accumulated
+    /// [`LinkingMetrics`], and - when built with `--features telemetry` -
+    /// into the process-wide linking-confidence histogram
+    fn record_report(&self, report: &LinkingReport) {
+        #[cfg(feature = "telemetry")]
+        if let Some(confidence) = report.selected_confidence {
+            crate::telemetry::pipeline_metrics().linking_confidence.record(confidence, &[]);
+        }
+
+        let mut metrics = self.metrics.lock().unwrap_or_else(std::sync::PoisonError::into_inner);
+        metrics.calls += 1;
+        if report.selected_confidence.is_none() {
+            metrics.nil_links += 1;
+        }
+        metrics.total_raw_candidates += report.raw_candidates as u64;
+        metrics.total_filtered_candidates += report.filtered_candidates as u64;
+        if report.llm_disambiguation_used {
+            metrics.llm_disambiguations += 1;
+        }
+        if report.cache_hit {
+            metrics.cache_hits += 1;
+        } else {
+            metrics.cache_misses += 1;
+        }
+        metrics.sparql_millis += report.sparql_millis;
+        metrics.ranking_millis += report.ranking_millis;
+        metrics.llm_millis += report.llm_millis;
+    }
+
+    /// Fold one [`Self::link_entities_batch_local`] call's aggregate stats
+    /// into the accumulated [`LinkingMetrics`] - the batch path never
+    /// consults the persistent cache, so every entity counts as a cache miss
+    #[allow(clippy::too_many_arguments)]
+    fn record_batch(
+        &self,
+        entity_count: usize,
+        nil_count: usize,
+        raw_total: usize,
+        filtered_total: usize,
+        llm_disambiguations: usize,
+        sparql_millis: u64,
+        ranking_millis: u64,
+        llm_millis: u64,
+    ) {
+        let mut metrics = self.metrics.lock().unwrap_or_else(std::sync::PoisonError::into_inner);
+        metrics.calls += entity_count as u64;
+        metrics.nil_links += nil_count as u64;
+        metrics.total_raw_candidates += raw_total as u64;
+        metrics.total_filtered_candidates += filtered_total as u64;
+        metrics.llm_disambiguations += llm_disambiguations as u64;
+        metrics.cache_misses += entity_count as u64;
+        metrics.sparql_millis += sparql_millis;
+        metrics.ranking_millis += ranking_millis;
+        metrics.llm_millis += llm_millis;
+    }
+
+    /// (Re-)load `sources` into the local KB store, skipping sources whose
+    /// content checksum hasn't changed since the last ingest, then persist
+    /// the updated [`IngestTracker`] state and invalidate any
+    /// [`LinkingCache`] entries computed against the prior KB version
+    ///
+    /// Requires `config.linking_cache_dir` to be set, since that's where
+    /// ingest state is tracked across process restarts.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the local store isn't initialized, if
+    /// `linking_cache_dir` isn't set, or if a source can't be read/ingested.
+    pub async fn ingest(&mut self, sources: &[PathBuf], format: RdfFormat) -> Result<Vec<PathBuf>> {
+        let cache_dir = self.config.linking_cache_dir.as_ref().ok_or_else(|| {
+            Error::Config("ingest requires linking_cache_dir to be set".to_string())
+        })?;
+        let store = self.store.as_ref().ok_or_else(|| {
+            Error::Config("Local store not initialized".to_string())
+        })?;
+
+        let tracker = self.ingest_tracker.get_or_insert_with(IngestTracker::new);
+        let refreshed = tracker.ingest(store, sources, format)?;
+        tracker.save(&cache_dir.join(INGEST_STATE_FILENAME))?;
+
+        if !refreshed.is_empty() {
+            if let Some(cache) = &self.cache {
+                cache.invalidate_stale(&tracker.kb_version()).await?;
+            }
+        }
+
+        Ok(refreshed)
     }
 
     /// Link an entity name to a canonical URI
     ///
-    /// Returns None if linking is disabled or no match found above confidence threshold
+    /// Returns None if linking is disabled or no match found above confidence
+    /// threshold. Equivalent to [`Self::link_entity_with_report`] with the
+    /// [`LinkingReport`] discarded; see that method for caching/telemetry
+    /// details.
     pub async fn link_entity(
         &self,
         text: &str,
         entity_name: &str,
-        _entity_type: Option<&str>,
+        entity_type: Option<&str>,
     ) -> Result<Option<LinkedEntity>> {
+        self.link_entity_with_report(text, entity_name, entity_type)
+            .await
+            .map(|(entity, _report)| entity)
+    }
+
+    /// Link an entity name to a canonical URI, returning a [`LinkingReport`]
+    /// describing how it was resolved alongside the result
+    ///
+    /// Checks the persistent [`LinkingCache`] first when
+    /// `config.linking_cache_dir` is set, falling back to
+    /// [`Self::link_entity_uncached`] on a miss and writing the result back.
+    /// Every call (cache hit or miss) is folded into the accumulated
+    /// [`LinkingMetrics`] (see [`Self::metrics_snapshot`]), except for calls
+    /// made while linking is disabled.
+    pub async fn link_entity_with_report(
+        &self,
+        text: &str,
+        entity_name: &str,
+        entity_type: Option<&str>,
+    ) -> Result<(Option<LinkedEntity>, LinkingReport)> {
         if !self.config.enabled {
-            return Ok(None);
+            return Ok((None, LinkingReport::default()));
         }
 
+        let mut report = LinkingReport::default();
+
+        let linked = if let Some(cache) = &self.cache {
+            let kb_version = self.ingest_tracker.as_ref().map_or_else(String::new, IngestTracker::kb_version);
+            let key = LinkingCache::key(&self.config.strategy, entity_name, entity_type, &context_hash(text));
+
+            if let Some(hit) = cache.get(&key, &kb_version).await? {
+                report.cache_hit = true;
+                hit
+            } else {
+                let linked = self.link_entity_uncached(text, entity_name, entity_type, &mut report).await?;
+                cache.put(&key, linked.clone(), &kb_version).await?;
+                linked
+            }
+        } else {
+            self.link_entity_uncached(text, entity_name, entity_type, &mut report).await?
+        };
+
+        report.selected_confidence = linked.as_ref().map(|e| e.confidence);
+        self.record_report(&report);
+        Ok((linked, report))
+    }
+
+    /// The actual per-strategy linking logic behind [`Self::link_entity`]'s
+    /// persistent-cache check, filling in `report`'s candidate counts and
+    /// timings along the way
+    async fn link_entity_uncached(
+        &self,
+        text: &str,
+        entity_name: &str,
+        entity_type: Option<&str>,
+        report: &mut LinkingReport,
+    ) -> Result<Option<LinkedEntity>> {
         match self.config.strategy {
             LinkingStrategy::Local => {
-                self.link_with_local(entity_name, _entity_type).await
+                self.link_with_local(entity_name, entity_type, report).await
             }
             LinkingStrategy::DbpediaSpotlight => {
-                self.link_with_dbpedia(text, entity_name).await
+                self.link_with_dbpedia(text, entity_name, report).await
             }
             LinkingStrategy::Wikidata => {
-                // Wikidata API implementation would go here
+                self.link_with_wikidata(entity_name, entity_type, report).await
+            }
+            LinkingStrategy::Embedding => {
+                // Embedding-based resolution works against a shared,
+                // persisted index rather than a single canonical URI per
+                // call, so it runs via
+                // `EmbeddingEntityIndex::resolve_or_insert` in the
+                // extraction pipeline (see `extract_from_document`) instead
+                // of through this single-entity linking path.
                 Ok(None)
             }
             LinkingStrategy::None => Ok(None),
@@ -185,20 +806,69 @@ impl EntityLinker {
         &self,
         text: &str,
         entity_name: &str,
+        report: &mut LinkingReport,
     ) -> Result<Option<LinkedEntity>> {
+        #[cfg(feature = "telemetry")]
+        let _linking_span = {
+            use opentelemetry::trace::Tracer;
+            opentelemetry::global::tracer(crate::telemetry::PIPELINE_SCOPE)
+                .start("extract.entity_linking.dbpedia")
+        };
+
+        let request_start = Instant::now();
         // Use cached version to avoid repeated API calls
-        link_with_dbpedia_cached(
+        let entities = link_with_dbpedia_cached(
             self.config.service_url.clone(),
             text.to_string(),
             self.config.confidence_threshold,
         )
-        .await
-        .map(|entities| {
-            // Find the entity that best matches the given name
-            entities
-                .into_iter()
-                .find(|e| e.surface_form.to_lowercase() == entity_name.to_lowercase())
-        })
+        .await?;
+        report.sparql_millis = request_start.elapsed().as_millis() as u64;
+
+        #[cfg(feature = "telemetry")]
+        crate::telemetry::pipeline_metrics().stage_latency.record(
+            request_start.elapsed().as_secs_f64(),
+            &[KeyValue::new("stage", "entity_linking")],
+        );
+        report.raw_candidates = entities.len();
+
+        // Find the entity that best matches the given name
+        let linked = entities
+            .into_iter()
+            .find(|e| e.surface_form.to_lowercase() == entity_name.to_lowercase());
+        report.filtered_candidates = usize::from(linked.is_some());
+        Ok(linked)
+    }
+
+    /// Link entity using the Wikidata `wbsearchentities` action API
+    async fn link_with_wikidata(
+        &self,
+        entity_name: &str,
+        entity_type: Option<&str>,
+        report: &mut LinkingReport,
+    ) -> Result<Option<LinkedEntity>> {
+        let request_start = Instant::now();
+        let mut candidates = link_with_wikidata_cached(entity_name.to_string()).await?;
+        report.sparql_millis = request_start.elapsed().as_millis() as u64;
+        report.raw_candidates = candidates.len();
+
+        candidates.retain(|c| c.confidence >= self.config.confidence_threshold);
+        report.filtered_candidates = candidates.len();
+
+        if candidates.is_empty() {
+            return Ok(None);
+        }
+
+        if candidates.len() >= self.config.min_candidates_for_llm && self.config.use_llm_disambiguation {
+            report.llm_disambiguation_used = true;
+            let llm_start = Instant::now();
+            let result = self.disambiguate_with_llm(entity_name, entity_type, &candidates).await;
+            report.llm_millis = llm_start.elapsed().as_millis() as u64;
+            return result;
+        }
+
+        candidates.sort_by(|a, b| b.confidence.partial_cmp(&a.confidence).unwrap());
+        Ok(candidates.into_iter().next())
     }
 
     /// Link entity using local Oxigraph-based knowledge base
@@ -209,36 +879,80 @@ impl EntityLinker {
         &self,
         entity_name: &str,
         entity_type: Option<&str>,
+        report: &mut LinkingReport,
     ) -> Result<Option<LinkedEntity>> {
         let store = self.store.as_ref().ok_or_else(|| {
             Error::Config("Local store not initialized".to_string())
         })?;
 
-        // Step 1: Retrieve candidates using fuzzy or exact matching
-        let mut candidates = if self.config.use_fuzzy_matching {
-            self.fuzzy_search_candidates(store, entity_name)?
-        } else {
-            self.exact_search_candidates(store, entity_name)?
-        };
-
-        // Step 2: Filter by confidence threshold
-        candidates.retain(|c| c.confidence >= self.config.confidence_threshold);
+        let (mut candidates, stats) = self.retrieve_local_candidates(store, entity_name)?;
+        report.sparql_millis = stats.sparql_millis;
+        report.ranking_millis = stats.ranking_millis;
+        report.raw_candidates = stats.raw_count;
+        report.filtered_candidates = candidates.len();
 
         if candidates.is_empty() {
             return Ok(None);
         }
 
-        // Step 3: If multiple candidates exist, use LLM disambiguation
+        // If multiple candidates exist, use LLM disambiguation
         if candidates.len() >= self.config.min_candidates_for_llm
             && self.config.use_llm_disambiguation {
-            return self.disambiguate_with_llm(entity_name, entity_type, &candidates).await;
+            report.llm_disambiguation_used = true;
+            let llm_start = Instant::now();
+            let result = self.disambiguate_with_llm(entity_name, entity_type, &candidates).await;
+            report.llm_millis = llm_start.elapsed().as_millis() as u64;
+            return result;
         }
 
-        // Step 4: Return best match (highest confidence)
+        // Return best match (highest confidence)
         candidates.sort_by(|a, b| b.confidence.partial_cmp(&a.confidence).unwrap());
         Ok(candidates.into_iter().next())
     }
 
+    /// Retrieve and confidence-filter local KB candidates for `entity_name`,
+    /// timing the SPARQL/fuzzy retrieval step and the embedding-ranking step
+    /// separately
+    ///
+    /// Combines exact SPARQL or BK-tree fuzzy search with the optional embedding index,
+    /// shared by [`Self::link_with_local`] and the batched
+    /// [`Self::link_entities_batch_local`] so both paths retrieve candidates
+    /// the same way.
+    fn retrieve_local_candidates(
+        &self,
+        store: &Store,
+        entity_name: &str,
+    ) -> Result<(Vec<LinkedEntity>, CandidateRetrievalStats)> {
+        // Step 1: Retrieve candidates using fuzzy or exact matching
+        let sparql_start = Instant::now();
+        let mut candidates = if self.config.use_fuzzy_matching {
+            self.fuzzy_search_candidates(entity_name)?
+        } else {
+            self.exact_search_candidates(store, entity_name)?
+        };
+        let sparql_millis = sparql_start.elapsed().as_millis() as u64;
+
+        // Step 1b: Also retrieve candidates by embedding similarity, for
+        // semantically equivalent but lexically different labels that
+        // exact SPARQL or BK-tree/Jaro-Winkler fuzzy matching misses (e.g. "NYC" vs "New
+        // York City"), and merge them into the same candidate set.
+        let ranking_start = Instant::now();
+        if let Some(embedding_index) = &self.embedding_index {
+            let query_vector = HashingEmbedder::default().embed(entity_name);
+            let embedding_candidates =
+                embedding_index.search(&query_vector, self.config.embedding_similarity_threshold);
+            merge_candidates(&mut candidates, embedding_candidates);
+        }
+
+        let raw_count = candidates.len();
+
+        // Step 2: Filter by confidence threshold
+        candidates.retain(|c| c.confidence >= self.config.confidence_threshold);
+        let ranking_millis = ranking_start.elapsed().as_millis() as u64;
+
+        Ok((candidates, CandidateRetrievalStats { sparql_millis, ranking_millis, raw_count }))
+    }
+
     /// Exact search for entity labels (original behavior)
     fn exact_search_candidates(
         &self,
@@ -270,36 +984,32 @@ impl EntityLinker {
         self.execute_candidate_query(store, &query, entity_name, true)
     }
 
-    /// Fuzzy search using Levenshtein/Jaro-Winkler distance
-    fn fuzzy_search_candidates(
-        &self,
-        store: &Store,
-        entity_name: &str,
-    ) -> Result<Vec<LinkedEntity>> {
-        // Query for similar labels (broader search)
-        let query = format!(
-            r#"
-            PREFIX rdfs: <http://www.w3.org/2000/01/rdf-schema#>
-            PREFIX schema: <http://schema.org/>
-            PREFIX rdf: <http://www.w3.org/1999/02/22-rdf-syntax-ns#>
+    /// Typo-tolerant fuzzy search over the [`BkTree`] label index built on
+    /// construction (see [`build_label_index`]), instead of a SPARQL
+    /// `CONTAINS`+`LIMIT` scan
+    fn fuzzy_search_candidates(&self, entity_name: &str) -> Result<Vec<LinkedEntity>> {
+        let label_index = self.label_index.as_ref().ok_or_else(|| {
+            Error::Config("Local label index not initialized".to_string())
+        })?;
 
-            SELECT ?entity ?label ?type WHERE {{
-                {{
-                    ?entity rdfs:label ?label .
-                    FILTER(CONTAINS(LCASE(STR(?label)), LCASE("{}")))
-                }} UNION {{
-                    ?entity schema:name ?label .
-                    FILTER(CONTAINS(LCASE(STR(?label)), LCASE("{}")))
-                }}
-                OPTIONAL {{ ?entity rdf:type ?type }}
-            }}
-            LIMIT 50
-            "#,
-            entity_name.replace('"', "\\\""),
-            entity_name.replace('"', "\\\"")
-        );
+        let max_distance = fuzzy_match_budget(entity_name, self.config.fuzzy_threshold);
+
+        let mut candidates: Vec<LinkedEntity> = label_index
+            .search(entity_name, max_distance)
+            .into_iter()
+            .filter_map(|(label, entry, _distance)| {
+                let similarity = strsim::jaro_winkler(&label.to_lowercase(), &entity_name.to_lowercase());
+                (similarity >= self.config.fuzzy_threshold).then(|| LinkedEntity {
+                    surface_form: label.to_string(),
+                    uri: entry.uri.clone(),
+                    types: entry.types.clone(),
+                    confidence: similarity,
+                })
+            })
+            .collect();
 
-        self.execute_candidate_query(store, &query, entity_name, false)
+        candidates.sort_by(|a, b| b.confidence.partial_cmp(&a.confidence).unwrap());
+        Ok(candidates)
     }
 
     /// Execute SPARQL query and calculate confidence scores
@@ -470,11 +1180,24 @@ Your response (just the number):"#,
     }
 
     /// Batch link multiple entities from the same text
+    ///
+    /// For the `Local` strategy with LLM disambiguation enabled, retrieves
+    /// candidates for every name concurrently and resolves every entity that
+    /// still has multiple candidates in a single LLM request instead of one
+    /// round-trip per entity (see [`Self::link_entities_batch_local`]).
+    /// Other strategies fall back to linking each name individually.
     pub async fn link_entities(
         &self,
         text: &str,
         entity_names: &[String],
     ) -> Result<Vec<Option<LinkedEntity>>> {
+        if self.config.enabled
+            && self.config.strategy == LinkingStrategy::Local
+            && self.config.use_llm_disambiguation
+        {
+            return self.link_entities_batch_local(entity_names).await;
+        }
+
         let mut results = Vec::new();
 
         for name in entity_names {
@@ -484,6 +1207,154 @@ Your response (just the number):"#,
 
         Ok(results)
     }
+
+    /// Batched `Local` strategy linking: candidate retrieval for every name
+    /// runs concurrently via [`join_all`], then every entity that still has
+    /// `>= min_candidates_for_llm` candidates is resolved in one LLM request
+    /// (see [`Self::disambiguate_batch_with_llm`]) instead of `N` separate
+    /// ones, while unambiguous entities are resolved directly
+    async fn link_entities_batch_local(&self, entity_names: &[String]) -> Result<Vec<Option<LinkedEntity>>> {
+        let store = self.store.as_ref().ok_or_else(|| {
+            Error::Config("Local store not initialized".to_string())
+        })?;
+
+        let candidate_sets = join_all(
+            entity_names
+                .iter()
+                .map(|name| async move { self.retrieve_local_candidates(store, name) }),
+        )
+        .await;
+
+        let mut results: Vec<Option<LinkedEntity>> = Vec::with_capacity(entity_names.len());
+        let mut llm_entries: Vec<(String, Vec<LinkedEntity>)> = Vec::new();
+        let mut llm_indices: Vec<usize> = Vec::new();
+
+        let mut raw_total = 0usize;
+        let mut filtered_total = 0usize;
+        let mut sparql_millis_total = 0u64;
+        let mut ranking_millis_total = 0u64;
+
+        for (idx, candidate_result) in candidate_sets.into_iter().enumerate() {
+            let (mut candidates, stats) = candidate_result?;
+            raw_total += stats.raw_count;
+            filtered_total += candidates.len();
+            sparql_millis_total += stats.sparql_millis;
+            ranking_millis_total += stats.ranking_millis;
+
+            if candidates.is_empty() {
+                results.push(None);
+            } else if candidates.len() >= self.config.min_candidates_for_llm {
+                llm_indices.push(idx);
+                llm_entries.push((entity_names[idx].clone(), candidates));
+                results.push(None);
+            } else {
+                candidates.sort_by(|a, b| b.confidence.partial_cmp(&a.confidence).unwrap());
+                results.push(candidates.into_iter().next());
+            }
+        }
+
+        let mut llm_millis_total = 0u64;
+        if !llm_entries.is_empty() {
+            let llm_start = Instant::now();
+            let selections = self.disambiguate_batch_with_llm(&llm_entries).await?;
+            llm_millis_total = llm_start.elapsed().as_millis() as u64;
+            for (idx, selection) in llm_indices.into_iter().zip(selections) {
+                results[idx] = selection;
+            }
+        }
+
+        let nil_count = results.iter().filter(|r| r.is_none()).count();
+        self.record_batch(
+            entity_names.len(),
+            nil_count,
+            raw_total,
+            filtered_total,
+            llm_entries.len(),
+            sparql_millis_total,
+            ranking_millis_total,
+            llm_millis_total,
+        );
+
+        Ok(results)
+    }
+
+    /// Resolve every `(surface_form, candidates)` entry in one LLM request
+    /// instead of `N` separate [`Self::disambiguate_with_llm`] calls: the LLM
+    /// sees every ambiguous entity from the passage at once and returns a
+    /// single JSON array of `{surface_form, selected_index}` selections
+    async fn disambiguate_batch_with_llm(
+        &self,
+        entries: &[(String, Vec<LinkedEntity>)],
+    ) -> Result<Vec<Option<LinkedEntity>>> {
+        let llm_client = self.llm_client.as_ref().ok_or_else(|| {
+            Error::Config("LLM client not initialized for disambiguation".to_string())
+        })?;
+
+        let entity_blocks: Vec<String> = entries
+            .iter()
+            .map(|(surface_form, candidates)| {
+                let candidate_list: Vec<String> = candidates
+                    .iter()
+                    .enumerate()
+                    .map(|(i, c)| {
+                        format!(
+                            "  {}. {} (URI: {}, Types: [{}], Confidence: {:.2})",
+                            i + 1,
+                            c.surface_form,
+                            c.uri,
+                            c.types.join(", "),
+                            c.confidence
+                        )
+                    })
+                    .collect();
+                format!("Entity \"{}\":\n{}", surface_form, candidate_list.join("\n"))
+            })
+            .collect();
+
+        let prompt = format!(
+            r#"Given the following entities and their candidate matches from a knowledge base, select the most appropriate match for each.
+
+{}
+
+Respond with ONLY a JSON array, one entry per entity, in the form:
+[{{"surface_form": "<entity name>", "selected_index": <1-based candidate number>}}, ...]
+
+Consider for each entity:
+- Semantic context and entity type
+- URI authority (Wikidata vs DBpedia)
+- Entity types and their relevance
+- Confidence scores"#,
+            entity_blocks.join("\n\n")
+        );
+
+        let user_msg = genai::chat::ChatMessage::user(prompt);
+        let chat_req = genai::chat::ChatRequest::new(vec![user_msg]);
+
+        let response = llm_client
+            .exec_chat(&self.config.service_url, chat_req, None)
+            .await
+            .map_err(|e| Error::Network(format!("Batch LLM disambiguation failed: {}", e)))?;
+
+        let response_text = response.first_text().unwrap_or("");
+        let selections: Vec<BatchDisambiguationSelection> = serde_json::from_str(response_text.trim())
+            .map_err(|e| Error::Extraction(format!("Invalid batch LLM response: {response_text}: {e}")))?;
+
+        let selected_by_name: HashMap<String, usize> = selections
+            .into_iter()
+            .map(|s| (s.surface_form, s.selected_index))
+            .collect();
+
+        Ok(entries
+            .iter()
+            .map(|(surface_form, candidates)| {
+                selected_by_name
+                    .get(surface_form)
+                    .and_then(|idx| idx.checked_sub(1))
+                    .and_then(|idx| candidates.get(idx))
+                    .cloned()
+            })
+            .collect())
+    }
 }
 
 /// Cached DBpedia Spotlight API call
@@ -547,6 +1418,121 @@ async fn link_with_dbpedia_cached(
     Ok(entities)
 }
 
+/// Cached Wikidata `wbsearchentities` lookup
+///
+/// Caches results for 1 hour to reduce API load, mirroring
+/// [`link_with_dbpedia_cached`].
+#[cached(
+    time = 3600,
+    result = true,
+    key = "String",
+    convert = r#"{ entity_name.clone() }"#
+)]
+async fn link_with_wikidata_cached(entity_name: String) -> Result<Vec<LinkedEntity>> {
+    let client = Client::builder()
+        .timeout(Duration::from_secs(10))
+        .build()
+        .map_err(|e| Error::Network(e.to_string()))?;
+
+    let response = client
+        .get(WIKIDATA_API_URL)
+        .query(&[
+            ("action", "wbsearchentities"),
+            ("search", entity_name.as_str()),
+            ("language", "en"),
+            ("format", "json"),
+            ("limit", &WIKIDATA_SEARCH_LIMIT.to_string()),
+        ])
+        .send()
+        .await
+        .map_err(|e| Error::Network(format!("Wikidata search request failed: {e}")))?;
+
+    if !response.status().is_success() {
+        return Ok(Vec::new());
+    }
+
+    let search_response: WikidataSearchResponse = response
+        .json()
+        .await
+        .map_err(|e| Error::Network(format!("Failed to parse Wikidata search response: {e}")))?;
+
+    if search_response.search.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let qids: Vec<String> = search_response.search.iter().map(|r| r.id.clone()).collect();
+    let types_by_qid = fetch_wikidata_types(&client, &qids).await?;
+
+    let entities = search_response
+        .search
+        .into_iter()
+        .map(|result| {
+            let surface_form = result.label.unwrap_or_else(|| entity_name.clone());
+            let confidence = strsim::jaro_winkler(&surface_form.to_lowercase(), &entity_name.to_lowercase());
+
+            LinkedEntity {
+                types: types_by_qid.get(&result.id).cloned().unwrap_or_default(),
+                uri: format!("http://www.wikidata.org/entity/{}", result.id),
+                surface_form,
+                confidence,
+            }
+        })
+        .collect();
+
+    Ok(entities)
+}
+
+/// Follow-up `wbgetentities` lookup of each candidate's `P31` (instance-of)
+/// claims, mapping each result to its type QIDs as full entity URIs
+async fn fetch_wikidata_types(client: &Client, qids: &[String]) -> Result<HashMap<String, Vec<String>>> {
+    if qids.is_empty() {
+        return Ok(HashMap::new());
+    }
+
+    let response = client
+        .get(WIKIDATA_API_URL)
+        .query(&[
+            ("action", "wbgetentities"),
+            ("ids", qids.join("|").as_str()),
+            ("props", "claims"),
+            ("format", "json"),
+        ])
+        .send()
+        .await
+        .map_err(|e| Error::Network(format!("Wikidata entities request failed: {e}")))?;
+
+    if !response.status().is_success() {
+        return Ok(HashMap::new());
+    }
+
+    let entities_response: WikidataEntitiesResponse = response
+        .json()
+        .await
+        .map_err(|e| Error::Network(format!("Failed to parse Wikidata entities response: {e}")))?;
+
+    let types_by_qid = entities_response
+        .entities
+        .into_iter()
+        .map(|(qid, entity)| {
+            let types = entity
+                .claims
+                .unwrap_or_default()
+                .get("P31")
+                .map(|claims| {
+                    claims
+                        .iter()
+                        .filter_map(|claim| claim.mainsnak.datavalue.as_ref())
+                        .map(|value| format!("http://www.wikidata.org/entity/{}", value.value.id))
+                        .collect()
+                })
+                .unwrap_or_default();
+            (qid, types)
+        })
+        .collect();
+
+    Ok(types_by_qid)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -580,6 +1566,18 @@ mod tests {
         assert!(linker.is_ok());
     }
 
+    #[tokio::test]
+    async fn test_link_entities_disabled_returns_none_for_each() {
+        let config = EntityLinkerConfig::default(); // disabled by default
+        let linker = EntityLinker::new(config).unwrap();
+
+        let names = vec!["Alan Bean".to_string(), "Pete Conrad".to_string()];
+        let results = linker.link_entities("Alan Bean and Pete Conrad", &names).await.unwrap();
+
+        assert_eq!(results.len(), 2);
+        assert!(results.iter().all(Option::is_none));
+    }
+
     #[tokio::test]
     async fn test_disabled_linker() {
         let config = EntityLinkerConfig::default(); // disabled by default
@@ -624,6 +1622,176 @@ mod tests {
         assert!(entity.confidence > 0.5);
     }
 
+    #[test]
+    fn test_embedding_strategy_config_defaults() {
+        let config = EntityLinkerConfig::default();
+        assert_eq!(config.embedding_model, "hashing");
+        assert!((config.embedding_similarity_threshold - 0.85).abs() < f64::EPSILON);
+        assert!(config.embedding_index_path.is_none());
+    }
+
+    // Integration test with real Wikidata API (ignored by default)
+    #[tokio::test]
+    #[ignore = "requires external Wikidata API"]
+    async fn test_wikidata_linking() {
+        let config = EntityLinkerConfig {
+            enabled: true,
+            strategy: LinkingStrategy::Wikidata,
+            confidence_threshold: 0.5,
+            ..Default::default()
+        };
+
+        let entity_linker = EntityLinker::new(config).unwrap();
+
+        let result = entity_linker
+            .link_entity("Alan Bean was an astronaut", "Alan Bean", Some("Person"))
+            .await;
+
+        assert!(result.is_ok(), "Wikidata API call failed");
+        let link_result = result.unwrap();
+        assert!(link_result.is_some(), "No entity found");
+
+        let entity = link_result.unwrap();
+        assert!(entity.uri.starts_with("http://www.wikidata.org/entity/Q"));
+        assert!(entity.confidence > 0.5);
+    }
+
+    #[test]
+    fn test_fuzzy_match_budget_scales_with_length_and_threshold() {
+        assert_eq!(fuzzy_match_budget("Einstein", 0.8), 2);
+        assert_eq!(fuzzy_match_budget("Bo", 0.8), 1);
+        assert_eq!(fuzzy_match_budget("Einstein", 1.0), 1);
+    }
+
+    #[test]
+    fn test_use_embeddings_defaults_false() {
+        let config = EntityLinkerConfig::default();
+        assert!(!config.use_embeddings);
+    }
+
+    #[test]
+    fn test_brute_force_embedding_index_search_respects_threshold() {
+        let embedder = HashingEmbedder::default();
+        let index = BruteForceEmbeddingIndex {
+            entries: vec![
+                EmbeddingIndexEntry {
+                    uri: "http://example.org/nyc".to_string(),
+                    label: "New York City".to_string(),
+                    types: vec!["Place".to_string()],
+                    vector: embedder.embed("New York City"),
+                },
+                EmbeddingIndexEntry {
+                    uri: "http://example.org/banana".to_string(),
+                    label: "banana split recipe".to_string(),
+                    types: vec![],
+                    vector: embedder.embed("banana split recipe"),
+                },
+            ],
+        };
+
+        let query = embedder.embed("NYC");
+        let matches = index.search(&query, 0.0);
+        assert_eq!(matches[0].uri, "http://example.org/nyc");
+
+        let strict_matches = index.search(&query, 0.99);
+        assert!(strict_matches.is_empty());
+    }
+
+    #[test]
+    fn test_merge_candidates_dedups_by_uri_keeping_higher_confidence() {
+        let mut candidates = vec![LinkedEntity {
+            surface_form: "NYC".to_string(),
+            uri: "http://example.org/nyc".to_string(),
+            types: vec![],
+            confidence: 0.6,
+        }];
+
+        merge_candidates(
+            &mut candidates,
+            vec![
+                LinkedEntity {
+                    surface_form: "New York City".to_string(),
+                    uri: "http://example.org/nyc".to_string(),
+                    types: vec![],
+                    confidence: 0.9,
+                },
+                LinkedEntity {
+                    surface_form: "Boston".to_string(),
+                    uri: "http://example.org/boston".to_string(),
+                    types: vec![],
+                    confidence: 0.8,
+                },
+            ],
+        );
+
+        assert_eq!(candidates.len(), 2);
+        let nyc = candidates.iter().find(|c| c.uri == "http://example.org/nyc").unwrap();
+        assert!((nyc.confidence - 0.9).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_linking_cache_config_defaults() {
+        let config = EntityLinkerConfig::default();
+        assert!(config.linking_cache_dir.is_none());
+        assert_eq!(config.linking_cache_ttl_secs, 86400);
+    }
+
+    #[tokio::test]
+    async fn test_link_entity_uses_persistent_cache_on_hit() {
+        let dir = std::env::temp_dir().join(format!(
+            "text_to_rdf_entity_linker_cache_test_{}",
+            std::process::id()
+        ));
+        let _ = std::fs::remove_dir_all(&dir);
+
+        let config = EntityLinkerConfig {
+            enabled: true,
+            strategy: LinkingStrategy::DbpediaSpotlight,
+            linking_cache_dir: Some(dir.clone()),
+            ..Default::default()
+        };
+        let linker = EntityLinker::new(config).unwrap();
+
+        let cache = linker.cache.as_ref().unwrap();
+        let key = LinkingCache::key(
+            &LinkingStrategy::DbpediaSpotlight,
+            "Alan Bean",
+            None,
+            &context_hash("Alan Bean flew to the Moon"),
+        );
+        let cached_entity = LinkedEntity {
+            surface_form: "Alan Bean".to_string(),
+            uri: "http://dbpedia.org/resource/Alan_Bean".to_string(),
+            types: vec!["Person".to_string()],
+            confidence: 0.9,
+        };
+        let kb_version = linker.ingest_tracker.as_ref().unwrap().kb_version();
+        cache.put(&key, Some(cached_entity.clone()), &kb_version).await.unwrap();
+
+        let result = linker
+            .link_entity("Alan Bean flew to the Moon", "Alan Bean", None)
+            .await
+            .unwrap();
+
+        assert_eq!(result.unwrap().uri, cached_entity.uri);
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[tokio::test]
+    async fn test_ingest_requires_linking_cache_dir() {
+        let config = EntityLinkerConfig {
+            enabled: true,
+            strategy: LinkingStrategy::None,
+            linking_cache_dir: None,
+            ..Default::default()
+        };
+        let mut linker = EntityLinker::new(config).unwrap();
+
+        let err = linker.ingest(&[], RdfFormat::Turtle).await.unwrap_err();
+        assert!(err.to_string().contains("linking_cache_dir"));
+    }
+
     #[test]
     fn test_local_strategy_requires_path() {
         let config = EntityLinkerConfig {
@@ -637,4 +1805,81 @@ mod tests {
         assert!(linker.is_err());
         assert!(linker.unwrap_err().to_string().contains("local_kb_path"));
     }
+
+    #[test]
+    fn test_metrics_snapshot_starts_at_zero() {
+        let config = EntityLinkerConfig::default();
+        let linker = EntityLinker::new(config).unwrap();
+
+        let metrics = linker.metrics_snapshot();
+        assert_eq!(metrics.calls, 0);
+        assert_eq!(metrics.nil_links, 0);
+        assert_eq!(metrics.cache_hits, 0);
+    }
+
+    #[tokio::test]
+    async fn test_link_entity_with_report_disabled_linker_returns_default_report() {
+        let config = EntityLinkerConfig::default(); // disabled by default
+        let linker = EntityLinker::new(config).unwrap();
+
+        let (entity, report) = linker
+            .link_entity_with_report("Alan Bean was an astronaut", "Alan Bean", None)
+            .await
+            .unwrap();
+
+        assert!(entity.is_none());
+        assert_eq!(report.raw_candidates, 0);
+        assert!(!report.cache_hit);
+
+        // Disabled calls are a no-op and shouldn't be folded into metrics.
+        assert_eq!(linker.metrics_snapshot().calls, 0);
+    }
+
+    #[tokio::test]
+    async fn test_link_entity_with_report_reflects_persistent_cache_hit() {
+        let dir = std::env::temp_dir().join(format!(
+            "text_to_rdf_entity_linker_metrics_cache_test_{}",
+            std::process::id()
+        ));
+        let _ = std::fs::remove_dir_all(&dir);
+
+        let config = EntityLinkerConfig {
+            enabled: true,
+            strategy: LinkingStrategy::DbpediaSpotlight,
+            linking_cache_dir: Some(dir.clone()),
+            ..Default::default()
+        };
+        let linker = EntityLinker::new(config).unwrap();
+
+        let cache = linker.cache.as_ref().unwrap();
+        let key = LinkingCache::key(
+            &LinkingStrategy::DbpediaSpotlight,
+            "Alan Bean",
+            None,
+            &context_hash("Alan Bean flew to the Moon"),
+        );
+        let cached_entity = LinkedEntity {
+            surface_form: "Alan Bean".to_string(),
+            uri: "http://dbpedia.org/resource/Alan_Bean".to_string(),
+            types: vec!["Person".to_string()],
+            confidence: 0.9,
+        };
+        let kb_version = linker.ingest_tracker.as_ref().unwrap().kb_version();
+        cache.put(&key, Some(cached_entity.clone()), &kb_version).await.unwrap();
+
+        let (entity, report) = linker
+            .link_entity_with_report("Alan Bean flew to the Moon", "Alan Bean", None)
+            .await
+            .unwrap();
+
+        assert_eq!(entity.unwrap().uri, cached_entity.uri);
+        assert!(report.cache_hit);
+
+        let metrics = linker.metrics_snapshot();
+        assert_eq!(metrics.calls, 1);
+        assert_eq!(metrics.cache_hits, 1);
+        assert_eq!(metrics.nil_links, 0);
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
 }
@@ -43,6 +43,10 @@ pub enum Error {
     /// Validation error
     #[error("Validation error: {0}")]
     Validation(String),
+
+    /// Error signing or verifying a Data-Integrity proof
+    #[error("Signing error: {0}")]
+    Signing(String),
 }
 
 // Allow conversion from genai errors if needed
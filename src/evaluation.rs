@@ -0,0 +1,739 @@
+//! Corpus-level evaluation: micro/macro-averaged metrics, a per-predicate
+//! breakdown, and bootstrap confidence intervals
+//!
+//! Averaging precision/recall/F1 across a handful of documents hides how much
+//! that average would move under a different sample, and gives no visibility
+//! into which relation types the extractor actually struggles with. This
+//! module pools per-document [`Triple`] sets into a reusable [`Evaluator`],
+//! which reports both micro-averaged (pool every triple across the corpus,
+//! then score once) and macro-averaged (score each document, then average)
+//! precision/recall/F1, a per-predicate breakdown with support counts, and a
+//! 95% bootstrap confidence interval on micro F1 - resampling documents with
+//! replacement `B` times and taking the 2.5th/97.5th percentiles of the
+//! resampled F1 scores - so evaluation harnesses (such as the DocRED
+//! example) can report "F1 = 42% ± 6%" instead of a single point estimate.
+
+use crate::reasoning::Triple;
+use std::collections::{HashMap, HashSet};
+
+/// Precision/recall/F1 for one slice of triples (a whole corpus, one
+/// document, or one predicate)
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct PrecisionRecallF1 {
+    pub precision: f64,
+    pub recall: f64,
+    pub f1: f64,
+}
+
+impl PrecisionRecallF1 {
+    fn from_counts(true_positives: usize, predicted: usize, expected: usize) -> Self {
+        let precision = if predicted == 0 {
+            0.0
+        } else {
+            true_positives as f64 / predicted as f64
+        };
+        let recall = if expected == 0 {
+            0.0
+        } else {
+            true_positives as f64 / expected as f64
+        };
+        let f1 = if precision + recall == 0.0 {
+            0.0
+        } else {
+            2.0 * precision * recall / (precision + recall)
+        };
+        Self {
+            precision,
+            recall,
+            f1,
+        }
+    }
+}
+
+/// A 95% confidence interval, as the 2.5th/97.5th percentiles of a bootstrap
+/// resample distribution
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ConfidenceInterval {
+    pub lower: f64,
+    pub upper: f64,
+}
+
+/// Precision/recall/F1 for a single Schema.org predicate, plus its support
+/// (how many gold triples use that predicate across the corpus)
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct PredicateBreakdown {
+    pub metrics: PrecisionRecallF1,
+    pub support: usize,
+}
+
+/// One document's predicted and expected triples, the unit [`Evaluator`] pools and resamples over
+#[derive(Debug, Clone, Default)]
+pub struct DocumentResult {
+    pub predicted: HashSet<Triple>,
+    pub expected: HashSet<Triple>,
+}
+
+impl DocumentResult {
+    #[must_use]
+    pub const fn new(predicted: HashSet<Triple>, expected: HashSet<Triple>) -> Self {
+        Self { predicted, expected }
+    }
+}
+
+/// Micro-averaged, macro-averaged, and per-predicate metrics for a corpus,
+/// plus a bootstrap confidence interval on micro F1
+#[derive(Debug, Clone, PartialEq)]
+pub struct MetricsReport {
+    /// Pool every document's triples together, then score once
+    pub micro: PrecisionRecallF1,
+    /// Score each document independently, then average
+    pub macro_averaged: PrecisionRecallF1,
+    /// Per-predicate precision/recall/F1/support, pooled across the corpus
+    pub per_predicate: HashMap<String, PredicateBreakdown>,
+    /// 95% confidence interval on micro F1, from resampling documents with replacement
+    pub micro_f1_ci: ConfidenceInterval,
+}
+
+/// Configuration for fuzzy, type-aware triple matching in [`Evaluator::evaluate_fuzzy`]
+///
+/// A predicted/expected pair's combined similarity is the weighted average
+/// of its subject, predicate, and object similarities; a pair counts as a
+/// match once that average reaches `threshold`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FuzzyMatchConfig {
+    /// Minimum combined similarity, in `[0, 1]`, for a pair to count as a match
+    pub threshold: f64,
+    /// Weight given to subject similarity in the combined score
+    pub subject_weight: f64,
+    /// Weight given to predicate similarity in the combined score
+    pub predicate_weight: f64,
+    /// Weight given to object similarity in the combined score
+    pub object_weight: f64,
+}
+
+impl Default for FuzzyMatchConfig {
+    fn default() -> Self {
+        Self {
+            threshold: 0.8,
+            subject_weight: 1.0,
+            predicate_weight: 1.0,
+            object_weight: 1.0,
+        }
+    }
+}
+
+/// Exact and fuzzy metrics for the same corpus, from [`Evaluator::evaluate_fuzzy`]
+///
+/// Comparing the two shows how much of an F1 gap is genuine extraction
+/// error versus surface-form formatting differences, e.g. "1932-03-15" vs
+/// "15 March 1932" or "NYC" vs "New York City".
+#[derive(Debug, Clone, PartialEq)]
+pub struct FuzzyMetricsReport {
+    /// Exact `HashSet` intersection, identical to [`Evaluator::evaluate`]
+    pub strict: MetricsReport,
+    /// Weighted partial-credit matching via [`FuzzyMatchConfig`]
+    pub fuzzy: MetricsReport,
+}
+
+/// Computes [`MetricsReport`]s over a corpus of per-document predicted/expected triple sets
+#[derive(Debug, Clone)]
+pub struct Evaluator {
+    bootstrap_samples: usize,
+    seed: u64,
+}
+
+impl Default for Evaluator {
+    fn default() -> Self {
+        Self {
+            bootstrap_samples: 1000,
+            seed: 0x2545_F491_4F6C_DD1D,
+        }
+    }
+}
+
+impl Evaluator {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of bootstrap resamples to draw when computing [`MetricsReport::micro_f1_ci`] (default 1000)
+    #[must_use]
+    pub const fn with_bootstrap_samples(mut self, bootstrap_samples: usize) -> Self {
+        self.bootstrap_samples = bootstrap_samples;
+        self
+    }
+
+    /// Seed for the bootstrap resampler, so results are reproducible across runs
+    #[must_use]
+    pub const fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    /// Evaluate a corpus of per-document results, producing a [`MetricsReport`]
+    #[must_use]
+    pub fn evaluate(&self, documents: &[DocumentResult]) -> MetricsReport {
+        let micro = micro_prf1(documents.iter().collect::<Vec<_>>().as_slice());
+        let macro_averaged = macro_prf1(documents);
+        let per_predicate = per_predicate_breakdown(documents);
+        let micro_f1_ci = self.bootstrap_micro_f1_ci(documents, micro_prf1);
+
+        MetricsReport {
+            micro,
+            macro_averaged,
+            per_predicate,
+            micro_f1_ci,
+        }
+    }
+
+    /// Evaluate `documents` twice: once with exact `HashSet` matching (as
+    /// [`Self::evaluate`]) and once with fuzzy, type-aware matching
+    /// configured by `config`, greedily pairing each predicted/expected
+    /// triple with at most one partner so no predicted triple is credited
+    /// twice
+    #[must_use]
+    pub fn evaluate_fuzzy(&self, documents: &[DocumentResult], config: &FuzzyMatchConfig) -> FuzzyMetricsReport {
+        let strict = self.evaluate(documents);
+
+        let micro = micro_prf1_fuzzy(documents.iter().collect::<Vec<_>>().as_slice(), config);
+        let macro_averaged = macro_prf1_fuzzy(documents, config);
+        let per_predicate = per_predicate_breakdown_fuzzy(documents, config);
+        let micro_f1_ci = self.bootstrap_micro_f1_ci(documents, |resample| micro_prf1_fuzzy(resample, config));
+
+        FuzzyMetricsReport {
+            strict,
+            fuzzy: MetricsReport {
+                micro,
+                macro_averaged,
+                per_predicate,
+                micro_f1_ci,
+            },
+        }
+    }
+
+    /// Resample `documents` with replacement [`Self::bootstrap_samples`]
+    /// times, recompute micro F1 via `score` over each resample, and return
+    /// the 2.5th/97.5th percentiles of the resulting distribution. `score`
+    /// is a parameter (rather than always [`micro_prf1`]) so
+    /// [`Self::evaluate_fuzzy`] can resample under fuzzy matching too.
+    fn bootstrap_micro_f1_ci(
+        &self,
+        documents: &[DocumentResult],
+        score: impl Fn(&[&DocumentResult]) -> PrecisionRecallF1,
+    ) -> ConfidenceInterval {
+        if documents.is_empty() {
+            return ConfidenceInterval {
+                lower: 0.0,
+                upper: 0.0,
+            };
+        }
+
+        let mut rng = SplitMix64::new(self.seed);
+        let mut scores: Vec<f64> = (0..self.bootstrap_samples)
+            .map(|_| {
+                let resample: Vec<&DocumentResult> = (0..documents.len())
+                    .map(|_| &documents[rng.next_index(documents.len())])
+                    .collect();
+                score(&resample).f1
+            })
+            .collect();
+        scores.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+        ConfidenceInterval {
+            lower: percentile(&scores, 2.5),
+            upper: percentile(&scores, 97.5),
+        }
+    }
+}
+
+/// Pool every triple across `documents` and score once
+fn micro_prf1(documents: &[&DocumentResult]) -> PrecisionRecallF1 {
+    let mut true_positives = 0;
+    let mut predicted = 0;
+    let mut expected = 0;
+    for doc in documents {
+        true_positives += doc.predicted.intersection(&doc.expected).count();
+        predicted += doc.predicted.len();
+        expected += doc.expected.len();
+    }
+    PrecisionRecallF1::from_counts(true_positives, predicted, expected)
+}
+
+/// Score each document independently, then average precision/recall/F1 across them
+fn macro_prf1(documents: &[DocumentResult]) -> PrecisionRecallF1 {
+    if documents.is_empty() {
+        return PrecisionRecallF1::default();
+    }
+
+    let mut sum = PrecisionRecallF1::default();
+    for doc in documents {
+        let true_positives = doc.predicted.intersection(&doc.expected).count();
+        let per_doc = PrecisionRecallF1::from_counts(true_positives, doc.predicted.len(), doc.expected.len());
+        sum.precision += per_doc.precision;
+        sum.recall += per_doc.recall;
+        sum.f1 += per_doc.f1;
+    }
+
+    let n = documents.len() as f64;
+    PrecisionRecallF1 {
+        precision: sum.precision / n,
+        recall: sum.recall / n,
+        f1: sum.f1 / n,
+    }
+}
+
+/// Pool every document's triples by predicate and score each predicate independently
+fn per_predicate_breakdown(documents: &[DocumentResult]) -> HashMap<String, PredicateBreakdown> {
+    let mut counts: HashMap<String, (usize, usize, usize)> = HashMap::new();
+
+    for doc in documents {
+        let true_positives: HashMap<&str, usize> =
+            doc.predicted
+                .intersection(&doc.expected)
+                .fold(HashMap::new(), |mut acc, t| {
+                    *acc.entry(t.predicate.as_str()).or_insert(0) += 1;
+                    acc
+                });
+        let predicted_counts = count_by_predicate(&doc.predicted);
+        let expected_counts = count_by_predicate(&doc.expected);
+
+        for predicate in predicted_counts
+            .keys()
+            .chain(expected_counts.keys())
+            .collect::<HashSet<_>>()
+        {
+            let entry = counts.entry((*predicate).clone()).or_insert((0, 0, 0));
+            entry.0 += true_positives.get(predicate.as_str()).copied().unwrap_or(0);
+            entry.1 += predicted_counts.get(predicate).copied().unwrap_or(0);
+            entry.2 += expected_counts.get(predicate).copied().unwrap_or(0);
+        }
+    }
+
+    counts
+        .into_iter()
+        .map(|(predicate, (true_positives, predicted, expected))| {
+            (
+                predicate,
+                PredicateBreakdown {
+                    metrics: PrecisionRecallF1::from_counts(true_positives, predicted, expected),
+                    support: expected,
+                },
+            )
+        })
+        .collect()
+}
+
+fn count_by_predicate(triples: &HashSet<Triple>) -> HashMap<String, usize> {
+    triples.iter().fold(HashMap::new(), |mut acc, t| {
+        *acc.entry(t.predicate.clone()).or_insert(0) += 1;
+        acc
+    })
+}
+
+/// Pool every triple across `documents`, fuzzy-match each document
+/// independently (matching never crosses a document boundary), and score once
+fn micro_prf1_fuzzy(documents: &[&DocumentResult], config: &FuzzyMatchConfig) -> PrecisionRecallF1 {
+    let mut true_positives = 0;
+    let mut predicted = 0;
+    let mut expected = 0;
+    for doc in documents {
+        true_positives += fuzzy_matches(doc, config).len();
+        predicted += doc.predicted.len();
+        expected += doc.expected.len();
+    }
+    PrecisionRecallF1::from_counts(true_positives, predicted, expected)
+}
+
+/// Fuzzy-score each document independently, then average precision/recall/F1 across them
+fn macro_prf1_fuzzy(documents: &[DocumentResult], config: &FuzzyMatchConfig) -> PrecisionRecallF1 {
+    if documents.is_empty() {
+        return PrecisionRecallF1::default();
+    }
+
+    let mut sum = PrecisionRecallF1::default();
+    for doc in documents {
+        let true_positives = fuzzy_matches(doc, config).len();
+        let per_doc = PrecisionRecallF1::from_counts(true_positives, doc.predicted.len(), doc.expected.len());
+        sum.precision += per_doc.precision;
+        sum.recall += per_doc.recall;
+        sum.f1 += per_doc.f1;
+    }
+
+    let n = documents.len() as f64;
+    PrecisionRecallF1 {
+        precision: sum.precision / n,
+        recall: sum.recall / n,
+        f1: sum.f1 / n,
+    }
+}
+
+/// Pool every document's triples by the *expected* triple's predicate and
+/// score each predicate independently, under fuzzy matching
+fn per_predicate_breakdown_fuzzy(documents: &[DocumentResult], config: &FuzzyMatchConfig) -> HashMap<String, PredicateBreakdown> {
+    let mut counts: HashMap<String, (usize, usize, usize)> = HashMap::new();
+
+    for doc in documents {
+        let expected: Vec<&Triple> = doc.expected.iter().collect();
+        for (_, expected_idx) in fuzzy_matches(doc, config) {
+            let entry = counts.entry(expected[expected_idx].predicate.clone()).or_insert((0, 0, 0));
+            entry.0 += 1;
+        }
+
+        let predicted_counts = count_by_predicate(&doc.predicted);
+        let expected_counts = count_by_predicate(&doc.expected);
+        for predicate in predicted_counts
+            .keys()
+            .chain(expected_counts.keys())
+            .collect::<HashSet<_>>()
+        {
+            let entry = counts.entry((*predicate).clone()).or_insert((0, 0, 0));
+            entry.1 += predicted_counts.get(predicate).copied().unwrap_or(0);
+            entry.2 += expected_counts.get(predicate).copied().unwrap_or(0);
+        }
+    }
+
+    counts
+        .into_iter()
+        .map(|(predicate, (true_positives, predicted, expected))| {
+            (
+                predicate,
+                PredicateBreakdown {
+                    metrics: PrecisionRecallF1::from_counts(true_positives, predicted, expected),
+                    support: expected,
+                },
+            )
+        })
+        .collect()
+}
+
+/// Greedily pair `doc`'s predicted and expected triples by descending
+/// combined similarity (subject/predicate/object, weighted by `config`),
+/// claiming the highest-scoring pairs first so no triple is matched twice,
+/// and return every `(predicted_index, expected_index)` pair that cleared
+/// `config.threshold`
+fn fuzzy_matches(doc: &DocumentResult, config: &FuzzyMatchConfig) -> Vec<(usize, usize)> {
+    let predicted: Vec<&Triple> = doc.predicted.iter().collect();
+    let expected: Vec<&Triple> = doc.expected.iter().collect();
+
+    let mut candidates: Vec<(usize, usize, f64)> = Vec::new();
+    for (pi, p) in predicted.iter().enumerate() {
+        for (ei, e) in expected.iter().enumerate() {
+            let score = triple_similarity(p, e, config);
+            if score >= config.threshold {
+                candidates.push((pi, ei, score));
+            }
+        }
+    }
+    candidates.sort_by(|a, b| b.2.partial_cmp(&a.2).unwrap());
+
+    let mut used_predicted = vec![false; predicted.len()];
+    let mut used_expected = vec![false; expected.len()];
+    let mut matches = Vec::new();
+    for (pi, ei, _) in candidates {
+        if !used_predicted[pi] && !used_expected[ei] {
+            used_predicted[pi] = true;
+            used_expected[ei] = true;
+            matches.push((pi, ei));
+        }
+    }
+    matches
+}
+
+/// Weighted-average similarity between a predicted and expected triple:
+/// subject/object compared via [`field_similarity`] (date-aware, falling
+/// back to normalized Levenshtein ratio) and predicate compared via
+/// [`predicate_similarity`] (stemmed via [`normalize_predicate`])
+fn triple_similarity(predicted: &Triple, expected: &Triple, config: &FuzzyMatchConfig) -> f64 {
+    let subject_sim = field_similarity(&predicted.subject, &expected.subject);
+    let predicate_sim = predicate_similarity(&predicted.predicate, &expected.predicate);
+    let object_sim = field_similarity(&predicted.object, &expected.object);
+
+    let total_weight = config.subject_weight + config.predicate_weight + config.object_weight;
+    if total_weight <= 0.0 {
+        return 0.0;
+    }
+
+    (config.subject_weight * subject_sim + config.predicate_weight * predicate_sim + config.object_weight * object_sim)
+        / total_weight
+}
+
+/// Similarity between two predicates: 1.0 if they stem to the same root via
+/// [`normalize_predicate`], otherwise the Levenshtein ratio of the stemmed forms
+fn predicate_similarity(a: &str, b: &str) -> f64 {
+    let (a, b) = (crate::normalize::normalize_predicate(a), crate::normalize::normalize_predicate(b));
+    if a == b {
+        1.0
+    } else {
+        levenshtein_ratio(&a, &b)
+    }
+}
+
+/// Similarity between two subject/object surface forms: 1.0/0.0 if both
+/// parse as dates (via [`parse_loose_date`]) since a date either matches or
+/// doesn't, otherwise the Levenshtein ratio of the lowercased strings
+fn field_similarity(a: &str, b: &str) -> f64 {
+    if let (Some(date_a), Some(date_b)) = (parse_loose_date(a), parse_loose_date(b)) {
+        return if date_a == date_b { 1.0 } else { 0.0 };
+    }
+    levenshtein_ratio(&a.to_lowercase(), &b.to_lowercase())
+}
+
+/// Levenshtein distance normalized to a `[0, 1]` similarity ratio, where `1.0`
+/// means identical and `0.0` means completely different
+fn levenshtein_ratio(a: &str, b: &str) -> f64 {
+    let max_len = a.chars().count().max(b.chars().count());
+    if max_len == 0 {
+        return 1.0;
+    }
+    1.0 - (crate::embedding::levenshtein_distance(a, b) as f64 / max_len as f64)
+}
+
+const MONTH_NAMES: [&str; 12] = [
+    "january", "february", "march", "april", "may", "june", "july", "august", "september", "october", "november",
+    "december",
+];
+
+/// The 1-based month number for an (English, case-insensitive) month name
+fn month_number(name: &str) -> Option<u32> {
+    let name = name.to_lowercase();
+    MONTH_NAMES.iter().position(|m| *m == name).map(|i| i as u32 + 1)
+}
+
+/// Loosely parse a date-like string into `(year, month, day)`, accepting
+/// both ISO 8601 (`"1932-03-15"`) and textual day/month/year forms
+/// (`"15 March 1932"`, `"March 15, 1932"`), so [`field_similarity`] can
+/// recognize these as the same date rather than scoring them by surface
+/// edit distance
+fn parse_loose_date(s: &str) -> Option<(i32, u32, u32)> {
+    parse_iso_date(s).or_else(|| parse_textual_date(s))
+}
+
+fn parse_iso_date(s: &str) -> Option<(i32, u32, u32)> {
+    let parts: Vec<&str> = s.trim().split('-').collect();
+    let [year, month, day] = parts[..] else {
+        return None;
+    };
+    let year: i32 = year.parse().ok()?;
+    let month: u32 = month.parse().ok()?;
+    let day: u32 = day.parse().ok()?;
+    ((1..=12).contains(&month) && (1..=31).contains(&day)).then_some((year, month, day))
+}
+
+fn parse_textual_date(s: &str) -> Option<(i32, u32, u32)> {
+    let cleaned: String = s.chars().filter(|c| !matches!(c, ',' | '.')).collect();
+    let tokens: Vec<&str> = cleaned.split_whitespace().collect();
+    let [first, second, year] = tokens[..] else {
+        return None;
+    };
+
+    let (day, month) = if let Ok(day) = first.parse::<u32>() {
+        (day, month_number(second)?)
+    } else {
+        (second.parse().ok()?, month_number(first)?)
+    };
+    let year: i32 = year.parse().ok()?;
+
+    (1..=31).contains(&day).then_some((year, month, day))
+}
+
+/// Linear-interpolated percentile of an already-sorted slice
+fn percentile(sorted: &[f64], pct: f64) -> f64 {
+    if sorted.is_empty() {
+        return 0.0;
+    }
+    if sorted.len() == 1 {
+        return sorted[0];
+    }
+
+    let rank = (pct / 100.0) * (sorted.len() - 1) as f64;
+    let lower = rank.floor() as usize;
+    let upper = rank.ceil() as usize;
+    if lower == upper {
+        sorted[lower]
+    } else {
+        let weight = rank - lower as f64;
+        sorted[lower] * (1.0 - weight) + sorted[upper] * weight
+    }
+}
+
+/// A small, dependency-free PRNG (`SplitMix64`) used only to pick bootstrap
+/// resample indices - no statistical properties beyond uniformity are needed here
+struct SplitMix64 {
+    state: u64,
+}
+
+impl SplitMix64 {
+    const fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// A uniformly distributed index in `0..bound`
+    fn next_index(&mut self, bound: usize) -> usize {
+        (self.next_u64() % bound as u64) as usize
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn doc(predicted: &[(&str, &str, &str)], expected: &[(&str, &str, &str)]) -> DocumentResult {
+        let to_set = |triples: &[(&str, &str, &str)]| {
+            triples
+                .iter()
+                .map(|(s, p, o)| Triple::new(*s, *p, *o))
+                .collect()
+        };
+        DocumentResult::new(to_set(predicted), to_set(expected))
+    }
+
+    #[test]
+    fn test_micro_averages_pool_across_documents() {
+        let documents = vec![
+            doc(&[("a", "knows", "b")], &[("a", "knows", "b"), ("a", "knows", "c")]),
+            doc(&[("d", "knows", "e")], &[("d", "knows", "e")]),
+        ];
+
+        let report = Evaluator::new().evaluate(&documents);
+
+        // 2 true positives / 2 predicted = 100% precision
+        assert!((report.micro.precision - 1.0).abs() < 1e-9);
+        // 2 true positives / 3 expected
+        assert!((report.micro.recall - 2.0 / 3.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_macro_averages_per_document_scores() {
+        let documents = vec![
+            doc(&[("a", "knows", "b")], &[("a", "knows", "b")]), // F1 = 1.0
+            doc(&[], &[("d", "knows", "e")]),                    // F1 = 0.0
+        ];
+
+        let report = Evaluator::new().evaluate(&documents);
+
+        assert!((report.macro_averaged.f1 - 0.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_per_predicate_breakdown_tracks_support() {
+        let documents = vec![doc(
+            &[("a", "birthPlace", "x")],
+            &[("a", "birthPlace", "x"), ("a", "deathPlace", "y")],
+        )];
+
+        let report = Evaluator::new().evaluate(&documents);
+
+        assert_eq!(report.per_predicate["birthPlace"].support, 1);
+        assert!((report.per_predicate["birthPlace"].metrics.f1 - 1.0).abs() < 1e-9);
+        assert_eq!(report.per_predicate["deathPlace"].support, 1);
+        assert!((report.per_predicate["deathPlace"].metrics.f1 - 0.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_bootstrap_ci_is_degenerate_for_uniform_documents() {
+        let documents = vec![
+            doc(&[("a", "knows", "b")], &[("a", "knows", "b")]),
+            doc(&[("c", "knows", "d")], &[("c", "knows", "d")]),
+            doc(&[("e", "knows", "f")], &[("e", "knows", "f")]),
+        ];
+
+        let report = Evaluator::new().with_bootstrap_samples(200).evaluate(&documents);
+
+        // Every document has F1 = 1.0, so every resample also scores 1.0
+        assert!((report.micro_f1_ci.lower - 1.0).abs() < 1e-9);
+        assert!((report.micro_f1_ci.upper - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_bootstrap_ci_widens_with_document_variance() {
+        let documents = vec![
+            doc(&[("a", "knows", "b")], &[("a", "knows", "b")]),
+            doc(&[], &[("c", "knows", "d")]),
+        ];
+
+        let report = Evaluator::new().with_bootstrap_samples(500).evaluate(&documents);
+
+        assert!(report.micro_f1_ci.lower < report.micro_f1_ci.upper);
+    }
+
+    #[test]
+    fn test_parse_loose_date_accepts_iso_and_textual_forms() {
+        assert_eq!(parse_loose_date("1932-03-15"), Some((1932, 3, 15)));
+        assert_eq!(parse_loose_date("15 March 1932"), Some((1932, 3, 15)));
+        assert_eq!(parse_loose_date("March 15, 1932"), Some((1932, 3, 15)));
+        assert_eq!(parse_loose_date("not a date"), None);
+    }
+
+    #[test]
+    fn test_field_similarity_matches_equivalent_dates() {
+        assert!((field_similarity("1932-03-15", "15 March 1932") - 1.0).abs() < 1e-9);
+        assert!((field_similarity("1932-03-15", "16 March 1932") - 0.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_field_similarity_falls_back_to_levenshtein_ratio_for_non_dates() {
+        let similarity = field_similarity("New York City", "NYC");
+        assert!(similarity > 0.0 && similarity < 1.0);
+        assert!((field_similarity("Paris", "Paris") - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_predicate_similarity_matches_after_stemming() {
+        assert!((predicate_similarity("graduated", "graduates") - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_evaluate_fuzzy_credits_a_near_miss_date_as_a_match() {
+        let documents = vec![doc(
+            &[("alan_bean", "birthDate", "15 March 1932")],
+            &[("alan_bean", "birthDate", "1932-03-15")],
+        )];
+
+        let report = Evaluator::new().evaluate_fuzzy(&documents, &FuzzyMatchConfig::default());
+
+        assert!((report.strict.micro.f1 - 0.0).abs() < 1e-9);
+        assert!((report.fuzzy.micro.f1 - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_evaluate_fuzzy_does_not_double_credit_a_predicted_triple() {
+        let documents = vec![doc(
+            &[("alan_bean", "birthDate", "1932-03-15")],
+            &[
+                ("alan_bean", "birthDate", "1932-03-15"),
+                ("alan_bean_jr", "birthDate", "1932-03-15"),
+            ],
+        )];
+
+        let report = Evaluator::new().evaluate_fuzzy(&documents, &FuzzyMatchConfig::default());
+
+        // Only one predicted triple exists, so at most one match is possible
+        // even though it's similar enough to both expected triples.
+        assert!((report.fuzzy.micro.recall - 0.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_evaluate_fuzzy_rejects_matches_below_threshold() {
+        let documents = vec![doc(
+            &[("alan_bean", "birthDate", "completely different value")],
+            &[("alan_bean", "birthDate", "1932-03-15")],
+        )];
+
+        let config = FuzzyMatchConfig {
+            threshold: 0.99,
+            ..FuzzyMatchConfig::default()
+        };
+        let report = Evaluator::new().evaluate_fuzzy(&documents, &config);
+
+        assert!((report.fuzzy.micro.f1 - 0.0).abs() < 1e-9);
+    }
+}
@@ -1,17 +1,33 @@
 //! RDF extraction implementation using genai crate
 
 use async_trait::async_trait;
+use futures::stream::{self, BoxStream, StreamExt};
 use genai::chat::{ChatMessage, ChatRequest};
 use genai::Client;
+use sha2::{Digest, Sha256};
 
-use crate::chunking::{DocumentChunk, SemanticChunker};
+use crate::cache::ExtractionCache;
+use crate::chunking::{DocumentChunk, SemanticChunker, TokenAwareChunker};
 use crate::coref::{CorefConfig, CorefResolver};
-use crate::entity_linker::EntityLinker;
-use crate::knowledge_buffer::KnowledgeBuffer;
+use crate::coverage::{compute_coverage, CoverageReport};
+use crate::embedding::{HashingEmbedder, TextEmbedder};
+use crate::entity_linker::{EntityLinker, LinkingStrategy};
+use crate::knowledge_buffer::{EmbeddingEntityIndex, KnowledgeBuffer};
+use crate::normalize::normalize_entity_name;
+use crate::persistent_chunk_cache::{open_default_backend, PersistentChunkCache};
+use crate::prov::{now_rfc3339, ProvActivity, ProvEntity, ProvenanceGraph};
+use crate::tokenizer::TokenCounter;
 use crate::{Error, ExtractionConfig, RdfDocument, RdfExtractor, Result};
+use std::sync::Mutex;
+#[cfg(feature = "telemetry")]
+use opentelemetry::trace::{Span, Tracer};
+#[cfg(feature = "telemetry")]
+use opentelemetry::{global, KeyValue};
+#[cfg(feature = "telemetry")]
+use std::time::Instant;
 
 /// Default system prompt for RDF extraction
-const DEFAULT_SYSTEM_PROMPT: &str = r#"You are an expert RDF extraction system. Extract ONLY explicitly stated facts from text.
+pub(crate) const DEFAULT_SYSTEM_PROMPT: &str = r#"You are an expert RDF extraction system. Extract ONLY explicitly stated facts from text.
 
 CRITICAL RULES:
 1. Return ONLY valid JSON-LD conforming to Schema.org
@@ -122,6 +138,361 @@ pub struct GenAiExtractor {
     config: ExtractionConfig,
     coref_resolver: CorefResolver,
     entity_linker: Option<EntityLinker>,
+    /// Embedder backing the `Embedding` entity-linking strategy's
+    /// cross-document resolution index
+    embedder: Box<dyn TextEmbedder + Send + Sync>,
+    /// Cross-document entity resolution index, shared across every
+    /// `extract_from_document` call on this extractor and persisted to
+    /// `config.entity_linker.embedding_index_path` when set
+    embedding_index: Mutex<EmbeddingEntityIndex>,
+    /// Content-addressed cache of extraction results, consulted before every
+    /// LLM call when `config.cache_dir` is set (see `ExtractionCache`)
+    cache: Option<ExtractionCache>,
+    /// In-memory, TTL-bounded cache of per-chunk extraction results, keyed by
+    /// [`ExtractionCache::key`] and consulted by
+    /// [`Self::extract_from_chunk`] so identical chunks across a batch job
+    /// (or re-runs during development) skip the LLM round-trip entirely.
+    /// `None` when `config.cache_capacity` or `config.cache_ttl_secs` is `0`.
+    chunk_cache: Option<moka::sync::Cache<String, RdfDocument>>,
+    /// Persistent, cross-run cache of per-chunk extraction results,
+    /// consulted by [`Self::extract_from_chunk`] alongside `chunk_cache` so a
+    /// crash or restart mid-document only re-pays for chunks whose text or
+    /// extraction configuration changed. `None` when `config.persistent_cache_path`
+    /// is unset.
+    persistent_chunk_cache: Option<PersistentChunkCache>,
+}
+
+/// Identity of a JSON-LD node as merged by [`JsonLdFlattener`]: its explicit
+/// `@id` when present, otherwise its normalized `@type`+`name` pair
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum NodeKey {
+    Id(String),
+    TypeName(String, String),
+}
+
+/// Identify the [`NodeKey`] a JSON-LD object should be merged under, if it
+/// qualifies as a node at all (has an `@id`, or a `@type` plus a string
+/// `name`); objects with neither are left untouched by [`JsonLdFlattener`]
+fn node_key_for(map: &serde_json::Map<String, serde_json::Value>) -> Option<NodeKey> {
+    if let Some(id) = map.get("@id").and_then(serde_json::Value::as_str) {
+        return Some(NodeKey::Id(id.to_string()));
+    }
+    let schema_type = map.get("@type").and_then(serde_json::Value::as_str)?;
+    let name = map.get("name").and_then(serde_json::Value::as_str)?;
+    Some(NodeKey::TypeName(schema_type.to_string(), normalize_entity_name(name)))
+}
+
+/// Merges a property value into an existing node, promoting to an array on
+/// the second distinct value and de-duplicating by structural equality
+/// (comparing serialized form, which `serde_json::Value`'s `PartialEq` does
+/// directly, independent of object key order)
+fn merge_property_value(existing: &mut serde_json::Value, incoming: serde_json::Value) {
+    match existing {
+        serde_json::Value::Array(items) => {
+            if !items.contains(&incoming) {
+                items.push(incoming);
+            }
+        }
+        _ => {
+            if *existing != incoming {
+                let previous = std::mem::replace(existing, serde_json::Value::Null);
+                *existing = serde_json::Value::Array(vec![previous, incoming]);
+            }
+        }
+    }
+}
+
+/// Union-find (disjoint-set) over node indices `0..n`, used by
+/// [`resolve_entities_by_embedding`] to cluster entities whose pairwise
+/// similarity exceeds the configured threshold, so transitive matches (A~B
+/// and B~C, even if A and C weren't themselves compared above threshold)
+/// merge into one cluster
+struct UnionFind {
+    parent: Vec<usize>,
+}
+
+impl UnionFind {
+    fn new(n: usize) -> Self {
+        Self { parent: (0..n).collect() }
+    }
+
+    fn find(&mut self, x: usize) -> usize {
+        if self.parent[x] != x {
+            self.parent[x] = self.find(self.parent[x]);
+        }
+        self.parent[x]
+    }
+
+    fn union(&mut self, a: usize, b: usize) {
+        let (root_a, root_b) = (self.find(a), self.find(b));
+        if root_a != root_b {
+            self.parent[root_a] = root_b;
+        }
+    }
+}
+
+/// Cluster `graph`'s nodes by embedding similarity of their `name` property,
+/// beyond the exact-match merge [`JsonLdFlattener`] already performs - so
+/// near-duplicate surface forms ("IBM" vs "International Business Machines")
+/// collapse into one node instead of appearing twice in the merged graph
+///
+/// Embeds each distinct entity name once into a `HashMap<String, Vec<f32>>`,
+/// unions every pair of nodes whose cosine similarity meets `threshold`, then
+/// for each resulting cluster picks a canonical node - preferring one that
+/// already carries an explicit `@id` (not a flattener-minted blank node
+/// like `_:b0`), else the one with the longest name, else the first-seen
+/// node for determinism - merges every other cluster member's properties
+/// into it, and rewrites every `{"@id": ...}` reference in the graph to
+/// point at the canonical id. Nodes with no `name` property are left
+/// untouched (they already went through exact-match merging only).
+fn resolve_entities_by_embedding(
+    mut graph: Vec<serde_json::Value>,
+    embedder: &dyn TextEmbedder,
+    threshold: f32,
+) -> Vec<serde_json::Value> {
+    let names: Vec<Option<String>> = graph
+        .iter()
+        .map(|node| node.get("name").and_then(serde_json::Value::as_str).map(str::to_string))
+        .collect();
+
+    let mut embeddings: std::collections::HashMap<String, Vec<f32>> = std::collections::HashMap::new();
+    for name in names.iter().flatten() {
+        embeddings.entry(name.clone()).or_insert_with(|| embedder.embed(name));
+    }
+
+    let mut clusters = UnionFind::new(graph.len());
+    for i in 0..graph.len() {
+        let Some(name_i) = &names[i] else { continue };
+        for j in (i + 1)..graph.len() {
+            let Some(name_j) = &names[j] else { continue };
+            if name_i == name_j {
+                continue; // already one node via JsonLdFlattener's exact match
+            }
+            if crate::embedding::cosine_similarity(&embeddings[name_i], &embeddings[name_j]) >= threshold {
+                clusters.union(i, j);
+            }
+        }
+    }
+
+    let mut members_by_root: std::collections::HashMap<usize, Vec<usize>> = std::collections::HashMap::new();
+    for i in 0..graph.len() {
+        members_by_root.entry(clusters.find(i)).or_default().push(i);
+    }
+
+    let mut id_remap: std::collections::HashMap<String, String> = std::collections::HashMap::new();
+    let mut canonical_indices: Vec<usize> = Vec::new();
+
+    for members in members_by_root.values() {
+        let canonical_idx = *members
+            .iter()
+            .min_by_key(|&&idx| {
+                let id = graph[idx].get("@id").and_then(serde_json::Value::as_str).unwrap_or("");
+                let is_blank_node = id.starts_with("_:b");
+                let name_len = names[idx].as_deref().map_or(0, str::len);
+                (is_blank_node, std::cmp::Reverse(name_len), idx)
+            })
+            .expect("members is non-empty");
+        canonical_indices.push(canonical_idx);
+
+        let canonical_id = graph[canonical_idx]
+            .get("@id")
+            .and_then(serde_json::Value::as_str)
+            .unwrap_or_default()
+            .to_string();
+
+        for &idx in members {
+            if idx == canonical_idx {
+                continue;
+            }
+            if let Some(member_id) = graph[idx].get("@id").and_then(serde_json::Value::as_str) {
+                id_remap.insert(member_id.to_string(), canonical_id.clone());
+            }
+
+            let Some(member_props) = graph[idx].as_object().cloned() else { continue };
+            let Some(canonical_obj) = graph[canonical_idx].as_object_mut() else { continue };
+            for (key, value) in member_props {
+                if key == "@id" {
+                    continue;
+                }
+                match canonical_obj.get_mut(&key) {
+                    Some(existing) => merge_property_value(existing, value),
+                    None => {
+                        canonical_obj.insert(key, value);
+                    }
+                }
+            }
+        }
+    }
+
+    canonical_indices.sort_unstable();
+    let mut resolved: Vec<serde_json::Value> =
+        canonical_indices.into_iter().map(|idx| graph[idx].clone()).collect();
+
+    if !id_remap.is_empty() {
+        for node in &mut resolved {
+            rewrite_id_references(node, &id_remap);
+        }
+    }
+
+    resolved
+}
+
+/// Recursively rewrite every `{"@id": ...}` reference within `value` per
+/// `id_remap`, so dropping a non-canonical node during entity resolution
+/// doesn't leave other nodes pointing at a now-missing id
+fn rewrite_id_references(value: &mut serde_json::Value, id_remap: &std::collections::HashMap<String, String>) {
+    match value {
+        serde_json::Value::Array(items) => {
+            for item in items {
+                rewrite_id_references(item, id_remap);
+            }
+        }
+        serde_json::Value::Object(map) => {
+            if let Some(serde_json::Value::String(id)) = map.get_mut("@id") {
+                if let Some(canonical) = id_remap.get(id) {
+                    *id = canonical.clone();
+                }
+            }
+            for (key, val) in map.iter_mut() {
+                if key != "@id" {
+                    rewrite_id_references(val, id_remap);
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+/// JSON-LD Flattening pass used by [`GenAiExtractor::merge_chunks`]: walks
+/// every chunk's extracted document, hoists every node (an object with an
+/// `@id` or a `@type`+`name`) into a single map keyed by [`NodeKey`], and
+/// replaces nested node objects in place with an `{"@id": ...}` reference so
+/// the result is a flat graph. The same entity mentioned in several chunks
+/// is merged into one node rather than duplicated, with property values
+/// unioned across occurrences
+#[derive(Debug, Default)]
+struct JsonLdFlattener {
+    nodes: std::collections::HashMap<NodeKey, serde_json::Map<String, serde_json::Value>>,
+    /// First-seen order of node keys, since `HashMap` iteration order is
+    /// nondeterministic and the merged `@graph` should be stable
+    order: Vec<NodeKey>,
+    next_blank_id: usize,
+}
+
+impl JsonLdFlattener {
+    fn new() -> Self {
+        Self::default()
+    }
+
+    /// Recursively hoist every node within `value` into `self.nodes`,
+    /// replacing it in place with an `{"@id": ...}` reference
+    fn flatten(&mut self, value: &mut serde_json::Value) -> Option<String> {
+        match value {
+            serde_json::Value::Array(items) => {
+                for item in items.iter_mut() {
+                    self.flatten(item);
+                }
+                None
+            }
+            serde_json::Value::Object(map) => {
+                for (key, val) in map.iter_mut() {
+                    if key != "@context" {
+                        self.flatten(val);
+                    }
+                }
+                // @context describes the document, not the node - don't let it
+                // ride along into the merged node's property map
+                map.remove("@context");
+
+                let key = node_key_for(map)?;
+                let taken = std::mem::take(map);
+                let id = self.register_node(key, taken);
+                *value = serde_json::json!({ "@id": id });
+                Some(id)
+            }
+            _ => None,
+        }
+    }
+
+    /// Merge `props` into the node identified by `key`, minting a blank node
+    /// id on first occurrence (or reusing the explicit `@id` if one was
+    /// given), and return that node's id
+    fn register_node(&mut self, key: NodeKey, props: serde_json::Map<String, serde_json::Value>) -> String {
+        if let Some(existing) = self.nodes.get_mut(&key) {
+            for (prop_key, prop_value) in props {
+                if prop_key == "@id" {
+                    continue;
+                }
+                match existing.get_mut(&prop_key) {
+                    Some(existing_value) => merge_property_value(existing_value, prop_value),
+                    None => {
+                        existing.insert(prop_key, prop_value);
+                    }
+                }
+            }
+            return existing.get("@id").and_then(serde_json::Value::as_str).unwrap().to_string();
+        }
+
+        let id = match &key {
+            NodeKey::Id(id) => id.clone(),
+            NodeKey::TypeName(..) => {
+                let id = format!("_:b{}", self.next_blank_id);
+                self.next_blank_id += 1;
+                id
+            }
+        };
+
+        let mut node = props;
+        node.insert("@id".to_string(), serde_json::Value::String(id.clone()));
+        self.nodes.insert(key.clone(), node);
+        self.order.push(key);
+        id
+    }
+
+    /// Drain the accumulated nodes into a `@graph` array, in first-seen order
+    fn into_graph(mut self) -> Vec<serde_json::Value> {
+        self.order
+            .into_iter()
+            .filter_map(|key| self.nodes.remove(&key))
+            .map(serde_json::Value::Object)
+            .collect()
+    }
+}
+
+/// A stable, content-addressed identifier for a source document, used as
+/// [`ProvEntity::was_derived_from`] - the same convention as
+/// [`ExtractionCache::key`](crate::cache::ExtractionCache::key)'s hashing,
+/// minus the model/temperature salt since this identifies the *source text*,
+/// not one particular extraction of it
+fn source_document_id(text: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(text.as_bytes());
+    let digest: String = hasher.finalize().iter().map(|byte| format!("{byte:02x}")).collect();
+    format!("urn:sha256:{digest}")
+}
+
+/// The `@id` of every top-level node in a chunk's extracted data (an `@graph`
+/// array, or a single entity object), falling back to a positional blank
+/// identifier for nodes the LLM didn't give an explicit `@id`
+fn top_level_node_ids(data: &serde_json::Value, chunk_idx: usize) -> Vec<String> {
+    let nodes: Vec<&serde_json::Value> = match data {
+        serde_json::Value::Object(obj) if obj.contains_key("@graph") => obj
+            .get("@graph")
+            .and_then(serde_json::Value::as_array)
+            .map(|items| items.iter().collect())
+            .unwrap_or_default(),
+        serde_json::Value::Object(obj) if !obj.is_empty() => vec![data],
+        _ => Vec::new(),
+    };
+    nodes
+        .into_iter()
+        .enumerate()
+        .map(|(node_idx, node)| {
+            node.get("@id")
+                .and_then(serde_json::Value::as_str)
+                .map_or_else(|| format!("_:chunk{chunk_idx}n{node_idx}"), str::to_string)
+        })
+        .collect()
 }
 
 impl GenAiExtractor {
@@ -144,11 +515,53 @@ impl GenAiExtractor {
             None
         };
 
+        // Embedder and resolution index backing the `Embedding` linking
+        // strategy. Only `"hashing"` has a real implementation today; any
+        // other configured model falls back to it (see `embedding_model`'s
+        // doc comment on `EntityLinkerConfig`).
+        let embedder: Box<dyn TextEmbedder + Send + Sync> = Box::new(HashingEmbedder::default());
+        let embedding_index = match &config.entity_linker.embedding_index_path {
+            Some(path) => EmbeddingEntityIndex::load_or_default(
+                path,
+                config.entity_linker.embedding_similarity_threshold as f32,
+            )?,
+            None => EmbeddingEntityIndex::new(
+                config.entity_linker.embedding_similarity_threshold as f32,
+            ),
+        };
+
+        let cache = config
+            .cache_dir
+            .as_ref()
+            .map(ExtractionCache::open)
+            .transpose()?;
+
+        let chunk_cache = (config.cache_capacity > 0 && config.cache_ttl_secs > 0).then(|| {
+            moka::sync::Cache::builder()
+                .max_capacity(config.cache_capacity)
+                .time_to_live(std::time::Duration::from_secs(config.cache_ttl_secs))
+                .build()
+        });
+
+        let persistent_chunk_cache = config
+            .persistent_cache_path
+            .as_ref()
+            .map(|path| -> Result<PersistentChunkCache> {
+                let backend = open_default_backend(path)?;
+                Ok(PersistentChunkCache::new(backend, config.persistent_cache_ttl_secs))
+            })
+            .transpose()?;
+
         Ok(Self {
             client,
             config,
             coref_resolver,
             entity_linker,
+            embedder,
+            embedding_index: Mutex::new(embedding_index),
+            cache,
+            chunk_cache,
+            persistent_chunk_cache,
         })
     }
 
@@ -217,6 +630,15 @@ impl GenAiExtractor {
         }
     }
 
+    /// Record one retry `extract_with_retry` fed back to the LLM, tagged by
+    /// the [`Error`] variant that triggered it
+    #[cfg(feature = "telemetry")]
+    fn record_retry(error: &Error) {
+        crate::telemetry::pipeline_metrics()
+            .retry_count
+            .add(1, &[KeyValue::new("error", crate::telemetry::error_variant_name(error))]);
+    }
+
     /// Extract with retry logic and error feedback (Instructor pattern)
     ///
     /// This implements the Instructor pattern by:
@@ -227,6 +649,7 @@ impl GenAiExtractor {
     async fn extract_with_retry(&self, text: &str) -> Result<RdfDocument> {
         let mut last_error = None;
         let mut conversation_history = Vec::new();
+        let run_started_at = self.config.record_provenance.then(now_rfc3339);
 
         // Initial system message
         conversation_history.push(ChatMessage::system(self.get_system_prompt()));
@@ -255,12 +678,32 @@ impl GenAiExtractor {
             conversation_history.push(ChatMessage::user(user_message));
 
             // Execute the chat request with conversation history
+            #[cfg(feature = "telemetry")]
+            let tracer = global::tracer(crate::telemetry::PIPELINE_SCOPE);
+            #[cfg(feature = "telemetry")]
+            let mut llm_span = tracer.start("extract.llm_request");
+            #[cfg(feature = "telemetry")]
+            let llm_started_at = Instant::now();
+
             let request = ChatRequest::new(conversation_history.clone());
-            let response = self
+            let chat_result = self
                 .client
                 .exec_chat(&self.config.model, request, None)
                 .await
-                .map_err(|e| Error::AiService(e.to_string()))?;
+                .map_err(|e| Error::AiService(e.to_string()));
+
+            #[cfg(feature = "telemetry")]
+            {
+                let elapsed = llm_started_at.elapsed().as_secs_f64();
+                let metrics = crate::telemetry::pipeline_metrics();
+                metrics.stage_latency.record(elapsed, &[KeyValue::new("stage", "llm_request")]);
+                metrics.llm_call_latency.record(elapsed, &[KeyValue::new("model", self.config.model.clone())]);
+                if let Err(e) = &chat_result {
+                    llm_span.set_attribute(KeyValue::new("error.type", crate::telemetry::error_variant_name(e)));
+                }
+            }
+
+            let response = chat_result?;
 
             // Get the response content
             let content_text = response
@@ -274,11 +717,30 @@ impl GenAiExtractor {
             let json_str = Self::extract_json_from_response(content_text);
 
             // Try to parse and validate
-            match RdfDocument::from_json(&json_str) {
+            #[cfg(feature = "telemetry")]
+            let _validation_span = tracer.start("extract.jsonld_validation");
+            #[cfg(feature = "telemetry")]
+            let validation_started_at = Instant::now();
+
+            let parse_result = RdfDocument::from_json(&json_str);
+
+            #[cfg(feature = "telemetry")]
+            crate::telemetry::pipeline_metrics().stage_latency.record(
+                validation_started_at.elapsed().as_secs_f64(),
+                &[KeyValue::new("stage", "jsonld_validation")],
+            );
+
+            match parse_result {
                 Ok(mut doc) => {
                     // Inject hardcoded context if enabled
                     if self.config.inject_hardcoded_context {
                         if let Err(e) = doc.inject_hardcoded_context() {
+                            #[cfg(feature = "telemetry")]
+                            {
+                                if attempt < self.config.max_retries {
+                                    Self::record_retry(&e);
+                                }
+                            }
                             last_error = Some(e);
                             continue;
                         }
@@ -287,18 +749,46 @@ impl GenAiExtractor {
                     // If strict validation is enabled, validate the document
                     if self.config.strict_validation {
                         if let Err(e) = doc.validate() {
+                            #[cfg(feature = "telemetry")]
+                            {
+                                if attempt < self.config.max_retries {
+                                    Self::record_retry(&e);
+                                }
+                            }
                             last_error = Some(e);
                             continue;
                         }
                     }
+
+                    // Record W3C PROV-O provenance if enabled
+                    if let Some(started_at) = &run_started_at {
+                        let source_id = source_document_id(text);
+                        let activity_id = format!("urn:run:{source_id}");
+                        let mut prov = ProvenanceGraph::new();
+                        prov.record_activity(ProvActivity::new(
+                            activity_id.clone(),
+                            started_at.clone(),
+                            now_rfc3339(),
+                            self.config.model.clone(),
+                            attempt,
+                        ));
+                        for entity_id in top_level_node_ids(&doc.data, 0) {
+                            prov.record_entity(ProvEntity::new(entity_id, activity_id.clone(), source_id.clone(), 0, text.len()));
+                        }
+                        doc.set_prov(prov);
+                    }
+
                     return Ok(doc);
                 }
                 Err(e) => {
-                    last_error = Some(e);
-                    // If we've exhausted retries, return the error
+                    // If we've exhausted retries, return the error without
+                    // recording a retry (there won't be another attempt)
                     if attempt == self.config.max_retries {
-                        return Err(last_error.unwrap());
+                        return Err(e);
                     }
+                    #[cfg(feature = "telemetry")]
+                    Self::record_retry(&e);
+                    last_error = Some(e);
                 }
             }
         }
@@ -307,12 +797,6 @@ impl GenAiExtractor {
         Err(last_error.unwrap_or_else(|| Error::Extraction("Unknown error".to_string())))
     }
 
-    /// Estimate the number of tokens in text (rough approximation)
-    const fn estimate_tokens(text: &str) -> usize {
-        // Rough approximation: 1 token ‚âà 4 characters for English
-        text.len() / 4
-    }
-
     /// Link entities in extracted document to canonical URIs
     async fn link_entities_in_document(&self, doc: &mut RdfDocument, text: &str) -> Result<()> {
         // Skip if no linker configured
@@ -320,6 +804,13 @@ impl GenAiExtractor {
             return Ok(());
         };
 
+        // The `Embedding` strategy resolves against a shared, persisted
+        // index instead of per-call URI lookups (see `EntityLinker::link_entity`),
+        // so it's wired in separately here.
+        if self.config.entity_linker.strategy == LinkingStrategy::Embedding {
+            return self.resolve_entities_with_embedding_index(doc);
+        }
+
         // Extract entity names from JSON-LD
         let entity_names = Self::extract_entity_names(&doc.data);
         if entity_names.is_empty() {
@@ -351,6 +842,13 @@ impl GenAiExtractor {
                     }
                 }
 
+                #[cfg(feature = "telemetry")]
+                {
+                    let metrics = crate::telemetry::pipeline_metrics();
+                    metrics.entities_attempted.add(entity_names.len() as u64, &[]);
+                    metrics.entities_linked.add(linked_count as u64, &[]);
+                }
+
                 if std::env::var("DEBUG_ENTITY_LINKING").is_ok() {
                     println!(
                         "    üîó Linked {}/{} entities successfully",
@@ -376,6 +874,94 @@ impl GenAiExtractor {
         names
     }
 
+    /// Resolve every entity's `name` in `doc` against the shared
+    /// cross-document [`EmbeddingEntityIndex`], rewriting it to the
+    /// canonical name of the closest match so surface-form variants (e.g.
+    /// "Marie Curie" vs "Marie Sk≈Çodowska Curie") collapse into one entity
+    /// across chunks and documents
+    fn resolve_entities_with_embedding_index(&self, doc: &mut RdfDocument) -> Result<()> {
+        let pairs = Self::extract_entity_name_types(&doc.data);
+        if pairs.is_empty() {
+            return Ok(());
+        }
+
+        let mut index = self
+            .embedding_index
+            .lock()
+            .map_err(|_| Error::Config("Embedding index lock poisoned".to_string()))?;
+
+        for (name, entity_type) in pairs {
+            let canonical = index.resolve_or_insert(self.embedder.as_ref(), &name, &entity_type);
+            if canonical != name {
+                if std::env::var("DEBUG_ENTITY_LINKING").is_ok() {
+                    println!("    üîó {name} ‚Üí {canonical} (embedding match)");
+                }
+                Self::rename_entity(&mut doc.data, &name, &canonical);
+            }
+        }
+
+        if let Some(path) = &self.config.entity_linker.embedding_index_path {
+            index.save(path)?;
+        }
+
+        Ok(())
+    }
+
+    /// Extract `(name, @type)` pairs for every entity in the JSON-LD document
+    fn extract_entity_name_types(value: &serde_json::Value) -> Vec<(String, String)> {
+        let mut pairs = Vec::new();
+        Self::extract_entity_name_types_recursive(value, &mut pairs);
+        pairs
+    }
+
+    fn extract_entity_name_types_recursive(
+        value: &serde_json::Value,
+        pairs: &mut Vec<(String, String)>,
+    ) {
+        match value {
+            serde_json::Value::Object(map) => {
+                if let (Some(name), Some(entity_type)) = (
+                    map.get("name").and_then(|v| v.as_str()),
+                    map.get("@type").and_then(|v| v.as_str()),
+                ) {
+                    pairs.push((name.to_string(), entity_type.to_string()));
+                }
+                for v in map.values() {
+                    Self::extract_entity_name_types_recursive(v, pairs);
+                }
+            }
+            serde_json::Value::Array(arr) => {
+                for v in arr {
+                    Self::extract_entity_name_types_recursive(v, pairs);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Rename the first entity matching `entity_name` to `canonical_name`
+    fn rename_entity(value: &mut serde_json::Value, entity_name: &str, canonical_name: &str) {
+        match value {
+            serde_json::Value::Object(map) => {
+                if let Some(name) = map.get("name").and_then(|v| v.as_str()) {
+                    if name == entity_name {
+                        map.insert("name".to_string(), serde_json::json!(canonical_name));
+                        return;
+                    }
+                }
+                for v in map.values_mut() {
+                    Self::rename_entity(v, entity_name, canonical_name);
+                }
+            }
+            serde_json::Value::Array(arr) => {
+                for v in arr {
+                    Self::rename_entity(v, entity_name, canonical_name);
+                }
+            }
+            _ => {}
+        }
+    }
+
     /// Recursively extract names from JSON-LD structure
     fn extract_names_recursive(value: &serde_json::Value, names: &mut Vec<String>) {
         match value {
@@ -396,6 +982,61 @@ impl GenAiExtractor {
         }
     }
 
+    /// Collect every `(property, value)` pair with a string value anywhere
+    /// in the JSON-LD document, skipping `@`-prefixed JSON-LD keywords - used
+    /// to locate each property's evidence span via substring search against
+    /// the source text
+    fn extract_string_properties(value: &serde_json::Value) -> Vec<(String, String)> {
+        let mut properties = Vec::new();
+        Self::extract_string_properties_recursive(value, &mut properties);
+        properties
+    }
+
+    fn extract_string_properties_recursive(
+        value: &serde_json::Value,
+        properties: &mut Vec<(String, String)>,
+    ) {
+        match value {
+            serde_json::Value::Object(map) => {
+                for (key, v) in map {
+                    if key.starts_with('@') {
+                        continue;
+                    }
+                    if let Some(s) = v.as_str() {
+                        properties.push((key.clone(), s.to_string()));
+                    } else {
+                        Self::extract_string_properties_recursive(v, properties);
+                    }
+                }
+            }
+            serde_json::Value::Array(arr) => {
+                for v in arr {
+                    Self::extract_string_properties_recursive(v, properties);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Build provenance for an extracted document, attaching an evidence
+    /// [`Span`](crate::types::Span) for every property whose value appears
+    /// verbatim in `source_text` (located via substring search)
+    fn build_provenance_with_spans(
+        doc: &serde_json::Value,
+        source_text: &str,
+        base_offset: usize,
+    ) -> crate::types::Provenance {
+        let mut provenance = crate::types::Provenance::new();
+
+        for (property, value) in Self::extract_string_properties(doc) {
+            if let Some(span) = crate::types::Span::find(source_text, &value, base_offset) {
+                provenance = provenance.with_property_span(property, span);
+            }
+        }
+
+        provenance
+    }
+
     /// Enrich a specific entity with a canonical URI
     fn enrich_entity_with_uri(value: &mut serde_json::Value, entity_name: &str, uri: &str) {
         match value {
@@ -445,6 +1086,43 @@ impl GenAiExtractor {
         chunk: &DocumentChunk,
         kb: &KnowledgeBuffer,
     ) -> Result<RdfDocument> {
+        // Consult the in-memory chunk-response cache before calling the LLM
+        let chunk_cache_key = self.chunk_cache.as_ref().map(|_| {
+            ExtractionCache::key(
+                &chunk.text,
+                &self.config.model,
+                self.config.temperature,
+                self.get_system_prompt(),
+                &self.config.ontologies,
+            )
+        });
+
+        if let (Some(cache), Some(key)) = (&self.chunk_cache, &chunk_cache_key) {
+            if let Some(cached) = cache.get(key) {
+                return Ok(cached);
+            }
+        }
+
+        // Consult the persistent, cross-run cache next - it survives a
+        // process restart that would otherwise empty `chunk_cache`
+        let persistent_cache_key = self.persistent_chunk_cache.as_ref().map(|_| {
+            PersistentChunkCache::key(
+                &chunk.text,
+                &self.config.model,
+                self.get_system_prompt(),
+                &format!("temperature={:?};ontologies={}", self.config.temperature, self.config.ontologies.join(",")),
+            )
+        });
+
+        if let (Some(cache), Some(key)) = (&self.persistent_chunk_cache, &persistent_cache_key) {
+            if let Some(cached) = cache.get(key).await? {
+                if let (Some(mem_cache), Some(mem_key)) = (&self.chunk_cache, &chunk_cache_key) {
+                    mem_cache.insert(mem_key.clone(), cached.clone());
+                }
+                return Ok(cached);
+            }
+        }
+
         // Build context-enriched prompt
         let context_prompt = self.build_context_prompt(kb);
 
@@ -460,12 +1138,32 @@ impl GenAiExtractor {
         conversation.push(ChatMessage::user(user_message));
 
         // Execute the chat request
+        #[cfg(feature = "telemetry")]
+        let tracer = global::tracer(crate::telemetry::PIPELINE_SCOPE);
+        #[cfg(feature = "telemetry")]
+        let mut llm_span = tracer.start("extract.chunk_llm_request");
+        #[cfg(feature = "telemetry")]
+        let llm_started_at = Instant::now();
+
         let request = ChatRequest::new(conversation);
-        let response = self
+        let chat_result = self
             .client
             .exec_chat(&self.config.model, request, None)
             .await
-            .map_err(|e| Error::AiService(e.to_string()))?;
+            .map_err(|e| Error::AiService(e.to_string()));
+
+        #[cfg(feature = "telemetry")]
+        {
+            let elapsed = llm_started_at.elapsed().as_secs_f64();
+            let metrics = crate::telemetry::pipeline_metrics();
+            metrics.stage_latency.record(elapsed, &[KeyValue::new("stage", "llm_request")]);
+            metrics.llm_call_latency.record(elapsed, &[KeyValue::new("model", self.config.model.clone())]);
+            if let Err(e) = &chat_result {
+                llm_span.set_attribute(KeyValue::new("error.type", crate::telemetry::error_variant_name(e)));
+            }
+        }
+
+        let response = chat_result?;
 
         // Get the response content
         let content_text = response
@@ -488,46 +1186,72 @@ impl GenAiExtractor {
             doc.validate()?;
         }
 
+        // Write through to the chunk-response cache, skipping empty results
+        // so a degenerate extraction doesn't poison future identical chunks
+        let is_empty = doc.data.as_object().is_some_and(serde_json::Map::is_empty);
+        if let (Some(cache), Some(key)) = (&self.chunk_cache, &chunk_cache_key) {
+            if !is_empty {
+                cache.insert(key.clone(), doc.clone());
+            }
+        }
+        if let (Some(cache), Some(key)) = (&self.persistent_chunk_cache, &persistent_cache_key) {
+            if !is_empty {
+                cache.put(key, &doc).await?;
+            }
+        }
+
         Ok(doc)
     }
 
-    /// Merge documents from multiple chunks, deduplicating entities and triples
+    /// Merge documents from multiple chunks via JSON-LD flattening: every
+    /// node (an object with an `@id` or a `@type`+`name`) across every chunk
+    /// is hoisted into a single node map keyed by [`NodeKey`], so the same
+    /// entity mentioned in several chunks becomes one node with its
+    /// properties unioned, rather than one duplicate node per chunk
     fn merge_chunks(docs: Vec<RdfDocument>) -> RdfDocument {
+        Self::merge_chunks_with_resolution(docs, None)
+    }
+
+    /// [`Self::merge_chunks`], optionally followed by an embedding-backed
+    /// entity resolution pass (see [`resolve_entities_by_embedding`]) when
+    /// `resolution` is `Some((embedder, threshold))` -
+    /// [`Self::extract_from_document`] passes this when
+    /// `config.merge_entity_resolution` is enabled, to additionally catch
+    /// near-duplicate surface forms ("IBM" vs "International Business
+    /// Machines") that `JsonLdFlattener`'s exact `@id`/`@type`+`name` match
+    /// can't
+    fn merge_chunks_with_resolution(
+        docs: Vec<RdfDocument>,
+        resolution: Option<(&dyn TextEmbedder, f32)>,
+    ) -> RdfDocument {
         if docs.is_empty() {
             // Return empty document with schema.org context
             return RdfDocument {
                 context: serde_json::json!("https://schema.org/"),
                 data: serde_json::json!({}),
                 provenance: None,
+                prov: None,
+                validity: None,
             };
         }
 
         // Use context from first document
         let context = docs[0].context.clone();
 
-        // Collect all entities in a @graph array
-        let mut graph = Vec::new();
-
+        let mut flattener = JsonLdFlattener::new();
+        let mut merged_prov = crate::prov::ProvenanceGraph::new();
         for doc in docs {
-            if let Some(obj) = doc.data.as_object() {
-                // Check if this document has a @graph key
-                if let Some(graph_array) = obj.get("@graph").and_then(|v| v.as_array()) {
-                    // Add all entities from the graph
-                    graph.extend(graph_array.iter().cloned());
-                } else if !obj.is_empty() {
-                    // This is a single entity - create a clean copy without @context
-                    let mut entity = serde_json::Map::new();
-                    for (key, value) in obj {
-                        if key != "@context" {
-                            entity.insert(key.clone(), value.clone());
-                        }
-                    }
-                    if !entity.is_empty() {
-                        graph.push(serde_json::json!(entity));
-                    }
-                }
+            let mut data = doc.data;
+            flattener.flatten(&mut data);
+            if let Some(prov) = doc.prov {
+                merged_prov.merge(prov);
             }
         }
+        let mut graph = flattener.into_graph();
+
+        if let Some((embedder, threshold)) = resolution {
+            graph = resolve_entities_by_embedding(graph, embedder, threshold);
+        }
 
         // Create merged document with @graph
         let merged_data = if graph.is_empty() {
@@ -546,6 +1270,8 @@ impl GenAiExtractor {
             context,
             data: merged_data,
             provenance: None,
+            prov: if merged_prov.is_empty() { None } else { Some(merged_prov) },
+            validity: None,
         }
     }
 
@@ -568,8 +1294,92 @@ impl GenAiExtractor {
     ///
     /// Returns an error if extraction fails
     pub async fn extract_from_document(&self, text: &str) -> Result<RdfDocument> {
+        let mut all_docs = Vec::new();
+        let mut stream = self.extract_stream(text);
+        while let Some(result) = stream.next().await {
+            match result {
+                Ok(doc) => all_docs.push(doc),
+                Err(e) => eprintln!("  \u{26a0}\u{fe0f}  Chunk extraction failed: {e}"),
+            }
+        }
+
+        // 5. Merge and deduplicate, optionally with embedding-backed entity
+        // resolution across chunks
+        println!("  Merging {} chunks", all_docs.len());
+        let resolution = self
+            .config
+            .merge_entity_resolution
+            .then_some((self.embedder.as_ref(), self.config.merge_entity_resolution_threshold));
+        Ok(Self::merge_chunks_with_resolution(all_docs, resolution))
+    }
+
+    /// Like [`Self::extract_from_document`], but also returns a
+    /// [`CoverageReport`] unioning every chunk's
+    /// [`Provenance::text_span`](crate::types::Provenance::text_span)
+    /// against `text`, so callers can see what fraction of the document was
+    /// actually captured and where the gaps are - regions worth a targeted
+    /// re-run with a stricter prompt.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `config.provenance_tracking` is disabled (there
+    /// would be no spans to union against `text`), or if extraction itself
+    /// fails.
+    pub async fn extract_from_document_with_coverage(
+        &self,
+        text: &str,
+    ) -> Result<(RdfDocument, CoverageReport)> {
+        if !self.config.provenance_tracking {
+            return Err(Error::Config(
+                "extract_from_document_with_coverage requires config.provenance_tracking".to_string(),
+            ));
+        }
+
+        let mut all_docs = Vec::new();
+        let mut stream = self.extract_stream(text);
+        while let Some(result) = stream.next().await {
+            match result {
+                Ok(doc) => all_docs.push(doc),
+                Err(e) => eprintln!("  \u{26a0}\u{fe0f}  Chunk extraction failed: {e}"),
+            }
+        }
+
+        let covered_spans: Vec<(usize, usize)> = all_docs
+            .iter()
+            .filter_map(|doc| doc.provenance.as_ref().and_then(|p| p.text_span))
+            .collect();
+        let report = compute_coverage(text, &covered_spans);
+
+        println!("  Merging {} chunks", all_docs.len());
+        let resolution = self
+            .config
+            .merge_entity_resolution
+            .then_some((self.embedder.as_ref(), self.config.merge_entity_resolution_threshold));
+        let merged = Self::merge_chunks_with_resolution(all_docs, resolution);
+
+        Ok((merged, report))
+    }
+
+    /// Stream each chunk's extracted [`RdfDocument`] as soon as it's ready,
+    /// instead of blocking until the whole document is chunked, extracted,
+    /// and merged (see [`Self::extract_from_document`], now a thin wrapper
+    /// that drains this stream and calls [`Self::merge_chunks_with_resolution`]).
+    ///
+    /// Short documents (below the chunking threshold) yield a single item
+    /// from [`RdfExtractor::extract`]. Longer documents are split up front
+    /// (by [`TokenAwareChunker`] when `config.max_context_tokens` is set,
+    /// otherwise by [`SemanticChunker`]), then processed one at a time through
+    /// [`Self::process_chunk`] - coreference resolution, extraction, entity
+    /// linking, and provenance - while a [`KnowledgeBuffer`] accumulates
+    /// context across chunks for continuity, exactly as
+    /// [`Self::extract_from_document`] did inline before this method
+    /// existed. A chunk that fails extraction yields `Err` rather than
+    /// aborting the stream, so later chunks still get a chance to run;
+    /// callers that want the old best-effort-and-skip behavior can match on
+    /// `Err` and continue, as [`Self::extract_from_document`] now does.
+    pub fn extract_stream<'a>(&'a self, text: &'a str) -> BoxStream<'a, Result<RdfDocument>> {
         // 1. Check if document needs chunking
-        let token_count = Self::estimate_tokens(text);
+        let token_count = self.config.count_tokens(text);
 
         // Use configurable threshold (default 2000, can be set lower for testing)
         let chunk_threshold = std::env::var("RDF_CHUNK_THRESHOLD")
@@ -578,130 +1388,313 @@ impl GenAiExtractor {
             .unwrap_or(2000);
 
         if token_count < chunk_threshold {
-            // Short document - extract normally
-            return self.extract(text).await;
+            // Short document - extract normally, as a single-item stream
+            return stream::once(async move { self.extract(text).await }).boxed();
         }
 
-        // 2. Semantic chunking
-        let chunker = SemanticChunker::default();
-        let chunks = chunker.chunk(text);
+        // 2. Chunking, sized to fit the model's context window when
+        // `max_context_tokens` is configured (budget = context window minus
+        // the response allowance and the system prompt). `TokenAwareChunker`
+        // measures exact tokens per candidate sentence/paragraph rather than
+        // `SemanticChunker`'s chars-per-token approximation, so a single
+        // token-dense sentence can't push a chunk over budget; falling back
+        // to the character-based default when no token budget is configured.
+        let chunks = if let Some(max_context_tokens) = self.config.max_context_tokens {
+            let counter = TokenCounter::for_model(&self.config.model);
+            let response_tokens = self.config.max_tokens.unwrap_or(0);
+            let system_prompt_tokens = self.config.count_tokens(self.get_system_prompt()) as u32;
+            let budget_tokens = max_context_tokens
+                .saturating_sub(response_tokens)
+                .saturating_sub(system_prompt_tokens)
+                .max(256);
+            TokenAwareChunker::new(budget_tokens as usize, 100, &counter).chunk(text)
+        } else {
+            SemanticChunker::default().chunk(text)
+        };
+        let total = chunks.len();
 
         println!(
-            "üìä Document is {} tokens, splitting into {} chunks",
-            token_count,
-            chunks.len()
+            "\u{1f4ca} Document is {} tokens, splitting into {} chunks",
+            token_count, total
         );
 
-        // 3. Knowledge buffer for entity tracking
-        let mut kb = KnowledgeBuffer::new();
-        let mut all_docs = Vec::new();
+        // 3. Knowledge buffer for entity tracking, threaded through the
+        // stream's state so it accumulates across chunks without needing a
+        // `Mutex` (only one chunk is ever in flight at a time)
+        let kb = KnowledgeBuffer::new();
+        let source_id = self.config.record_provenance.then(|| source_document_id(text));
 
         // 4. Process chunks sequentially (preserve order for coreference)
-        for (idx, chunk) in chunks.iter().enumerate() {
-            println!("  Processing chunk {}/{}", idx + 1, chunks.len());
-
-            // Apply coreference resolution BEFORE extraction
-            let resolved_chunk = match self.coref_resolver.resolve(&chunk.text).await {
-                Ok(coref_result) => {
-                    // Enrich KB with pronoun‚Üíentity mappings
-                    for (pronoun, entity) in &coref_result.mention_map {
-                        kb.add_alias(pronoun, entity);
-                    }
-
-                    // Debug logging
-                    if std::env::var("DEBUG_COREF").is_ok() && !coref_result.mention_map.is_empty()
-                    {
-                        println!(
-                            "    üîç Coref: {} pronouns resolved",
-                            coref_result.mention_map.len()
-                        );
-                    }
+        stream::unfold((chunks.into_iter().enumerate(), kb), move |(mut iter, mut kb)| {
+            let source_id = source_id.clone();
+            async move {
+                let (idx, chunk) = iter.next()?;
+                let result = self
+                    .process_chunk(idx, total, &chunk, &mut kb, source_id.as_deref())
+                    .await;
+                Some((result, (iter, kb)))
+            }
+        })
+        .boxed()
+    }
 
-                    // Create chunk with resolved text, preserving original offsets
-                    DocumentChunk {
-                        id: chunk.id,
-                        text: coref_result.resolved_text,
-                        start_offset: chunk.start_offset,
-                        end_offset: chunk.end_offset,
-                        entities_mentioned: chunk.entities_mentioned.clone(),
-                    }
+    /// The body of one [`Self::extract_stream`] step: coreference-resolve
+    /// `chunk`, extract it, link entities, attach provenance, and fold the
+    /// discovered entities into `kb` for the next chunk's context
+    async fn process_chunk(
+        &self,
+        idx: usize,
+        total: usize,
+        chunk: &DocumentChunk,
+        kb: &mut KnowledgeBuffer,
+        source_id: Option<&str>,
+    ) -> Result<RdfDocument> {
+        println!("  Processing chunk {}/{}", idx + 1, total);
+
+        #[cfg(feature = "telemetry")]
+        let chunk_tracer = global::tracer(crate::telemetry::PIPELINE_SCOPE);
+        #[cfg(feature = "telemetry")]
+        let mut chunk_span = chunk_tracer.start("extract.chunk");
+        #[cfg(feature = "telemetry")]
+        chunk_span.set_attribute(KeyValue::new("chunk.index", idx as i64));
+        #[cfg(feature = "telemetry")]
+        crate::telemetry::pipeline_metrics()
+            .chunk_tokens
+            .record(self.config.count_tokens(&chunk.text) as u64, &[]);
+
+        #[cfg(feature = "telemetry")]
+        let _coref_span = chunk_tracer.start("extract.coref_resolve");
+        #[cfg(feature = "telemetry")]
+        let coref_started_at = Instant::now();
+
+        // Apply coreference resolution BEFORE extraction
+        let resolved_chunk = match self.coref_resolver.resolve(&chunk.text).await {
+            Ok(coref_result) => {
+                // Enrich KB with pronoun\u2192entity mappings
+                for (pronoun, entity) in &coref_result.mention_map {
+                    kb.add_alias(pronoun, entity);
                 }
-                Err(e) => {
-                    eprintln!(
-                        "  ‚ö†Ô∏è  Coref failed for chunk {}: {}. Using original text.",
-                        idx + 1,
-                        e
+
+                // Debug logging
+                if std::env::var("DEBUG_COREF").is_ok() && !coref_result.mention_map.is_empty()
+                {
+                    println!(
+                        "    \u{1f50d} Coref: {} pronouns resolved",
+                        coref_result.mention_map.len()
                     );
-                    chunk.clone()
                 }
-            };
 
-            // Extract from resolved chunk
-            match self.extract_from_chunk(&resolved_chunk, &kb).await {
-                Ok(mut chunk_doc) => {
-                    // Link entities before updating KB
-                    if let Err(e) = self
-                        .link_entities_in_document(&mut chunk_doc, &resolved_chunk.text)
-                        .await
-                    {
-                        eprintln!("  ‚ö†Ô∏è  Chunk {} entity linking failed: {}", idx + 1, e);
-                    }
+                // Create chunk with resolved text, preserving original offsets
+                DocumentChunk {
+                    id: chunk.id,
+                    text: coref_result.resolved_text,
+                    start_offset: chunk.start_offset,
+                    end_offset: chunk.end_offset,
+                    entities_mentioned: chunk.entities_mentioned.clone(),
+                }
+            }
+            Err(e) => {
+                eprintln!(
+                    "  \u{26a0}\u{fe0f}  Coref failed for chunk {}: {}. Using original text.",
+                    idx + 1,
+                    e
+                );
+                chunk.clone()
+            }
+        };
 
-                    // Add provenance metadata if enabled
-                    if self.config.provenance_tracking {
-                        let provenance = crate::types::Provenance::new()
-                            .with_chunk_id(idx)
-                            .with_text_span(resolved_chunk.start_offset, resolved_chunk.end_offset)
-                            .with_method("llm".to_string())
-                            .with_source_text(resolved_chunk.text.clone());
+        #[cfg(feature = "telemetry")]
+        crate::telemetry::pipeline_metrics()
+            .stage_latency
+            .record(coref_started_at.elapsed().as_secs_f64(), &[KeyValue::new("stage", "coref_resolve")]);
 
-                        chunk_doc.set_provenance(provenance);
+        // Extract from resolved chunk
+        let chunk_started_at = self.config.record_provenance.then(now_rfc3339);
+        let mut chunk_doc = self.extract_from_chunk(&resolved_chunk, kb).await?;
 
-                        if std::env::var("DEBUG_PROVENANCE").is_ok() {
-                            println!(
-                                "  üìç Provenance: chunk={}, span=({}, {})",
-                                idx, resolved_chunk.start_offset, resolved_chunk.end_offset
-                            );
-                        }
-                    }
+        // Link entities before updating KB
+        #[cfg(feature = "telemetry")]
+        let _chunk_linking_span = chunk_tracer.start("extract.entity_linking");
+        #[cfg(feature = "telemetry")]
+        let chunk_linking_started_at = Instant::now();
 
-                    // Update KB with discovered entities (with URIs if linked)
-                    if let Some(obj) = chunk_doc.data.as_object() {
-                        if let (Some(entity_type), Some(entity_name)) = (
-                            obj.get("@type").and_then(|v| v.as_str()),
-                            obj.get("name").and_then(|v| v.as_str()),
-                        ) {
-                            kb.add_entity(
-                                entity_name,
-                                entity_type,
-                                resolved_chunk.start_offset,
-                                resolved_chunk.id,
-                            );
+        if let Err(e) = self
+            .link_entities_in_document(&mut chunk_doc, &resolved_chunk.text)
+            .await
+        {
+            eprintln!("  \u{26a0}\u{fe0f}  Chunk {} entity linking failed: {}", idx + 1, e);
+        }
 
-                            // Add canonical URI to KB if linked
-                            if let Some(id) = obj.get("@id").and_then(|v| v.as_str()) {
-                                kb.add_property(entity_name, "@id", id);
-                            }
-                        }
-                    }
-                    all_docs.push(chunk_doc);
-                }
-                Err(e) => {
-                    eprintln!("  ‚ö†Ô∏è  Chunk {} extraction failed: {}", idx + 1, e);
+        #[cfg(feature = "telemetry")]
+        crate::telemetry::pipeline_metrics().stage_latency.record(
+            chunk_linking_started_at.elapsed().as_secs_f64(),
+            &[KeyValue::new("stage", "entity_linking")],
+        );
+
+        // Add provenance metadata if enabled
+        if self.config.provenance_tracking {
+            let provenance = Self::build_provenance_with_spans(
+                &chunk_doc.data,
+                &resolved_chunk.text,
+                resolved_chunk.start_offset,
+            )
+            .with_chunk_id(idx)
+            .with_text_span(resolved_chunk.start_offset, resolved_chunk.end_offset)
+            .with_method("llm".to_string())
+            .with_source_text(resolved_chunk.text.clone());
+
+            chunk_doc.set_provenance(provenance);
+
+            if std::env::var("DEBUG_PROVENANCE").is_ok() {
+                println!(
+                    "  \u{1f4cd} Provenance: chunk={}, span=({}, {})",
+                    idx, resolved_chunk.start_offset, resolved_chunk.end_offset
+                );
+            }
+        }
+
+        // Record W3C PROV-O provenance if enabled
+        if let Some(source_id) = source_id {
+            let activity_id = format!("urn:run:{source_id}:{idx}");
+            let mut prov = ProvenanceGraph::new();
+            prov.record_activity(ProvActivity::new(
+                activity_id.clone(),
+                chunk_started_at.unwrap_or_default(),
+                now_rfc3339(),
+                self.config.model.clone(),
+                0,
+            ));
+            for entity_id in top_level_node_ids(&chunk_doc.data, idx) {
+                prov.record_entity(ProvEntity::new(
+                    entity_id,
+                    activity_id.clone(),
+                    source_id.to_string(),
+                    resolved_chunk.start_offset,
+                    resolved_chunk.end_offset,
+                ));
+            }
+            chunk_doc.set_prov(prov);
+        }
+
+        // Update KB with discovered entities (with URIs if linked)
+        if let Some(obj) = chunk_doc.data.as_object() {
+            if let (Some(entity_type), Some(entity_name)) = (
+                obj.get("@type").and_then(|v| v.as_str()),
+                obj.get("name").and_then(|v| v.as_str()),
+            ) {
+                kb.add_entity(
+                    entity_name,
+                    entity_type,
+                    resolved_chunk.start_offset,
+                    resolved_chunk.id,
+                );
+
+                // Add canonical URI to KB if linked
+                if let Some(id) = obj.get("@id").and_then(|v| v.as_str()) {
+                    kb.add_property(entity_name, "@id", id);
                 }
             }
         }
 
-        // 5. Merge and deduplicate
-        println!("  Merging {} chunks", all_docs.len());
-        Ok(Self::merge_chunks(all_docs))
+        Ok(chunk_doc)
+    }
+
+    /// Extract `samples` independent completions of `text` via
+    /// [`Self::extract_from_document`], for deriving per-triple confidence
+    /// from self-consistency (see [`crate::confidence::self_consistency_confidence`])
+    /// rather than trusting a single extraction.
+    ///
+    /// Short documents (below the chunking threshold) are extracted through
+    /// [`RdfExtractor::extract`], which consults the content-addressed cache
+    /// when configured - on a cache hit every sample would be identical, so
+    /// disable `config.cache_dir` when sampling a short document for
+    /// meaningful confidence estimates.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if every sample fails to extract.
+    pub async fn extract_samples(&self, text: &str, samples: usize) -> Result<Vec<RdfDocument>> {
+        let mut docs = Vec::new();
+        let mut last_error = None;
+
+        for _ in 0..samples.max(1) {
+            match self.extract_from_document(text).await {
+                Ok(doc) => docs.push(doc),
+                Err(e) => last_error = Some(e),
+            }
+        }
+
+        if docs.is_empty() {
+            return Err(last_error
+                .unwrap_or_else(|| Error::Extraction("All self-consistency samples failed".to_string())));
+        }
+
+        Ok(docs)
     }
 }
 
 #[async_trait]
 impl RdfExtractor for GenAiExtractor {
     async fn extract(&self, text: &str) -> Result<RdfDocument> {
+        #[cfg(feature = "telemetry")]
+        {
+            let tracer = global::tracer(crate::telemetry::PIPELINE_SCOPE);
+            let mut span = tracer.start("extract.pipeline");
+            span.set_attribute(KeyValue::new("input_len", text.len() as i64));
+
+            let result = self.extract_inner(text).await;
+
+            match &result {
+                Ok(doc) => {
+                    let triples = doc.data.get("@graph").and_then(serde_json::Value::as_array).map_or(1, Vec::len);
+                    span.set_attribute(KeyValue::new("triples_extracted", triples as i64));
+                    crate::telemetry::pipeline_metrics()
+                        .triples_extracted
+                        .add(triples as u64, &[]);
+                }
+                Err(e) => {
+                    span.set_attribute(KeyValue::new("error.type", crate::telemetry::error_variant_name(e)));
+                }
+            }
+
+            return result;
+        }
+
+        #[cfg(not(feature = "telemetry"))]
+        self.extract_inner(text).await
+    }
+}
+
+impl GenAiExtractor {
+    /// The body of [`RdfExtractor::extract`], factored out so the
+    /// `telemetry` feature can wrap it in a root span without duplicating
+    /// the cache/coreference/retry/linking logic below
+    async fn extract_inner(&self, text: &str) -> Result<RdfDocument> {
+        // Consult the content-addressed cache before calling the LLM at all
+        let cache_key = self.cache.as_ref().map(|_| {
+            ExtractionCache::key(
+                text,
+                &self.config.model,
+                self.config.temperature,
+                self.get_system_prompt(),
+                &self.config.ontologies,
+            )
+        });
+
+        if let (Some(cache), Some(key)) = (&self.cache, &cache_key) {
+            if let Some(cached) = cache.get(key)? {
+                return Ok(cached);
+            }
+        }
+
         // Apply coreference resolution for short documents too
+        #[cfg(feature = "telemetry")]
+        let tracer = global::tracer(crate::telemetry::PIPELINE_SCOPE);
+        #[cfg(feature = "telemetry")]
+        let _coref_span = tracer.start("extract.coref_resolve");
+        #[cfg(feature = "telemetry")]
+        let coref_started_at = Instant::now();
+
         let resolved_text = match self.coref_resolver.resolve(text).await {
             Ok(coref_result) => {
                 if std::env::var("DEBUG_COREF").is_ok() && !coref_result.mention_map.is_empty() {
@@ -718,16 +1711,31 @@ impl RdfExtractor for GenAiExtractor {
             }
         };
 
+        #[cfg(feature = "telemetry")]
+        crate::telemetry::pipeline_metrics()
+            .stage_latency
+            .record(coref_started_at.elapsed().as_secs_f64(), &[KeyValue::new("stage", "coref_resolve")]);
+
         // Use the Instructor pattern with retry logic on resolved text
         let mut result = self.extract_with_retry(&resolved_text).await?;
 
         // Link entities to canonical URIs
+        #[cfg(feature = "telemetry")]
+        let _linking_span = tracer.start("extract.entity_linking");
+        #[cfg(feature = "telemetry")]
+        let linking_started_at = Instant::now();
+
         self.link_entities_in_document(&mut result, &resolved_text)
             .await?;
 
+        #[cfg(feature = "telemetry")]
+        crate::telemetry::pipeline_metrics()
+            .stage_latency
+            .record(linking_started_at.elapsed().as_secs_f64(), &[KeyValue::new("stage", "entity_linking")]);
+
         // Add provenance metadata if enabled
         if self.config.provenance_tracking {
-            let provenance = crate::types::Provenance::new()
+            let provenance = Self::build_provenance_with_spans(&result.data, &resolved_text, 0)
                 .with_text_span(0, text.len())
                 .with_method("llm".to_string())
                 .with_source_text(text.to_string());
@@ -739,6 +1747,11 @@ impl RdfExtractor for GenAiExtractor {
             }
         }
 
+        // Write through to the cache on a miss
+        if let (Some(cache), Some(key)) = (&self.cache, &cache_key) {
+            cache.put(key, &result)?;
+        }
+
         Ok(result)
     }
 }
@@ -779,4 +1792,261 @@ Hope this helps!"#;
         let json = GenAiExtractor::extract_json_from_response(response);
         assert!(json.contains("@context"));
     }
+
+    fn doc(data: serde_json::Value) -> RdfDocument {
+        RdfDocument {
+            context: serde_json::json!("https://schema.org/"),
+            data,
+            provenance: None,
+            prov: None,
+            validity: None,
+        }
+    }
+
+    #[test]
+    fn test_merge_chunks_merges_same_id_entity_across_chunks() {
+        let a = doc(serde_json::json!({
+            "@id": "http://dbpedia.org/resource/Alan_Bean",
+            "@type": "Person",
+            "name": "Alan Bean",
+            "birthDate": "1932-03-15",
+        }));
+        let b = doc(serde_json::json!({
+            "@id": "http://dbpedia.org/resource/Alan_Bean",
+            "@type": "Person",
+            "name": "Alan Bean",
+            "deathDate": "2018-05-26",
+        }));
+
+        let merged = GenAiExtractor::merge_chunks(vec![a, b]);
+
+        let obj = merged.data.as_object().expect("single merged node");
+        assert_eq!(obj.get("birthDate").and_then(serde_json::Value::as_str), Some("1932-03-15"));
+        assert_eq!(obj.get("deathDate").and_then(serde_json::Value::as_str), Some("2018-05-26"));
+    }
+
+    #[test]
+    fn test_merge_chunks_strips_context_from_merged_node() {
+        // A real RdfDocument keeps "@context" inside `data` itself (unlike
+        // `doc()`'s hand-built fixtures), so it must not leak into the
+        // merged node's property map
+        let a = doc(serde_json::json!({
+            "@context": "https://schema.org/",
+            "@id": "http://dbpedia.org/resource/Alan_Bean",
+            "@type": "Person",
+            "name": "Alan Bean",
+        }));
+        let b = doc(serde_json::json!({
+            "@context": "https://schema.org/",
+            "@id": "http://dbpedia.org/resource/Alan_Bean",
+            "@type": "Person",
+            "deathDate": "2018-05-26",
+        }));
+
+        let merged = GenAiExtractor::merge_chunks(vec![a, b]);
+
+        let obj = merged.data.as_object().expect("single merged node");
+        assert!(!obj.contains_key("@context"));
+    }
+
+    #[test]
+    fn test_merge_chunks_merges_id_less_entity_by_normalized_type_and_name() {
+        let a = doc(serde_json::json!({ "@type": "Person", "name": "Alan Bean", "birthDate": "1932-03-15" }));
+        let b = doc(serde_json::json!({ "@type": "Person", "name": "alan bean", "alumniOf": "UT Austin" }));
+
+        let merged = GenAiExtractor::merge_chunks(vec![a, b]);
+
+        let obj = merged.data.as_object().expect("single merged node");
+        assert_eq!(obj.get("birthDate").and_then(serde_json::Value::as_str), Some("1932-03-15"));
+        assert_eq!(obj.get("alumniOf").and_then(serde_json::Value::as_str), Some("UT Austin"));
+    }
+
+    #[test]
+    fn test_merge_chunks_unions_array_values_without_duplicates() {
+        let a = doc(serde_json::json!({ "@type": "Person", "name": "Alan Bean", "knows": "Pete Conrad" }));
+        let b = doc(serde_json::json!({ "@type": "Person", "name": "Alan Bean", "knows": "Pete Conrad" }));
+        let c = doc(serde_json::json!({ "@type": "Person", "name": "Alan Bean", "knows": "Dick Gordon" }));
+
+        let merged = GenAiExtractor::merge_chunks(vec![a, b, c]);
+
+        let obj = merged.data.as_object().expect("single merged node");
+        let knows = obj.get("knows").and_then(serde_json::Value::as_array).expect("array of names");
+        assert_eq!(knows.len(), 2);
+        assert!(knows.contains(&serde_json::json!("Pete Conrad")));
+        assert!(knows.contains(&serde_json::json!("Dick Gordon")));
+    }
+
+    #[test]
+    fn test_merge_chunks_hoists_nested_entity_into_flat_graph() {
+        let a = doc(serde_json::json!({
+            "@type": "Person",
+            "name": "Alan Bean",
+            "alumniOf": { "@type": "EducationalOrganization", "name": "UT Austin" },
+        }));
+
+        let merged = GenAiExtractor::merge_chunks(vec![a]);
+
+        let graph = merged.data.get("@graph").and_then(serde_json::Value::as_array).expect("two hoisted nodes");
+        assert_eq!(graph.len(), 2);
+
+        let person = graph
+            .iter()
+            .find(|node| node.get("name").and_then(serde_json::Value::as_str) == Some("Alan Bean"))
+            .expect("person node");
+        let alumni_ref = person.get("alumniOf").expect("alumniOf reference");
+        assert!(alumni_ref.get("@id").is_some());
+        assert!(alumni_ref.get("@type").is_none(), "nested entity should be replaced by an @id reference");
+
+        let university = graph
+            .iter()
+            .find(|node| node.get("name").and_then(serde_json::Value::as_str) == Some("UT Austin"))
+            .expect("university node hoisted into the graph");
+        assert_eq!(university.get("@id"), alumni_ref.get("@id"));
+    }
+
+    #[test]
+    fn test_merge_chunks_unions_provenance_graphs() {
+        let mut a = doc(serde_json::json!({"@type": "Person", "name": "Alan Bean"}));
+        a.set_prov({
+            let mut prov = ProvenanceGraph::new();
+            prov.record_activity(ProvActivity::new("urn:run:1", "2026-07-31T00:00:00Z", "2026-07-31T00:00:01Z", "claude-3-5-sonnet", 0));
+            prov
+        });
+
+        let mut b = doc(serde_json::json!({"@type": "Person", "name": "Sally Ride"}));
+        b.set_prov({
+            let mut prov = ProvenanceGraph::new();
+            prov.record_activity(ProvActivity::new("urn:run:2", "2026-07-31T00:00:02Z", "2026-07-31T00:00:03Z", "claude-3-5-sonnet", 1));
+            prov
+        });
+
+        let merged = GenAiExtractor::merge_chunks(vec![a, b]);
+
+        let prov = merged.get_prov().expect("unioned provenance graph");
+        assert_eq!(prov.activities().len(), 2);
+    }
+
+    #[test]
+    fn test_merge_chunks_omits_prov_when_no_chunk_recorded_it() {
+        let a = doc(serde_json::json!({"@type": "Person", "name": "Alan Bean"}));
+        let merged = GenAiExtractor::merge_chunks(vec![a]);
+        assert!(merged.get_prov().is_none());
+    }
+
+    /// Fixed, hand-picked vectors for a couple of entity names, so the
+    /// embedding-resolution tests below don't depend on `HashingEmbedder`'s
+    /// trigram-hash output happening to land above or below a threshold
+    struct StubEmbedder;
+
+    impl TextEmbedder for StubEmbedder {
+        fn embed(&self, text: &str) -> Vec<f32> {
+            match text {
+                "IBM" => vec![1.0, 0.0],
+                "International Business Machines" => vec![0.99, 0.01],
+                "Acme Corp" => vec![0.0, 1.0],
+                _ => vec![0.0, 0.0],
+            }
+        }
+    }
+
+    #[test]
+    fn test_merge_chunks_with_resolution_clusters_similar_entity_names() {
+        let a = doc(serde_json::json!({
+            "@id": "http://dbpedia.org/resource/IBM",
+            "@type": "Organization",
+            "name": "IBM",
+            "foundingDate": "1911-06-16",
+        }));
+        let b = doc(serde_json::json!({
+            "@type": "Organization",
+            "name": "International Business Machines",
+            "numberOfEmployees": "350000",
+        }));
+
+        let embedder = StubEmbedder;
+        let merged = GenAiExtractor::merge_chunks_with_resolution(vec![a, b], Some((&embedder, 0.9)));
+
+        let obj = merged.data.as_object().expect("clustered into a single merged node");
+        assert_eq!(obj.get("@id").and_then(serde_json::Value::as_str), Some("http://dbpedia.org/resource/IBM"));
+        assert_eq!(obj.get("foundingDate").and_then(serde_json::Value::as_str), Some("1911-06-16"));
+        assert_eq!(obj.get("numberOfEmployees").and_then(serde_json::Value::as_str), Some("350000"));
+    }
+
+    #[test]
+    fn test_merge_chunks_with_resolution_leaves_dissimilar_entities_separate() {
+        let a = doc(serde_json::json!({"@type": "Organization", "name": "IBM"}));
+        let b = doc(serde_json::json!({"@type": "Organization", "name": "Acme Corp"}));
+
+        let embedder = StubEmbedder;
+        let merged = GenAiExtractor::merge_chunks_with_resolution(vec![a, b], Some((&embedder, 0.9)));
+
+        let graph = merged.data.get("@graph").and_then(serde_json::Value::as_array).expect("two distinct nodes");
+        assert_eq!(graph.len(), 2);
+    }
+
+    #[test]
+    fn test_merge_chunks_with_resolution_rewrites_references_to_canonical_id() {
+        let a = doc(serde_json::json!({
+            "@id": "http://dbpedia.org/resource/IBM",
+            "@type": "Organization",
+            "name": "IBM",
+        }));
+        let employee = doc(serde_json::json!({
+            "@type": "Person",
+            "name": "Someone Person",
+            "worksFor": { "@type": "Organization", "name": "International Business Machines" },
+        }));
+
+        let embedder = StubEmbedder;
+        let merged = GenAiExtractor::merge_chunks_with_resolution(vec![a, employee], Some((&embedder, 0.9)));
+
+        let graph = merged.data.get("@graph").and_then(serde_json::Value::as_array).expect("graph of nodes");
+        assert_eq!(graph.len(), 2, "IBM and the blank Business-Machines node should have merged");
+
+        let person = graph
+            .iter()
+            .find(|n| n.get("name").and_then(serde_json::Value::as_str) == Some("Someone Person"))
+            .expect("person node");
+        let works_for_id = person.get("worksFor").and_then(|v| v.get("@id")).and_then(serde_json::Value::as_str);
+        assert_eq!(works_for_id, Some("http://dbpedia.org/resource/IBM"));
+    }
+
+    #[test]
+    fn test_merge_chunks_without_resolution_keeps_similar_names_separate() {
+        let a = doc(serde_json::json!({"@type": "Organization", "name": "IBM"}));
+        let b = doc(serde_json::json!({"@type": "Organization", "name": "International Business Machines"}));
+
+        let merged = GenAiExtractor::merge_chunks(vec![a, b]);
+
+        let graph = merged.data.get("@graph").and_then(serde_json::Value::as_array).expect("two distinct nodes");
+        assert_eq!(graph.len(), 2, "merge_chunks without resolution only does exact-match merging");
+    }
+
+    #[test]
+    fn test_top_level_node_ids_uses_explicit_id_or_falls_back_to_blank() {
+        let with_id = serde_json::json!({"@id": "http://example.org/alan", "@type": "Person"});
+        assert_eq!(top_level_node_ids(&with_id, 0), vec!["http://example.org/alan".to_string()]);
+
+        let without_id = serde_json::json!({"@type": "Person", "name": "Alan Bean"});
+        assert_eq!(top_level_node_ids(&without_id, 3), vec!["_:chunk3n0".to_string()]);
+
+        let graph = serde_json::json!({"@graph": [
+            {"@id": "http://example.org/alan"},
+            {"@type": "Person", "name": "Sally Ride"},
+        ]});
+        assert_eq!(
+            top_level_node_ids(&graph, 0),
+            vec!["http://example.org/alan".to_string(), "_:chunk0n1".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_source_document_id_is_stable_and_content_addressed() {
+        let a = source_document_id("Alan Bean was born in 1932.");
+        let b = source_document_id("Alan Bean was born in 1932.");
+        let c = source_document_id("Sally Ride was born in 1951.");
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+        assert!(a.starts_with("urn:sha256:"));
+    }
 }
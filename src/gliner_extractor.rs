@@ -27,16 +27,54 @@ use gliner::model::pipeline::span::SpanMode;
 #[cfg(feature = "gliner")]
 use orp::params::RuntimeParameters;
 #[cfg(feature = "gliner")]
-use serde_json::json;
+use serde_json::{json, Value};
+#[cfg(feature = "gliner")]
+use std::time::{SystemTime, UNIX_EPOCH};
 #[cfg(feature = "gliner")]
 use std::collections::HashMap;
 #[cfg(feature = "gliner")]
 use std::path::PathBuf;
+#[cfg(all(feature = "gliner", feature = "otel"))]
+use opentelemetry::metrics::{Counter, Histogram};
+#[cfg(all(feature = "gliner", feature = "otel"))]
+use opentelemetry::trace::{Span, Tracer};
+#[cfg(all(feature = "gliner", feature = "otel"))]
+use opentelemetry::{global, KeyValue};
+#[cfg(all(feature = "gliner", feature = "arrow"))]
+use arrow::array::{Float32Array, StringArray, UInt32Array};
+#[cfg(all(feature = "gliner", feature = "arrow"))]
+use arrow::datatypes::{DataType, Field, Schema};
+#[cfg(all(feature = "gliner", feature = "arrow"))]
+use arrow::record_batch::RecordBatch;
+#[cfg(all(feature = "gliner", feature = "arrow"))]
+use parquet::arrow::ArrowWriter;
+#[cfg(all(feature = "gliner", feature = "arrow"))]
+use std::fs::File;
+#[cfg(all(feature = "gliner", feature = "arrow"))]
+use std::path::Path;
+#[cfg(all(feature = "gliner", feature = "arrow"))]
+use std::sync::Arc;
 
 #[cfg(feature = "gliner")]
 /// Extracted entity with provenance: (text, entity_type, confidence, start_offset, end_offset)
 type ExtractedEntity = (String, String, f32, usize, usize);
 
+#[cfg(feature = "gliner")]
+/// Shape of the `@graph` produced by [`GlinerExtractor::extract`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GraphMode {
+    /// Plain Schema.org entities, as before (default)
+    #[default]
+    SchemaOrg,
+
+    /// A W3C PROV-O graph: a `prov:Activity` for the extraction run, a
+    /// `prov:SoftwareAgent` for GLiNER, and each entity as a `prov:Entity`
+    /// linked back to the run and the source-document span it was derived
+    /// from - for pipelines where every triple must trace to a text offset
+    /// and a model version
+    ProvO,
+}
+
 #[cfg(feature = "gliner")]
 /// Configuration for GLiNER-based extraction
 #[derive(Debug, Clone)]
@@ -56,6 +94,29 @@ pub struct GlinerConfig {
 
     /// Number of threads for inference (0 = auto)
     pub num_threads: usize,
+
+    /// Split input into overlapping character windows of this size before
+    /// inference, so documents longer than GLiNER/ONNX's token limit don't
+    /// silently lose entities past the cutoff. `0` disables windowing (the
+    /// whole text is passed as a single `TextInput`, the original behavior).
+    pub window_chars: usize,
+
+    /// Overlap, in characters, between consecutive windows when
+    /// `window_chars > 0` - keeps an entity straddling a window boundary
+    /// visible to whichever window actually contains it. Ignored when
+    /// `window_chars` is `0`.
+    pub overlap_chars: usize,
+
+    /// OpenTelemetry instrumentation scope name for the spans/metrics this
+    /// extractor emits (only active when built with `--features otel`) - a
+    /// shared scope lets this extractor's telemetry nest alongside the
+    /// crate's other extractors under one logical instrumentation name
+    #[cfg(feature = "otel")]
+    pub otel_scope: String,
+
+    /// Whether `extract()` emits plain Schema.org entities or a full W3C
+    /// PROV-O provenance graph
+    pub graph_mode: GraphMode,
 }
 
 #[cfg(feature = "gliner")]
@@ -74,6 +135,11 @@ impl Default for GlinerConfig {
             confidence_threshold: 0.5,
             flat_ner: true,
             num_threads: 0, // Auto-detect
+            window_chars: 0,
+            overlap_chars: 0,
+            #[cfg(feature = "otel")]
+            otel_scope: "gliner_extractor".to_string(),
+            graph_mode: GraphMode::SchemaOrg,
         }
     }
 }
@@ -87,6 +153,11 @@ impl GlinerConfig {
     /// - `GLINER_ENTITY_TYPES`: Comma-separated list of entity types
     /// - `GLINER_CONFIDENCE`: Confidence threshold (0.0-1.0)
     /// - `GLINER_THREADS`: Number of threads (0 = auto)
+    /// - `GLINER_WINDOW`: Sliding-window size in characters (0 = disabled, default)
+    /// - `GLINER_OVERLAP`: Overlap between consecutive windows in characters
+    /// - `GLINER_OTEL_SCOPE`: OpenTelemetry instrumentation scope name (only
+    ///   read when built with `--features otel`)
+    /// - `GLINER_GRAPH_MODE`: `"schema-org"` (default) or `"prov-o"`
     pub fn from_env() -> Result<Self> {
         dotenvy::dotenv().ok();
 
@@ -108,21 +179,115 @@ impl GlinerConfig {
             .and_then(|v| v.parse::<usize>().ok())
             .unwrap_or(0);
 
+        let window_chars = std::env::var("GLINER_WINDOW")
+            .ok()
+            .and_then(|v| v.parse::<usize>().ok())
+            .unwrap_or(0);
+
+        let overlap_chars = std::env::var("GLINER_OVERLAP")
+            .ok()
+            .and_then(|v| v.parse::<usize>().ok())
+            .unwrap_or(0);
+
+        #[cfg(feature = "otel")]
+        let otel_scope = std::env::var("GLINER_OTEL_SCOPE")
+            .unwrap_or_else(|_| "gliner_extractor".to_string());
+
+        let graph_mode = match std::env::var("GLINER_GRAPH_MODE").as_deref() {
+            Ok("prov-o") => GraphMode::ProvO,
+            _ => GraphMode::SchemaOrg,
+        };
+
         Ok(Self {
             model_path,
             entity_types,
             confidence_threshold,
             flat_ner: true,
             num_threads,
+            window_chars,
+            overlap_chars,
+            #[cfg(feature = "otel")]
+            otel_scope,
+            graph_mode,
         })
     }
 }
 
+#[cfg(feature = "gliner")]
+/// Split `text` into `(start_byte_offset, window)` pairs of at most
+/// `window_chars` characters each, overlapping by `overlap_chars` so an
+/// entity straddling a window boundary still falls entirely within at least
+/// one window. `window_chars == 0` (or a text no longer than `window_chars`)
+/// disables windowing, returning the whole text as a single window at
+/// offset `0`.
+fn gliner_windows(text: &str, window_chars: usize, overlap_chars: usize) -> Vec<(usize, &str)> {
+    if window_chars == 0 {
+        return vec![(0, text)];
+    }
+
+    let char_boundaries: Vec<usize> =
+        text.char_indices().map(|(i, _)| i).chain(std::iter::once(text.len())).collect();
+    let total_chars = char_boundaries.len() - 1;
+
+    if total_chars <= window_chars {
+        return vec![(0, text)];
+    }
+
+    let step = window_chars.saturating_sub(overlap_chars).max(1);
+
+    let mut windows = Vec::new();
+    let mut start_char = 0;
+    loop {
+        let end_char = (start_char + window_chars).min(total_chars);
+        let start_byte = char_boundaries[start_char];
+        let end_byte = char_boundaries[end_char];
+        windows.push((start_byte, &text[start_byte..end_byte]));
+
+        if end_char == total_chars {
+            break;
+        }
+        start_char += step;
+    }
+
+    windows
+}
+
+/// OpenTelemetry metrics emitted by [`GlinerExtractor`]
+#[cfg(all(feature = "gliner", feature = "otel"))]
+struct GlinerMetrics {
+    /// Wall-clock duration of each `inference()` call, in seconds
+    inference_latency: Histogram<f64>,
+    /// Entities surfaced by the model, tagged `outcome`="kept"/"dropped" by
+    /// the confidence threshold
+    entities_counter: Counter<u64>,
+    /// Raw `span.probability()` distribution, useful for tuning
+    /// `confidence_threshold`
+    probability_histogram: Histogram<f64>,
+}
+
+#[cfg(all(feature = "gliner", feature = "otel"))]
+impl GlinerMetrics {
+    fn new(scope: &str) -> Self {
+        let meter = global::meter(scope.to_string());
+        Self {
+            inference_latency: meter
+                .f64_histogram("gliner.inference.latency")
+                .build(),
+            entities_counter: meter.u64_counter("gliner.entities").build(),
+            probability_histogram: meter
+                .f64_histogram("gliner.entities.probability")
+                .build(),
+        }
+    }
+}
+
 #[cfg(feature = "gliner")]
 /// GLiNER-based extractor for zero-shot Named Entity Recognition
 pub struct GlinerExtractor {
     model: GLiNER<SpanMode>,
     config: GlinerConfig,
+    #[cfg(feature = "otel")]
+    metrics: GlinerMetrics,
 }
 
 #[cfg(feature = "gliner")]
@@ -137,6 +302,18 @@ impl GlinerExtractor {
     ///
     /// Returns an error if the model cannot be loaded
     pub fn new(config: GlinerConfig) -> Result<Self> {
+        #[cfg(feature = "otel")]
+        let tracer = global::tracer(config.otel_scope.clone());
+        #[cfg(feature = "otel")]
+        let mut span = tracer.start("gliner.load_model");
+        #[cfg(feature = "otel")]
+        span.set_attribute(KeyValue::new(
+            "model_path",
+            config.model_path.display().to_string(),
+        ));
+        #[cfg(feature = "otel")]
+        span.set_attribute(KeyValue::new("num_threads", i64::from(config.num_threads)));
+
         // Validate model path
         if !config.model_path.exists() {
             return Err(Error::Config(format!(
@@ -183,49 +360,165 @@ impl GlinerExtractor {
         )
         .map_err(|e| Error::Config(format!("Failed to load GLiNER model: {}", e)))?;
 
-        Ok(Self { model, config })
+        #[cfg(feature = "otel")]
+        let metrics = GlinerMetrics::new(&config.otel_scope);
+
+        Ok(Self {
+            model,
+            #[cfg(feature = "otel")]
+            metrics,
+            config,
+        })
     }
 
     /// Extract entities with provenance (character offsets)
     ///
+    /// When `config.window_chars > 0`, `text` is split into overlapping
+    /// character windows (see [`gliner_windows`]) and run as a single batched
+    /// `TextInput` so book-length inputs aren't truncated by GLiNER/ONNX's
+    /// token limit while still paying the ONNX session cost only once. Each
+    /// window's locally-offset spans are remapped back to absolute positions,
+    /// and entities recurring in the overlap between two adjacent windows are
+    /// de-duplicated by `(absolute_start, absolute_end, class)`, keeping the
+    /// occurrence with the higher `probability()`.
+    ///
     /// Returns entities with exact start/end positions in the original text
     fn extract_entities_with_provenance(
         &self,
         text: &str,
     ) -> Result<Vec<ExtractedEntity>> {
-        // Create input with single text and configured entity types
         let entity_type_refs: Vec<&str> = self.config.entity_types.iter().map(|s| s.as_str()).collect();
 
-        let input = TextInput::from_str(&[text], &entity_type_refs)
+        let windows = gliner_windows(text, self.config.window_chars, self.config.overlap_chars);
+        let window_texts: Vec<&str> = windows.iter().map(|(_, window)| *window).collect();
+
+        #[cfg(feature = "otel")]
+        let tracer = global::tracer(self.config.otel_scope.clone());
+        #[cfg(feature = "otel")]
+        let mut span = tracer.start("gliner.inference");
+        #[cfg(feature = "otel")]
+        span.set_attribute(KeyValue::new("input_len", text.len() as i64));
+        #[cfg(feature = "otel")]
+        span.set_attribute(KeyValue::new("window_count", windows.len() as i64));
+
+        let input = TextInput::from_str(&window_texts, &entity_type_refs)
             .map_err(|e| Error::Extraction(format!("Failed to create TextInput: {}", e)))?;
 
         // Run inference
+        #[cfg(feature = "otel")]
+        let inference_started_at = std::time::Instant::now();
         let output = self.model.inference(input)
             .map_err(|e| Error::Extraction(format!("GLiNER inference failed: {}", e)))?;
+        #[cfg(feature = "otel")]
+        self.metrics
+            .inference_latency
+            .record(inference_started_at.elapsed().as_secs_f64(), &[]);
+
+        // Remap each window's local offsets to absolute ones, keeping the
+        // higher-probability occurrence of any entity seen in more than one
+        // window's overlap region.
+        let mut best: HashMap<(usize, usize, String), ExtractedEntity> = HashMap::new();
+        for (window_idx, spans) in output.spans.iter().enumerate() {
+            let Some(&(window_start, _)) = windows.get(window_idx) else {
+                continue;
+            };
+
+            for span_candidate in spans {
+                let confidence = span_candidate.probability();
+
+                #[cfg(feature = "otel")]
+                self.metrics
+                    .probability_histogram
+                    .record(f64::from(confidence), &[]);
+
+                if confidence < self.config.confidence_threshold {
+                    #[cfg(feature = "otel")]
+                    self.metrics
+                        .entities_counter
+                        .add(1, &[KeyValue::new("outcome", "dropped")]);
+                    continue;
+                }
 
-        // Extract spans from first result (we only passed one text)
-        let mut entities = Vec::new();
-        if let Some(spans) = output.spans.first() {
-            for span in spans {
-                let confidence = span.probability();
-
-                // Filter by confidence threshold
-                if confidence >= self.config.confidence_threshold {
-                    let (start, end) = span.offsets();
-                    entities.push((
-                        span.text().to_string(),
-                        span.class().to_string(),
-                        confidence,
-                        start,
-                        end,
-                    ));
+                #[cfg(feature = "otel")]
+                self.metrics
+                    .entities_counter
+                    .add(1, &[KeyValue::new("outcome", "kept")]);
+
+                let (local_start, local_end) = span_candidate.offsets();
+                let start = window_start + local_start;
+                let end = window_start + local_end;
+                let class = span_candidate.class().to_string();
+
+                let is_better = best
+                    .get(&(start, end, class.clone()))
+                    .is_none_or(|(_, _, existing_confidence, _, _)| confidence > *existing_confidence);
+                if is_better {
+                    best.insert(
+                        (start, end, class.clone()),
+                        (span_candidate.text().to_string(), class, confidence, start, end),
+                    );
                 }
             }
         }
 
+        let mut entities: Vec<ExtractedEntity> = best.into_values().collect();
+        entities.sort_by_key(|(_, _, _, start, _)| *start);
+
+        #[cfg(feature = "otel")]
+        span.set_attribute(KeyValue::new("returned_span_count", entities.len() as i64));
+
         Ok(entities)
     }
 
+    /// Extract entities and return them as a single Arrow `RecordBatch` instead
+    /// of an `RdfDocument`, for bulk analytics over many documents
+    ///
+    /// Columns: `source_id`, `text`, `schema_type`, `gliner_type`,
+    /// `confidence`, `start_offset`, `end_offset` - one row per extracted
+    /// entity. `source_id` identifies which document a row came from once
+    /// many batches are concatenated or written to the same Parquet file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if GLiNER inference fails or the batch cannot be
+    /// assembled
+    #[cfg(feature = "arrow")]
+    pub fn extract_to_batch(&self, source_id: &str, text: &str) -> Result<RecordBatch> {
+        let entities = self.extract_entities_with_provenance(text)?;
+
+        let mut source_ids = Vec::with_capacity(entities.len());
+        let mut texts = Vec::with_capacity(entities.len());
+        let mut schema_types = Vec::with_capacity(entities.len());
+        let mut gliner_types = Vec::with_capacity(entities.len());
+        let mut confidences = Vec::with_capacity(entities.len());
+        let mut start_offsets = Vec::with_capacity(entities.len());
+        let mut end_offsets = Vec::with_capacity(entities.len());
+
+        for (entity_text, gliner_type, confidence, start, end) in &entities {
+            source_ids.push(source_id.to_string());
+            texts.push(entity_text.clone());
+            schema_types.push(serialize_entity_type(&self.map_to_schema_type(gliner_type)));
+            gliner_types.push(gliner_type.clone());
+            confidences.push(*confidence);
+            start_offsets.push(*start as u32);
+            end_offsets.push(*end as u32);
+        }
+
+        RecordBatch::try_new(
+            Arc::new(gliner_entity_batch_schema()),
+            vec![
+                Arc::new(StringArray::from(source_ids)),
+                Arc::new(StringArray::from(texts)),
+                Arc::new(StringArray::from(schema_types)),
+                Arc::new(StringArray::from(gliner_types)),
+                Arc::new(Float32Array::from(confidences)),
+                Arc::new(UInt32Array::from(start_offsets)),
+                Arc::new(UInt32Array::from(end_offsets)),
+            ],
+        )
+        .map_err(|e| Error::Extraction(format!("Failed to build Arrow RecordBatch: {}", e)))
+    }
+
     /// Map GLiNER entity type to Schema.org type
     fn map_to_schema_type(&self, gliner_type: &str) -> EntityType {
         match gliner_type.to_lowercase().as_str() {
@@ -236,6 +529,77 @@ impl GlinerExtractor {
             _ => EntityType::Custom(gliner_type.to_string()),
         }
     }
+
+    /// Build a W3C PROV-O graph for one extraction run: a `prov:Activity`
+    /// for the run (with its plan, parameters, and timing), a
+    /// `prov:SoftwareAgent` for GLiNER, a `prov:Entity` for the source
+    /// document, and each extracted entity as a `prov:Entity` that is
+    /// `prov:wasGeneratedBy` the activity and `prov:wasDerivedFrom` the
+    /// source document
+    fn build_prov_o_document(
+        &self,
+        text: &str,
+        entities: &[ExtractedEntity],
+        started_at_secs: u64,
+        ended_at_secs: u64,
+    ) -> Value {
+        let activity_id = "activity_extraction";
+        let agent_id = "agent_gliner";
+        let source_id = "entity_source_document";
+
+        let mut graph = vec![
+            json!({
+                "@id": activity_id,
+                "@type": "prov:Activity",
+                "prov:startedAtTime": started_at_secs,
+                "prov:endedAtTime": ended_at_secs,
+                "prov:wasAssociatedWith": { "@id": agent_id },
+                "prov:used": { "@id": self.config.model_path.display().to_string() },
+                "parameters": {
+                    "entityTypes": self.config.entity_types,
+                    "confidenceThreshold": self.config.confidence_threshold,
+                },
+            }),
+            json!({
+                "@id": agent_id,
+                "@type": "prov:SoftwareAgent",
+                "name": "GLiNER",
+                "modelPath": self.config.model_path.display().to_string(),
+            }),
+            json!({
+                "@id": source_id,
+                "@type": "prov:Entity",
+                "_textLength": text.len(),
+            }),
+        ];
+
+        for (entity_text, entity_type, confidence, start, end) in entities {
+            let schema_type = self.map_to_schema_type(entity_type);
+
+            graph.push(json!({
+                "@id": format!("entity_{}", start),
+                "@type": [serialize_entity_type(&schema_type), "prov:Entity"],
+                "name": entity_text,
+                "prov:wasGeneratedBy": { "@id": activity_id },
+                "prov:wasDerivedFrom": { "@id": source_id },
+                "_metadata": {
+                    "text": entity_text,
+                    "startOffset": start,
+                    "endOffset": end,
+                    "confidence": confidence,
+                    "glinerType": entity_type,
+                }
+            }));
+        }
+
+        json!({
+            "@context": {
+                "@vocab": "https://schema.org/",
+                "prov": "http://www.w3.org/ns/prov#",
+            },
+            "@graph": graph,
+        })
+    }
 }
 
 #[cfg(feature = "gliner")]
@@ -256,8 +620,16 @@ impl RdfExtractor for GlinerExtractor {
     /// Returns an error if GLiNER inference fails
     async fn extract(&self, text: &str) -> Result<RdfDocument> {
         // Extract entities with GLiNER
+        let started_at_secs = now_secs();
         let gliner_entities = self.extract_entities_with_provenance(text)?;
 
+        if self.config.graph_mode == GraphMode::ProvO {
+            let ended_at_secs = now_secs();
+            let data =
+                self.build_prov_o_document(text, &gliner_entities, started_at_secs, ended_at_secs);
+            return RdfDocument::from_value(data);
+        }
+
         // If only one entity, create single entity document
         if gliner_entities.len() == 1 {
             let (entity_text, entity_type, confidence, start, end) = &gliner_entities[0];
@@ -322,6 +694,15 @@ impl RdfExtractor for GlinerExtractor {
     }
 }
 
+#[cfg(feature = "gliner")]
+/// Unix epoch seconds, used as the `prov:startedAtTime`/`prov:endedAtTime`
+/// values for [`GraphMode::ProvO`] output
+fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_secs())
+}
+
 #[cfg(feature = "gliner")]
 /// Helper function to serialize EntityType to string for JSON
 fn serialize_entity_type(entity_type: &EntityType) -> String {
@@ -337,6 +718,69 @@ fn serialize_entity_type(entity_type: &EntityType) -> String {
     }
 }
 
+/// Arrow schema shared by every [`GlinerExtractor::extract_to_batch`] batch -
+/// kept as a free function so [`ParquetBatchWriter`] can build its Parquet
+/// writer against the same column layout without constructing an extractor
+#[cfg(all(feature = "gliner", feature = "arrow"))]
+fn gliner_entity_batch_schema() -> Schema {
+    Schema::new(vec![
+        Field::new("source_id", DataType::Utf8, false),
+        Field::new("text", DataType::Utf8, false),
+        Field::new("schema_type", DataType::Utf8, false),
+        Field::new("gliner_type", DataType::Utf8, false),
+        Field::new("confidence", DataType::Float32, false),
+        Field::new("start_offset", DataType::UInt32, false),
+        Field::new("end_offset", DataType::UInt32, false),
+    ])
+}
+
+/// Streams many [`GlinerExtractor::extract_to_batch`] batches into a single
+/// Parquet file, so a corpus can be exported for bulk analytics (DuckDB,
+/// Polars, ...) without holding every document's entities in memory at once
+#[cfg(all(feature = "gliner", feature = "arrow"))]
+pub struct ParquetBatchWriter {
+    writer: ArrowWriter<File>,
+}
+
+#[cfg(all(feature = "gliner", feature = "arrow"))]
+impl ParquetBatchWriter {
+    /// Create a new writer, truncating or creating the file at `path`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be created or the Parquet writer
+    /// cannot be initialized
+    pub fn create(path: impl AsRef<Path>) -> Result<Self> {
+        let file = File::create(path)?;
+        let writer = ArrowWriter::try_new(file, Arc::new(gliner_entity_batch_schema()), None)
+            .map_err(|e| Error::Extraction(format!("Failed to create Parquet writer: {}", e)))?;
+        Ok(Self { writer })
+    }
+
+    /// Append one batch to the Parquet file
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the batch cannot be written
+    pub fn write_batch(&mut self, batch: &RecordBatch) -> Result<()> {
+        self.writer
+            .write(batch)
+            .map_err(|e| Error::Extraction(format!("Failed to write Parquet row group: {}", e)))
+    }
+
+    /// Flush and finalize the Parquet file, writing its footer
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the footer cannot be written
+    pub fn close(self) -> Result<()> {
+        self.writer
+            .close()
+            .map_err(|e| Error::Extraction(format!("Failed to finalize Parquet file: {}", e)))?;
+        Ok(())
+    }
+}
+
 // Stub implementations when feature is disabled
 #[cfg(not(feature = "gliner"))]
 pub struct GlinerConfig;
@@ -363,6 +807,7 @@ mod tests {
         assert_eq!(config.confidence_threshold, 0.5);
         assert!(config.flat_ner);
         assert!(config.entity_types.contains(&"Person".to_string()));
+        assert_eq!(config.graph_mode, GraphMode::SchemaOrg);
     }
 
     #[test]
@@ -377,4 +822,47 @@ mod tests {
             "Organization"
         );
     }
+
+    #[test]
+    fn test_gliner_windows_disabled_returns_single_window() {
+        let windows = gliner_windows("short text", 0, 0);
+        assert_eq!(windows, vec![(0, "short text")]);
+    }
+
+    #[test]
+    fn test_gliner_windows_short_text_returns_single_window() {
+        let windows = gliner_windows("short text", 100, 10);
+        assert_eq!(windows, vec![(0, "short text")]);
+    }
+
+    #[test]
+    fn test_gliner_windows_splits_with_overlap() {
+        let text = "0123456789abcdefghij"; // 20 chars
+        let windows = gliner_windows(text, 8, 3);
+
+        assert_eq!(windows.len(), 4);
+        assert_eq!(windows[0], (0, "01234567"));
+        assert_eq!(windows[1], (5, "56789abc"));
+        assert_eq!(windows[2], (10, "abcdefgh"));
+        assert_eq!(windows[3], (15, "fghij"));
+    }
+
+    #[cfg(feature = "arrow")]
+    #[test]
+    fn test_gliner_entity_batch_schema_columns() {
+        let schema = gliner_entity_batch_schema();
+        let names: Vec<&str> = schema.fields().iter().map(|f| f.name().as_str()).collect();
+        assert_eq!(
+            names,
+            vec![
+                "source_id",
+                "text",
+                "schema_type",
+                "gliner_type",
+                "confidence",
+                "start_offset",
+                "end_offset",
+            ]
+        );
+    }
 }
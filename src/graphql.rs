@@ -0,0 +1,360 @@
+//! GraphQL query surface over accumulated extractions
+//!
+//! Tests and batch jobs compare `HashSet<Triple>` snapshots directly, but
+//! nothing lets a caller ask the extracted/linked knowledge questions
+//! interactively. [`ExtractionStore`] accumulates every triple and linked
+//! entity produced by an `extract` mutation in memory, and [`QueryRoot`]
+//! exposes it through an `async-graphql` schema: `entities`/`triples`/
+//! `entity` resolvers read the store, and `metrics` scores it against a
+//! supplied gold set using the same [`Evaluator`](crate::evaluation::Evaluator)
+//! logic the offline tuning/evaluation harnesses use. [`MutationRoot::extract`]
+//! runs the [`GenAiExtractor`] + [`EntityLinker`] pipeline and folds the
+//! result back into the store, turning the library into a serviceable
+//! extraction backend instead of an offline batch tool.
+
+use serde_json::Value;
+use std::sync::{Mutex, PoisonError};
+
+#[cfg(feature = "graphql")]
+use async_graphql::{Context, EmptySubscription, InputObject, Object, Schema, SimpleObject};
+
+#[cfg(feature = "graphql")]
+use crate::entity_linker::EntityLinker;
+#[cfg(feature = "graphql")]
+use crate::evaluation::{DocumentResult, Evaluator, PrecisionRecallF1};
+#[cfg(feature = "graphql")]
+use crate::extractor::GenAiExtractor;
+#[cfg(feature = "graphql")]
+use crate::reasoning::Triple;
+#[cfg(feature = "graphql")]
+use crate::RdfExtractor;
+
+/// One linked entity accumulated in an [`ExtractionStore`]
+#[cfg(feature = "graphql")]
+#[derive(Debug, Clone)]
+struct StoredEntity {
+    surface_form: String,
+    uri: String,
+    schema_type: String,
+    confidence: f64,
+}
+
+/// In-memory accumulation of every triple and linked entity produced by
+/// [`MutationRoot::extract`] calls so far, read by [`QueryRoot`]'s resolvers
+#[cfg(feature = "graphql")]
+#[derive(Debug, Default)]
+struct ExtractionStore {
+    entities: Vec<StoredEntity>,
+    triples: Vec<Triple>,
+}
+
+#[cfg(feature = "graphql")]
+impl ExtractionStore {
+    fn insert(&mut self, triples: impl IntoIterator<Item = Triple>, entities: impl IntoIterator<Item = StoredEntity>) {
+        self.triples.extend(triples);
+        self.entities.extend(entities);
+    }
+}
+
+/// Extractor, linker, and accumulated store backing a [`QueryRoot`]/
+/// [`MutationRoot`] pair, installed into the schema as context data
+#[cfg(feature = "graphql")]
+struct GraphqlState {
+    store: Mutex<ExtractionStore>,
+    extractor: GenAiExtractor,
+    linker: EntityLinker,
+}
+
+/// GraphQL-facing view of a [`StoredEntity`]
+#[cfg(feature = "graphql")]
+#[derive(SimpleObject)]
+struct EntityNode {
+    surface_form: String,
+    uri: String,
+    schema_type: String,
+    confidence: f64,
+}
+
+#[cfg(feature = "graphql")]
+impl From<&StoredEntity> for EntityNode {
+    fn from(entity: &StoredEntity) -> Self {
+        Self {
+            surface_form: entity.surface_form.clone(),
+            uri: entity.uri.clone(),
+            schema_type: entity.schema_type.clone(),
+            confidence: entity.confidence,
+        }
+    }
+}
+
+/// GraphQL-facing view of a [`Triple`]
+#[cfg(feature = "graphql")]
+#[derive(SimpleObject)]
+struct TripleNode {
+    subject: String,
+    predicate: String,
+    object: String,
+}
+
+#[cfg(feature = "graphql")]
+impl From<&Triple> for TripleNode {
+    fn from(triple: &Triple) -> Self {
+        Self {
+            subject: triple.subject.clone(),
+            predicate: triple.predicate.clone(),
+            object: triple.object.clone(),
+        }
+    }
+}
+
+/// One gold triple supplied to the `metrics` query
+#[cfg(feature = "graphql")]
+#[derive(InputObject)]
+struct TripleInput {
+    subject: String,
+    predicate: String,
+    object: String,
+}
+
+#[cfg(feature = "graphql")]
+impl From<TripleInput> for Triple {
+    fn from(input: TripleInput) -> Self {
+        Triple::new(input.subject, input.predicate, input.object)
+    }
+}
+
+/// Precision/recall/F1, as returned by the `metrics` query
+#[cfg(feature = "graphql")]
+#[derive(SimpleObject)]
+struct PrecisionRecallF1Node {
+    precision: f64,
+    recall: f64,
+    f1: f64,
+}
+
+#[cfg(feature = "graphql")]
+impl From<PrecisionRecallF1> for PrecisionRecallF1Node {
+    fn from(metrics: PrecisionRecallF1) -> Self {
+        Self { precision: metrics.precision, recall: metrics.recall, f1: metrics.f1 }
+    }
+}
+
+/// Summary returned by the `extract` mutation
+#[cfg(feature = "graphql")]
+#[derive(SimpleObject)]
+struct ExtractSummary {
+    triples_extracted: i32,
+    entities_linked: i32,
+}
+
+/// Flatten an `RdfDocument`'s JSON-LD `@graph` into the entity names it
+/// mentions, the same shape [`GenAiExtractor`]'s own internal linking pass
+/// extracts, so the `extract` mutation can re-link them with
+/// [`EntityLinker::link_entities`] to recover confidence and type
+/// information the internal pass discards after writing `@id`
+#[cfg(feature = "graphql")]
+fn entity_names_in_document(value: &Value) -> Vec<String> {
+    let mut names = Vec::new();
+    collect_entity_names(value, &mut names);
+    names.sort();
+    names.dedup();
+    names
+}
+
+#[cfg(feature = "graphql")]
+fn collect_entity_names(value: &Value, names: &mut Vec<String>) {
+    match value {
+        Value::Object(map) => {
+            if map.contains_key("@type") {
+                if let Some(name) = map.get("name").and_then(Value::as_str) {
+                    names.push(name.to_string());
+                }
+            }
+            for val in map.values() {
+                collect_entity_names(val, names);
+            }
+        }
+        Value::Array(items) => {
+            for item in items {
+                collect_entity_names(item, names);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Root query type: `entities`, `triples`, `entity`, and `metrics` resolvers
+/// over the accumulated [`ExtractionStore`]
+#[cfg(feature = "graphql")]
+pub struct QueryRoot;
+
+#[cfg(feature = "graphql")]
+#[Object]
+impl QueryRoot {
+    /// Linked entities accumulated so far, optionally filtered by
+    /// schema.org `type` and capped at `limit` (default: all)
+    async fn entities(&self, ctx: &Context<'_>, r#type: Option<String>, limit: Option<i32>) -> Vec<EntityNode> {
+        let state = ctx.data_unchecked::<GraphqlState>();
+        let store = state.store.lock().unwrap_or_else(PoisonError::into_inner);
+
+        let mut matches: Vec<EntityNode> = store
+            .entities
+            .iter()
+            .filter(|entity| r#type.as_deref().is_none_or(|t| entity.schema_type == t))
+            .map(EntityNode::from)
+            .collect();
+
+        if let Some(limit) = limit {
+            matches.truncate(limit.max(0) as usize);
+        }
+        matches
+    }
+
+    /// Triples accumulated so far, optionally filtered by `subject` and/or
+    /// `predicate`
+    async fn triples(&self, ctx: &Context<'_>, subject: Option<String>, predicate: Option<String>) -> Vec<TripleNode> {
+        let state = ctx.data_unchecked::<GraphqlState>();
+        let store = state.store.lock().unwrap_or_else(PoisonError::into_inner);
+
+        store
+            .triples
+            .iter()
+            .filter(|triple| subject.as_deref().is_none_or(|s| triple.subject == s))
+            .filter(|triple| predicate.as_deref().is_none_or(|p| triple.predicate == p))
+            .map(TripleNode::from)
+            .collect()
+    }
+
+    /// The linked entity whose canonical URI is `uri`, if one has been
+    /// accumulated
+    async fn entity(&self, ctx: &Context<'_>, uri: String) -> Option<EntityNode> {
+        let state = ctx.data_unchecked::<GraphqlState>();
+        let store = state.store.lock().unwrap_or_else(PoisonError::into_inner);
+        store.entities.iter().find(|entity| entity.uri == uri).map(EntityNode::from)
+    }
+
+    /// Precision/recall/F1 of every triple accumulated so far against a
+    /// supplied `gold` set, via [`Evaluator`]
+    async fn metrics(&self, ctx: &Context<'_>, gold: Vec<TripleInput>) -> PrecisionRecallF1Node {
+        let state = ctx.data_unchecked::<GraphqlState>();
+        let store = state.store.lock().unwrap_or_else(PoisonError::into_inner);
+
+        let predicted = store.triples.iter().cloned().collect();
+        let expected = gold.into_iter().map(Triple::from).collect();
+        let report = Evaluator::new().evaluate(&[DocumentResult::new(predicted, expected)]);
+        report.micro.into()
+    }
+}
+
+/// Root mutation type: `extract` runs the extraction + linking pipeline and
+/// folds the result into the accumulated [`ExtractionStore`]
+#[cfg(feature = "graphql")]
+pub struct MutationRoot;
+
+#[cfg(feature = "graphql")]
+#[Object]
+impl MutationRoot {
+    /// Run the `GenAiExtractor` + `EntityLinker` pipeline over `text`,
+    /// insert the resulting triples and linked entities into the store,
+    /// and return how many of each were produced
+    async fn extract(&self, ctx: &Context<'_>, text: String) -> async_graphql::Result<ExtractSummary> {
+        let state = ctx.data_unchecked::<GraphqlState>();
+
+        let doc = state
+            .extractor
+            .extract(&text)
+            .await
+            .map_err(|e| async_graphql::Error::new(e.to_string()))?;
+
+        let triples = crate::tuning::document_triples(&doc);
+
+        let names = entity_names_in_document(&doc.data);
+        let linked = state
+            .linker
+            .link_entities(&text, &names)
+            .await
+            .map_err(|e| async_graphql::Error::new(e.to_string()))?;
+
+        let entities: Vec<StoredEntity> = names
+            .into_iter()
+            .zip(linked)
+            .filter_map(|(surface_form, maybe_linked)| {
+                maybe_linked.map(|linked| StoredEntity {
+                    surface_form,
+                    uri: linked.uri,
+                    schema_type: linked.types.first().cloned().unwrap_or_default(),
+                    confidence: linked.confidence,
+                })
+            })
+            .collect();
+
+        let summary = ExtractSummary {
+            triples_extracted: triples.len() as i32,
+            entities_linked: entities.len() as i32,
+        };
+
+        state.store.lock().unwrap_or_else(PoisonError::into_inner).insert(triples, entities);
+
+        Ok(summary)
+    }
+}
+
+/// The schema type served by [`build_schema`]
+#[cfg(feature = "graphql")]
+pub type ExtractionSchema = Schema<QueryRoot, MutationRoot, EmptySubscription>;
+
+/// Build a GraphQL schema over `extractor`/`linker`, starting with an empty
+/// [`ExtractionStore`]
+#[cfg(feature = "graphql")]
+#[must_use]
+pub fn build_schema(extractor: GenAiExtractor, linker: EntityLinker) -> ExtractionSchema {
+    let state = GraphqlState {
+        store: Mutex::new(ExtractionStore::default()),
+        extractor,
+        linker,
+    };
+    Schema::build(QueryRoot, MutationRoot, EmptySubscription).data(state).finish()
+}
+
+#[cfg(all(test, feature = "graphql"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_entity_names_in_document_collects_typed_entity_names() {
+        let doc = serde_json::json!({
+            "@context": "https://schema.org/",
+            "@graph": [
+                { "@type": "Person", "name": "Alan Bean" },
+                { "@type": "Place", "name": "Moon" },
+            ],
+        });
+
+        let names = entity_names_in_document(&doc);
+        assert_eq!(names, vec!["Alan Bean".to_string(), "Moon".to_string()]);
+    }
+
+    #[test]
+    fn test_entity_names_in_document_ignores_untyped_objects() {
+        let doc = serde_json::json!({ "name": "not an entity, no @type" });
+        assert!(entity_names_in_document(&doc).is_empty());
+    }
+
+    #[test]
+    fn test_extraction_store_insert_accumulates_across_calls() {
+        let mut store = ExtractionStore::default();
+        store.insert(
+            vec![Triple::new("Alan Bean", "walkedOn", "Moon")],
+            vec![StoredEntity {
+                surface_form: "Alan Bean".to_string(),
+                uri: "http://dbpedia.org/resource/Alan_Bean".to_string(),
+                schema_type: "Person".to_string(),
+                confidence: 0.9,
+            }],
+        );
+        store.insert(vec![Triple::new("Moon", "orbits", "Earth")], Vec::new());
+
+        assert_eq!(store.triples.len(), 2);
+        assert_eq!(store.entities.len(), 1);
+    }
+}
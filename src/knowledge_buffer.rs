@@ -1,4 +1,10 @@
 use std::collections::HashMap;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::embedding::{cosine_similarity, TextEmbedder};
+use crate::error::{Error, Result};
 
 /// Tracks entities discovered across document chunks to maintain context
 pub struct KnowledgeBuffer {
@@ -168,6 +174,125 @@ impl Default for KnowledgeBuffer {
     }
 }
 
+/// A single entity's embedded surface form, as persisted by
+/// [`EmbeddingEntityIndex`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct EmbeddedEntityRecord {
+    /// Canonical name this entity resolves to
+    canonical_name: String,
+    /// `@type` this record was embedded under (e.g. "Person")
+    entity_type: String,
+    /// Embedding of `canonical_name` (or the surface form that introduced it)
+    embedding: Vec<f32>,
+}
+
+/// Nearest-neighbor index over entity surface forms, used to resolve
+/// cross-document coreference ("Marie Curie" vs "Marie Skłodowska Curie")
+/// that exact string matching on `name` misses entirely
+///
+/// Each lookup is an exhaustive linear scan over every stored record; there
+/// is no approximate-search structure, so lookup cost grows with the number
+/// of records.
+///
+/// Unlike [`KnowledgeBuffer`], which is rebuilt per document, this index is
+/// meant to be loaded once, shared across every `extract_from_document` call
+/// in a corpus, and saved back so canonical IRIs stay stable across runs.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct EmbeddingEntityIndex {
+    records: Vec<EmbeddedEntityRecord>,
+    /// Minimum cosine similarity for two same-typed surface forms to be
+    /// considered the same entity
+    similarity_threshold: f32,
+}
+
+impl EmbeddingEntityIndex {
+    /// Create a new, empty index with the given similarity threshold
+    #[must_use]
+    pub const fn new(similarity_threshold: f32) -> Self {
+        Self {
+            records: Vec::new(),
+            similarity_threshold,
+        }
+    }
+
+    /// Resolve `name` to an existing canonical entity of the same `@type`, or
+    /// register it as a new canonical entity if no close match exists
+    ///
+    /// Returns the canonical name to use for this mention: either a
+    /// previously seen entity whose embedding is within
+    /// `similarity_threshold` cosine similarity (and whose type matches), or
+    /// `name` itself if this is the first time this entity is seen.
+    pub fn resolve_or_insert(
+        &mut self,
+        embedder: &dyn TextEmbedder,
+        name: &str,
+        entity_type: &str,
+    ) -> String {
+        let embedding = embedder.embed(name);
+
+        let best_match = self
+            .records
+            .iter()
+            .filter(|record| record.entity_type == entity_type)
+            .map(|record| (record, cosine_similarity(&record.embedding, &embedding)))
+            .filter(|(_, similarity)| *similarity >= self.similarity_threshold)
+            .max_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap());
+
+        if let Some((record, _)) = best_match {
+            return record.canonical_name.clone();
+        }
+
+        self.records.push(EmbeddedEntityRecord {
+            canonical_name: name.to_string(),
+            entity_type: entity_type.to_string(),
+            embedding,
+        });
+        name.to_string()
+    }
+
+    /// Number of canonical entities tracked by this index
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.records.len()
+    }
+
+    /// Whether this index has no tracked entities
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.records.is_empty()
+    }
+
+    /// Load an index previously saved with [`EmbeddingEntityIndex::save`], or
+    /// an empty index with `similarity_threshold` if `path` does not exist
+    /// yet
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `path` exists but cannot be read or does not
+    /// contain a valid serialized index
+    pub fn load_or_default(path: impl AsRef<Path>, similarity_threshold: f32) -> Result<Self> {
+        let path = path.as_ref();
+        if !path.exists() {
+            return Ok(Self::new(similarity_threshold));
+        }
+
+        let contents = std::fs::read_to_string(path)
+            .map_err(|e| Error::Config(format!("Failed to read embedding index: {e}")))?;
+        serde_json::from_str(&contents).map_err(Error::JsonParse)
+    }
+
+    /// Persist this index to `path` as JSON
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `path` cannot be written or serialization fails
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        let json = serde_json::to_string(self).map_err(Error::JsonParse)?;
+        std::fs::write(path, json)
+            .map_err(|e| Error::Config(format!("Failed to write embedding index: {e}")))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -270,6 +395,61 @@ mod tests {
         assert_eq!(orgs.len(), 1);
     }
 
+    #[test]
+    fn test_embedding_index_resolves_similar_names() {
+        use crate::embedding::HashingEmbedder;
+
+        let embedder = HashingEmbedder::default();
+        let mut index = EmbeddingEntityIndex::new(0.5);
+
+        let first = index.resolve_or_insert(&embedder, "Marie Curie", "Person");
+        let second = index.resolve_or_insert(&embedder, "Marie Sklodowska Curie", "Person");
+
+        assert_eq!(first, "Marie Curie");
+        assert_eq!(second, "Marie Curie");
+        assert_eq!(index.len(), 1);
+    }
+
+    #[test]
+    fn test_embedding_index_keeps_different_types_separate() {
+        use crate::embedding::HashingEmbedder;
+
+        let embedder = HashingEmbedder::default();
+        let mut index = EmbeddingEntityIndex::new(0.9);
+
+        index.resolve_or_insert(&embedder, "Paris", "Place");
+        let resolved = index.resolve_or_insert(&embedder, "Paris", "Person");
+
+        assert_eq!(resolved, "Paris");
+        assert_eq!(index.len(), 2);
+    }
+
+    #[test]
+    fn test_embedding_index_save_and_load_roundtrip() {
+        use crate::embedding::HashingEmbedder;
+
+        let embedder = HashingEmbedder::default();
+        let mut index = EmbeddingEntityIndex::new(0.85);
+        index.resolve_or_insert(&embedder, "Marie Curie", "Person");
+
+        let path = std::env::temp_dir().join("kb_embedding_index_test_roundtrip.json");
+        index.save(&path).unwrap();
+
+        let loaded = EmbeddingEntityIndex::load_or_default(&path, 0.85).unwrap();
+        assert_eq!(loaded.len(), 1);
+
+        std::fs::remove_file(&path).ok();
+    }
+
+    #[test]
+    fn test_embedding_index_load_or_default_missing_file() {
+        let path = std::env::temp_dir().join("kb_embedding_index_test_missing_does_not_exist.json");
+        std::fs::remove_file(&path).ok();
+
+        let index = EmbeddingEntityIndex::load_or_default(&path, 0.8).unwrap();
+        assert!(index.is_empty());
+    }
+
     #[test]
     fn test_clear() {
         let mut kb = KnowledgeBuffer::new();
@@ -33,29 +33,98 @@
 use async_trait::async_trait;
 use std::env;
 
+pub mod arrow;
+pub mod bk_tree;
+pub mod cache;
+pub mod candle_extractor;
 pub mod chunking;
+pub mod confidence;
+pub mod coverage;
+pub mod credential;
+pub mod datatype;
+pub mod embedding;
 pub mod entity_linker;
 pub mod error;
+pub mod evaluation;
 pub mod extractor;
 pub mod gliner_extractor;
+pub mod graphql;
 pub mod knowledge_buffer;
+pub mod linking_cache;
+pub mod namespace;
 pub mod normalize;
+pub mod object_cache;
+pub mod person_name;
+pub mod persistent_chunk_cache;
+pub mod probabilistic_reasoning;
+pub mod prov;
+pub mod reasoning;
+pub mod shacl;
+pub mod telemetry;
+pub mod temporal;
+pub mod tokenizer;
+pub mod triple_filter;
+pub mod tuning;
 pub mod types;
 pub mod validation;
+pub mod validation_report;
+pub mod vc;
 
+pub use cache::ExtractionCache;
 pub use chunking::{DocumentChunk, SemanticChunker};
+pub use confidence::{resolve_disjunctions, self_consistency_confidence, ConfidentTriple};
+pub use credential::VerifiableCredential;
+pub use embedding::{cosine_similarity, greedy_max_weight_match, HashingEmbedder, TextEmbedder};
 pub use entity_linker::{EntityLinker, EntityLinkerConfig, LinkedEntity, LinkingStrategy};
 pub use error::{Error, Result};
+pub use evaluation::{
+    ConfidenceInterval, DocumentResult, Evaluator, FuzzyMatchConfig, FuzzyMetricsReport, MetricsReport,
+    PrecisionRecallF1, PredicateBreakdown,
+};
 pub use extractor::GenAiExtractor;
-pub use knowledge_buffer::{EntityContext, KnowledgeBuffer};
+pub use knowledge_buffer::{EmbeddingEntityIndex, EntityContext, KnowledgeBuffer};
+pub use namespace::NamespaceRegistry;
+pub use probabilistic_reasoning::{
+    infer_relations, InferredRelation, ProbabilisticReasoner, ProbabilisticReasonerConfig,
+    ProximityRule, WeightedEntity,
+};
+pub use prov::{ProvActivity, ProvEntity, ProvenanceGraph};
+pub use reasoning::{Atom, OntologyRules, Reasoner, Rule, Term, Triple as EntailedTriple};
+pub use tokenizer::TokenCounter;
+pub use triple_filter::{FilterAction, FilterRule, TripleFilter, TriplePredicate};
+pub use tuning::{optimize, optimize_with_budget};
 pub use types::{EntityType, RdfDocument, RdfEntity};
 pub use validation::{
-    RdfValidator, Severity, ValidationConfig, ValidationResult, ValidationRule, Violation,
+    ConfidenceFactor, RdfValidator, Severity, ValidationConfig, ValidationResult, ValidationRule,
+    Violation,
 };
 
 #[cfg(feature = "gliner")]
 pub use gliner_extractor::{GlinerConfig, GlinerExtractor};
 
+#[cfg(feature = "candle")]
+pub use candle_extractor::{CandleConfig, CandleExtractor};
+
+#[cfg(feature = "telemetry")]
+pub use telemetry::{init as init_telemetry, TelemetryConfig, TelemetryGuard};
+
+#[cfg(feature = "arrow")]
+pub use arrow::{triples_to_batch, ArrowTripleWriter, LinkedTriple};
+
+#[cfg(feature = "arrow-flight")]
+pub use arrow::FlightServer;
+
+#[cfg(feature = "vc-jwt")]
+pub use vc::{issue_credential, sign_credential, verify_credential, verify_credential_jwk, Jwk, SignedCredential};
+
+pub use object_cache::{CacheBackend, FilesystemCacheBackend};
+
+#[cfg(feature = "s3-cache")]
+pub use object_cache::{S3CacheBackend, S3CacheConfig};
+
+#[cfg(feature = "graphql")]
+pub use graphql::{build_schema, ExtractionSchema, MutationRoot, QueryRoot};
+
 /// Initialize the library by loading .env file
 ///
 /// This should be called at the start of your application to load environment variables
@@ -69,9 +138,29 @@ pub fn init() -> Result<()> {
     Ok(())
 }
 
+/// Which extractor backend `from_env()` should construct
+///
+/// `GenAi` talks to a cloud provider (or Ollama, since `genai` treats it as
+/// just another provider) via the `genai` crate; `Candle` runs a quantized
+/// model fully in-process via [`CandleExtractor`](crate::candle_extractor::CandleExtractor)
+/// with no external service at all.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ExtractionBackend {
+    /// Cloud/local provider reached through the `genai` crate (default)
+    #[default]
+    GenAi,
+    /// Ollama reached through the `genai` crate's Ollama provider
+    Ollama,
+    /// In-process inference via `candle` - no external service required
+    Candle,
+}
+
 /// Configuration for RDF extraction
 #[derive(Debug, Clone)]
 pub struct ExtractionConfig {
+    /// Which extractor backend to use (default: [`ExtractionBackend::GenAi`])
+    pub backend: ExtractionBackend,
+
     /// The AI model to use (e.g., "claude-3-5-sonnet", "gpt-4o", "gemini-1.5-pro")
     pub model: String,
 
@@ -86,6 +175,14 @@ pub struct ExtractionConfig {
     /// Maximum tokens in the response
     pub max_tokens: Option<u32>,
 
+    /// Maximum context window size in tokens for `model`. When set, documents
+    /// are split by [`TokenAwareChunker`](crate::chunking::TokenAwareChunker)
+    /// into chunks that fit this window alongside the system prompt and the
+    /// response allowance (`max_tokens`). `None` leaves chunking to
+    /// [`SemanticChunker`](crate::chunking::SemanticChunker)'s character-based
+    /// heuristic.
+    pub max_context_tokens: Option<u32>,
+
     /// Custom system prompt override
     pub system_prompt: Option<String>,
 
@@ -105,21 +202,91 @@ pub struct ExtractionConfig {
     /// Inject hardcoded @context instead of trusting LLM (default: true)
     /// Prevents URI hallucinations by using context.jsonld
     pub inject_hardcoded_context: bool,
+
+    /// Directory for the content-addressed extraction cache (see
+    /// [`ExtractionCache`](crate::cache::ExtractionCache)). When set,
+    /// `extract`/`extract_and_validate` consult the cache before calling the
+    /// LLM and write the result through on a miss. `None` disables caching.
+    pub cache_dir: Option<std::path::PathBuf>,
+
+    /// Declarative rules for dropping likely-incorrect triples post-extraction
+    /// (see [`TripleFilter`](crate::triple_filter::TripleFilter)). `None`
+    /// applies no filtering.
+    pub triple_filter: Option<TripleFilter>,
+
+    /// Attach [`Provenance`](crate::types::Provenance) (source text, chunk
+    /// ID, and per-property evidence spans) to extraction results
+    /// (default: false)
+    pub provenance_tracking: bool,
+
+    /// Record a [`ProvenanceGraph`](crate::prov::ProvenanceGraph) (W3C PROV-O
+    /// activities and entities) for each extraction run, linking generated
+    /// nodes back to the run that produced them and the source chunk they
+    /// were derived from (default: false)
+    pub record_provenance: bool,
+
+    /// Maximum number of entries the in-memory, `moka`-backed chunk-response
+    /// cache holds (see [`GenAiExtractor::extract_from_chunk`](crate::extractor::GenAiExtractor))
+    /// before evicting the least-recently-used entry. `0` disables the cache
+    /// (default: 1000)
+    pub cache_capacity: u64,
+
+    /// How long an entry in the in-memory chunk-response cache stays valid
+    /// before being recomputed, in seconds. `0` disables the cache
+    /// (default: 3600)
+    pub cache_ttl_secs: u64,
+
+    /// Run an embedding-backed entity resolution pass over
+    /// [`GenAiExtractor::merge_chunks`](crate::extractor::GenAiExtractor)'s
+    /// output, clustering entities whose names are similar but not identical
+    /// (e.g. "IBM" and "International Business Machines") in addition to the
+    /// exact-match merge `merge_chunks` always does (default: false)
+    pub merge_entity_resolution: bool,
+
+    /// Cosine similarity threshold (`[-1.0, 1.0]`) above which two entity
+    /// names are clustered together by the embedding resolution pass.
+    /// Ignored when `merge_entity_resolution` is false (default: 0.85)
+    pub merge_entity_resolution_threshold: f32,
+
+    /// Path to a persistent, cross-run cache of per-chunk extraction results
+    /// (see [`PersistentChunkCache`](crate::persistent_chunk_cache::PersistentChunkCache)).
+    /// Unlike the in-memory `cache_capacity`/`cache_ttl_secs` cache, this one
+    /// survives a process restart, so re-processing a document after a crash
+    /// only re-pays for chunks whose text or extraction configuration
+    /// actually changed. `None` disables it (default: `None`)
+    pub persistent_cache_path: Option<std::path::PathBuf>,
+
+    /// How long an entry in the persistent chunk-extraction cache stays
+    /// valid before being recomputed, in seconds. `0` disables expiry
+    /// (default: 0)
+    pub persistent_cache_ttl_secs: u64,
 }
 
 impl Default for ExtractionConfig {
     fn default() -> Self {
         Self {
+            backend: ExtractionBackend::GenAi,
             model: "claude-3-5-sonnet".to_string(),
             simple_model: None,
             temperature: Some(0.3),
             max_tokens: Some(4096),
+            max_context_tokens: None,
             system_prompt: None,
             ontologies: vec!["https://schema.org/".to_string()],
             entity_linker: EntityLinkerConfig::default(),
             max_retries: 2,
             strict_validation: true,
             inject_hardcoded_context: true,
+            cache_dir: None,
+            triple_filter: None,
+            provenance_tracking: false,
+            record_provenance: false,
+            cache_capacity: 1000,
+            cache_ttl_secs: 3600,
+            merge_entity_resolution: false,
+            merge_entity_resolution_threshold: 0.85,
+            persistent_cache_path: None,
+            persistent_cache_ttl_secs: 0,
         }
     }
 }
@@ -131,6 +298,14 @@ impl ExtractionConfig {
         Self::default()
     }
 
+    /// Count the number of tokens `text` would occupy for this config's
+    /// `model`, using a real BPE tokenizer instead of the `text.len() / 4`
+    /// heuristic
+    #[must_use]
+    pub fn count_tokens(&self, text: &str) -> usize {
+        crate::tokenizer::TokenCounter::for_model(&self.model).count(text)
+    }
+
     /// Load configuration from environment variables
     ///
     /// Automatically loads .env file if present. Supports these variables:
@@ -141,12 +316,26 @@ impl ExtractionConfig {
     /// - `GENAI_SYSTEM_PROMPT`: Custom system prompt
     /// - `RDF_ONTOLOGIES`: Comma-separated ontology URLs
     /// - `ENTITY_LINKING_ENABLED`: Enable entity linking (default: false)
-    /// - `ENTITY_LINKING_STRATEGY`: Strategy: "local", "dbpedia", "wikidata", or "none" (default: "none")
+    /// - `ENTITY_LINKING_STRATEGY`: Strategy: "local", "dbpedia", "wikidata", "embedding", or "none" (default: "none")
     /// - `ENTITY_LINKING_KB_PATH`: Path to local RDF knowledge base (required for "local" strategy)
     /// - `ENTITY_LINKING_SERVICE_URL`: Service URL for remote strategies (default: `DBpedia` Spotlight)
     /// - `ENTITY_LINKING_CONFIDENCE`: Confidence threshold 0.0-1.0 (default: 0.5)
+    /// - `ENTITY_LINKING_EMBEDDING_MODEL`: Embedding model for the "embedding" strategy (default: "hashing")
+    /// - `ENTITY_LINKING_EMBEDDING_SIMILARITY_THRESHOLD`: Cosine similarity threshold 0.0-1.0 for the "embedding" strategy (default: 0.85)
+    /// - `ENTITY_LINKING_EMBEDDING_INDEX_PATH`: Path to persist the cross-document embedding entity index
+    /// - `RDF_EXTRACTION_MAX_CONTEXT_TOKENS`: Model context window in tokens, used to budget chunk size (default: unset)
     /// - `RDF_EXTRACTION_MAX_RETRIES`: Max retry attempts for failed extractions (default: 2)
     /// - `RDF_EXTRACTION_STRICT_VALIDATION`: Enable strict validation (default: true)
+    /// - `RDF_EXTRACTION_BACKEND`: Extractor backend: "genai", "ollama", or "candle" (default: "genai")
+    /// - `RDF_EXTRACTION_CACHE_DIR`: Directory for the content-addressed extraction cache (default: disabled)
+    /// - `RDF_EXTRACTION_TRIPLE_FILTER_PATH`: Path to a JSON [`TripleFilter`](crate::triple_filter::TripleFilter) file (default: disabled)
+    /// - `RDF_EXTRACTION_PROVENANCE_TRACKING`: Attach source-text/span provenance to results (default: false)
+    /// - `RDF_EXTRACTION_CACHE_CAPACITY`: Max entries in the in-memory chunk-response cache, 0 disables (default: 1000)
+    /// - `RDF_EXTRACTION_CACHE_TTL_SECS`: TTL in seconds for the in-memory chunk-response cache, 0 disables (default: 3600)
+    /// - `RDF_EXTRACTION_MERGE_ENTITY_RESOLUTION`: Run embedding-backed entity resolution during chunk merging (default: false)
+    /// - `RDF_EXTRACTION_MERGE_ENTITY_RESOLUTION_THRESHOLD`: Cosine similarity threshold 0.0-1.0 for clustering entities during merge (default: 0.85)
+    /// - `RDF_EXTRACTION_PERSISTENT_CACHE_PATH`: Path to the persistent, cross-run chunk-extraction cache database (default: disabled)
+    /// - `RDF_EXTRACTION_PERSISTENT_CACHE_TTL_SECS`: TTL in seconds for the persistent chunk-extraction cache, 0 disables expiry (default: 0)
     ///
     /// # Errors
     ///
@@ -163,8 +352,18 @@ impl ExtractionConfig {
         // Load .env file
         dotenvy::dotenv().ok();
 
-        // Check for required API key
-        if env::var("GENAI_API_KEY").is_err() {
+        let backend = match env::var("RDF_EXTRACTION_BACKEND")
+            .unwrap_or_else(|_| "genai".to_string())
+            .to_lowercase()
+            .as_str()
+        {
+            "ollama" => ExtractionBackend::Ollama,
+            "candle" => ExtractionBackend::Candle,
+            _ => ExtractionBackend::GenAi,
+        };
+
+        // The candle backend runs fully in-process, so it has no API key to check
+        if backend != ExtractionBackend::Candle && env::var("GENAI_API_KEY").is_err() {
             return Err(Error::Config(
                 "GENAI_API_KEY environment variable is required".to_string(),
             ));
@@ -183,6 +382,10 @@ impl ExtractionConfig {
 
         let system_prompt = env::var("GENAI_SYSTEM_PROMPT").ok();
 
+        let max_context_tokens = env::var("RDF_EXTRACTION_MAX_CONTEXT_TOKENS")
+            .ok()
+            .and_then(|v| v.parse::<u32>().ok());
+
         let ontologies = env::var("RDF_ONTOLOGIES").map_or_else(
             |_| vec!["https://schema.org/".to_string()],
             |v| v.split(',').map(|s| s.trim().to_string()).collect(),
@@ -202,6 +405,7 @@ impl ExtractionConfig {
             "local" => LinkingStrategy::Local,
             "dbpedia" | "dbpedia_spotlight" => LinkingStrategy::DbpediaSpotlight,
             "wikidata" => LinkingStrategy::Wikidata,
+            "embedding" => LinkingStrategy::Embedding,
             _ => LinkingStrategy::None,
         };
 
@@ -217,12 +421,28 @@ impl ExtractionConfig {
             .ok()
             .map(std::path::PathBuf::from);
 
+        let entity_linker_embedding_model = env::var("ENTITY_LINKING_EMBEDDING_MODEL")
+            .unwrap_or_else(|_| "hashing".to_string());
+
+        let entity_linker_embedding_similarity_threshold =
+            env::var("ENTITY_LINKING_EMBEDDING_SIMILARITY_THRESHOLD")
+                .ok()
+                .and_then(|v| v.parse::<f64>().ok())
+                .unwrap_or(0.85);
+
+        let entity_linker_embedding_index_path = env::var("ENTITY_LINKING_EMBEDDING_INDEX_PATH")
+            .ok()
+            .map(std::path::PathBuf::from);
+
         let entity_linker = EntityLinkerConfig {
             enabled: entity_linker_enabled,
             strategy: entity_linker_strategy,
             service_url: entity_linker_service_url,
             confidence_threshold: entity_linker_confidence,
             local_kb_path: entity_linker_kb_path,
+            embedding_model: entity_linker_embedding_model,
+            embedding_similarity_threshold: entity_linker_embedding_similarity_threshold,
+            embedding_index_path: entity_linker_embedding_index_path,
             ..EntityLinkerConfig::default()
         };
 
@@ -244,20 +464,88 @@ impl ExtractionConfig {
             .and_then(|v| v.parse::<bool>().ok())
             .unwrap_or(true);
 
+        let cache_dir = env::var("RDF_EXTRACTION_CACHE_DIR")
+            .ok()
+            .map(std::path::PathBuf::from);
+
+        let triple_filter = match env::var("RDF_EXTRACTION_TRIPLE_FILTER_PATH") {
+            Ok(path) => Some(crate::triple_filter::TripleFilter::from_file(path)?),
+            Err(_) => None,
+        };
+
+        let provenance_tracking = env::var("RDF_EXTRACTION_PROVENANCE_TRACKING")
+            .ok()
+            .and_then(|v| v.parse::<bool>().ok())
+            .unwrap_or(false);
+
+        let record_provenance = env::var("RDF_EXTRACTION_RECORD_PROVENANCE")
+            .ok()
+            .and_then(|v| v.parse::<bool>().ok())
+            .unwrap_or(false);
+
+        let cache_capacity = env::var("RDF_EXTRACTION_CACHE_CAPACITY")
+            .ok()
+            .and_then(|v| v.parse::<u64>().ok())
+            .unwrap_or(1000);
+
+        let cache_ttl_secs = env::var("RDF_EXTRACTION_CACHE_TTL_SECS")
+            .ok()
+            .and_then(|v| v.parse::<u64>().ok())
+            .unwrap_or(3600);
+
+        let merge_entity_resolution = env::var("RDF_EXTRACTION_MERGE_ENTITY_RESOLUTION")
+            .ok()
+            .and_then(|v| v.parse::<bool>().ok())
+            .unwrap_or(false);
+
+        let merge_entity_resolution_threshold =
+            env::var("RDF_EXTRACTION_MERGE_ENTITY_RESOLUTION_THRESHOLD")
+                .ok()
+                .and_then(|v| v.parse::<f32>().ok())
+                .unwrap_or(0.85);
+
+        let persistent_cache_path = env::var("RDF_EXTRACTION_PERSISTENT_CACHE_PATH")
+            .ok()
+            .map(std::path::PathBuf::from);
+
+        let persistent_cache_ttl_secs = env::var("RDF_EXTRACTION_PERSISTENT_CACHE_TTL_SECS")
+            .ok()
+            .and_then(|v| v.parse::<u64>().ok())
+            .unwrap_or(0);
+
         Ok(Self {
+            backend,
             model,
             simple_model,
             temperature,
             max_tokens,
+            max_context_tokens,
             system_prompt,
             ontologies,
             entity_linker,
             max_retries,
             strict_validation,
             inject_hardcoded_context,
+            cache_dir,
+            triple_filter,
+            provenance_tracking,
+            record_provenance,
+            cache_capacity,
+            cache_ttl_secs,
+            merge_entity_resolution,
+            merge_entity_resolution_threshold,
+            persistent_cache_path,
+            persistent_cache_ttl_secs,
         })
     }
 
+    /// Set the extractor backend
+    #[must_use]
+    pub const fn with_backend(mut self, backend: ExtractionBackend) -> Self {
+        self.backend = backend;
+        self
+    }
+
     /// Set the AI model to use
     #[must_use]
     pub fn with_model(mut self, model: impl Into<String>) -> Self {
@@ -279,6 +567,14 @@ impl ExtractionConfig {
         self
     }
 
+    /// Set the model's context window size in tokens, used to budget
+    /// chunking so documents don't overflow the model's context window
+    #[must_use]
+    pub const fn with_max_context_tokens(mut self, max_context_tokens: u32) -> Self {
+        self.max_context_tokens = Some(max_context_tokens);
+        self
+    }
+
     /// Add an ontology namespace
     #[must_use]
     pub fn with_ontology(mut self, ontology: impl Into<String>) -> Self {
@@ -321,6 +617,81 @@ impl ExtractionConfig {
         self.inject_hardcoded_context = inject;
         self
     }
+
+    /// Enable the content-addressed extraction cache, persisted under `dir`
+    #[must_use]
+    pub fn with_cache(mut self, dir: impl Into<std::path::PathBuf>) -> Self {
+        self.cache_dir = Some(dir.into());
+        self
+    }
+
+    /// Set the declarative rules used to drop likely-incorrect triples
+    #[must_use]
+    pub fn with_triple_filter(mut self, filter: TripleFilter) -> Self {
+        self.triple_filter = Some(filter);
+        self
+    }
+
+    /// Enable or disable provenance tracking (source text, chunk ID, and
+    /// per-property evidence spans)
+    #[must_use]
+    pub const fn with_provenance_tracking(mut self, enabled: bool) -> Self {
+        self.provenance_tracking = enabled;
+        self
+    }
+
+    /// Enable or disable recording a [`ProvenanceGraph`](crate::prov::ProvenanceGraph)
+    /// of PROV-O activities and entities for each extraction run
+    #[must_use]
+    pub const fn with_record_provenance(mut self, enabled: bool) -> Self {
+        self.record_provenance = enabled;
+        self
+    }
+
+    /// Set the in-memory chunk-response cache's capacity; `0` disables it
+    #[must_use]
+    pub const fn with_cache_capacity(mut self, capacity: u64) -> Self {
+        self.cache_capacity = capacity;
+        self
+    }
+
+    /// Set the in-memory chunk-response cache's TTL, in seconds; `0`
+    /// disables it
+    #[must_use]
+    pub const fn with_cache_ttl_secs(mut self, ttl_secs: u64) -> Self {
+        self.cache_ttl_secs = ttl_secs;
+        self
+    }
+
+    /// Enable or disable the embedding-backed entity resolution pass over
+    /// [`GenAiExtractor::merge_chunks`](crate::extractor::GenAiExtractor)'s output
+    #[must_use]
+    pub const fn with_merge_entity_resolution(mut self, enabled: bool) -> Self {
+        self.merge_entity_resolution = enabled;
+        self
+    }
+
+    /// Set the cosine similarity threshold for the merge-time entity
+    /// resolution pass
+    #[must_use]
+    pub const fn with_merge_entity_resolution_threshold(mut self, threshold: f32) -> Self {
+        self.merge_entity_resolution_threshold = threshold;
+        self
+    }
+
+    /// Enable the persistent, cross-run chunk-extraction cache, persisted at `path`
+    #[must_use]
+    pub fn with_persistent_cache(mut self, path: impl Into<std::path::PathBuf>) -> Self {
+        self.persistent_cache_path = Some(path.into());
+        self
+    }
+
+    /// Set the TTL, in seconds, for the persistent chunk-extraction cache. `0` disables expiry.
+    #[must_use]
+    pub const fn with_persistent_cache_ttl_secs(mut self, ttl_secs: u64) -> Self {
+        self.persistent_cache_ttl_secs = ttl_secs;
+        self
+    }
 }
 
 /// Main trait for RDF extraction from text
@@ -364,6 +735,36 @@ pub trait RdfExtractor: Send + Sync {
     }
 }
 
+/// Construct the [`RdfExtractor`] selected by `config.backend`
+///
+/// `GenAi` and `Ollama` are both built as a [`GenAiExtractor`] (the `genai`
+/// crate treats Ollama as just another provider); `Candle` builds a
+/// [`CandleExtractor`](candle_extractor::CandleExtractor) running fully
+/// in-process instead, configured from its own `CANDLE_*` environment
+/// variables (see [`CandleConfig::from_env`](candle_extractor::CandleConfig::from_env))
+/// rather than `config`.
+///
+/// # Errors
+///
+/// Returns an error if the selected backend fails to initialize - e.g. a
+/// missing `GENAI_API_KEY` for `GenAi`/`Ollama`, or missing GGUF weights
+/// (or a binary built without the `candle` feature) for `Candle`
+pub fn build_extractor(config: ExtractionConfig) -> Result<Box<dyn RdfExtractor>> {
+    match config.backend {
+        ExtractionBackend::GenAi | ExtractionBackend::Ollama => {
+            Ok(Box::new(GenAiExtractor::new(config)?))
+        }
+        ExtractionBackend::Candle => {
+            #[cfg(feature = "candle")]
+            let candle_config = candle_extractor::CandleConfig::from_env()?;
+            #[cfg(not(feature = "candle"))]
+            let candle_config = candle_extractor::CandleConfig;
+
+            Ok(Box::new(candle_extractor::CandleExtractor::new(candle_config)?))
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -389,6 +790,43 @@ mod tests {
             .contains(&"https://schema.org/".to_string()));
     }
 
+    #[test]
+    fn test_default_backend_is_genai() {
+        let config = ExtractionConfig::default();
+        assert_eq!(config.backend, ExtractionBackend::GenAi);
+    }
+
+    #[test]
+    fn test_with_backend() {
+        let config = ExtractionConfig::new().with_backend(ExtractionBackend::Candle);
+        assert_eq!(config.backend, ExtractionBackend::Candle);
+    }
+
+    #[test]
+    fn test_build_extractor_dispatches_candle_backend_to_candle_extractor() {
+        // No GGUF weights are available in the test environment (and a
+        // default build has the `candle` feature disabled), so this must
+        // fail either way - but it proves the Candle backend actually
+        // reaches CandleExtractor::new rather than silently falling
+        // through to GenAiExtractor, which would instead fail demanding
+        // GENAI_API_KEY
+        let config = ExtractionConfig::new().with_backend(ExtractionBackend::Candle);
+        assert!(build_extractor(config).is_err());
+    }
+
+    #[test]
+    fn test_with_max_context_tokens() {
+        let config = ExtractionConfig::new().with_max_context_tokens(200_000);
+        assert_eq!(config.max_context_tokens, Some(200_000));
+    }
+
+    #[test]
+    fn test_count_tokens_nonzero_for_nonempty_text() {
+        let config = ExtractionConfig::new();
+        assert!(config.count_tokens("Hello, world!") > 0);
+        assert_eq!(config.count_tokens(""), 0);
+    }
+
     #[test]
     fn test_config_with_system_prompt() {
         let config = ExtractionConfig::new().with_system_prompt("Custom prompt");
@@ -396,6 +834,59 @@ mod tests {
         assert_eq!(config.system_prompt, Some("Custom prompt".to_string()));
     }
 
+    #[test]
+    fn test_provenance_tracking_defaults_to_disabled() {
+        let config = ExtractionConfig::default();
+        assert!(!config.provenance_tracking);
+
+        let config = config.with_provenance_tracking(true);
+        assert!(config.provenance_tracking);
+    }
+
+    #[test]
+    fn test_record_provenance_defaults_to_disabled() {
+        let config = ExtractionConfig::default();
+        assert!(!config.record_provenance);
+
+        let config = config.with_record_provenance(true);
+        assert!(config.record_provenance);
+    }
+
+    #[test]
+    fn test_cache_capacity_and_ttl_default_to_enabled_and_are_configurable() {
+        let config = ExtractionConfig::default();
+        assert_eq!(config.cache_capacity, 1000);
+        assert_eq!(config.cache_ttl_secs, 3600);
+
+        let config = config.with_cache_capacity(0).with_cache_ttl_secs(0);
+        assert_eq!(config.cache_capacity, 0);
+        assert_eq!(config.cache_ttl_secs, 0);
+    }
+
+    #[test]
+    fn test_merge_entity_resolution_defaults_to_disabled_and_is_configurable() {
+        let config = ExtractionConfig::default();
+        assert!(!config.merge_entity_resolution);
+        assert!((config.merge_entity_resolution_threshold - 0.85).abs() < f32::EPSILON);
+
+        let config = config
+            .with_merge_entity_resolution(true)
+            .with_merge_entity_resolution_threshold(0.9);
+        assert!(config.merge_entity_resolution);
+        assert!((config.merge_entity_resolution_threshold - 0.9).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn test_persistent_cache_defaults_to_disabled_and_is_configurable() {
+        let config = ExtractionConfig::default();
+        assert!(config.persistent_cache_path.is_none());
+        assert_eq!(config.persistent_cache_ttl_secs, 0);
+
+        let config = config.with_persistent_cache("/tmp/chunk-cache.db").with_persistent_cache_ttl_secs(86400);
+        assert_eq!(config.persistent_cache_path, Some(std::path::PathBuf::from("/tmp/chunk-cache.db")));
+        assert_eq!(config.persistent_cache_ttl_secs, 86400);
+    }
+
     #[test]
     fn test_init() {
         // Should not fail even if .env doesn't exist
@@ -0,0 +1,409 @@
+//! Persistent entity-linking cache and incremental local-KB ingestion
+//!
+//! The only caching entity linking previously had was the in-memory
+//! `#[cached]` wrapper around the DBpedia call (see
+//! [`crate::entity_linker::link_with_dbpedia_cached`]), so local linking
+//! recomputed candidates and re-ran LLM disambiguation on every process
+//! restart. [`LinkingCache`] persists linking decisions behind a pluggable
+//! [`CacheBackend`](crate::object_cache::CacheBackend) - by default a local
+//! `sled` database, or an S3-compatible object store for sharing cached
+//! links across machines (see [`crate::object_cache`]) - keyed by
+//! `(strategy, normalized_surface_form, entity_type, context_hash)`. Entries
+//! may optionally be gzip-compressed to keep stored objects small.
+//! [`IngestTracker`] tracks which RDF sources have been loaded into the
+//! local KB and their checksums, so re-ingestion only re-reads changed
+//! sources and a version derived from those checksums lets [`LinkingCache`]
+//! entries computed against a stale KB get invalidated.
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use oxigraph::io::RdfFormat;
+use oxigraph::store::Store;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::entity_linker::{LinkedEntity, LinkingStrategy};
+use crate::error::{Error, Result};
+use crate::object_cache::{maybe_compress, maybe_decompress, CacheBackend, FilesystemCacheBackend};
+
+/// One persisted linking decision: the resolved entity (or `None` for a
+/// confirmed no-match), when it expires, and which KB version it was
+/// computed against
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CachedLinkingEntry {
+    entity: Option<LinkedEntity>,
+    expires_at_secs: u64,
+    kb_version: String,
+}
+
+/// A cache of entity-linking decisions behind a pluggable
+/// [`CacheBackend`], keyed by
+/// `(strategy, normalized_surface_form, entity_type, context_hash)`
+#[derive(Clone)]
+pub struct LinkingCache {
+    backend: Arc<dyn CacheBackend>,
+    ttl_secs: u64,
+    compress: bool,
+}
+
+impl std::fmt::Debug for LinkingCache {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LinkingCache")
+            .field("ttl_secs", &self.ttl_secs)
+            .field("compress", &self.compress)
+            .finish()
+    }
+}
+
+impl LinkingCache {
+    /// Open (creating if necessary) a local `sled`-backed cache rooted at
+    /// `dir`, whose entries live for `ttl_secs` before being recomputed
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `dir` cannot be opened as a `sled` database
+    pub fn open(dir: impl Into<PathBuf>, ttl_secs: u64) -> Result<Self> {
+        Ok(Self::with_backend(Arc::new(FilesystemCacheBackend::open(dir)?), ttl_secs, false))
+    }
+
+    /// Build a cache on top of an arbitrary [`CacheBackend`] (e.g.
+    /// [`crate::object_cache::S3CacheBackend`] for sharing cached links
+    /// across machines), optionally gzip-compressing stored entries
+    #[must_use]
+    pub fn with_backend(backend: Arc<dyn CacheBackend>, ttl_secs: u64, compress: bool) -> Self {
+        Self { backend, ttl_secs, compress }
+    }
+
+    /// Compute the cache key for a `(strategy, surface_form, entity_type,
+    /// context_hash)` linking decision
+    #[must_use]
+    pub fn key(
+        strategy: &LinkingStrategy,
+        surface_form: &str,
+        entity_type: Option<&str>,
+        context_hash: &str,
+    ) -> String {
+        let mut hasher = Sha256::new();
+        hasher.update(format!("{strategy:?}").as_bytes());
+        hasher.update(b"\0surface_form=");
+        hasher.update(normalize_surface_form(surface_form).as_bytes());
+        hasher.update(b"\0entity_type=");
+        hasher.update(entity_type.unwrap_or("none").as_bytes());
+        hasher.update(b"\0context_hash=");
+        hasher.update(context_hash.as_bytes());
+        hex_digest(hasher)
+    }
+
+    /// Look up a cached decision
+    ///
+    /// Returns `Ok(None)` on a miss, or if the cached entry has expired or
+    /// was computed against a KB version other than `current_kb_version`
+    /// (the stale entry is removed in that case). A hit returns
+    /// `Ok(Some(entity))`, where `entity` may itself be `None` for a
+    /// confirmed no-match.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend read fails or the stored entry
+    /// cannot be decompressed or deserialized.
+    pub async fn get(&self, key: &str, current_kb_version: &str) -> Result<Option<Option<LinkedEntity>>> {
+        let Some(stored) = self.backend.get(key).await? else {
+            return Ok(None);
+        };
+
+        let bytes = maybe_decompress(&stored, self.compress)?;
+        let entry: CachedLinkingEntry = serde_json::from_slice(&bytes).map_err(Error::from)?;
+
+        if entry.expires_at_secs <= now_secs() || entry.kb_version != current_kb_version {
+            self.backend.remove(key).await?;
+            return Ok(None);
+        }
+
+        Ok(Some(entry.entity))
+    }
+
+    /// Persist a linking decision (including a `None` no-match result),
+    /// stamped with `kb_version` so a later KB change invalidates it
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the entry cannot be serialized, compressed, or
+    /// written to the backend
+    pub async fn put(&self, key: &str, entity: Option<LinkedEntity>, kb_version: &str) -> Result<()> {
+        let entry = CachedLinkingEntry {
+            entity,
+            expires_at_secs: now_secs() + self.ttl_secs,
+            kb_version: kb_version.to_string(),
+        };
+        let bytes = serde_json::to_vec(&entry).map_err(Error::from)?;
+        let stored = maybe_compress(&bytes, self.compress)?;
+        self.backend.put(key, &stored).await
+    }
+
+    /// Remove every cached entry whose `kb_version` doesn't match
+    /// `current_kb_version`, called after [`IngestTracker::ingest`] detects
+    /// changed sources
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend's key listing or an entry read fails
+    pub async fn invalidate_stale(&self, current_kb_version: &str) -> Result<usize> {
+        let mut removed = 0;
+
+        for key in self.backend.list_keys().await? {
+            let Some(bytes) = self.backend.get(&key).await? else { continue };
+            let Ok(decompressed) = maybe_decompress(&bytes, self.compress) else { continue };
+            let Ok(cached) = serde_json::from_slice::<CachedLinkingEntry>(&decompressed) else { continue };
+
+            if cached.kb_version != current_kb_version {
+                self.backend.remove(&key).await?;
+                removed += 1;
+            }
+        }
+
+        Ok(removed)
+    }
+}
+
+/// Tracks which RDF source files have been ingested into a local KB store
+/// and their SHA-256 checksums, so re-ingestion only re-reads changed
+/// sources and [`LinkingCache`] can be invalidated against a version derived
+/// from the current checksum set
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct IngestTracker {
+    /// Source file path -> checksum of its contents as of the last ingest
+    checksums: HashMap<String, String>,
+}
+
+impl IngestTracker {
+    /// An empty tracker, as if no sources had ever been ingested
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Load a tracker's persisted state from `path`, or start empty if it
+    /// doesn't exist yet
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `path` exists but cannot be read or deserialized
+    pub fn load(path: &Path) -> Result<Self> {
+        match fs::read(path) {
+            Ok(bytes) => serde_json::from_slice(&bytes).map_err(Error::from),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(Error::from(e)),
+        }
+    }
+
+    /// Persist this tracker's state to `path`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if serialization or the write fails
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let bytes = serde_json::to_vec_pretty(self).map_err(Error::from)?;
+        fs::write(path, bytes)?;
+        Ok(())
+    }
+
+    /// (Re-)load `sources` into `store`, skipping any whose checksum hasn't
+    /// changed since the last ingest. Returns the sources that were actually
+    /// (re-)loaded.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a source can't be read, or the store rejects it
+    /// as `format`.
+    // TODO: pin down against a released oxigraph version once Cargo.lock
+    // exists - `Store::load_from_reader`'s exact signature has moved across
+    // 0.4/0.5 betas (see the similar caveat on `execute_candidate_query` in
+    // `entity_linker.rs`).
+    #[allow(deprecated)]
+    pub fn ingest(&mut self, store: &Store, sources: &[PathBuf], format: RdfFormat) -> Result<Vec<PathBuf>> {
+        let mut refreshed = Vec::new();
+
+        for source in sources {
+            let contents = fs::read(source)?;
+            let mut hasher = Sha256::new();
+            hasher.update(&contents);
+            let checksum = hex_digest(hasher);
+            let key = source.to_string_lossy().to_string();
+
+            if self.checksums.get(&key) == Some(&checksum) {
+                continue;
+            }
+
+            store
+                .load_from_reader(format, contents.as_slice())
+                .map_err(|e| Error::Extraction(format!("Failed to ingest {}: {e}", source.display())))?;
+
+            self.checksums.insert(key, checksum);
+            refreshed.push(source.clone());
+        }
+
+        Ok(refreshed)
+    }
+
+    /// A version string for the KB's current ingested state: a hash over
+    /// every tracked `(source, checksum)` pair, sorted by path for
+    /// determinism. Any change to the tracked sources (new, removed, or
+    /// updated) changes this version, which [`LinkingCache`] uses to
+    /// invalidate entries computed against an earlier KB state.
+    #[must_use]
+    pub fn kb_version(&self) -> String {
+        let mut entries: Vec<(&String, &String)> = self.checksums.iter().collect();
+        entries.sort_by_key(|(path, _)| path.as_str());
+
+        let mut hasher = Sha256::new();
+        for (path, checksum) in entries {
+            hasher.update(path.as_bytes());
+            hasher.update(b"=");
+            hasher.update(checksum.as_bytes());
+            hasher.update(b";");
+        }
+        hex_digest(hasher)
+    }
+}
+
+fn hex_digest(hasher: Sha256) -> String {
+    hasher.finalize().iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_secs())
+}
+
+/// Normalize a surface form for cache-key comparison, independent of case or
+/// surrounding whitespace
+fn normalize_surface_form(name: &str) -> String {
+    name.trim().to_lowercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_cache(suffix: &str) -> (LinkingCache, PathBuf) {
+        let dir = std::env::temp_dir().join(format!(
+            "text_to_rdf_linking_cache_test_{}_{suffix}",
+            std::process::id()
+        ));
+        let _ = fs::remove_dir_all(&dir);
+        (LinkingCache::open(&dir, 3600).unwrap(), dir)
+    }
+
+    fn sample_entity() -> LinkedEntity {
+        LinkedEntity {
+            surface_form: "Alan Bean".to_string(),
+            uri: "http://dbpedia.org/resource/Alan_Bean".to_string(),
+            types: vec!["Person".to_string()],
+            confidence: 0.9,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_cache_roundtrips_a_hit() {
+        let (cache, dir) = temp_cache("roundtrip");
+        let key = LinkingCache::key(&LinkingStrategy::Local, "Alan Bean", Some("Person"), "ctx-hash");
+
+        cache.put(&key, Some(sample_entity()), "v1").await.unwrap();
+
+        let hit = cache.get(&key, "v1").await.unwrap();
+        assert_eq!(hit, Some(Some(sample_entity())));
+
+        fs::remove_dir_all(&dir).ok();
+    }
+
+    #[tokio::test]
+    async fn test_cache_miss_for_unknown_key() {
+        let (cache, dir) = temp_cache("miss");
+        let key = LinkingCache::key(&LinkingStrategy::Local, "Alan Bean", Some("Person"), "ctx-hash");
+
+        assert_eq!(cache.get(&key, "v1").await.unwrap(), None);
+
+        fs::remove_dir_all(&dir).ok();
+    }
+
+    #[tokio::test]
+    async fn test_cache_invalidates_entries_from_a_stale_kb_version() {
+        let (cache, dir) = temp_cache("stale_version");
+        let key = LinkingCache::key(&LinkingStrategy::Local, "Alan Bean", Some("Person"), "ctx-hash");
+
+        cache.put(&key, Some(sample_entity()), "v1").await.unwrap();
+
+        // A lookup against a newer KB version treats the entry as stale.
+        assert_eq!(cache.get(&key, "v2").await.unwrap(), None);
+
+        fs::remove_dir_all(&dir).ok();
+    }
+
+    #[tokio::test]
+    async fn test_invalidate_stale_removes_only_mismatched_versions() {
+        let (cache, dir) = temp_cache("invalidate_stale");
+        let stale_key = LinkingCache::key(&LinkingStrategy::Local, "Alan Bean", Some("Person"), "ctx-1");
+        let fresh_key = LinkingCache::key(&LinkingStrategy::Local, "Pete Conrad", Some("Person"), "ctx-2");
+
+        cache.put(&stale_key, Some(sample_entity()), "v1").await.unwrap();
+        cache.put(&fresh_key, Some(sample_entity()), "v2").await.unwrap();
+
+        let removed = cache.invalidate_stale("v2").await.unwrap();
+
+        assert_eq!(removed, 1);
+        assert_eq!(cache.get(&fresh_key, "v2").await.unwrap(), Some(Some(sample_entity())));
+
+        fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn test_ingest_tracker_kb_version_changes_when_checksums_change() {
+        let mut tracker = IngestTracker::new();
+        let empty_version = tracker.kb_version();
+
+        tracker.checksums.insert("kb.ttl".to_string(), "abc123".to_string());
+        let v1 = tracker.kb_version();
+
+        tracker.checksums.insert("kb.ttl".to_string(), "def456".to_string());
+        let v2 = tracker.kb_version();
+
+        assert_ne!(empty_version, v1);
+        assert_ne!(v1, v2);
+    }
+
+    #[test]
+    fn test_ingest_tracker_save_and_load_roundtrip() {
+        let dir = std::env::temp_dir().join(format!(
+            "text_to_rdf_ingest_tracker_test_{}",
+            std::process::id()
+        ));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("ingest_state.json");
+
+        let mut tracker = IngestTracker::new();
+        tracker.checksums.insert("kb.ttl".to_string(), "abc123".to_string());
+        tracker.save(&path).unwrap();
+
+        let loaded = IngestTracker::load(&path).unwrap();
+        assert_eq!(loaded.kb_version(), tracker.kb_version());
+
+        fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn test_ingest_tracker_load_missing_file_starts_empty() {
+        let dir = std::env::temp_dir().join(format!(
+            "text_to_rdf_ingest_tracker_missing_test_{}",
+            std::process::id()
+        ));
+        let path = dir.join("does_not_exist.json");
+
+        let tracker = IngestTracker::load(&path).unwrap();
+        assert_eq!(tracker.kb_version(), IngestTracker::new().kb_version());
+    }
+}
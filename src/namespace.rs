@@ -0,0 +1,94 @@
+//! Namespace-qualified IRI expansion for custom entity types and predicates
+//!
+//! `EntityType::Custom(String)` and free-form property keys outside the
+//! hardcoded Schema.org context (see
+//! [`RdfDocument::inject_hardcoded_context`](crate::types::RdfDocument::inject_hardcoded_context))
+//! are emitted verbatim, so a CURIE like `"wd:Q42"` or `"dbo:birthPlace"` has
+//! no resolvable URI on its own. [`NamespaceRegistry`] lets callers register
+//! `prefix` -> IRI bindings and expand such CURIEs into full IRIs, the same
+//! way an XML `QName` is built from a namespace plus a local name.
+
+use std::collections::HashMap;
+
+/// A registry of CURIE prefix -> IRI bindings (e.g. `"wd"` -> the Wikidata
+/// entity namespace, `"dbo"` -> the DBpedia ontology namespace), used to
+/// expand `prefix:localName` terms into full IRIs
+#[derive(Debug, Clone, Default)]
+pub struct NamespaceRegistry {
+    prefixes: HashMap<String, String>,
+}
+
+impl NamespaceRegistry {
+    /// Create an empty registry
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a CURIE prefix -> IRI namespace binding
+    #[must_use]
+    pub fn with_prefix(mut self, prefix: impl Into<String>, iri: impl Into<String>) -> Self {
+        self.prefixes.insert(prefix.into(), iri.into());
+        self
+    }
+
+    /// Expand a `prefix:localName` CURIE into a full IRI by concatenating
+    /// the registered namespace IRI with the local name
+    ///
+    /// Returns `None` if `term` has no `prefix:` part, or the prefix isn't
+    /// registered - including absolute `http(s)://` IRIs, which already
+    /// contain a `:` but no namespace bound to `"http"`/`"https"`.
+    #[must_use]
+    pub fn expand(&self, term: &str) -> Option<String> {
+        let (prefix, local) = term.split_once(':')?;
+        let namespace = self.prefixes.get(prefix)?;
+        Some(format!("{namespace}{local}"))
+    }
+
+    /// This registry's prefix -> IRI bindings, for merging into a JSON-LD
+    /// `@context` prefix map (see
+    /// [`RdfDocument::inject_hardcoded_context_with_namespaces`](crate::types::RdfDocument::inject_hardcoded_context_with_namespaces))
+    #[must_use]
+    pub fn bindings(&self) -> &HashMap<String, String> {
+        &self.prefixes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expand_known_prefix() {
+        let namespaces = NamespaceRegistry::new()
+            .with_prefix("wd", "https://www.wikidata.org/entity/")
+            .with_prefix("dbo", "https://dbpedia.org/ontology/");
+
+        assert_eq!(
+            namespaces.expand("wd:Q42"),
+            Some("https://www.wikidata.org/entity/Q42".to_string())
+        );
+        assert_eq!(
+            namespaces.expand("dbo:birthPlace"),
+            Some("https://dbpedia.org/ontology/birthPlace".to_string())
+        );
+    }
+
+    #[test]
+    fn test_expand_unregistered_prefix_returns_none() {
+        let namespaces = NamespaceRegistry::new().with_prefix("wd", "https://www.wikidata.org/entity/");
+        assert_eq!(namespaces.expand("dbo:birthPlace"), None);
+    }
+
+    #[test]
+    fn test_expand_term_without_prefix_returns_none() {
+        let namespaces = NamespaceRegistry::new().with_prefix("wd", "https://www.wikidata.org/entity/");
+        assert_eq!(namespaces.expand("birthPlace"), None);
+    }
+
+    #[test]
+    fn test_expand_does_not_mistake_absolute_iri_for_curie() {
+        let namespaces = NamespaceRegistry::new().with_prefix("wd", "https://www.wikidata.org/entity/");
+        assert_eq!(namespaces.expand("https://example.org/Q42"), None);
+    }
+}
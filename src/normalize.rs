@@ -1,10 +1,147 @@
 //! Text normalization utilities for RDF entity names and predicates
 //!
-//! - Entity names: Uses `slug` crate for robust Unicode handling
+//! - Entity names: Uses `slug` crate for robust Unicode handling, composed
+//!   through the [`Normalizer`]/[`NormalizeStep`] pipeline below
 //! - Predicates: Uses `rust-stemmers` for relation normalization (e.g., "runs"/"running" → "run")
 
 use rust_stemmers::{Algorithm, Stemmer};
+use serde::{de, Deserialize, Serialize};
 use slug::slugify;
+use unicode_normalization::UnicodeNormalization;
+
+/// A single step in a text-normalization pipeline
+///
+/// Modeled on tokenizer normalizer stacks: each step is a small, composable
+/// transform, and a [`NormalizeStep::Sequence`] chains several of them in
+/// order. Implement this trait for a custom type to extend the pipeline
+/// beyond the built-in [`NormalizeStep`] variants.
+pub trait Normalizer {
+    fn normalize(&self, s: &str) -> String;
+}
+
+/// A built-in normalization step, loadable from config as
+/// `{"type": "<Variant>", ...fields}` (see [`NormalizeStep`]'s `Deserialize`
+/// impl for the exact shape each variant expects)
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "type")]
+pub enum NormalizeStep {
+    /// Unicode canonical composition
+    #[serde(rename = "NFC")]
+    Nfc,
+    /// Unicode canonical decomposition
+    #[serde(rename = "NFD")]
+    Nfd,
+    /// Unicode compatibility composition
+    #[serde(rename = "NFKC")]
+    Nfkc,
+    /// Unicode compatibility decomposition
+    #[serde(rename = "NFKD")]
+    Nfkd,
+    /// Lowercase the entire string
+    Lowercase,
+    /// Decompose (NFD) and drop combining diacritical marks, e.g. "café" -> "cafe"
+    StripAccents,
+    /// Replace every occurrence of `from` with `to`
+    Replace { from: String, to: String },
+    /// ASCII-transliterate and slug-case via the `slug` crate (lowercase,
+    /// non-alphanumerics collapsed to `-`)
+    Slugify,
+    /// Apply each step in order, threading the output of one into the next
+    Sequence { steps: Vec<NormalizeStep> },
+}
+
+impl Normalizer for NormalizeStep {
+    fn normalize(&self, s: &str) -> String {
+        match self {
+            Self::Nfc => s.nfc().collect(),
+            Self::Nfd => s.nfd().collect(),
+            Self::Nfkc => s.nfkc().collect(),
+            Self::Nfkd => s.nfkd().collect(),
+            Self::Lowercase => s.to_lowercase(),
+            Self::StripAccents => s.nfd().filter(|c| !is_combining_mark(*c)).collect(),
+            Self::Replace { from, to } => s.replace(from.as_str(), to.as_str()),
+            Self::Slugify => slugify(s),
+            Self::Sequence { steps } => steps
+                .iter()
+                .fold(s.to_string(), |acc, step| step.normalize(&acc)),
+        }
+    }
+}
+
+/// Whether `c` falls in the Combining Diacritical Marks block (U+0300-U+036F),
+/// the range an NFD decomposition isolates accents into
+fn is_combining_mark(c: char) -> bool {
+    ('\u{0300}'..='\u{036F}').contains(&c)
+}
+
+impl<'de> Deserialize<'de> for NormalizeStep {
+    /// Reads the `"type"` tag first, then deserializes whatever fields that
+    /// variant needs from the rest of the object - a manual internally-tagged
+    /// deserializer, since the tag values (`"NFC"`, `"NFKD"`, ...) follow
+    /// Unicode normalization-form naming rather than the variants' Rust names
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        let tag = value
+            .get("type")
+            .and_then(serde_json::Value::as_str)
+            .ok_or_else(|| de::Error::missing_field("type"))?;
+
+        match tag {
+            "NFC" => Ok(Self::Nfc),
+            "NFD" => Ok(Self::Nfd),
+            "NFKC" => Ok(Self::Nfkc),
+            "NFKD" => Ok(Self::Nfkd),
+            "Lowercase" => Ok(Self::Lowercase),
+            "StripAccents" => Ok(Self::StripAccents),
+            "Slugify" => Ok(Self::Slugify),
+            "Replace" => {
+                let from = value
+                    .get("from")
+                    .and_then(serde_json::Value::as_str)
+                    .ok_or_else(|| de::Error::missing_field("from"))?
+                    .to_string();
+                let to = value
+                    .get("to")
+                    .and_then(serde_json::Value::as_str)
+                    .ok_or_else(|| de::Error::missing_field("to"))?
+                    .to_string();
+                Ok(Self::Replace { from, to })
+            }
+            "Sequence" => {
+                let steps_value = value
+                    .get("steps")
+                    .cloned()
+                    .ok_or_else(|| de::Error::missing_field("steps"))?;
+                let steps = serde_json::from_value(steps_value).map_err(de::Error::custom)?;
+                Ok(Self::Sequence { steps })
+            }
+            other => Err(de::Error::unknown_variant(
+                other,
+                &[
+                    "NFC",
+                    "NFD",
+                    "NFKC",
+                    "NFKD",
+                    "Lowercase",
+                    "StripAccents",
+                    "Replace",
+                    "Slugify",
+                    "Sequence",
+                ],
+            )),
+        }
+    }
+}
+
+/// The default [`normalize_entity_name`] pipeline: ASCII-transliterating
+/// slugify, matching the `slug`-crate-only behavior this module had before
+/// [`NormalizeStep`] existed
+fn default_entity_name_pipeline() -> NormalizeStep {
+    NormalizeStep::Slugify
+}
 
 /// Normalize an entity name for consistent RDF representation
 ///
@@ -23,11 +160,15 @@ use slug::slugify;
 /// assert_eq!(normalize_entity_name("José García"), "jose_garcia");
 /// assert_eq!(normalize_entity_name("MIT"), "mit");
 /// ```
+///
+/// A thin wrapper over [`default_entity_name_pipeline`]; callers that need a
+/// different pipeline (e.g. `NormalizeStep::Nfc` to keep diacritics for a
+/// non-Latin corpus instead of forcing ASCII) can call [`Normalizer::normalize`]
+/// on their own [`NormalizeStep`] directly.
 #[must_use]
 pub fn normalize_entity_name(name: &str) -> String {
-    // Use slug crate for proper Unicode normalization
     // Replace hyphens with underscores to match RDF conventions
-    slugify(name).replace('-', "_")
+    default_entity_name_pipeline().normalize(name).replace('-', "_")
 }
 
 /// Normalize a predicate/relation name using stemming
@@ -90,6 +231,62 @@ fn split_camel_case(s: &str) -> Vec<String> {
     words
 }
 
+/// Normalize a root entity's `name` field, which may be a plain string or a
+/// JSON-LD language-tagged value (`{"@value": "...", "@language": "ja"}`) or
+/// an array of such values for an entity with names in several languages
+///
+/// A plain string gets the usual ASCII-slugging [`normalize_entity_name`]
+/// treatment. A language-tagged value only gets ASCII-slugged when its
+/// `@language` tag is a Latin-script language - see [`is_latin_language_tag`]
+/// - otherwise it's NFC-normalized and trimmed, preserving the script rather
+/// than mangling it into nothing.
+fn normalize_root_name(value: &mut serde_json::Value) {
+    use serde_json::Value;
+
+    match value {
+        Value::String(s) => {
+            *s = normalize_entity_name(s);
+        }
+        Value::Object(entry) => normalize_lang_tagged_entry(entry),
+        Value::Array(entries) => {
+            for entry in entries.iter_mut() {
+                if let Value::Object(entry) = entry {
+                    normalize_lang_tagged_entry(entry);
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+fn normalize_lang_tagged_entry(entry: &mut serde_json::Map<String, serde_json::Value>) {
+    use serde_json::Value;
+
+    let is_latin = entry
+        .get("@language")
+        .and_then(Value::as_str)
+        .is_none_or(is_latin_language_tag);
+
+    if let Some(Value::String(v)) = entry.get_mut("@value") {
+        *v = if is_latin {
+            normalize_entity_name(v)
+        } else {
+            v.nfc().collect::<String>().trim().to_string()
+        };
+    }
+}
+
+/// Whether a BCP-47 language tag's primary subtag is (usually) written in
+/// Latin script, i.e. whether ASCII-slugging it is reasonable rather than
+/// destructive
+fn is_latin_language_tag(tag: &str) -> bool {
+    let primary = tag.split('-').next().unwrap_or(tag).to_lowercase();
+    !matches!(
+        primary.as_str(),
+        "ja" | "zh" | "ko" | "ar" | "he" | "hi" | "th" | "ru" | "el" | "ka" | "am" | "bn" | "ta" | "fa" | "ur"
+    )
+}
+
 /// Normalize a JSON-LD value by recursively processing all string fields
 ///
 /// **Important**: Only normalizes the root entity's name field, not nested entities.
@@ -119,9 +316,8 @@ fn normalize_jsonld_value_impl(value: &mut serde_json::Value, is_root: bool) {
             // Only normalize the root entity's name field
             // Preserve nested entity names for external KB matching
             if is_root {
-                if let Some(Value::String(name)) = map.get_mut("name") {
-                    let normalized = normalize_entity_name(name);
-                    *name = normalized;
+                if let Some(name) = map.get_mut("name") {
+                    normalize_root_name(name);
                 }
             }
 
@@ -263,4 +459,124 @@ mod tests {
         assert_eq!(value["location"]["name"], "Aarhus");
         assert_eq!(value["location"]["addressCountry"], "Denmark");
     }
+
+    #[test]
+    fn test_normalize_step_nfc_only_keeps_diacritics() {
+        let pipeline = NormalizeStep::Nfc;
+        assert_eq!(pipeline.normalize("José García"), "José García");
+    }
+
+    #[test]
+    fn test_normalize_step_strip_accents() {
+        let pipeline = NormalizeStep::StripAccents;
+        assert_eq!(pipeline.normalize("café"), "cafe");
+    }
+
+    #[test]
+    fn test_normalize_step_replace() {
+        let pipeline = NormalizeStep::Replace {
+            from: " ".to_string(),
+            to: "_".to_string(),
+        };
+        assert_eq!(pipeline.normalize("New York"), "New_York");
+    }
+
+    #[test]
+    fn test_normalize_step_sequence_applies_in_order() {
+        let pipeline = NormalizeStep::Sequence {
+            steps: vec![
+                NormalizeStep::Nfkd,
+                NormalizeStep::StripAccents,
+                NormalizeStep::Lowercase,
+                NormalizeStep::Replace {
+                    from: " ".to_string(),
+                    to: "_".to_string(),
+                },
+            ],
+        };
+        assert_eq!(pipeline.normalize("José García"), "jose_garcia");
+    }
+
+    #[test]
+    fn test_normalize_step_deserialize_from_json() {
+        let json = r#"{"type": "Sequence", "steps": [{"type": "NFC"}, {"type": "Lowercase"}]}"#;
+        let step: NormalizeStep = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            step,
+            NormalizeStep::Sequence {
+                steps: vec![NormalizeStep::Nfc, NormalizeStep::Lowercase]
+            }
+        );
+        assert_eq!(step.normalize("ABC"), "abc");
+    }
+
+    #[test]
+    fn test_normalize_step_deserialize_replace_requires_fields() {
+        let json = r#"{"type": "Replace", "from": "-"}"#;
+        let result: Result<NormalizeStep, _> = serde_json::from_str(json);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_normalize_step_serialize_round_trips() {
+        let step = NormalizeStep::Replace {
+            from: "-".to_string(),
+            to: "_".to_string(),
+        };
+        let json = serde_json::to_string(&step).unwrap();
+        let round_tripped: NormalizeStep = serde_json::from_str(&json).unwrap();
+        assert_eq!(step, round_tripped);
+    }
+
+    #[test]
+    fn test_default_entity_name_pipeline_matches_normalize_entity_name() {
+        assert_eq!(
+            default_entity_name_pipeline().normalize("Alan Bean").replace('-', "_"),
+            normalize_entity_name("Alan Bean")
+        );
+    }
+
+    #[test]
+    fn test_normalize_jsonld_slugs_latin_language_tagged_name() {
+        use serde_json::json;
+
+        let mut value = json!({
+            "@context": "https://schema.org/",
+            "@type": "Person",
+            "name": { "@value": "Jean Dupont", "@language": "fr" },
+        });
+
+        normalize_jsonld_value(&mut value);
+
+        assert_eq!(value["name"]["@value"], "jean_dupont");
+        assert_eq!(value["name"]["@language"], "fr");
+    }
+
+    #[test]
+    fn test_normalize_jsonld_preserves_non_latin_language_tagged_name() {
+        use serde_json::json;
+
+        let mut value = json!({
+            "@context": "https://schema.org/",
+            "@type": "Person",
+            "name": [
+                { "@value": "Jean Dupont", "@language": "fr" },
+                { "@value": "ジャン・デュポン", "@language": "ja" },
+            ],
+        });
+
+        normalize_jsonld_value(&mut value);
+
+        assert_eq!(value["name"][0]["@value"], "jean_dupont");
+        assert_eq!(value["name"][1]["@value"], "ジャン・デュポン");
+        assert_eq!(value["name"][1]["@language"], "ja");
+    }
+
+    #[test]
+    fn test_is_latin_language_tag() {
+        assert!(is_latin_language_tag("fr"));
+        assert!(is_latin_language_tag("en-US"));
+        assert!(!is_latin_language_tag("ja"));
+        assert!(!is_latin_language_tag("ar"));
+    }
 }
@@ -0,0 +1,495 @@
+//! Pluggable storage backend for the entity-linking cache
+//!
+//! [`LinkingCache`](crate::linking_cache::LinkingCache) used to write one
+//! JSON file per key straight to the filesystem. [`CacheBackend`] pulls that
+//! storage behind a trait instead, so the same cache can sit on an embedded,
+//! single-host KV store ([`FilesystemCacheBackend`]), a single-file `SQLite`
+//! database ([`SqliteCacheBackend`]), an S3-compatible object store
+//! ([`S3CacheBackend`]) for sharing cached links across machines, or a plain
+//! in-memory map ([`InMemoryCacheBackend`]) for tests, without the cache
+//! owner itself knowing which. Entries may optionally be gzip-compressed in
+//! transit/at rest via [`maybe_compress`]/[`maybe_decompress`] to keep
+//! objects small, which matters most for the S3 backend where every cached
+//! entry is a billed network round-trip.
+
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::io::{Read, Write};
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use crate::error::{Error, Result};
+
+/// Storage backend for cached linking-decision bytes, keyed by an opaque
+/// string (see [`LinkingCache::key`](crate::linking_cache::LinkingCache::key))
+#[async_trait]
+pub trait CacheBackend: Send + Sync {
+    /// Fetch the raw bytes stored under `key`, or `Ok(None)` on a miss
+    async fn get(&self, key: &str) -> Result<Option<Vec<u8>>>;
+
+    /// Store `value` under `key`, replacing any existing entry
+    async fn put(&self, key: &str, value: &[u8]) -> Result<()>;
+
+    /// Remove the entry stored under `key`, if any
+    async fn remove(&self, key: &str) -> Result<()>;
+
+    /// Every key currently stored, used by
+    /// [`LinkingCache::invalidate_stale`](crate::linking_cache::LinkingCache::invalidate_stale)
+    /// to sweep entries computed against a stale KB version
+    async fn list_keys(&self) -> Result<Vec<String>>;
+
+    /// Remove every entry. The default implementation removes keys one at a
+    /// time via [`Self::list_keys`]/[`Self::remove`]; backends may override
+    /// this with a faster native bulk clear.
+    async fn clear(&self) -> Result<()> {
+        for key in self.list_keys().await? {
+            self.remove(&key).await?;
+        }
+        Ok(())
+    }
+}
+
+/// An in-memory [`CacheBackend`], for tests that shouldn't have to touch the
+/// filesystem or stand up a real embedded database
+#[derive(Debug, Default)]
+pub struct InMemoryCacheBackend {
+    entries: Mutex<HashMap<String, Vec<u8>>>,
+}
+
+impl InMemoryCacheBackend {
+    /// Build an empty in-memory backend
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl CacheBackend for InMemoryCacheBackend {
+    async fn get(&self, key: &str) -> Result<Option<Vec<u8>>> {
+        Ok(self.entries.lock().unwrap().get(key).cloned())
+    }
+
+    async fn put(&self, key: &str, value: &[u8]) -> Result<()> {
+        self.entries.lock().unwrap().insert(key.to_string(), value.to_vec());
+        Ok(())
+    }
+
+    async fn remove(&self, key: &str) -> Result<()> {
+        self.entries.lock().unwrap().remove(key);
+        Ok(())
+    }
+
+    async fn list_keys(&self) -> Result<Vec<String>> {
+        Ok(self.entries.lock().unwrap().keys().cloned().collect())
+    }
+
+    async fn clear(&self) -> Result<()> {
+        self.entries.lock().unwrap().clear();
+        Ok(())
+    }
+}
+
+/// An embedded-database, single-host [`CacheBackend`] backed by `sled`
+pub struct FilesystemCacheBackend {
+    db: sled::Db,
+}
+
+impl FilesystemCacheBackend {
+    /// Open (creating if necessary) a `sled` database rooted at `dir`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `dir` cannot be opened as a `sled` database
+    pub fn open(dir: impl Into<PathBuf>) -> Result<Self> {
+        let db = sled::open(dir.into())
+            .map_err(|e| Error::Config(format!("Failed to open cache database: {e}")))?;
+        Ok(Self { db })
+    }
+}
+
+#[async_trait]
+impl CacheBackend for FilesystemCacheBackend {
+    async fn get(&self, key: &str) -> Result<Option<Vec<u8>>> {
+        self.db
+            .get(key)
+            .map(|entry| entry.map(|ivec| ivec.to_vec()))
+            .map_err(|e| Error::Config(format!("Cache read failed for {key}: {e}")))
+    }
+
+    async fn put(&self, key: &str, value: &[u8]) -> Result<()> {
+        self.db
+            .insert(key, value)
+            .map_err(|e| Error::Config(format!("Cache write failed for {key}: {e}")))?;
+        Ok(())
+    }
+
+    async fn remove(&self, key: &str) -> Result<()> {
+        self.db
+            .remove(key)
+            .map_err(|e| Error::Config(format!("Cache removal failed for {key}: {e}")))?;
+        Ok(())
+    }
+
+    async fn list_keys(&self) -> Result<Vec<String>> {
+        self.db
+            .iter()
+            .keys()
+            .map(|entry| {
+                entry
+                    .map(|key| String::from_utf8_lossy(&key).into_owned())
+                    .map_err(|e| Error::Config(format!("Cache key listing failed: {e}")))
+            })
+            .collect()
+    }
+}
+
+/// A single-file, embedded-database [`CacheBackend`] backed by `SQLite`,
+/// for a cache that survives process restarts without requiring a `sled`
+/// database directory (e.g.
+/// [`PersistentChunkCache`](crate::persistent_chunk_cache::PersistentChunkCache),
+/// which needs the cache to outlive a crash mid-document)
+#[cfg(feature = "sqlite-cache")]
+pub struct SqliteCacheBackend {
+    conn: Mutex<rusqlite::Connection>,
+}
+
+#[cfg(feature = "sqlite-cache")]
+impl SqliteCacheBackend {
+    /// Open (creating if necessary) a `SQLite` database at `path`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `path` cannot be opened as a `SQLite` database or
+    /// the cache table cannot be created
+    pub fn open(path: impl AsRef<std::path::Path>) -> Result<Self> {
+        let conn = rusqlite::Connection::open(path)
+            .map_err(|e| Error::Config(format!("Failed to open SQLite cache database: {e}")))?;
+        conn.execute(
+            "CREATE TABLE IF NOT EXISTS cache_entries (key TEXT PRIMARY KEY, value BLOB NOT NULL)",
+            [],
+        )
+        .map_err(|e| Error::Config(format!("Failed to initialize SQLite cache schema: {e}")))?;
+        Ok(Self { conn: Mutex::new(conn) })
+    }
+}
+
+#[cfg(feature = "sqlite-cache")]
+#[async_trait]
+impl CacheBackend for SqliteCacheBackend {
+    async fn get(&self, key: &str) -> Result<Option<Vec<u8>>> {
+        use rusqlite::OptionalExtension;
+        self.conn
+            .lock()
+            .unwrap()
+            .query_row("SELECT value FROM cache_entries WHERE key = ?1", [key], |row| row.get(0))
+            .optional()
+            .map_err(|e| Error::Config(format!("SQLite cache read failed for {key}: {e}")))
+    }
+
+    async fn put(&self, key: &str, value: &[u8]) -> Result<()> {
+        self.conn
+            .lock()
+            .unwrap()
+            .execute(
+                "INSERT INTO cache_entries (key, value) VALUES (?1, ?2)
+                 ON CONFLICT(key) DO UPDATE SET value = excluded.value",
+                rusqlite::params![key, value],
+            )
+            .map_err(|e| Error::Config(format!("SQLite cache write failed for {key}: {e}")))?;
+        Ok(())
+    }
+
+    async fn remove(&self, key: &str) -> Result<()> {
+        self.conn
+            .lock()
+            .unwrap()
+            .execute("DELETE FROM cache_entries WHERE key = ?1", [key])
+            .map_err(|e| Error::Config(format!("SQLite cache removal failed for {key}: {e}")))?;
+        Ok(())
+    }
+
+    async fn list_keys(&self) -> Result<Vec<String>> {
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn
+            .prepare("SELECT key FROM cache_entries")
+            .map_err(|e| Error::Config(format!("SQLite cache key listing failed: {e}")))?;
+        stmt.query_map([], |row| row.get(0))
+            .map_err(|e| Error::Config(format!("SQLite cache key listing failed: {e}")))?
+            .collect::<std::result::Result<Vec<String>, _>>()
+            .map_err(|e| Error::Config(format!("SQLite cache key listing failed: {e}")))
+    }
+
+    async fn clear(&self) -> Result<()> {
+        self.conn
+            .lock()
+            .unwrap()
+            .execute("DELETE FROM cache_entries", [])
+            .map_err(|e| Error::Config(format!("SQLite cache clear failed: {e}")))?;
+        Ok(())
+    }
+}
+
+/// Connection details for an S3-compatible object store backing an
+/// entity-linking cache
+#[derive(Debug, Clone)]
+pub struct S3CacheConfig {
+    /// Custom endpoint URL, for S3-compatible stores (MinIO, R2, ...)
+    /// rather than AWS S3 itself
+    pub endpoint: Option<String>,
+    /// Bucket holding cached entries
+    pub bucket: String,
+    /// AWS region (or a placeholder region for endpoints that ignore it)
+    pub region: String,
+    /// Access key ID
+    pub access_key_id: String,
+    /// Secret access key
+    pub secret_access_key: String,
+    /// Key prefix under which cache entries are stored, e.g. `"linking-cache/"`
+    pub prefix: String,
+}
+
+/// An [`CacheBackend`] backed by an S3-compatible object store, for sharing
+/// cached linking decisions across machines instead of keeping them
+/// per-host
+#[cfg(feature = "s3-cache")]
+pub struct S3CacheBackend {
+    client: aws_sdk_s3::Client,
+    bucket: String,
+    prefix: String,
+}
+
+#[cfg(feature = "s3-cache")]
+impl S3CacheBackend {
+    /// Build a client for `config`'s bucket and credentials
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a custom `endpoint` is set but cannot be parsed
+    pub async fn new(config: &S3CacheConfig) -> Result<Self> {
+        let credentials = aws_sdk_s3::config::Credentials::new(
+            &config.access_key_id,
+            &config.secret_access_key,
+            None,
+            None,
+            "object_cache",
+        );
+
+        let mut builder = aws_sdk_s3::Config::builder()
+            .region(aws_sdk_s3::config::Region::new(config.region.clone()))
+            .credentials_provider(credentials)
+            .force_path_style(true);
+
+        if let Some(endpoint) = &config.endpoint {
+            builder = builder.endpoint_url(endpoint);
+        }
+
+        Ok(Self {
+            client: aws_sdk_s3::Client::from_conf(builder.build()),
+            bucket: config.bucket.clone(),
+            prefix: config.prefix.clone(),
+        })
+    }
+
+    fn object_key(&self, key: &str) -> String {
+        format!("{}{key}", self.prefix)
+    }
+}
+
+#[cfg(feature = "s3-cache")]
+#[async_trait]
+impl CacheBackend for S3CacheBackend {
+    async fn get(&self, key: &str) -> Result<Option<Vec<u8>>> {
+        let result = self
+            .client
+            .get_object()
+            .bucket(&self.bucket)
+            .key(self.object_key(key))
+            .send()
+            .await;
+
+        let output = match result {
+            Ok(output) => output,
+            Err(e) if e.as_service_error().is_some_and(|e| e.is_no_such_key()) => return Ok(None),
+            Err(e) => return Err(Error::Network(format!("S3 get_object failed for {key}: {e}"))),
+        };
+
+        let bytes = output
+            .body
+            .collect()
+            .await
+            .map_err(|e| Error::Network(format!("S3 object body read failed for {key}: {e}")))?;
+
+        Ok(Some(bytes.into_bytes().to_vec()))
+    }
+
+    async fn put(&self, key: &str, value: &[u8]) -> Result<()> {
+        self.client
+            .put_object()
+            .bucket(&self.bucket)
+            .key(self.object_key(key))
+            .body(value.to_vec().into())
+            .send()
+            .await
+            .map_err(|e| Error::Network(format!("S3 put_object failed for {key}: {e}")))?;
+        Ok(())
+    }
+
+    async fn remove(&self, key: &str) -> Result<()> {
+        self.client
+            .delete_object()
+            .bucket(&self.bucket)
+            .key(self.object_key(key))
+            .send()
+            .await
+            .map_err(|e| Error::Network(format!("S3 delete_object failed for {key}: {e}")))?;
+        Ok(())
+    }
+
+    async fn list_keys(&self) -> Result<Vec<String>> {
+        let mut keys = Vec::new();
+        let mut continuation_token = None;
+
+        loop {
+            let mut request = self.client.list_objects_v2().bucket(&self.bucket).prefix(&self.prefix);
+            if let Some(token) = &continuation_token {
+                request = request.continuation_token(token);
+            }
+
+            let response = request
+                .send()
+                .await
+                .map_err(|e| Error::Network(format!("S3 list_objects_v2 failed: {e}")))?;
+
+            for object in response.contents() {
+                if let Some(object_key) = object.key() {
+                    keys.push(object_key.trim_start_matches(&self.prefix).to_string());
+                }
+            }
+
+            continuation_token = response.next_continuation_token().map(ToString::to_string);
+            if continuation_token.is_none() {
+                break;
+            }
+        }
+
+        Ok(keys)
+    }
+}
+
+/// Gzip-compress `bytes` when `compress` is set, otherwise return them
+/// unchanged
+///
+/// # Errors
+///
+/// Returns an error if the gzip encoder fails
+pub fn maybe_compress(bytes: &[u8], compress: bool) -> Result<Vec<u8>> {
+    if !compress {
+        return Ok(bytes.to_vec());
+    }
+
+    let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+    encoder.write_all(bytes)?;
+    Ok(encoder.finish()?)
+}
+
+/// Reverse [`maybe_compress`]: gunzip `bytes` when `compressed` is set,
+/// otherwise return them unchanged
+///
+/// # Errors
+///
+/// Returns an error if the gzip decoder fails (e.g. the bytes aren't valid
+/// gzip)
+pub fn maybe_decompress(bytes: &[u8], compressed: bool) -> Result<Vec<u8>> {
+    if !compressed {
+        return Ok(bytes.to_vec());
+    }
+
+    let mut decoder = flate2::read::GzDecoder::new(bytes);
+    let mut out = Vec::new();
+    decoder.read_to_end(&mut out)?;
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_backend(suffix: &str) -> (FilesystemCacheBackend, PathBuf) {
+        let dir = std::env::temp_dir().join(format!(
+            "text_to_rdf_object_cache_test_{}_{suffix}",
+            std::process::id()
+        ));
+        let _ = std::fs::remove_dir_all(&dir);
+        (FilesystemCacheBackend::open(&dir).unwrap(), dir)
+    }
+
+    #[tokio::test]
+    async fn test_filesystem_backend_roundtrips_a_put() {
+        let (backend, dir) = temp_backend("roundtrip");
+
+        backend.put("key-1", b"hello").await.unwrap();
+        assert_eq!(backend.get("key-1").await.unwrap(), Some(b"hello".to_vec()));
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[tokio::test]
+    async fn test_filesystem_backend_miss_for_unknown_key() {
+        let (backend, dir) = temp_backend("miss");
+
+        assert_eq!(backend.get("does-not-exist").await.unwrap(), None);
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[tokio::test]
+    async fn test_filesystem_backend_remove_and_list_keys() {
+        let (backend, dir) = temp_backend("remove_and_list");
+
+        backend.put("a", b"1").await.unwrap();
+        backend.put("b", b"2").await.unwrap();
+        backend.remove("a").await.unwrap();
+
+        let keys = backend.list_keys().await.unwrap();
+        assert_eq!(keys, vec!["b".to_string()]);
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_compress_roundtrips() {
+        let original = b"some moderately repetitive linking cache payload payload payload";
+        let compressed = maybe_compress(original, true).unwrap();
+        assert_ne!(compressed, original);
+
+        let decompressed = maybe_decompress(&compressed, true).unwrap();
+        assert_eq!(decompressed, original);
+    }
+
+    #[test]
+    fn test_compress_is_a_no_op_when_disabled() {
+        let original = b"payload";
+        assert_eq!(maybe_compress(original, false).unwrap(), original);
+        assert_eq!(maybe_decompress(original, false).unwrap(), original);
+    }
+
+    #[tokio::test]
+    async fn test_in_memory_backend_roundtrips_a_put() {
+        let backend = InMemoryCacheBackend::new();
+        backend.put("key-1", b"hello").await.unwrap();
+        assert_eq!(backend.get("key-1").await.unwrap(), Some(b"hello".to_vec()));
+    }
+
+    #[tokio::test]
+    async fn test_in_memory_backend_default_clear_removes_all_entries() {
+        let backend = InMemoryCacheBackend::new();
+        backend.put("a", b"1").await.unwrap();
+        backend.put("b", b"2").await.unwrap();
+
+        backend.clear().await.unwrap();
+
+        assert!(backend.list_keys().await.unwrap().is_empty());
+        assert_eq!(backend.get("a").await.unwrap(), None);
+    }
+}
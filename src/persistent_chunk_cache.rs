@@ -0,0 +1,232 @@
+//! Cross-run cache for per-chunk extraction results
+//!
+//! [`GenAiExtractor`](crate::extractor::GenAiExtractor)'s in-memory
+//! `chunk_cache` (an LRU/TTL [`moka`] cache) only survives as long as the
+//! process does, so a crash partway through a large document re-pays for
+//! every chunk on the next run. [`PersistentChunkCache`] sits behind the same
+//! [`CacheBackend`] abstraction [`LinkingCache`](crate::linking_cache::LinkingCache)
+//! uses - a `SQLite`-backed
+//! [`SqliteCacheBackend`](crate::object_cache::SqliteCacheBackend) in
+//! production, or an
+//! [`InMemoryCacheBackend`](crate::object_cache::InMemoryCacheBackend) in
+//! tests - so that re-processing a document after an interruption (or a
+//! small edit to part of it) only re-invokes the LLM for chunks whose text,
+//! model, or extraction configuration actually changed.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::error::{Error, Result};
+use crate::object_cache::CacheBackend;
+use crate::types::RdfDocument;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CachedChunkEntry {
+    context_json: String,
+    data_json: String,
+    cached_at_secs: u64,
+}
+
+/// Persistent cache of per-chunk extraction results, keyed by a hash of
+/// `(chunk text, model, prompt version, extraction config)`, behind a
+/// pluggable [`CacheBackend`]
+#[derive(Clone)]
+pub struct PersistentChunkCache {
+    backend: Arc<dyn CacheBackend>,
+    ttl_secs: u64,
+    hits: Arc<AtomicU64>,
+    misses: Arc<AtomicU64>,
+}
+
+impl std::fmt::Debug for PersistentChunkCache {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PersistentChunkCache")
+            .field("ttl_secs", &self.ttl_secs)
+            .field("hits", &self.hits())
+            .field("misses", &self.misses())
+            .finish()
+    }
+}
+
+impl PersistentChunkCache {
+    /// Build a cache on top of `backend`, whose entries are treated as a
+    /// miss once older than `ttl_secs` (`0` disables expiry)
+    #[must_use]
+    pub fn new(backend: Arc<dyn CacheBackend>, ttl_secs: u64) -> Self {
+        Self { backend, ttl_secs, hits: Arc::new(AtomicU64::new(0)), misses: Arc::new(AtomicU64::new(0)) }
+    }
+
+    /// Compute the cache key for `(chunk_text, model, prompt_version,
+    /// extraction_config)` - everything that can change the extracted
+    /// output for an otherwise-identical chunk of text
+    #[must_use]
+    pub fn key(chunk_text: &str, model: &str, prompt_version: &str, extraction_config: &str) -> String {
+        let mut hasher = Sha256::new();
+        hasher.update(chunk_text.trim().as_bytes());
+        hasher.update(b"\0model=");
+        hasher.update(model.as_bytes());
+        hasher.update(b"\0prompt_version=");
+        hasher.update(prompt_version.as_bytes());
+        hasher.update(b"\0config=");
+        hasher.update(extraction_config.as_bytes());
+        hasher.finalize().iter().map(|byte| format!("{byte:02x}")).collect()
+    }
+
+    /// Look up the cached document for `key`, counting the lookup as a hit
+    /// or a miss. An entry older than the configured TTL counts as a miss
+    /// and is evicted.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend read fails or a stored entry is
+    /// corrupt
+    pub async fn get(&self, key: &str) -> Result<Option<RdfDocument>> {
+        let Some(bytes) = self.backend.get(key).await? else {
+            self.misses.fetch_add(1, Ordering::Relaxed);
+            return Ok(None);
+        };
+
+        let entry: CachedChunkEntry = serde_json::from_slice(&bytes)?;
+
+        if self.ttl_secs > 0 && now_secs().saturating_sub(entry.cached_at_secs) > self.ttl_secs {
+            self.backend.remove(key).await?;
+            self.misses.fetch_add(1, Ordering::Relaxed);
+            return Ok(None);
+        }
+
+        let context = serde_json::from_str(&entry.context_json)?;
+        let data = serde_json::from_str(&entry.data_json)?;
+        self.hits.fetch_add(1, Ordering::Relaxed);
+        Ok(Some(RdfDocument { context, data, provenance: None, prov: None, validity: None }))
+    }
+
+    /// Persist `doc` under `key`, stamped with the current time for TTL
+    /// expiry
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if serialization or the backend write fails
+    pub async fn put(&self, key: &str, doc: &RdfDocument) -> Result<()> {
+        let entry = CachedChunkEntry {
+            context_json: serde_json::to_string(&doc.context)?,
+            data_json: serde_json::to_string(&doc.data)?,
+            cached_at_secs: now_secs(),
+        };
+        let bytes = serde_json::to_vec(&entry)?;
+        self.backend.put(key, &bytes).await
+    }
+
+    /// Remove every cached entry
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend's clear fails
+    pub async fn clear(&self) -> Result<()> {
+        self.backend.clear().await
+    }
+
+    /// Number of [`Self::get`] calls that found a valid, unexpired entry
+    #[must_use]
+    pub fn hits(&self) -> u64 {
+        self.hits.load(Ordering::Relaxed)
+    }
+
+    /// Number of [`Self::get`] calls that found no entry, or an expired one
+    #[must_use]
+    pub fn misses(&self) -> u64 {
+        self.misses.load(Ordering::Relaxed)
+    }
+}
+
+/// Open the durable [`CacheBackend`] this crate was built with: a `SQLite`
+/// database at `path` when the `sqlite-cache` feature is enabled, otherwise
+/// the always-available `sled`-backed
+/// [`FilesystemCacheBackend`](crate::object_cache::FilesystemCacheBackend)
+///
+/// # Errors
+///
+/// Returns an error if `path` cannot be opened as the chosen backend's
+/// database
+pub fn open_default_backend(path: &std::path::Path) -> Result<Arc<dyn CacheBackend>> {
+    #[cfg(feature = "sqlite-cache")]
+    {
+        Ok(Arc::new(crate::object_cache::SqliteCacheBackend::open(path)?))
+    }
+    #[cfg(not(feature = "sqlite-cache"))]
+    {
+        Ok(Arc::new(crate::object_cache::FilesystemCacheBackend::open(path)?))
+    }
+}
+
+fn now_secs() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::object_cache::InMemoryCacheBackend;
+
+    fn sample_doc() -> RdfDocument {
+        RdfDocument {
+            context: serde_json::json!("https://schema.org/"),
+            data: serde_json::json!({"@type": "Person", "name": "Ada"}),
+            provenance: None,
+            prov: None,
+            validity: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_miss_then_hit_roundtrip_and_counts() {
+        let cache = PersistentChunkCache::new(Arc::new(InMemoryCacheBackend::new()), 3600);
+        let key = PersistentChunkCache::key("chunk text", "claude-3-5-sonnet", "v1", "cfg-hash");
+
+        assert!(cache.get(&key).await.unwrap().is_none());
+        assert_eq!(cache.misses(), 1);
+
+        cache.put(&key, &sample_doc()).await.unwrap();
+
+        let hit = cache.get(&key).await.unwrap().expect("cache hit");
+        assert_eq!(hit.data, sample_doc().data);
+        assert_eq!(cache.hits(), 1);
+        assert_eq!(cache.misses(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_ttl_zero_disables_expiry() {
+        let cache = PersistentChunkCache::new(Arc::new(InMemoryCacheBackend::new()), 0);
+        let key = PersistentChunkCache::key("chunk text", "model", "v1", "cfg");
+
+        cache.put(&key, &sample_doc()).await.unwrap();
+
+        assert!(cache.get(&key).await.unwrap().is_some());
+    }
+
+    #[tokio::test]
+    async fn test_clear_removes_all_entries() {
+        let cache = PersistentChunkCache::new(Arc::new(InMemoryCacheBackend::new()), 3600);
+        let key_a = PersistentChunkCache::key("a", "model", "v1", "cfg");
+        let key_b = PersistentChunkCache::key("b", "model", "v1", "cfg");
+        cache.put(&key_a, &sample_doc()).await.unwrap();
+        cache.put(&key_b, &sample_doc()).await.unwrap();
+
+        cache.clear().await.unwrap();
+
+        assert!(cache.get(&key_a).await.unwrap().is_none());
+        assert!(cache.get(&key_b).await.unwrap().is_none());
+    }
+
+    #[test]
+    fn test_key_changes_with_model_and_prompt_version() {
+        let a = PersistentChunkCache::key("text", "model-a", "v1", "cfg");
+        let b = PersistentChunkCache::key("text", "model-b", "v1", "cfg");
+        let c = PersistentChunkCache::key("text", "model-a", "v2", "cfg");
+        assert_ne!(a, b);
+        assert_ne!(a, c);
+    }
+}
@@ -0,0 +1,232 @@
+//! Structured decomposition of personal names into Schema.org name parts
+//!
+//! Collapsing a whole display name into a single normalized slug loses the
+//! given/family structure that downstream knowledge-base matching needs.
+//! [`parse_person_name`] splits a name into ordered [`NamePart`]s - an
+//! optional honorific prefix (title), given name, middle name(s), an
+//! optional lowercase surname-prefix particle ("van", "de"), surname, and an
+//! optional honorific suffix - so callers can populate Schema.org
+//! `givenName`/`familyName`/`honorificPrefix`/`honorificSuffix` alongside the
+//! plain `name` string (see [`crate::types::RdfEntity::with_decomposed_name`]).
+
+/// Honorific prefixes recognized ahead of the given name, matched
+/// case-insensitively with any trailing `.` stripped
+const HONORIFIC_PREFIXES: &[&str] = &["dr", "mr", "mrs", "ms", "mx", "prof", "sir", "rev"];
+
+/// Honorific suffixes recognized after the surname, matched the same way
+const HONORIFIC_SUFFIXES: &[&str] = &["jr", "sr", "ii", "iii", "iv", "v", "phd", "md", "esq"];
+
+/// Lowercase surname-prefix particles treated as part of the surname rather
+/// than a middle name (e.g. "van Gogh", "de la Cruz")
+const SURNAME_PREFIXES: &[&str] = &[
+    "van", "von", "de", "der", "den", "del", "da", "di", "la", "le", "bin", "ibn",
+];
+
+/// What role a [`NamePart`] plays in a decomposed [`NameForm`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NamePartKind {
+    HonorificPrefix,
+    Given,
+    Middle,
+    SurnamePrefix,
+    Surname,
+    HonorificSuffix,
+}
+
+/// One token of a decomposed name, tagged with the role it plays
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NamePart {
+    pub kind: NamePartKind,
+    pub value: String,
+}
+
+impl NamePart {
+    fn new(kind: NamePartKind, value: &str) -> Self {
+        Self {
+            kind,
+            value: value.to_string(),
+        }
+    }
+}
+
+/// A personal name decomposed into ordered parts by [`parse_person_name`]
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct NameForm {
+    pub full_text: String,
+    pub parts: Vec<NamePart>,
+}
+
+impl NameForm {
+    fn joined(&self, kind: NamePartKind) -> Option<String> {
+        let words: Vec<&str> = self
+            .parts
+            .iter()
+            .filter(|p| p.kind == kind)
+            .map(|p| p.value.as_str())
+            .collect();
+        (!words.is_empty()).then(|| words.join(" "))
+    }
+
+    /// Schema.org `givenName`: given name plus any middle name(s), joined
+    #[must_use]
+    pub fn given_name(&self) -> Option<String> {
+        let words: Vec<&str> = self
+            .parts
+            .iter()
+            .filter(|p| matches!(p.kind, NamePartKind::Given | NamePartKind::Middle))
+            .map(|p| p.value.as_str())
+            .collect();
+        (!words.is_empty()).then(|| words.join(" "))
+    }
+
+    /// Schema.org `familyName`: surname-prefix particle (if any) plus surname
+    #[must_use]
+    pub fn family_name(&self) -> Option<String> {
+        let words: Vec<&str> = self
+            .parts
+            .iter()
+            .filter(|p| matches!(p.kind, NamePartKind::SurnamePrefix | NamePartKind::Surname))
+            .map(|p| p.value.as_str())
+            .collect();
+        (!words.is_empty()).then(|| words.join(" "))
+    }
+
+    /// Schema.org `honorificPrefix`
+    #[must_use]
+    pub fn honorific_prefix(&self) -> Option<String> {
+        self.joined(NamePartKind::HonorificPrefix)
+    }
+
+    /// Schema.org `honorificSuffix`
+    #[must_use]
+    pub fn honorific_suffix(&self) -> Option<String> {
+        self.joined(NamePartKind::HonorificSuffix)
+    }
+}
+
+fn strip_for_match(token: &str) -> String {
+    token.trim_end_matches('.').to_lowercase()
+}
+
+fn is_honorific_prefix(token: &str) -> bool {
+    HONORIFIC_PREFIXES.contains(&strip_for_match(token).as_str())
+}
+
+fn is_honorific_suffix(token: &str) -> bool {
+    HONORIFIC_SUFFIXES.contains(&strip_for_match(token).as_str())
+}
+
+/// Whether `token` is a known surname-prefix particle AND is lowercase in
+/// the original text - "Van" in "Vincent Van Gogh" is a surname proper noun,
+/// while "van" in "Vincent van Gogh" is the particle
+fn is_surname_prefix(token: &str) -> bool {
+    token.starts_with(|c: char| c.is_lowercase()) && SURNAME_PREFIXES.contains(&token.to_lowercase().as_str())
+}
+
+/// Decompose a personal name into ordered [`NamePart`]s
+///
+/// Strips a known honorific prefix/suffix from the ends, then - among the
+/// remaining tokens - treats a lowercase surname-prefix particle immediately
+/// before the last token as part of the surname, the last token as the
+/// surname itself, and every token before that as given/middle names. A
+/// single remaining token (a mononym) is treated as the given name.
+#[must_use]
+pub fn parse_person_name(full_text: &str) -> NameForm {
+    let mut tokens: Vec<&str> = full_text.split_whitespace().collect();
+
+    let prefix = tokens.first().copied().filter(|t| is_honorific_prefix(t));
+    if prefix.is_some() {
+        tokens.remove(0);
+    }
+
+    let suffix = tokens.last().copied().filter(|t| is_honorific_suffix(t));
+    if suffix.is_some() {
+        tokens.pop();
+    }
+
+    let mut parts = Vec::new();
+    if let Some(prefix) = prefix {
+        parts.push(NamePart::new(NamePartKind::HonorificPrefix, prefix));
+    }
+
+    let surname_start = match tokens.len() {
+        0 => 0,
+        1 => 1,
+        n if is_surname_prefix(tokens[n - 2]) => n - 2,
+        n => n - 1,
+    };
+
+    for (i, token) in tokens[..surname_start].iter().enumerate() {
+        let kind = if i == 0 { NamePartKind::Given } else { NamePartKind::Middle };
+        parts.push(NamePart::new(kind, token));
+    }
+    for token in &tokens[surname_start..] {
+        let kind = if is_surname_prefix(token) {
+            NamePartKind::SurnamePrefix
+        } else {
+            NamePartKind::Surname
+        };
+        parts.push(NamePart::new(kind, token));
+    }
+
+    if let Some(suffix) = suffix {
+        parts.push(NamePart::new(NamePartKind::HonorificSuffix, suffix));
+    }
+
+    NameForm {
+        full_text: full_text.to_string(),
+        parts,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_simple_two_part_name() {
+        let form = parse_person_name("Alan Bean");
+        assert_eq!(form.given_name(), Some("Alan".to_string()));
+        assert_eq!(form.family_name(), Some("Bean".to_string()));
+        assert_eq!(form.honorific_prefix(), None);
+        assert_eq!(form.honorific_suffix(), None);
+    }
+
+    #[test]
+    fn test_parse_mononym_is_given_name() {
+        let form = parse_person_name("Cher");
+        assert_eq!(form.given_name(), Some("Cher".to_string()));
+        assert_eq!(form.family_name(), None);
+    }
+
+    #[test]
+    fn test_parse_prefix_and_suffix() {
+        let form = parse_person_name("Dr. José García Jr.");
+        assert_eq!(form.honorific_prefix(), Some("Dr.".to_string()));
+        assert_eq!(form.given_name(), Some("José".to_string()));
+        assert_eq!(form.family_name(), Some("García".to_string()));
+        assert_eq!(form.honorific_suffix(), Some("Jr.".to_string()));
+    }
+
+    #[test]
+    fn test_parse_surname_prefix_particle() {
+        let form = parse_person_name("Vincent van Gogh");
+        assert_eq!(form.given_name(), Some("Vincent".to_string()));
+        assert_eq!(form.family_name(), Some("van Gogh".to_string()));
+    }
+
+    #[test]
+    fn test_parse_capitalized_particle_is_not_surname_prefix() {
+        // "Van" here is a proper noun, not the lowercase particle
+        let form = parse_person_name("Vincent Van Gogh");
+        assert_eq!(form.given_name(), Some("Vincent Van".to_string()));
+        assert_eq!(form.family_name(), Some("Gogh".to_string()));
+    }
+
+    #[test]
+    fn test_parse_middle_name() {
+        let form = parse_person_name("John Fitzgerald Kennedy");
+        assert_eq!(form.given_name(), Some("John Fitzgerald".to_string()));
+        assert_eq!(form.family_name(), Some("Kennedy".to_string()));
+    }
+}
@@ -0,0 +1,382 @@
+//! Probabilistic relation inference over extracted entities
+//!
+//! [`reasoning::Reasoner`](crate::reasoning::Reasoner) derives entailed
+//! triples from ontology shapes, but treats every fact as certain. GLiNER
+//! entities aren't: each mention carries its own `confidence`, and a
+//! proximity-based rule like "a Person mention within N chars of an
+//! Organization mention implies `worksFor`" is itself only weak evidence, not
+//! a hard inference. This module runs a small forward-chaining weighted-Datalog
+//! evaluator over `entity(id, type)` facts - weighted by GLiNER's confidence -
+//! to derive `relation(subj, pred, obj)` triples with a calibrated confidence
+//! rather than a hard yes/no edge.
+//!
+//! # Proof semiring
+//!
+//! A derived fact may be reachable through more than one proof (e.g. two
+//! rules implying the same predicate for the same pair). Each conjunctive
+//! proof's probability is the product of its body atoms' weights - here, the
+//! two entity confidences, since the proximity check itself is a hard
+//! constraint rather than a weighted atom. The `top_k_proofs` highest-
+//! probability proofs for a fact are kept and combined with the noisy-or
+//! approximation `1 - Π(1 - p_i)`: the probability that at least one proof
+//! holds, treating each as independent evidence for the same fact.
+
+use crate::error::Result;
+use crate::types::RdfDocument;
+use serde_json::{json, Value};
+use std::collections::HashMap;
+
+/// An `entity(id, type)` fact carrying GLiNER's confidence as its weight,
+/// plus the character span needed to evaluate [`ProximityRule`]s
+#[derive(Debug, Clone, PartialEq)]
+pub struct WeightedEntity {
+    pub id: String,
+    pub entity_type: String,
+    pub confidence: f32,
+    pub start: usize,
+    pub end: usize,
+}
+
+/// A proximity-based inference rule: "a `subject_type` mention within
+/// `max_distance` characters of an `object_type` mention implies `predicate`"
+#[derive(Debug, Clone)]
+pub struct ProximityRule {
+    pub subject_type: String,
+    pub object_type: String,
+    pub predicate: String,
+    pub max_distance: usize,
+}
+
+impl ProximityRule {
+    #[must_use]
+    pub fn new(
+        subject_type: impl Into<String>,
+        object_type: impl Into<String>,
+        predicate: impl Into<String>,
+        max_distance: usize,
+    ) -> Self {
+        Self {
+            subject_type: subject_type.into(),
+            object_type: object_type.into(),
+            predicate: predicate.into(),
+            max_distance,
+        }
+    }
+}
+
+/// A derived `relation(subj, pred, obj)` fact with a confidence combined from
+/// every proof that derives it
+#[derive(Debug, Clone, PartialEq)]
+pub struct InferredRelation {
+    pub subject: String,
+    pub predicate: String,
+    pub object: String,
+    pub confidence: f32,
+}
+
+/// Configuration for [`ProbabilisticReasoner`]
+#[derive(Debug, Clone)]
+pub struct ProbabilisticReasonerConfig {
+    /// Proximity rules evaluated over the entity set
+    pub rules: Vec<ProximityRule>,
+
+    /// Highest-probability proofs kept per derived fact before combining
+    /// them with noisy-or - bounds the cost of facts derivable through many
+    /// redundant proofs
+    pub top_k_proofs: usize,
+}
+
+impl Default for ProbabilisticReasonerConfig {
+    fn default() -> Self {
+        Self { rules: Vec::new(), top_k_proofs: 5 }
+    }
+}
+
+/// Forward-chaining weighted-Datalog evaluator deriving probabilistic
+/// relation facts from [`ProximityRule`]s over a set of [`WeightedEntity`]
+/// facts
+pub struct ProbabilisticReasoner {
+    config: ProbabilisticReasonerConfig,
+}
+
+impl ProbabilisticReasoner {
+    #[must_use]
+    pub const fn new(config: ProbabilisticReasonerConfig) -> Self {
+        Self { config }
+    }
+
+    /// Evaluate every configured rule over `entities`, combining multiple
+    /// proofs of the same derived fact with noisy-or
+    #[must_use]
+    pub fn infer(&self, entities: &[WeightedEntity]) -> Vec<InferredRelation> {
+        let mut proof_probabilities: HashMap<(String, String, String), Vec<f32>> = HashMap::new();
+
+        for rule in &self.config.rules {
+            for subject in entities.iter().filter(|e| e.entity_type == rule.subject_type) {
+                for object in entities.iter().filter(|e| e.entity_type == rule.object_type) {
+                    if subject.id == object.id {
+                        continue;
+                    }
+
+                    if textual_distance(subject, object) > rule.max_distance {
+                        continue;
+                    }
+
+                    let probability = subject.confidence * object.confidence;
+                    let key = (subject.id.clone(), rule.predicate.clone(), object.id.clone());
+                    proof_probabilities.entry(key).or_default().push(probability);
+                }
+            }
+        }
+
+        let mut relations: Vec<InferredRelation> = proof_probabilities
+            .into_iter()
+            .map(|((subject, predicate, object), mut probabilities)| {
+                probabilities
+                    .sort_by(|a, b| b.partial_cmp(a).unwrap_or(std::cmp::Ordering::Equal));
+                probabilities.truncate(self.config.top_k_proofs.max(1));
+
+                InferredRelation {
+                    subject,
+                    predicate,
+                    object,
+                    confidence: combine_noisy_or(&probabilities),
+                }
+            })
+            .collect();
+
+        relations.sort_by(|a, b| {
+            a.subject.cmp(&b.subject).then_with(|| a.object.cmp(&b.object))
+        });
+        relations
+    }
+}
+
+/// Character gap between two mentions - `0` if they overlap or are adjacent
+fn textual_distance(a: &WeightedEntity, b: &WeightedEntity) -> usize {
+    if a.end <= b.start {
+        b.start - a.end
+    } else if b.end <= a.start {
+        a.start - b.end
+    } else {
+        0
+    }
+}
+
+/// Combine independent proof probabilities with the noisy-or approximation
+/// `1 - Π(1 - p_i)`
+fn combine_noisy_or(probabilities: &[f32]) -> f32 {
+    1.0 - probabilities.iter().fold(1.0_f32, |acc, p| acc * (1.0 - p))
+}
+
+/// The `@graph` entries of `doc`, whether it's a single-entity document (as
+/// [`GlinerExtractor`](crate::gliner_extractor::GlinerExtractor) returns for
+/// one entity) or already wrapped in `@graph`
+fn nodes_from_document(doc: &RdfDocument) -> Vec<Value> {
+    if let Some(graph) = doc.data.get("@graph").and_then(Value::as_array) {
+        return graph.clone();
+    }
+
+    match doc.data.as_object() {
+        Some(obj) if !obj.is_empty() => vec![doc.data.clone()],
+        _ => Vec::new(),
+    }
+}
+
+/// Parse a [`WeightedEntity`] from one `@graph` node, reading the
+/// `_metadata.confidence`/`startOffset`/`endOffset` fields
+/// [`GlinerExtractor`](crate::gliner_extractor::GlinerExtractor) attaches to
+/// every entity it extracts
+fn weighted_entity_from_node(node: &Value) -> Option<WeightedEntity> {
+    let id = node.get("@id")?.as_str()?.to_string();
+    let entity_type = node.get("@type")?.as_str()?.to_string();
+    let metadata = node.get("_metadata")?;
+
+    Some(WeightedEntity {
+        id,
+        entity_type,
+        confidence: metadata.get("confidence")?.as_f64()? as f32,
+        start: metadata.get("startOffset")?.as_u64()? as usize,
+        end: metadata.get("endOffset")?.as_u64()? as usize,
+    })
+}
+
+/// Run `reasoner` over every entity in `doc`'s `@graph`, returning a new
+/// `RdfDocument` with the original entities plus one extra node per inferred
+/// relation: `{"@id", "@type": "Relation", "subject", "predicate", "object",
+/// "_confidence"}`, so downstream consumers get a calibrated relation score
+/// instead of a hard yes/no edge.
+///
+/// # Errors
+///
+/// Returns an error if the resulting document fails [`RdfDocument`]'s
+/// `@context` validation
+pub fn infer_relations(doc: &RdfDocument, reasoner: &ProbabilisticReasoner) -> Result<RdfDocument> {
+    let mut graph = nodes_from_document(doc);
+
+    let entities: Vec<WeightedEntity> =
+        graph.iter().filter_map(weighted_entity_from_node).collect();
+
+    for (idx, relation) in reasoner.infer(&entities).into_iter().enumerate() {
+        graph.push(json!({
+            "@id": format!("relation_{idx}"),
+            "@type": "Relation",
+            "subject": relation.subject,
+            "predicate": relation.predicate,
+            "object": relation.object,
+            "_confidence": relation.confidence,
+        }));
+    }
+
+    let mut data = if graph.len() == 1 {
+        graph.pop().unwrap_or_else(|| json!({}))
+    } else {
+        json!({ "@graph": graph })
+    };
+
+    if let Some(obj) = data.as_object_mut() {
+        obj.insert("@context".to_string(), doc.context.clone());
+    }
+
+    RdfDocument::from_value(data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entity(id: &str, entity_type: &str, confidence: f32, start: usize, end: usize) -> WeightedEntity {
+        WeightedEntity { id: id.to_string(), entity_type: entity_type.to_string(), confidence, start, end }
+    }
+
+    #[test]
+    fn test_infer_derives_relation_within_distance() {
+        let reasoner = ProbabilisticReasoner::new(ProbabilisticReasonerConfig {
+            rules: vec![ProximityRule::new("Person", "Organization", "worksFor", 50)],
+            ..Default::default()
+        });
+
+        let entities = vec![
+            entity("e1", "Person", 0.9, 0, 10),
+            entity("e2", "Organization", 0.8, 20, 30),
+        ];
+
+        let relations = reasoner.infer(&entities);
+        assert_eq!(relations.len(), 1);
+        assert_eq!(relations[0].subject, "e1");
+        assert_eq!(relations[0].predicate, "worksFor");
+        assert_eq!(relations[0].object, "e2");
+        assert!((relations[0].confidence - 0.9 * 0.8).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_infer_excludes_pairs_beyond_max_distance() {
+        let reasoner = ProbabilisticReasoner::new(ProbabilisticReasonerConfig {
+            rules: vec![ProximityRule::new("Person", "Organization", "worksFor", 5)],
+            ..Default::default()
+        });
+
+        let entities = vec![
+            entity("e1", "Person", 0.9, 0, 10),
+            entity("e2", "Organization", 0.8, 100, 110),
+        ];
+
+        assert!(reasoner.infer(&entities).is_empty());
+    }
+
+    #[test]
+    fn test_infer_combines_multiple_proofs_with_noisy_or() {
+        let reasoner = ProbabilisticReasoner::new(ProbabilisticReasonerConfig {
+            rules: vec![
+                ProximityRule::new("Person", "Organization", "worksFor", 100),
+                ProximityRule::new("Person", "Organization", "worksFor", 200),
+            ],
+            top_k_proofs: 5,
+        });
+
+        let entities = vec![
+            entity("e1", "Person", 0.9, 0, 10),
+            entity("e2", "Organization", 0.8, 20, 30),
+        ];
+
+        let relations = reasoner.infer(&entities);
+        assert_eq!(relations.len(), 1);
+
+        let expected = 1.0 - (1.0 - 0.9 * 0.8) * (1.0 - 0.9 * 0.8);
+        assert!((relations[0].confidence - expected).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_infer_caps_proofs_at_top_k() {
+        let reasoner = ProbabilisticReasoner::new(ProbabilisticReasonerConfig {
+            rules: vec![
+                ProximityRule::new("Person", "Organization", "worksFor", 1000),
+                ProximityRule::new("Person", "Organization", "worksFor", 2000),
+            ],
+            top_k_proofs: 1,
+        });
+
+        // Both rules derive worksFor(e1, e2), but only the single best proof
+        // is kept, so the confidence is one proof's 0.9 * 0.8 rather than the
+        // noisy-or of two.
+        let entities = vec![
+            entity("e1", "Person", 0.9, 0, 10),
+            entity("e2", "Organization", 0.8, 20, 30),
+        ];
+
+        let relations = reasoner.infer(&entities);
+        assert_eq!(relations.len(), 1);
+        assert!((relations[0].confidence - 0.9 * 0.8).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_textual_distance_overlapping_mentions_is_zero() {
+        let a = entity("a", "Person", 1.0, 0, 20);
+        let b = entity("b", "Organization", 1.0, 10, 30);
+        assert_eq!(textual_distance(&a, &b), 0);
+    }
+
+    #[test]
+    fn test_combine_noisy_or_single_proof_is_identity() {
+        assert!((combine_noisy_or(&[0.7]) - 0.7).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_infer_relations_emits_relation_nodes_into_graph() {
+        let doc = RdfDocument::from_value(json!({
+            "@context": "https://schema.org/",
+            "@graph": [
+                {
+                    "@id": "entity_0",
+                    "@type": "Person",
+                    "name": "Dan Shalev",
+                    "_metadata": { "startOffset": 0, "endOffset": 10, "confidence": 0.9 },
+                },
+                {
+                    "@id": "entity_20",
+                    "@type": "Organization",
+                    "name": "Acme Inc.",
+                    "_metadata": { "startOffset": 20, "endOffset": 29, "confidence": 0.8 },
+                },
+            ],
+        }))
+        .unwrap();
+
+        let reasoner = ProbabilisticReasoner::new(ProbabilisticReasonerConfig {
+            rules: vec![ProximityRule::new("Person", "Organization", "worksFor", 50)],
+            ..Default::default()
+        });
+
+        let result = infer_relations(&doc, &reasoner).unwrap();
+        let graph = result.data.get("@graph").and_then(Value::as_array).unwrap();
+
+        let relation_node = graph
+            .iter()
+            .find(|node| node.get("@type").and_then(Value::as_str) == Some("Relation"))
+            .expect("expected an inferred relation node in the graph");
+        assert_eq!(relation_node["subject"], "entity_0");
+        assert_eq!(relation_node["predicate"], "worksFor");
+        assert_eq!(relation_node["object"], "entity_20");
+        assert!(relation_node["_confidence"].as_f64().unwrap() > 0.0);
+    }
+}
@@ -0,0 +1,243 @@
+//! W3C PROV-O records for extraction runs
+//!
+//! [`Provenance`](crate::types::Provenance) already tracks per-document
+//! evidence (source text, confidence, property spans), but says nothing
+//! about the *run* that produced a document - which model was used, how
+//! many retries it took, or which chunk of the source text an entity came
+//! from. [`ProvenanceGraph`] fills that gap with the PROV-O vocabulary: one
+//! [`ProvActivity`] per extraction run and one [`ProvEntity`] per top-level
+//! node it generated, linked back to the run and to the source document's
+//! character range. `GenAiExtractor::merge_chunks` unions every chunk's
+//! graph into the merged document's instead of discarding it, so the final
+//! result carries an auditable trail of which chunk, model, and run
+//! produced every fact.
+
+use serde_json::{json, Value};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// The PROV-O namespace, merged into a document's `@context` alongside its
+/// Schema.org context by
+/// [`RdfDocument::to_jsonld_with_prov`](crate::types::RdfDocument::to_jsonld_with_prov)
+pub const PROV_CONTEXT: &str = "http://www.w3.org/ns/prov#";
+
+/// RFC 3339 UTC timestamp for the current time, for [`ProvActivity::started_at`]/`ended_at`
+///
+/// Computed from the system clock via the civil calendar conversion in
+/// [`civil_from_days`] rather than pulling in a date/time crate just for
+/// this - the same no-new-dependency tradeoff as
+/// [`VerifiableCredential::canonical_digest`](crate::credential::VerifiableCredential::canonical_digest)'s
+/// `"sha256:"` prefix.
+#[must_use]
+pub(crate) fn now_rfc3339() -> String {
+    let secs = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |duration| duration.as_secs());
+    let (year, month, day) = civil_from_days((secs / 86_400) as i64);
+    let time_of_day = secs % 86_400;
+    let (hour, minute, second) = (time_of_day / 3_600, (time_of_day / 60) % 60, time_of_day % 60);
+    format!("{year:04}-{month:02}-{day:02}T{hour:02}:{minute:02}:{second:02}Z")
+}
+
+/// Days since the Unix epoch -> (year, month, day), per Howard Hinnant's
+/// `civil_from_days` algorithm (proleptic Gregorian calendar)
+fn civil_from_days(days_since_epoch: i64) -> (i64, u32, u32) {
+    let z = days_since_epoch + 719_468;
+    let era = if z >= 0 { z } else { z - 146_096 } / 146_097;
+    let doe = (z - era * 146_097) as u64;
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let y = yoe as i64 + era * 400;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = if month <= 2 { y + 1 } else { y };
+    (year, month, day)
+}
+
+/// One extraction run, as a PROV-O `prov:Activity`
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProvActivity {
+    pub id: String,
+    /// RFC 3339 timestamp the run started at
+    pub started_at: String,
+    /// RFC 3339 timestamp the run ended at
+    pub ended_at: String,
+    /// The model name, recorded as `prov:used`
+    pub used: String,
+    /// How many retries the run took before succeeding
+    pub retry_count: u32,
+}
+
+impl ProvActivity {
+    #[must_use]
+    pub fn new(
+        id: impl Into<String>,
+        started_at: impl Into<String>,
+        ended_at: impl Into<String>,
+        used: impl Into<String>,
+        retry_count: u32,
+    ) -> Self {
+        Self {
+            id: id.into(),
+            started_at: started_at.into(),
+            ended_at: ended_at.into(),
+            used: used.into(),
+            retry_count,
+        }
+    }
+
+    fn to_jsonld(&self) -> Value {
+        json!({
+            "@id": self.id,
+            "@type": "prov:Activity",
+            "prov:startedAtTime": self.started_at,
+            "prov:endedAtTime": self.ended_at,
+            "prov:used": self.used,
+            "retryCount": self.retry_count,
+        })
+    }
+}
+
+/// One top-level extracted node's derivation, as a PROV-O `prov:Entity`
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProvEntity {
+    /// The node's `@id` in the data graph (minted or explicit)
+    pub id: String,
+    /// The [`ProvActivity::id`] of the run that generated this node
+    pub was_generated_by: String,
+    /// An identifier for the source document this node was derived from
+    pub was_derived_from: String,
+    /// Character offset in the source document where the generating chunk started
+    pub start_offset: usize,
+    /// Character offset in the source document where the generating chunk ended
+    pub end_offset: usize,
+}
+
+impl ProvEntity {
+    #[must_use]
+    pub fn new(
+        id: impl Into<String>,
+        was_generated_by: impl Into<String>,
+        was_derived_from: impl Into<String>,
+        start_offset: usize,
+        end_offset: usize,
+    ) -> Self {
+        Self {
+            id: id.into(),
+            was_generated_by: was_generated_by.into(),
+            was_derived_from: was_derived_from.into(),
+            start_offset,
+            end_offset,
+        }
+    }
+
+    fn to_jsonld(&self) -> Value {
+        json!({
+            "@id": self.id,
+            "@type": "prov:Entity",
+            "prov:wasGeneratedBy": self.was_generated_by,
+            "prov:wasDerivedFrom": self.was_derived_from,
+            "characterRange": { "start": self.start_offset, "end": self.end_offset },
+        })
+    }
+}
+
+/// Accumulated PROV-O record for one or more extraction runs
+///
+/// `GenAiExtractor::merge_chunks` unions every chunk's graph into the merged
+/// document's via [`Self::merge`] instead of discarding it, so the result
+/// carries every chunk's activity and entity records rather than just the
+/// last one.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ProvenanceGraph {
+    activities: Vec<ProvActivity>,
+    entities: Vec<ProvEntity>,
+}
+
+impl ProvenanceGraph {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.activities.is_empty() && self.entities.is_empty()
+    }
+
+    /// Record one run's activity
+    pub fn record_activity(&mut self, activity: ProvActivity) {
+        self.activities.push(activity);
+    }
+
+    /// Record one generated node's derivation
+    pub fn record_entity(&mut self, entity: ProvEntity) {
+        self.entities.push(entity);
+    }
+
+    /// Union `other`'s activities and entities into `self`
+    pub fn merge(&mut self, other: ProvenanceGraph) {
+        self.activities.extend(other.activities);
+        self.entities.extend(other.entities);
+    }
+
+    #[must_use]
+    pub fn activities(&self) -> &[ProvActivity] {
+        &self.activities
+    }
+
+    #[must_use]
+    pub fn entities(&self) -> &[ProvEntity] {
+        &self.entities
+    }
+
+    /// The accumulated activities and entities as PROV-O JSON-LD nodes,
+    /// suitable for appending to a document's `@graph`
+    #[must_use]
+    pub fn to_jsonld_nodes(&self) -> Vec<Value> {
+        self.activities
+            .iter()
+            .map(ProvActivity::to_jsonld)
+            .chain(self.entities.iter().map(ProvEntity::to_jsonld))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_provenance_graph_merge_unions_activities_and_entities() {
+        let mut a = ProvenanceGraph::new();
+        a.record_activity(ProvActivity::new("urn:run:1", "2026-07-31T00:00:00Z", "2026-07-31T00:00:01Z", "claude-3-5-sonnet", 0));
+        a.record_entity(ProvEntity::new("urn:sha256:aaa", "urn:run:1", "urn:sha256:source", 0, 10));
+
+        let mut b = ProvenanceGraph::new();
+        b.record_activity(ProvActivity::new("urn:run:2", "2026-07-31T00:00:02Z", "2026-07-31T00:00:03Z", "claude-3-5-sonnet", 1));
+        b.record_entity(ProvEntity::new("urn:sha256:bbb", "urn:run:2", "urn:sha256:source", 10, 20));
+
+        a.merge(b);
+
+        assert_eq!(a.activities().len(), 2);
+        assert_eq!(a.entities().len(), 2);
+    }
+
+    #[test]
+    fn test_to_jsonld_nodes_renders_activity_and_entity_shapes() {
+        let mut graph = ProvenanceGraph::new();
+        graph.record_activity(ProvActivity::new("urn:run:1", "2026-07-31T00:00:00Z", "2026-07-31T00:00:01Z", "claude-3-5-sonnet", 2));
+        graph.record_entity(ProvEntity::new("urn:sha256:aaa", "urn:run:1", "urn:sha256:source", 0, 10));
+
+        let nodes = graph.to_jsonld_nodes();
+        assert_eq!(nodes.len(), 2);
+        assert_eq!(nodes[0]["@type"], "prov:Activity");
+        assert_eq!(nodes[0]["retryCount"], 2);
+        assert_eq!(nodes[1]["@type"], "prov:Entity");
+        assert_eq!(nodes[1]["characterRange"]["end"], 10);
+    }
+
+    #[test]
+    fn test_empty_graph_has_no_nodes() {
+        assert!(ProvenanceGraph::new().is_empty());
+        assert!(ProvenanceGraph::new().to_jsonld_nodes().is_empty());
+    }
+}
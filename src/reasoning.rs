@@ -0,0 +1,517 @@
+//! Datalog-style forward-chaining reasoner for deriving entailed triples
+//!
+//! Extracted triple sets are often incomplete relative to what a human would
+//! consider "stated": if the text says "Apollo 12 launched Alan Bean" we may
+//! only extract `(Apollo 12, hasCrew, Alan Bean)` even though the inverse
+//! `(Alan Bean, missionOf, Apollo 12)` is equally true. This module applies a
+//! small set of ontology-driven inference rules - `owl:inverseOf`,
+//! `rdfs:subPropertyOf`, and predicate transitivity - to materialize such
+//! entailed triples, running to a fixpoint so evaluation harnesses can score
+//! against gold facts the extractor only stated indirectly.
+
+use crate::error::{Error, Result};
+use serde::Deserialize;
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+
+/// A subject-predicate-object fact
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Triple {
+    pub subject: String,
+    pub predicate: String,
+    pub object: String,
+}
+
+impl Triple {
+    #[must_use]
+    pub fn new(subject: impl Into<String>, predicate: impl Into<String>, object: impl Into<String>) -> Self {
+        Self {
+            subject: subject.into(),
+            predicate: predicate.into(),
+            object: object.into(),
+        }
+    }
+}
+
+/// Ontology rules driving the forward-chaining reasoner, typically loaded
+/// from a small JSON file checked in alongside a dataset's gold ontology
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct OntologyRules {
+    /// `owl:inverseOf`: predicate -> its inverse predicate
+    #[serde(default)]
+    pub inverse_of: HashMap<String, String>,
+
+    /// `rdfs:subPropertyOf`: specific predicate -> the general predicate it implies
+    #[serde(default)]
+    pub sub_property_of: HashMap<String, String>,
+
+    /// Predicates that are transitive: `(a,P,b) & (b,P,c) => (a,P,c)`
+    #[serde(default)]
+    pub transitive: HashSet<String>,
+}
+
+impl OntologyRules {
+    /// Load ontology rules from a JSON file
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or does not contain valid
+    /// rule JSON
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self> {
+        let contents = std::fs::read_to_string(path.as_ref())
+            .map_err(|e| Error::Config(format!("Failed to read ontology file: {e}")))?;
+        serde_json::from_str(&contents).map_err(Error::JsonParse)
+    }
+}
+
+/// A term in a [`Rule`] atom: either a variable bound by joining across the
+/// rule body, or a constant matched literally
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Term {
+    /// A literal value, e.g. `"schema.org"`
+    Const(String),
+    /// A variable, e.g. `?x`, bound by unifying against a triple slot and
+    /// substituted wherever else it appears in the rule
+    Var(String),
+}
+
+impl From<&str> for Term {
+    /// Parses `?name` as a variable and anything else as a constant
+    fn from(s: &str) -> Self {
+        s.strip_prefix('?')
+            .map_or_else(|| Self::Const(s.to_string()), |name| Self::Var(name.to_string()))
+    }
+}
+
+/// A subject-predicate-object atom in a [`Rule`]'s head or body, whose slots
+/// may be [`Term::Var`] placeholders instead of literal values
+#[derive(Debug, Clone)]
+pub struct Atom {
+    pub subject: Term,
+    pub predicate: Term,
+    pub object: Term,
+}
+
+impl Atom {
+    #[must_use]
+    pub fn new(subject: impl Into<Term>, predicate: impl Into<Term>, object: impl Into<Term>) -> Self {
+        Self {
+            subject: subject.into(),
+            predicate: predicate.into(),
+            object: object.into(),
+        }
+    }
+}
+
+/// A Horn-clause inference rule: `head :- positive_body, not negative_body`
+///
+/// For example, transitivity of `containedInPlace` is
+/// `containedInPlace(?x, ?z) :- containedInPlace(?x, ?y), containedInPlace(?y, ?z)`:
+/// `Rule::new(Atom::new("?x", "containedInPlace", "?z"), vec![Atom::new("?x", "containedInPlace", "?y"), Atom::new("?y", "containedInPlace", "?z")])`.
+#[derive(Debug, Clone)]
+pub struct Rule {
+    pub head: Atom,
+    pub positive_body: Vec<Atom>,
+    pub negative_body: Vec<Atom>,
+}
+
+impl Rule {
+    /// Create a rule with no negated conditions
+    #[must_use]
+    pub fn new(head: Atom, positive_body: Vec<Atom>) -> Self {
+        Self {
+            head,
+            positive_body,
+            negative_body: Vec::new(),
+        }
+    }
+
+    /// Require that `atom` has no match in the current fact set (stratified
+    /// negation: only evaluated once the positive-only rules have reached a
+    /// fixpoint)
+    #[must_use]
+    pub fn with_negated(mut self, atom: Atom) -> Self {
+        self.negative_body.push(atom);
+        self
+    }
+}
+
+/// Variable bindings accumulated while joining a rule's body atoms
+type Bindings = HashMap<String, String>;
+
+fn unify(atom: &Atom, triple: &Triple, bindings: &Bindings) -> Option<Bindings> {
+    let mut bindings = bindings.clone();
+    for (term, value) in [
+        (&atom.subject, &triple.subject),
+        (&atom.predicate, &triple.predicate),
+        (&atom.object, &triple.object),
+    ] {
+        match term {
+            Term::Const(constant) => {
+                if constant != value {
+                    return None;
+                }
+            }
+            Term::Var(name) => match bindings.get(name) {
+                Some(bound) if bound != value => return None,
+                Some(_) => {}
+                None => {
+                    bindings.insert(name.clone(), value.clone());
+                }
+            },
+        }
+    }
+    Some(bindings)
+}
+
+fn instantiate(atom: &Atom, bindings: &Bindings) -> Option<Triple> {
+    let resolve = |term: &Term| match term {
+        Term::Const(constant) => Some(constant.clone()),
+        Term::Var(name) => bindings.get(name).cloned(),
+    };
+    Some(Triple::new(
+        resolve(&atom.subject)?,
+        resolve(&atom.predicate)?,
+        resolve(&atom.object)?,
+    ))
+}
+
+/// Join a rule body against `facts`, returning every set of variable
+/// bindings that satisfies every atom in order
+///
+/// Each atom is matched against every candidate fact (restricted to facts
+/// sharing its predicate when the predicate is a constant, which is the
+/// common case) and only bindings consistent with the atoms already joined
+/// survive - a standard nested-loop join.
+fn join_body(body: &[Atom], facts: &HashSet<Triple>, by_predicate: &HashMap<&str, Vec<&Triple>>) -> Vec<Bindings> {
+    let mut bindings_set = vec![Bindings::new()];
+
+    for atom in body {
+        let candidates: Vec<&Triple> = match &atom.predicate {
+            Term::Const(predicate) => by_predicate.get(predicate.as_str()).cloned().unwrap_or_default(),
+            Term::Var(_) => facts.iter().collect(),
+        };
+
+        bindings_set = bindings_set
+            .iter()
+            .flat_map(|bindings| {
+                candidates
+                    .iter()
+                    .filter_map(|triple| unify(atom, triple, bindings))
+            })
+            .collect();
+
+        if bindings_set.is_empty() {
+            break;
+        }
+    }
+
+    bindings_set
+}
+
+fn index_by_predicate(facts: &HashSet<Triple>) -> HashMap<&str, Vec<&Triple>> {
+    let mut index: HashMap<&str, Vec<&Triple>> = HashMap::new();
+    for fact in facts {
+        index.entry(fact.predicate.as_str()).or_default().push(fact);
+    }
+    index
+}
+
+/// Whether `atom` has any matching fact consistent with the (possibly
+/// partial) `bindings` already established by the rest of the rule body -
+/// the existence check stratified negation needs for `not atom(...)`
+fn atom_satisfied(atom: &Atom, facts: &HashSet<Triple>, by_predicate: &HashMap<&str, Vec<&Triple>>, bindings: &Bindings) -> bool {
+    let candidates: Vec<&Triple> = match &atom.predicate {
+        Term::Const(predicate) => by_predicate.get(predicate.as_str()).cloned().unwrap_or_default(),
+        Term::Var(_) => facts.iter().collect(),
+    };
+    candidates.iter().any(|triple| unify(atom, triple, bindings).is_some())
+}
+
+/// Forward-chain only positive (negation-free) rules to a fixpoint - the
+/// first stratum of stratified evaluation
+fn saturate_positive(mut facts: HashSet<Triple>, rules: &[&Rule]) -> HashSet<Triple> {
+    loop {
+        let index = index_by_predicate(&facts);
+        let mut derived = Vec::new();
+
+        for rule in rules {
+            for bindings in join_body(&rule.positive_body, &facts, &index) {
+                if let Some(triple) = instantiate(&rule.head, &bindings) {
+                    derived.push(triple);
+                }
+            }
+        }
+
+        let mut changed = false;
+        for triple in derived {
+            changed |= facts.insert(triple);
+        }
+
+        if !changed {
+            return facts;
+        }
+    }
+}
+
+/// Forward-chaining reasoner that materializes entailed triples to a fixpoint
+#[derive(Debug, Clone)]
+pub struct Reasoner {
+    rules: OntologyRules,
+}
+
+impl Reasoner {
+    #[must_use]
+    pub const fn new(rules: OntologyRules) -> Self {
+        Self { rules }
+    }
+
+    /// Derive every triple entailed by `triples` under the configured rules
+    ///
+    /// Runs `owl:inverseOf`, `rdfs:subPropertyOf`, and transitivity to a
+    /// fixpoint using a seen-set, which guarantees termination since each
+    /// rule only ever adds triples drawn from a bounded universe (existing
+    /// subjects/objects paired with existing or mapped predicates).
+    #[must_use]
+    pub fn saturate(&self, triples: &[Triple]) -> Vec<Triple> {
+        let mut seen: HashSet<Triple> = triples.iter().cloned().collect();
+        let mut frontier: Vec<Triple> = triples.to_vec();
+
+        while !frontier.is_empty() {
+            let mut next_frontier = Vec::new();
+
+            for triple in &frontier {
+                for derived in self.apply_rules(triple, &seen) {
+                    if seen.insert(derived.clone()) {
+                        next_frontier.push(derived);
+                    }
+                }
+            }
+
+            frontier = next_frontier;
+        }
+
+        seen.into_iter().collect()
+    }
+
+    /// Derive every triple entailed by `triples` under a general set of Horn-clause
+    /// [`Rule`]s with variable bindings, rather than the fixed ontology shapes
+    /// [`Self::saturate`] supports
+    ///
+    /// Evaluates in two strata: first every rule with no negated atoms runs
+    /// to a fixpoint (naive evaluation - rejoin every rule against the whole
+    /// fact set each round). Rules with a negated atom (stratified negation)
+    /// are then applied on top, each negated atom checked against that
+    /// fixpoint, with the positive stratum re-run afterward so any newly
+    /// derived facts can chain through transitivity/join rules too. This
+    /// repeats until the whole rule set reaches a fixpoint, which terminates
+    /// because every derived triple is built only from subjects/predicates/objects
+    /// already present in `triples` or in the rules' constants.
+    #[must_use]
+    pub fn saturate_rules(triples: HashSet<Triple>, rules: &[Rule]) -> HashSet<Triple> {
+        let (positive_rules, negation_rules): (Vec<&Rule>, Vec<&Rule>) =
+            rules.iter().partition(|rule| rule.negative_body.is_empty());
+
+        let mut facts = saturate_positive(triples, &positive_rules);
+
+        loop {
+            let mut derived = Vec::new();
+
+            let index = index_by_predicate(&facts);
+            for rule in &negation_rules {
+                for bindings in join_body(&rule.positive_body, &facts, &index) {
+                    let blocked = rule
+                        .negative_body
+                        .iter()
+                        .any(|atom| atom_satisfied(atom, &facts, &index, &bindings));
+
+                    if !blocked {
+                        if let Some(triple) = instantiate(&rule.head, &bindings) {
+                            derived.push(triple);
+                        }
+                    }
+                }
+            }
+
+            let mut changed = false;
+            for triple in derived {
+                changed |= facts.insert(triple);
+            }
+
+            if !changed {
+                return facts;
+            }
+
+            facts = saturate_positive(facts, &positive_rules);
+        }
+    }
+
+    /// Apply each rule once to `triple`, given the full set seen so far (needed
+    /// to find the partner fact for transitivity)
+    fn apply_rules(&self, triple: &Triple, seen: &HashSet<Triple>) -> Vec<Triple> {
+        let mut derived = Vec::new();
+
+        if let Some(inverse_predicate) = self.rules.inverse_of.get(&triple.predicate) {
+            derived.push(Triple::new(
+                triple.object.clone(),
+                inverse_predicate.clone(),
+                triple.subject.clone(),
+            ));
+        }
+
+        if let Some(general_predicate) = self.rules.sub_property_of.get(&triple.predicate) {
+            derived.push(Triple::new(
+                triple.subject.clone(),
+                general_predicate.clone(),
+                triple.object.clone(),
+            ));
+        }
+
+        if self.rules.transitive.contains(&triple.predicate) {
+            for other in seen {
+                if other.predicate == triple.predicate && other.subject == triple.object {
+                    derived.push(Triple::new(
+                        triple.subject.clone(),
+                        triple.predicate.clone(),
+                        other.object.clone(),
+                    ));
+                }
+            }
+        }
+
+        derived
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rules() -> OntologyRules {
+        OntologyRules {
+            inverse_of: HashMap::from([("hasCrew".to_string(), "missionOf".to_string())]),
+            sub_property_of: HashMap::from([("bornIn".to_string(), "birthPlace".to_string())]),
+            transitive: HashSet::from(["containedInPlace".to_string()]),
+        }
+    }
+
+    #[test]
+    fn test_inverse_of() {
+        let reasoner = Reasoner::new(rules());
+        let triples = vec![Triple::new("Apollo 12", "hasCrew", "Alan Bean")];
+
+        let closure = reasoner.saturate(&triples);
+
+        assert!(closure.contains(&Triple::new("Alan Bean", "missionOf", "Apollo 12")));
+    }
+
+    #[test]
+    fn test_sub_property_of() {
+        let reasoner = Reasoner::new(rules());
+        let triples = vec![Triple::new("Alan Bean", "bornIn", "Wheeler")];
+
+        let closure = reasoner.saturate(&triples);
+
+        assert!(closure.contains(&Triple::new("Alan Bean", "birthPlace", "Wheeler")));
+    }
+
+    #[test]
+    fn test_transitive_closure() {
+        let reasoner = Reasoner::new(rules());
+        let triples = vec![
+            Triple::new("Wheeler", "containedInPlace", "Texas"),
+            Triple::new("Texas", "containedInPlace", "USA"),
+        ];
+
+        let closure = reasoner.saturate(&triples);
+
+        assert!(closure.contains(&Triple::new("Wheeler", "containedInPlace", "USA")));
+    }
+
+    #[test]
+    fn test_saturate_terminates_with_no_rules() {
+        let reasoner = Reasoner::new(OntologyRules::default());
+        let triples = vec![Triple::new("A", "p", "B")];
+
+        let closure = reasoner.saturate(&triples);
+
+        assert_eq!(closure.len(), 1);
+    }
+
+    #[test]
+    fn test_saturate_rules_transitivity() {
+        // containedInPlace(?x, ?z) :- containedInPlace(?x, ?y), containedInPlace(?y, ?z)
+        let rule = Rule::new(
+            Atom::new("?x", "containedInPlace", "?z"),
+            vec![
+                Atom::new("?x", "containedInPlace", "?y"),
+                Atom::new("?y", "containedInPlace", "?z"),
+            ],
+        );
+        let triples = HashSet::from([
+            Triple::new("Wheeler", "containedInPlace", "Texas"),
+            Triple::new("Texas", "containedInPlace", "USA"),
+        ]);
+
+        let closure = Reasoner::saturate_rules(triples, std::slice::from_ref(&rule));
+
+        assert!(closure.contains(&Triple::new("Wheeler", "containedInPlace", "USA")));
+    }
+
+    #[test]
+    fn test_saturate_rules_location_chaining() {
+        // addressCountry(?x, ?c) :- location(?x, ?y), addressCountry(?y, ?c)
+        let rule = Rule::new(
+            Atom::new("?x", "addressCountry", "?c"),
+            vec![
+                Atom::new("?x", "location", "?y"),
+                Atom::new("?y", "addressCountry", "?c"),
+            ],
+        );
+        let triples = HashSet::from([
+            Triple::new("Apollo 12 Launch", "location", "Kennedy Space Center"),
+            Triple::new("Kennedy Space Center", "addressCountry", "USA"),
+        ]);
+
+        let closure = Reasoner::saturate_rules(triples, std::slice::from_ref(&rule));
+
+        assert!(closure.contains(&Triple::new("Apollo 12 Launch", "addressCountry", "USA")));
+    }
+
+    #[test]
+    fn test_saturate_rules_stratified_negation() {
+        // worksFor(?x, ?y) :- memberOf(?x, ?y), not formerMemberOf(?x, ?y)
+        let rule = Rule::new(
+            Atom::new("?x", "worksFor", "?y"),
+            vec![Atom::new("?x", "memberOf", "?y")],
+        )
+        .with_negated(Atom::new("?x", "formerMemberOf", "?y"));
+
+        let current = HashSet::from([Triple::new("Jane", "memberOf", "Acme")]);
+        let closure = Reasoner::saturate_rules(current, std::slice::from_ref(&rule));
+        assert!(closure.contains(&Triple::new("Jane", "worksFor", "Acme")));
+
+        let former = HashSet::from([
+            Triple::new("Jane", "memberOf", "Acme"),
+            Triple::new("Jane", "formerMemberOf", "Acme"),
+        ]);
+        let closure = Reasoner::saturate_rules(former, std::slice::from_ref(&rule));
+        assert!(!closure.contains(&Triple::new("Jane", "worksFor", "Acme")));
+    }
+
+    #[test]
+    fn test_saturate_rules_no_match_is_noop() {
+        let rule = Rule::new(
+            Atom::new("?x", "containedInPlace", "?z"),
+            vec![
+                Atom::new("?x", "containedInPlace", "?y"),
+                Atom::new("?y", "containedInPlace", "?z"),
+            ],
+        );
+        let triples = HashSet::from([Triple::new("Wheeler", "containedInPlace", "Texas")]);
+
+        let closure = Reasoner::saturate_rules(triples.clone(), std::slice::from_ref(&rule));
+
+        assert_eq!(closure, triples);
+    }
+}
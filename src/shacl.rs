@@ -0,0 +1,283 @@
+//! Compile SHACL shape graphs into `ValidationRule`s
+//!
+//! `validation.rs` advertises "SHACL-like validation" and "Cardinality
+//! Constraints" but only ever hardcoded four `required_properties` rules and
+//! never enforced min/max counts. [`ShaclLoader`] closes that gap for real:
+//! it parses a SHACL shapes graph (Turtle, JSON-LD, or any format
+//! [`oxigraph::io::RdfFormat`] supports) into the attached Oxigraph
+//! [`Store`], then walks `sh:NodeShape -> sh:property -> sh:PropertyShape`
+//! via SPARQL to compile one [`ValidationRule`] per shape: `sh:targetClass`
+//! becomes `entity_type`, `sh:minCount`/`sh:maxCount` become
+//! [`CardinalityConstraint`]s, `sh:pattern` becomes a `Constraint::Regex`,
+//! and `sh:datatype` feeds a [`DatatypeValidator`] mapping. This lets users
+//! drive validation from standard `.ttl` shape files instead of editing Rust.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use oxigraph::io::RdfFormat;
+use oxigraph::model::Term;
+use oxigraph::sparql::QueryResults;
+use oxigraph::store::Store;
+
+use crate::datatype::{DatatypeValidator, XsdDatatype};
+use crate::error::{Error, Result};
+use crate::validation::{CardinalityConstraint, Constraint, ValidationRule, ValueConstraint};
+
+const SHACL_PREFIX: &str = "PREFIX sh: <http://www.w3.org/ns/shacl#>";
+
+/// The compiled result of a SHACL shapes graph: one [`ValidationRule`] per
+/// `sh:NodeShape`, plus any `sh:datatype` constraints folded into a
+/// [`DatatypeValidator`]
+#[derive(Debug, Default)]
+pub struct CompiledShapes {
+    pub rules: Vec<ValidationRule>,
+    pub datatypes: DatatypeValidator,
+}
+
+/// Loads SHACL shape graphs into an Oxigraph [`Store`] and compiles them
+/// into [`ValidationRule`]s
+pub struct ShaclLoader {
+    store: Arc<Store>,
+}
+
+impl ShaclLoader {
+    #[must_use]
+    pub fn new(store: Arc<Store>) -> Self {
+        Self { store }
+    }
+
+    /// Parse `shapes` (in `format`, e.g. Turtle or JSON-LD) into the attached store
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `shapes` isn't valid `format`
+    #[allow(deprecated)]
+    pub fn load(&self, shapes: &str, format: RdfFormat) -> Result<()> {
+        self.store
+            .load_from_reader(format, shapes.as_bytes())
+            .map_err(|e| Error::Extraction(format!("Failed to load SHACL shapes: {e}")))
+    }
+
+    /// Walk every `sh:NodeShape -> sh:property -> sh:PropertyShape` loaded so
+    /// far and compile it into a [`ValidationRule`] (and, where `sh:datatype`
+    /// is present, a [`DatatypeValidator`] mapping)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the compiled SPARQL query fails against the
+    /// store, or if a `sh:pattern` isn't a valid regular expression
+    #[allow(deprecated)]
+    pub fn compile(&self) -> Result<CompiledShapes> {
+        let query = format!(
+            "{SHACL_PREFIX}
+             SELECT ?shape ?targetClass ?path ?minCount ?maxCount ?datatype ?pattern WHERE {{
+                 ?shape a sh:NodeShape .
+                 OPTIONAL {{ ?shape sh:targetClass ?targetClass }}
+                 ?shape sh:property ?property .
+                 ?property sh:path ?path .
+                 OPTIONAL {{ ?property sh:minCount ?minCount }}
+                 OPTIONAL {{ ?property sh:maxCount ?maxCount }}
+                 OPTIONAL {{ ?property sh:datatype ?datatype }}
+                 OPTIONAL {{ ?property sh:pattern ?pattern }}
+             }}"
+        );
+
+        let results = self.store.query(&query).map_err(|e| Error::Extraction(format!("SHACL SPARQL query failed: {e}")))?;
+        let QueryResults::Solutions(solutions) = results else {
+            return Err(Error::Extraction("SHACL shape query did not return solutions".to_string()));
+        };
+
+        let mut shapes: HashMap<String, ShapeAccumulator> = HashMap::new();
+        let mut datatypes = DatatypeValidator::new();
+
+        for solution in solutions {
+            let solution = solution.map_err(|e| Error::Extraction(format!("SHACL query solution error: {e}")))?;
+
+            let Some(Term::NamedNode(shape)) = solution.get("shape") else { continue };
+            let Some(path) = solution.get("path").and_then(term_local_name) else { continue };
+
+            let accumulator = shapes.entry(shape.as_str().to_string()).or_insert_with(|| ShapeAccumulator::new(shape.as_str()));
+
+            if let Some(target_class) = solution.get("targetClass").and_then(term_local_name) {
+                accumulator.entity_type = Some(target_class);
+            }
+
+            let min_count = solution.get("minCount").and_then(term_literal_value).and_then(|v| v.parse().ok());
+            let max_count = solution.get("maxCount").and_then(term_literal_value).and_then(|v| v.parse().ok());
+            if min_count.is_some() || max_count.is_some() {
+                accumulator.cardinality.push(CardinalityConstraint { property: path.clone(), min_count, max_count });
+            }
+
+            if let Some(pattern) = solution.get("pattern").and_then(term_literal_value) {
+                let constraint = Constraint::regex(path.clone(), &pattern)
+                    .map_err(|e| Error::Extraction(format!("Invalid sh:pattern for '{path}': {e}")))?;
+                accumulator.constraints.push(ValueConstraint { constraint, confidence_impact: -0.1 });
+            }
+
+            if let Some(datatype_iri) = solution.get("datatype").and_then(term_local_name) {
+                if let Some(xsd_type) = xsd_datatype_from_local_name(&datatype_iri) {
+                    datatypes = match &accumulator.entity_type {
+                        Some(entity_type) => datatypes.with_mapping(entity_type.clone(), path, xsd_type),
+                        None => datatypes.with_default_mapping(path, xsd_type),
+                    };
+                }
+            }
+        }
+
+        let rules = shapes.into_values().map(ShapeAccumulator::into_rule).collect();
+        Ok(CompiledShapes { rules, datatypes })
+    }
+}
+
+/// Accumulates every `sh:property` found for one `sh:NodeShape` before
+/// being turned into a single [`ValidationRule`]
+struct ShapeAccumulator {
+    name: String,
+    entity_type: Option<String>,
+    cardinality: Vec<CardinalityConstraint>,
+    constraints: Vec<ValueConstraint>,
+}
+
+impl ShapeAccumulator {
+    fn new(shape_iri: &str) -> Self {
+        Self {
+            name: local_name(shape_iri).to_string(),
+            entity_type: None,
+            cardinality: Vec::new(),
+            constraints: Vec::new(),
+        }
+    }
+
+    fn into_rule(self) -> ValidationRule {
+        ValidationRule {
+            name: self.name.clone(),
+            description: format!("Compiled from SHACL shape '{}'", self.name),
+            required_properties: Vec::new(),
+            entity_type: self.entity_type,
+            sparql_ask: None,
+            constraints: self.constraints,
+            cardinality: self.cardinality,
+        }
+    }
+}
+
+/// The fragment or final path segment of an IRI (`https://schema.org/Person` -> `Person`)
+fn local_name(iri: &str) -> &str {
+    iri.rsplit(['#', '/']).next().filter(|s| !s.is_empty()).unwrap_or(iri)
+}
+
+fn term_local_name(term: &Term) -> Option<String> {
+    match term {
+        Term::NamedNode(node) => Some(local_name(node.as_str()).to_string()),
+        Term::Literal(literal) => Some(literal.value().to_string()),
+        Term::BlankNode(_) => None,
+    }
+}
+
+fn term_literal_value(term: &Term) -> Option<String> {
+    match term {
+        Term::Literal(literal) => Some(literal.value().to_string()),
+        _ => None,
+    }
+}
+
+/// Map a `xsd:` datatype's local name to an [`XsdDatatype`], or `None` for
+/// datatypes this validator doesn't model
+fn xsd_datatype_from_local_name(local_name: &str) -> Option<XsdDatatype> {
+    match local_name {
+        "date" => Some(XsdDatatype::Date),
+        "dateTime" => Some(XsdDatatype::DateTime),
+        "gYear" => Some(XsdDatatype::GYear),
+        "duration" => Some(XsdDatatype::Duration),
+        "decimal" => Some(XsdDatatype::Decimal { min: f64::MIN, max: f64::MAX }),
+        "integer" => Some(XsdDatatype::Integer { min: i64::MIN, max: i64::MAX }),
+        "anyURI" => Some(XsdDatatype::AnyUri),
+        "boolean" => Some(XsdDatatype::Boolean),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    use crate::types::RdfDocument;
+
+    const PERSON_SHAPE: &str = r#"
+        @prefix sh: <http://www.w3.org/ns/shacl#> .
+        @prefix schema: <https://schema.org/> .
+        @prefix xsd: <http://www.w3.org/2001/XMLSchema#> .
+
+        schema:PersonShape a sh:NodeShape ;
+            sh:targetClass schema:Person ;
+            sh:property [
+                sh:path schema:name ;
+                sh:minCount 1 ;
+                sh:maxCount 1 ;
+            ] ;
+            sh:property [
+                sh:path schema:birthDate ;
+                sh:datatype xsd:date ;
+            ] ;
+            sh:property [
+                sh:path schema:email ;
+                sh:pattern "^[^@]+@[^@]+$" ;
+            ] .
+    "#;
+
+    #[test]
+    fn test_compile_produces_one_rule_per_shape() {
+        let loader = ShaclLoader::new(Arc::new(Store::new().unwrap()));
+        loader.load(PERSON_SHAPE, RdfFormat::Turtle).unwrap();
+        let compiled = loader.compile().unwrap();
+
+        assert_eq!(compiled.rules.len(), 1);
+        let rule = &compiled.rules[0];
+        assert_eq!(rule.entity_type.as_deref(), Some("Person"));
+        assert_eq!(rule.cardinality.len(), 1);
+        assert_eq!(rule.cardinality[0].property, "name");
+        assert_eq!(rule.cardinality[0].min_count, Some(1));
+        assert_eq!(rule.cardinality[0].max_count, Some(1));
+        assert_eq!(rule.constraints.len(), 1);
+    }
+
+    #[test]
+    fn test_compiled_cardinality_rule_flags_missing_name() {
+        let loader = ShaclLoader::new(Arc::new(Store::new().unwrap()));
+        loader.load(PERSON_SHAPE, RdfFormat::Turtle).unwrap();
+        let compiled = loader.compile().unwrap();
+
+        let mut validator = crate::validation::RdfValidator::new().with_datatypes(compiled.datatypes);
+        for rule in compiled.rules {
+            validator.add_rule(rule);
+        }
+
+        let doc = RdfDocument::from_value(json!({
+            "@context": "https://schema.org/",
+            "@type": "Person"
+        }))
+        .unwrap();
+
+        let result = validator.validate(&doc);
+        assert!(!result.is_valid());
+        assert!(result.errors().iter().any(|v| v.message.contains("minCount")));
+    }
+
+    #[test]
+    fn test_compiled_datatype_mapping_flags_bad_date() {
+        let loader = ShaclLoader::new(Arc::new(Store::new().unwrap()));
+        loader.load(PERSON_SHAPE, RdfFormat::Turtle).unwrap();
+        let compiled = loader.compile().unwrap();
+
+        let doc = RdfDocument::from_value(json!({
+            "@context": "https://schema.org/",
+            "@type": "Person",
+            "birthDate": "2024-13-45"
+        }))
+        .unwrap();
+
+        assert_eq!(compiled.datatypes.validate(&doc).len(), 1);
+    }
+}
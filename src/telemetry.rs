@@ -0,0 +1,256 @@
+//! End-to-end OpenTelemetry instrumentation for the extraction pipeline
+//!
+//! [`gliner_extractor`](crate::gliner_extractor)'s `otel` feature instruments
+//! just the GLiNER stage. Integration tests like `test_end_to_end_extraction`
+//! and `test_entity_linking_integration` exercise the full pipeline -
+//! [`GenAiExtractor::extract`](crate::extractor::GenAiExtractor), its LLM
+//! round-trip and JSON-LD validation, and every
+//! [`EntityLinker::link_entity`](crate::entity_linker::EntityLinker) HTTP
+//! call - with no visibility into where time is actually spent. This module,
+//! gated behind the `telemetry` feature, wires a root span per `extract()`
+//! call with child spans for the stages above, counters/histograms for
+//! triples extracted, linking confidence, and per-stage latency, and exports
+//! traces and metrics to a single OTLP endpoint so that visibility carries
+//! into production deployments, not just local debugging. Logs are not
+//! exported: [`init`] installs no OTLP log pipeline.
+//!
+//! # Setup
+//!
+//! Call [`init`] once at process startup (before any pipeline call) with a
+//! [`TelemetryConfig`] loaded from env, and keep the returned [`TelemetryGuard`]
+//! alive for the process lifetime - dropping it flushes and shuts down the
+//! exporters. Instrumentation sites elsewhere in the crate pull a tracer/meter
+//! from `opentelemetry::global` on demand, so they work whether or not
+//! [`init`] was ever called (falling back to OpenTelemetry's no-op
+//! implementation).
+
+#[cfg(feature = "telemetry")]
+use crate::error::Error;
+#[cfg(feature = "telemetry")]
+use opentelemetry::metrics::{Counter, Histogram};
+#[cfg(feature = "telemetry")]
+use opentelemetry::trace::TracerProvider as _;
+#[cfg(feature = "telemetry")]
+use opentelemetry::{global, KeyValue};
+#[cfg(feature = "telemetry")]
+use opentelemetry_otlp::WithExportConfig;
+#[cfg(feature = "telemetry")]
+use std::collections::HashMap;
+#[cfg(feature = "telemetry")]
+use std::sync::OnceLock;
+
+/// Configuration for the process-wide OTLP exporter, loaded from the
+/// standard OpenTelemetry SDK environment variables
+#[cfg(feature = "telemetry")]
+#[derive(Debug, Clone)]
+pub struct TelemetryConfig {
+    /// `service.name` resource attribute attached to every span/metric
+    pub service_name: String,
+
+    /// OTLP collector endpoint, e.g. `http://localhost:4317`
+    pub otlp_endpoint: String,
+
+    /// Extra headers sent with every OTLP export request (e.g. auth tokens)
+    pub otlp_headers: HashMap<String, String>,
+}
+
+#[cfg(feature = "telemetry")]
+impl TelemetryConfig {
+    /// Load configuration from the standard OpenTelemetry SDK environment
+    /// variables:
+    /// - `OTEL_SERVICE_NAME` (default: `"text-to-rdf"`)
+    /// - `OTEL_EXPORTER_OTLP_ENDPOINT` (default: `"http://localhost:4317"`)
+    /// - `OTEL_EXPORTER_OTLP_HEADERS`: comma-separated `key=value` pairs
+    #[must_use]
+    pub fn from_env() -> Self {
+        let service_name =
+            std::env::var("OTEL_SERVICE_NAME").unwrap_or_else(|_| "text-to-rdf".to_string());
+
+        let otlp_endpoint = std::env::var("OTEL_EXPORTER_OTLP_ENDPOINT")
+            .unwrap_or_else(|_| "http://localhost:4317".to_string());
+
+        let otlp_headers = std::env::var("OTEL_EXPORTER_OTLP_HEADERS")
+            .map(|raw| parse_otlp_headers(&raw))
+            .unwrap_or_default();
+
+        Self { service_name, otlp_endpoint, otlp_headers }
+    }
+}
+
+/// Parse `OTEL_EXPORTER_OTLP_HEADERS`-style `key1=value1,key2=value2` pairs
+#[cfg(feature = "telemetry")]
+fn parse_otlp_headers(raw: &str) -> HashMap<String, String> {
+    raw.split(',')
+        .filter_map(|pair| pair.split_once('='))
+        .map(|(key, value)| (key.trim().to_string(), value.trim().to_string()))
+        .collect()
+}
+
+/// Holds the tracer and meter providers installed by [`init`] - dropping it
+/// flushes and shuts both providers down, so callers must keep it alive for
+/// as long as the pipeline should emit telemetry
+#[cfg(feature = "telemetry")]
+#[must_use = "telemetry stops exporting as soon as this guard is dropped"]
+pub struct TelemetryGuard {
+    tracer_provider: opentelemetry_sdk::trace::TracerProvider,
+    meter_provider: opentelemetry_sdk::metrics::SdkMeterProvider,
+}
+
+#[cfg(feature = "telemetry")]
+impl Drop for TelemetryGuard {
+    fn drop(&mut self) {
+        let _ = self.tracer_provider.shutdown();
+        let _ = self.meter_provider.shutdown();
+    }
+}
+
+/// Install a global tracer provider and meter provider that export through a
+/// single OTLP endpoint, per `config`
+///
+/// # Errors
+///
+/// Returns an error if the OTLP exporters cannot be built (e.g. an invalid
+/// endpoint)
+#[cfg(feature = "telemetry")]
+pub fn init(config: &TelemetryConfig) -> crate::error::Result<TelemetryGuard> {
+    let resource = opentelemetry_sdk::Resource::new(vec![KeyValue::new(
+        "service.name",
+        config.service_name.clone(),
+    )]);
+
+    let span_exporter = opentelemetry_otlp::SpanExporter::builder()
+        .with_tonic()
+        .with_endpoint(&config.otlp_endpoint)
+        .with_headers(config.otlp_headers.clone())
+        .build()
+        .map_err(|e| Error::Config(format!("Failed to build OTLP span exporter: {e}")))?;
+
+    let tracer_provider = opentelemetry_sdk::trace::TracerProvider::builder()
+        .with_resource(resource.clone())
+        .with_batch_exporter(span_exporter, opentelemetry_sdk::runtime::Tokio)
+        .build();
+    global::set_tracer_provider(tracer_provider.clone());
+
+    let metric_exporter = opentelemetry_otlp::MetricExporter::builder()
+        .with_tonic()
+        .with_endpoint(&config.otlp_endpoint)
+        .with_headers(config.otlp_headers.clone())
+        .build()
+        .map_err(|e| Error::Config(format!("Failed to build OTLP metric exporter: {e}")))?;
+
+    let meter_provider = opentelemetry_sdk::metrics::SdkMeterProvider::builder()
+        .with_resource(resource)
+        .with_periodic_exporter(metric_exporter)
+        .build();
+    global::set_meter_provider(meter_provider.clone());
+
+    Ok(TelemetryGuard { tracer_provider, meter_provider })
+}
+
+/// Instrumentation scope shared by every pipeline stage, so spans/metrics
+/// from `extractor.rs` and `entity_linker.rs` nest under one logical name
+#[cfg(feature = "telemetry")]
+pub const PIPELINE_SCOPE: &str = "text_to_rdf_pipeline";
+
+/// The `Error` variant name (e.g. `"AiService"`, `"Network"`, `"Validation"`)
+/// for use as a span attribute on failure, so a trace backend can
+/// distinguish failure modes without parsing the error message
+#[cfg(feature = "telemetry")]
+#[must_use]
+pub const fn error_variant_name(err: &Error) -> &'static str {
+    match err {
+        Error::AiService(_) => "AiService",
+        Error::JsonParse(_) => "JsonParse",
+        Error::InvalidRdf(_) => "InvalidRdf",
+        Error::MissingField(_) => "MissingField",
+        Error::Config(_) => "Config",
+        Error::Extraction(_) => "Extraction",
+        Error::Io(_) => "Io",
+        Error::Network(_) => "Network",
+        Error::Validation(_) => "Validation",
+        Error::Signing(_) => "Signing",
+    }
+}
+
+/// Counters/histograms shared across the extraction and linking stages
+#[cfg(feature = "telemetry")]
+pub struct PipelineMetrics {
+    /// Number of triples/entities a single `extract()` call produced
+    pub triples_extracted: Counter<u64>,
+    /// Confidence of each entity-linking decision (`0.0`-`1.0`)
+    pub linking_confidence: Histogram<f64>,
+    /// Wall-clock duration of one pipeline stage, in seconds, tagged by a
+    /// `stage` attribute (`"llm_request"`, `"jsonld_validation"`, `"entity_linking"`, ...)
+    pub stage_latency: Histogram<f64>,
+    /// Wall-clock duration of a single `client.exec_chat` call, in seconds,
+    /// tagged by a `model` attribute
+    pub llm_call_latency: Histogram<f64>,
+    /// Number of retries `extract_with_retry` fed back to the LLM, tagged by
+    /// the `error` attribute (the [`Error`] variant name from [`error_variant_name`])
+    /// that triggered the feedback loop
+    pub retry_count: Counter<u64>,
+    /// Estimated token count of each chunk `extract_from_document` sends to
+    /// the LLM
+    pub chunk_tokens: Histogram<u64>,
+    /// Number of entities a batch-linking call attempted to resolve
+    pub entities_attempted: Counter<u64>,
+    /// Number of entities a batch-linking call successfully resolved to a
+    /// canonical URI
+    pub entities_linked: Counter<u64>,
+}
+
+#[cfg(feature = "telemetry")]
+impl PipelineMetrics {
+    fn new() -> Self {
+        let meter = global::meter(PIPELINE_SCOPE);
+        Self {
+            triples_extracted: meter.u64_counter("pipeline.triples_extracted").build(),
+            linking_confidence: meter.f64_histogram("pipeline.linking_confidence").build(),
+            stage_latency: meter.f64_histogram("pipeline.stage_latency").build(),
+            llm_call_latency: meter.f64_histogram("pipeline.llm_call_latency").build(),
+            retry_count: meter.u64_counter("pipeline.retry_count").build(),
+            chunk_tokens: meter.u64_histogram("pipeline.chunk_tokens").build(),
+            entities_attempted: meter.u64_counter("pipeline.entities_attempted").build(),
+            entities_linked: meter.u64_counter("pipeline.entities_linked").build(),
+        }
+    }
+}
+
+/// Process-wide [`PipelineMetrics`] instance, lazily built against whichever
+/// global meter provider is installed at first use
+#[cfg(feature = "telemetry")]
+pub fn pipeline_metrics() -> &'static PipelineMetrics {
+    static METRICS: OnceLock<PipelineMetrics> = OnceLock::new();
+    METRICS.get_or_init(PipelineMetrics::new)
+}
+
+#[cfg(all(test, feature = "telemetry"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_telemetry_config_defaults() {
+        std::env::remove_var("OTEL_SERVICE_NAME");
+        std::env::remove_var("OTEL_EXPORTER_OTLP_ENDPOINT");
+        std::env::remove_var("OTEL_EXPORTER_OTLP_HEADERS");
+
+        let config = TelemetryConfig::from_env();
+        assert_eq!(config.service_name, "text-to-rdf");
+        assert_eq!(config.otlp_endpoint, "http://localhost:4317");
+        assert!(config.otlp_headers.is_empty());
+    }
+
+    #[test]
+    fn test_parse_otlp_headers() {
+        let headers = parse_otlp_headers("api-key=abc123, x-tenant=acme");
+        assert_eq!(headers.get("api-key"), Some(&"abc123".to_string()));
+        assert_eq!(headers.get("x-tenant"), Some(&"acme".to_string()));
+    }
+
+    #[test]
+    fn test_error_variant_name() {
+        assert_eq!(error_variant_name(&Error::AiService("x".to_string())), "AiService");
+        assert_eq!(error_variant_name(&Error::Network("x".to_string())), "Network");
+        assert_eq!(error_variant_name(&Error::Validation("x".to_string())), "Validation");
+    }
+}
@@ -0,0 +1,303 @@
+//! Temporal validity intervals and time-travel consistency checking
+//!
+//! Borrows the time-travel model from temporal graph stores: a property's
+//! value can carry a half-open `[validFrom, validTo)` window instead of
+//! being asserted as eternally true, via [`RdfDocument::add_validity_interval`].
+//! [`TemporalValidator`] checks two kinds of temporal soundness - simple date
+//! ordering pairs like `birthDate <= deathDate`, and contradictions where two
+//! *different* values of the same functional property (e.g. two distinct
+//! `spouse` values) have overlapping validity windows - and can answer "what
+//! value(s) held at instant `t`" via [`TemporalValidator::values_at`].
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::types::RdfDocument;
+use crate::validation::{Severity, Violation};
+
+/// A parsed point in time, comparable without pulling in a date/time crate -
+/// the same no-new-dependency tradeoff as [`crate::prov::now_rfc3339`]'s
+/// hand-rolled civil calendar conversion
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Instant {
+    pub year: i64,
+    pub month: u32,
+    pub day: u32,
+    /// Seconds since midnight, `0` if the source string had no time component
+    pub secs: u32,
+}
+
+/// Sentinel used in place of a missing `valid_to`, so "open to infinity"
+/// compares greater than any real date we'll encounter
+const OPEN_END: Instant = Instant { year: i64::MAX, month: 12, day: 31, secs: 86_399 };
+
+impl Instant {
+    /// Parse an ISO 8601 date (`YYYY-MM-DD`) or date-time
+    /// (`YYYY-MM-DDTHH:MM:SS`, optionally `Z`-suffixed)
+    #[must_use]
+    pub fn parse(value: &str) -> Option<Self> {
+        let (date_part, time_part) = value.split_once('T').map_or((value, None), |(d, t)| (d, Some(t)));
+
+        let mut date_fields = date_part.splitn(3, '-');
+        let year: i64 = date_fields.next()?.parse().ok()?;
+        let month: u32 = date_fields.next()?.parse().ok()?;
+        let day: u32 = date_fields.next()?.parse().ok()?;
+        if !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+            return None;
+        }
+
+        let secs = match time_part {
+            Some(raw) => {
+                let mut time_fields = raw.trim_end_matches('Z').splitn(3, ':');
+                let hour: u32 = time_fields.next()?.parse().ok()?;
+                let minute: u32 = time_fields.next()?.parse().ok()?;
+                let second: u32 = time_fields.next().unwrap_or("0").parse().ok()?;
+                hour * 3_600 + minute * 60 + second
+            }
+            None => 0,
+        };
+
+        Some(Self { year, month, day, secs })
+    }
+}
+
+/// A value's `[valid_from, valid_to)` window, as carried by
+/// [`RdfDocument::add_validity_interval`]. A missing `valid_to` means "open
+/// to infinity."
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ValidityInterval {
+    pub value: Value,
+    pub valid_from: String,
+    pub valid_to: Option<String>,
+}
+
+impl ValidityInterval {
+    #[must_use]
+    pub fn new(value: Value, valid_from: impl Into<String>) -> Self {
+        Self { value, valid_from: valid_from.into(), valid_to: None }
+    }
+
+    #[must_use]
+    pub fn with_valid_to(mut self, valid_to: impl Into<String>) -> Self {
+        self.valid_to = Some(valid_to.into());
+        self
+    }
+
+    fn from_instant(&self) -> Option<Instant> {
+        Instant::parse(&self.valid_from)
+    }
+
+    fn to_instant(&self) -> Option<Instant> {
+        self.valid_to.as_deref().and_then(Instant::parse)
+    }
+
+    /// Whether this interval covers `at`; unparseable bounds never cover anything
+    #[must_use]
+    pub fn covers(&self, at: Instant) -> bool {
+        let Some(from) = self.from_instant() else { return false };
+        at >= from && at < self.to_instant().unwrap_or(OPEN_END)
+    }
+
+    /// Two half-open intervals `[a1,a2)` and `[b1,b2)` overlap iff `a1 < b2 && b1 < a2`
+    fn overlaps(&self, other: &Self) -> bool {
+        let (Some(a1), Some(b1)) = (self.from_instant(), other.from_instant()) else { return false };
+        let a2 = self.to_instant().unwrap_or(OPEN_END);
+        let b2 = other.to_instant().unwrap_or(OPEN_END);
+        a1 < b2 && b1 < a2
+    }
+}
+
+/// Checks temporal soundness of an `RdfDocument`: date ordering pairs (e.g.
+/// `birthDate <= deathDate`) and overlapping validity windows asserted for
+/// distinct values of the same functional property
+pub struct TemporalValidator {
+    /// `(earlier, later)` property name pairs that must satisfy `earlier <= later`
+    ordering_pairs: Vec<(String, String)>,
+}
+
+impl Default for TemporalValidator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TemporalValidator {
+    /// A validator with the Schema.org ordering pairs this repo extracts
+    /// most often: `birthDate <= deathDate` and `startDate <= endDate`
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            ordering_pairs: vec![
+                ("birthDate".to_string(), "deathDate".to_string()),
+                ("startDate".to_string(), "endDate".to_string()),
+            ],
+        }
+    }
+
+    /// Register an additional `earlier <= later` ordering pair to check
+    #[must_use]
+    pub fn with_ordering_pair(mut self, earlier: impl Into<String>, later: impl Into<String>) -> Self {
+        self.ordering_pairs.push((earlier.into(), later.into()));
+        self
+    }
+
+    /// Check `document` for ordering violations and validity-interval overlaps
+    #[must_use]
+    pub fn validate(&self, document: &RdfDocument) -> Vec<Violation> {
+        let mut violations = Vec::new();
+
+        for (earlier, later) in &self.ordering_pairs {
+            let earlier_at = document.get(earlier).and_then(Value::as_str).and_then(Instant::parse);
+            let later_at = document.get(later).and_then(Value::as_str).and_then(Instant::parse);
+            if let (Some(earlier_at), Some(later_at)) = (earlier_at, later_at) {
+                if earlier_at > later_at {
+                    violations.push(Violation {
+                        rule: "temporal_ordering".to_string(),
+                        message: format!("'{earlier}' must not be after '{later}'"),
+                        severity: Severity::Error,
+                        property: Some(earlier.clone()),
+                        confidence_impact: -0.2,
+                        source: None,
+                    });
+                }
+            }
+        }
+
+        if let Some(validity) = &document.validity {
+            for (property, intervals) in validity {
+                for (i, a) in intervals.iter().enumerate() {
+                    for b in &intervals[i + 1..] {
+                        if a.value != b.value && a.overlaps(b) {
+                            violations.push(Violation {
+                                rule: "temporal_overlap".to_string(),
+                                message: format!(
+                                    "'{property}' has overlapping validity intervals for distinct values {} and {}",
+                                    a.value, b.value
+                                ),
+                                severity: Severity::Warning,
+                                property: Some(property.clone()),
+                                confidence_impact: -0.1,
+                                source: None,
+                            });
+                        }
+                    }
+                }
+            }
+        }
+
+        violations
+    }
+
+    /// The value(s) of `property` asserted valid at instant `at`, per
+    /// `document`'s validity intervals (empty if `document` carries none)
+    #[must_use]
+    pub fn values_at<'a>(document: &'a RdfDocument, property: &str, at: Instant) -> Vec<&'a Value> {
+        document
+            .validity
+            .as_ref()
+            .and_then(|by_property| by_property.get(property))
+            .map(|intervals| intervals.iter().filter(|interval| interval.covers(at)).map(|interval| &interval.value).collect())
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_instant_parse_orders_dates_correctly() {
+        assert!(Instant::parse("1990-01-01").unwrap() < Instant::parse("2020-06-15").unwrap());
+        assert!(Instant::parse("2020-06-15T10:00:00Z").unwrap() < Instant::parse("2020-06-15T10:00:01").unwrap());
+        assert!(Instant::parse("not-a-date").is_none());
+    }
+
+    #[test]
+    fn test_ordering_pair_flags_death_before_birth() {
+        let doc = RdfDocument::from_value(json!({
+            "@context": "https://schema.org/",
+            "@type": "Person",
+            "birthDate": "2020-01-01",
+            "deathDate": "1990-01-01"
+        }))
+        .unwrap();
+
+        let violations = TemporalValidator::new().validate(&doc);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].severity, Severity::Error);
+        assert!(violations[0].message.contains("birthDate"));
+    }
+
+    #[test]
+    fn test_ordering_pair_passes_when_properties_absent_or_in_order() {
+        let doc = RdfDocument::from_value(json!({
+            "@context": "https://schema.org/",
+            "@type": "Person",
+            "birthDate": "1990-01-01",
+            "deathDate": "2020-01-01"
+        }))
+        .unwrap();
+
+        assert!(TemporalValidator::new().validate(&doc).is_empty());
+    }
+
+    #[test]
+    fn test_overlapping_validity_intervals_for_distinct_values_is_flagged() {
+        let mut doc = RdfDocument::from_value(json!({
+            "@context": "https://schema.org/",
+            "@type": "Person"
+        }))
+        .unwrap();
+        doc.add_validity_interval(
+            "spouse",
+            ValidityInterval::new(json!("Alice"), "2000-01-01").with_valid_to("2015-01-01"),
+        );
+        doc.add_validity_interval(
+            "spouse",
+            ValidityInterval::new(json!("Bob"), "2010-01-01").with_valid_to("2020-01-01"),
+        );
+
+        let violations = TemporalValidator::new().validate(&doc);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].severity, Severity::Warning);
+        assert_eq!(violations[0].rule, "temporal_overlap");
+    }
+
+    #[test]
+    fn test_adjacent_validity_intervals_do_not_overlap() {
+        let mut doc = RdfDocument::from_value(json!({
+            "@context": "https://schema.org/",
+            "@type": "Person"
+        }))
+        .unwrap();
+        doc.add_validity_interval(
+            "jobTitle",
+            ValidityInterval::new(json!("Engineer"), "2000-01-01").with_valid_to("2010-01-01"),
+        );
+        doc.add_validity_interval(
+            "jobTitle",
+            ValidityInterval::new(json!("Manager"), "2010-01-01"),
+        );
+
+        assert!(TemporalValidator::new().validate(&doc).is_empty());
+    }
+
+    #[test]
+    fn test_values_at_returns_the_value_covering_the_instant() {
+        let mut doc = RdfDocument::from_value(json!({
+            "@context": "https://schema.org/",
+            "@type": "Person"
+        }))
+        .unwrap();
+        doc.add_validity_interval(
+            "jobTitle",
+            ValidityInterval::new(json!("Engineer"), "2000-01-01").with_valid_to("2010-01-01"),
+        );
+        doc.add_validity_interval("jobTitle", ValidityInterval::new(json!("Manager"), "2010-01-01"));
+
+        let at = Instant::parse("2012-06-01").unwrap();
+        let values = TemporalValidator::values_at(&doc, "jobTitle", at);
+        assert_eq!(values, vec![&json!("Manager")]);
+    }
+}
@@ -0,0 +1,90 @@
+//! Tokenizer-backed token counting for context budgeting
+//!
+//! Token counts were previously estimated as `text.len() / 4` (see
+//! `examples/test_entity_consistency.rs`), which is wildly inaccurate for
+//! non-English text, code, or URLs and silently lets large documents overflow
+//! a model's context window. This module wraps `tiktoken-rs` with a
+//! per-model encoding lookup (GPT/Claude/Gemini all tokenize close enough to
+//! `cl100k_base` for budgeting purposes) and a byte-length fallback for
+//! models with no known encoding.
+
+use tiktoken_rs::{cl100k_base, CoreBPE};
+
+/// Counts tokens for a specific model's encoding, used to budget how much
+/// text fits in a model's context window alongside the system prompt and
+/// response allowance
+pub struct TokenCounter {
+    encoding: Option<CoreBPE>,
+}
+
+impl TokenCounter {
+    /// Build a token counter for `model`
+    ///
+    /// Every Claude/GPT/Gemini model name known to this crate maps to the
+    /// `cl100k_base` encoding, which is close enough across providers for
+    /// budgeting purposes (it need not be byte-exact, only a reliable
+    /// estimate that doesn't systematically undercount). Unknown models fall
+    /// back to a byte-length heuristic.
+    #[must_use]
+    pub fn for_model(model: &str) -> Self {
+        let known_encoding = model.contains("gpt")
+            || model.contains("claude")
+            || model.contains("gemini")
+            || model.contains("llama")
+            || model.contains("qwen");
+
+        let encoding = if known_encoding {
+            cl100k_base().ok()
+        } else {
+            None
+        };
+
+        Self { encoding }
+    }
+
+    /// Count the number of tokens `text` would occupy
+    ///
+    /// Falls back to `text.len() / 4` (the crate's prior heuristic) when no
+    /// tokenizer encoding is available for the configured model.
+    #[must_use]
+    pub fn count(&self, text: &str) -> usize {
+        self.encoding
+            .as_ref()
+            .map_or_else(|| text.len() / 4, |enc| enc.encode_ordinary(text).len())
+    }
+
+    /// Average characters per token observed in `sample`, used to translate a
+    /// token budget into an approximate character budget for chunking
+    #[must_use]
+    pub fn chars_per_token(&self, sample: &str) -> f64 {
+        let token_count = self.count(sample).max(1);
+        sample.len() as f64 / token_count as f64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_known_model_uses_real_tokenizer() {
+        let counter = TokenCounter::for_model("claude-3-5-sonnet");
+        // cl100k_base splits "Hello, world!" into 4 tokens ("Hello", ",",
+        // " world", "!"), where the len/4 fallback would give 13 / 4 = 3.
+        let count = counter.count("Hello, world!");
+        assert!(count > 0);
+        assert_eq!(count, 4);
+    }
+
+    #[test]
+    fn test_unknown_model_falls_back_to_heuristic() {
+        let counter = TokenCounter::for_model("some-unreleased-model");
+        assert_eq!(counter.count("12345678"), 2);
+    }
+
+    #[test]
+    fn test_chars_per_token_is_positive() {
+        let counter = TokenCounter::for_model("claude-3-5-sonnet");
+        assert!(counter.chars_per_token("The quick brown fox jumps over the lazy dog.") > 0.0);
+    }
+}
@@ -0,0 +1,230 @@
+//! Declarative triple-filter DSL
+//!
+//! Extraction pipelines often need to drop a handful of likely-incorrect
+//! triples (a predicate the model hallucinates, a reversed relation) before
+//! scoring or publishing results. Hardcoding those checks as Rust `if`
+//! statements means every dataset that wants different cleanup rules has to
+//! fork the code. This module expresses the same checks as data: a
+//! [`TriplePredicate`] tree that can be deserialized from JSON/YAML/etc, and
+//! an ordered list of [`FilterRule`]s (`{ when, action }`) evaluated against
+//! each triple, falling back to a default action when no rule matches.
+
+use crate::error::{Error, Result};
+use crate::reasoning::Triple;
+use regex::Regex;
+use serde::Deserialize;
+use std::path::Path;
+
+/// A compiled regular expression that can be deserialized from its pattern string
+#[derive(Debug, Clone)]
+pub struct RegexPattern(Regex);
+
+impl<'de> Deserialize<'de> for RegexPattern {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let pattern = String::deserialize(deserializer)?;
+        Regex::new(&pattern)
+            .map(RegexPattern)
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+/// A predicate over a [`Triple`], used to decide whether a [`FilterRule`] applies
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "predicate", content = "argument")]
+pub enum TriplePredicate {
+    /// The triple's RDF predicate equals this value exactly
+    PredicateEquals(String),
+    /// The triple's RDF predicate contains this substring, ignoring case
+    PredicateContains(String),
+    /// The triple's subject equals this value exactly
+    SubjectEquals(String),
+    /// The triple's object matches this regular expression
+    ObjectMatches(RegexPattern),
+    /// Matches when the inner predicate does not
+    Not(Box<TriplePredicate>),
+    /// Matches when any inner predicate matches
+    AnyOf(Vec<TriplePredicate>),
+    /// Matches when every inner predicate matches
+    AllOf(Vec<TriplePredicate>),
+}
+
+impl TriplePredicate {
+    /// Evaluate this predicate against `triple`
+    #[must_use]
+    pub fn matches(&self, triple: &Triple) -> bool {
+        match self {
+            Self::PredicateEquals(value) => triple.predicate == *value,
+            Self::PredicateContains(value) => triple.predicate.to_lowercase().contains(&value.to_lowercase()),
+            Self::SubjectEquals(value) => triple.subject == *value,
+            Self::ObjectMatches(pattern) => pattern.0.is_match(&triple.object),
+            Self::Not(inner) => !inner.matches(triple),
+            Self::AnyOf(predicates) => predicates.iter().any(|p| p.matches(triple)),
+            Self::AllOf(predicates) => predicates.iter().all(|p| p.matches(triple)),
+        }
+    }
+}
+
+/// What to do with a triple that matches a [`FilterRule`]'s predicate
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Default)]
+pub enum FilterAction {
+    /// Keep the triple in the output
+    #[default]
+    Keep,
+    /// Remove the triple from the output
+    Drop,
+}
+
+/// One rule in a [`TripleFilter`]: when `when` matches a triple, apply `action`
+#[derive(Debug, Clone, Deserialize)]
+pub struct FilterRule {
+    pub when: TriplePredicate,
+    pub action: FilterAction,
+}
+
+/// An ordered list of [`FilterRule`]s plus a default action for triples that
+/// match none of them
+///
+/// Rules are evaluated in order; the first one whose `when` matches decides
+/// the triple's fate, so more specific rules should come before broader ones.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct TripleFilter {
+    #[serde(default)]
+    pub rules: Vec<FilterRule>,
+    #[serde(default)]
+    pub default_action: FilterAction,
+}
+
+impl TripleFilter {
+    /// Create a filter from an explicit rule list and default action
+    #[must_use]
+    pub const fn new(rules: Vec<FilterRule>, default_action: FilterAction) -> Self {
+        Self {
+            rules,
+            default_action,
+        }
+    }
+
+    /// Load a filter from a JSON file
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or does not contain valid
+    /// filter JSON (including an invalid regex in an `ObjectMatches` rule)
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self> {
+        let contents = std::fs::read_to_string(path.as_ref())
+            .map_err(|e| Error::Config(format!("Failed to read triple filter file: {e}")))?;
+        serde_json::from_str(&contents).map_err(Error::JsonParse)
+    }
+
+    /// Whether `triple` should be kept, per the first matching rule or the
+    /// default action if no rule matches
+    #[must_use]
+    pub fn keep(&self, triple: &Triple) -> bool {
+        for rule in &self.rules {
+            if rule.when.matches(triple) {
+                return rule.action == FilterAction::Keep;
+            }
+        }
+        self.default_action == FilterAction::Keep
+    }
+
+    /// Filter a collection of triples, keeping only those [`Self::keep`] accepts
+    #[must_use]
+    pub fn apply(&self, triples: Vec<Triple>) -> Vec<Triple> {
+        triples.into_iter().filter(|t| self.keep(t)).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn triple(subject: &str, predicate: &str, object: &str) -> Triple {
+        Triple::new(subject, predicate, object)
+    }
+
+    #[test]
+    fn test_predicate_equals() {
+        let pred = TriplePredicate::PredicateEquals("ceo".to_string());
+        assert!(pred.matches(&triple("Acme", "ceo", "Jane")));
+        assert!(!pred.matches(&triple("Acme", "currentCeo", "Jane")));
+    }
+
+    #[test]
+    fn test_predicate_contains() {
+        let pred = TriplePredicate::PredicateContains("founder".to_string());
+        assert!(pred.matches(&triple("Acme", "hasFounder", "Jane")));
+    }
+
+    #[test]
+    fn test_not_combinator() {
+        let pred = TriplePredicate::Not(Box::new(TriplePredicate::PredicateContains(
+            "alumniof".to_string(),
+        )));
+        assert!(pred.matches(&triple("Jane", "alumni", "MIT")));
+        assert!(!pred.matches(&triple("Jane", "alumniOf", "MIT")));
+    }
+
+    #[test]
+    fn test_any_of_and_all_of() {
+        let any = TriplePredicate::AnyOf(vec![
+            TriplePredicate::PredicateContains("founder".to_string()),
+            TriplePredicate::PredicateContains("funder".to_string()),
+        ]);
+        assert!(any.matches(&triple("Acme", "funder", "Jane")));
+        assert!(!any.matches(&triple("Acme", "worksFor", "Jane")));
+
+        let all = TriplePredicate::AllOf(vec![
+            TriplePredicate::PredicateContains("alumni".to_string()),
+            TriplePredicate::Not(Box::new(TriplePredicate::PredicateContains(
+                "alumniof".to_string(),
+            ))),
+        ]);
+        assert!(all.matches(&triple("Jane", "alumni", "MIT")));
+        assert!(!all.matches(&triple("Jane", "alumniOf", "MIT")));
+    }
+
+    #[test]
+    fn test_object_matches_regex() {
+        let filter = TripleFilter::new(
+            vec![FilterRule {
+                when: TriplePredicate::ObjectMatches(
+                    serde_json::from_str(r#""^\\d{4}$""#).unwrap(),
+                ),
+                action: FilterAction::Drop,
+            }],
+            FilterAction::Keep,
+        );
+        assert!(!filter.keep(&triple("Alan Bean", "birthDate", "1932")));
+        assert!(filter.keep(&triple("Alan Bean", "birthPlace", "Wheeler")));
+    }
+
+    #[test]
+    fn test_default_action_applies_when_no_rule_matches() {
+        let filter = TripleFilter::new(
+            vec![FilterRule {
+                when: TriplePredicate::PredicateEquals("ceo".to_string()),
+                action: FilterAction::Drop,
+            }],
+            FilterAction::Keep,
+        );
+        assert!(filter.keep(&triple("Acme", "worksFor", "Jane")));
+        assert!(!filter.keep(&triple("Acme", "ceo", "Jane")));
+    }
+
+    #[test]
+    fn test_deserialize_filter_rule_from_json() {
+        let json = r#"{
+            "rules": [
+                { "when": { "predicate": "PredicateContains", "argument": "founder" }, "action": "Drop" }
+            ],
+            "default_action": "Keep"
+        }"#;
+        let filter: TripleFilter = serde_json::from_str(json).unwrap();
+        assert!(!filter.keep(&triple("Acme", "hasFounder", "Jane")));
+        assert!(filter.keep(&triple("Acme", "worksFor", "Jane")));
+    }
+}
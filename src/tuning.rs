@@ -0,0 +1,365 @@
+//! Nelder-Mead auto-tuning of extraction hyperparameters
+//!
+//! Users currently guess at `temperature`, `entity_linker.confidence_threshold`,
+//! and `max_retries`. This module derivative-free-optimizes those continuous
+//! knobs against a small labeled gold set, maximizing entity/relation F1,
+//! using the Nelder-Mead simplex method implemented directly (no external
+//! optimization crate) so the stopping rule and coefficients stay explicit
+//! and auditable.
+
+use crate::reasoning::Triple;
+use crate::{ExtractionConfig, GenAiExtractor, RdfDocument, RdfExtractor, Result};
+use serde_json::Value;
+use std::collections::HashSet;
+
+/// Reflection coefficient
+const ALPHA: f64 = 1.0;
+/// Expansion coefficient
+const GAMMA: f64 = 2.0;
+/// Contraction coefficient
+const RHO: f64 = 0.5;
+/// Shrink coefficient
+const SIGMA: f64 = 0.5;
+
+/// Number of continuous parameters being tuned: temperature,
+/// `confidence_threshold`, `max_retries`
+const DIM: usize = 3;
+
+/// A point in parameter space: `[temperature, confidence_threshold, max_retries]`
+type Vertex = [f64; DIM];
+
+/// Default simplex diameter / objective-spread tolerance
+const DEFAULT_TOLERANCE: f64 = 1e-3;
+/// Default iteration budget
+const DEFAULT_MAX_ITERATIONS: usize = 50;
+
+/// Tune `config`'s continuous hyperparameters (`temperature`,
+/// `entity_linker.confidence_threshold`, `max_retries`) against `gold` using
+/// Nelder-Mead simplex optimization, maximizing average entity/relation F1.
+///
+/// Runs [`DEFAULT_MAX_ITERATIONS`] simplex steps with tolerance
+/// [`DEFAULT_TOLERANCE`]; see [`optimize_with_budget`] to customize either.
+///
+/// # Errors
+///
+/// Returns an error if constructing an extractor or extracting from a gold
+/// text fails for any candidate configuration.
+pub async fn optimize(
+    config: ExtractionConfig,
+    gold: &[(String, RdfDocument)],
+) -> Result<ExtractionConfig> {
+    optimize_with_budget(config, gold, DEFAULT_MAX_ITERATIONS, DEFAULT_TOLERANCE).await
+}
+
+/// Like [`optimize`], with an explicit iteration budget and convergence
+/// tolerance
+///
+/// # Errors
+///
+/// Returns an error if constructing an extractor or extracting from a gold
+/// text fails for any candidate configuration.
+pub async fn optimize_with_budget(
+    config: ExtractionConfig,
+    gold: &[(String, RdfDocument)],
+    max_iterations: usize,
+    tolerance: f64,
+) -> Result<ExtractionConfig> {
+    if gold.is_empty() {
+        return Ok(config);
+    }
+
+    let start = [
+        f64::from(config.temperature.unwrap_or(0.3)),
+        config.entity_linker.confidence_threshold,
+        f64::from(config.max_retries),
+    ];
+
+    // Build the initial simplex: the starting point plus one perturbation
+    // per dimension (the standard Nelder-Mead initialization)
+    let mut simplex: Vec<Vertex> = vec![start];
+    for dim in 0..DIM {
+        let mut vertex = start;
+        vertex[dim] += if vertex[dim].abs() < f64::EPSILON {
+            0.1
+        } else {
+            0.1 * vertex[dim]
+        };
+        simplex.push(vertex);
+    }
+
+    let mut scores = Vec::with_capacity(simplex.len());
+    for vertex in &simplex {
+        scores.push(objective(&config, *vertex, gold).await?);
+    }
+
+    for _ in 0..max_iterations {
+        // Sort vertices best (lowest objective) to worst
+        let mut order: Vec<usize> = (0..simplex.len()).collect();
+        order.sort_by(|&a, &b| scores[a].partial_cmp(&scores[b]).unwrap());
+        simplex = order.iter().map(|&i| simplex[i]).collect();
+        scores = order.iter().map(|&i| scores[i]).collect();
+
+        let best_score = scores[0];
+        let second_worst_score = scores[scores.len() - 2];
+        let worst_score = scores[scores.len() - 1];
+        let worst = simplex[simplex.len() - 1];
+
+        let diameter = simplex
+            .iter()
+            .flat_map(|a| simplex.iter().map(move |b| vertex_distance(a, b)))
+            .fold(0.0_f64, f64::max);
+        let spread = worst_score - best_score;
+        if diameter < tolerance || spread < tolerance {
+            break;
+        }
+
+        let centroid = centroid_excluding_last(&simplex);
+
+        let reflected = reflect(&centroid, &worst, ALPHA);
+        let reflected_score = objective(&config, reflected, gold).await?;
+
+        if reflected_score < best_score {
+            // Reflection beats the current best - try expanding further
+            let expanded = reflect(&centroid, &worst, GAMMA);
+            let expanded_score = objective(&config, expanded, gold).await?;
+            if expanded_score < reflected_score {
+                *simplex.last_mut().unwrap() = expanded;
+                *scores.last_mut().unwrap() = expanded_score;
+            } else {
+                *simplex.last_mut().unwrap() = reflected;
+                *scores.last_mut().unwrap() = reflected_score;
+            }
+        } else if reflected_score < second_worst_score {
+            *simplex.last_mut().unwrap() = reflected;
+            *scores.last_mut().unwrap() = reflected_score;
+        } else {
+            // Contract the worst vertex toward the centroid
+            let contracted = reflect(&centroid, &worst, -RHO);
+            let contracted_score = objective(&config, contracted, gold).await?;
+            if contracted_score < worst_score {
+                *simplex.last_mut().unwrap() = contracted;
+                *scores.last_mut().unwrap() = contracted_score;
+            } else {
+                // Contraction failed - shrink every vertex toward the best
+                let best = simplex[0];
+                for (vertex, score) in simplex.iter_mut().zip(scores.iter_mut()).skip(1) {
+                    *vertex = shrink_toward(&best, vertex, SIGMA);
+                    *score = objective(&config, *vertex, gold).await?;
+                }
+            }
+        }
+    }
+
+    let best_index = (0..scores.len())
+        .min_by(|&a, &b| scores[a].partial_cmp(&scores[b]).unwrap())
+        .unwrap();
+    Ok(vertex_to_config(&config, simplex[best_index]))
+}
+
+/// `centroid + coefficient * (centroid - worst)`
+///
+/// Reflection uses `coefficient = alpha`; expansion reuses this with
+/// `gamma` to move further past the reflected point, and contraction with
+/// `-rho` to move from the centroid back toward the worst vertex, all along
+/// the same axis.
+fn reflect(centroid: &Vertex, worst: &Vertex, coefficient: f64) -> Vertex {
+    let mut out = [0.0; DIM];
+    for i in 0..DIM {
+        out[i] = centroid[i] + coefficient * (centroid[i] - worst[i]);
+    }
+    out
+}
+
+fn shrink_toward(best: &Vertex, vertex: &Vertex, sigma: f64) -> Vertex {
+    let mut out = [0.0; DIM];
+    for i in 0..DIM {
+        out[i] = best[i] + sigma * (vertex[i] - best[i]);
+    }
+    out
+}
+
+fn centroid_excluding_last(simplex: &[Vertex]) -> Vertex {
+    let n = simplex.len() - 1;
+    let mut out = [0.0; DIM];
+    for vertex in &simplex[..n] {
+        for i in 0..DIM {
+            out[i] += vertex[i];
+        }
+    }
+    for value in &mut out {
+        *value /= n as f64;
+    }
+    out
+}
+
+fn vertex_distance(a: &Vertex, b: &Vertex) -> f64 {
+    (0..DIM)
+        .map(|i| (a[i] - b[i]).powi(2))
+        .sum::<f64>()
+        .sqrt()
+}
+
+/// Build a candidate `ExtractionConfig` from a simplex vertex, clamping
+/// `temperature`/`confidence_threshold` to `[0, 1]` and rounding
+/// `max_retries` to the nearest non-negative integer
+fn vertex_to_config(template: &ExtractionConfig, vertex: Vertex) -> ExtractionConfig {
+    let mut config = template.clone();
+    config.temperature = Some(vertex[0].clamp(0.0, 1.0) as f32);
+    config.entity_linker.confidence_threshold = vertex[1].clamp(0.0, 1.0);
+    config.max_retries = vertex[2].round().max(0.0) as u32;
+    config
+}
+
+/// Negative average F1 of extracting every `gold` text with the
+/// configuration encoded by `vertex`, i.e. the quantity Nelder-Mead
+/// minimizes
+async fn objective(
+    template: &ExtractionConfig,
+    vertex: Vertex,
+    gold: &[(String, RdfDocument)],
+) -> Result<f64> {
+    let config = vertex_to_config(template, vertex);
+    let extractor = GenAiExtractor::new(config)?;
+
+    let mut total_f1 = 0.0;
+    for (text, expected) in gold {
+        let predicted = extractor.extract(text).await?;
+        total_f1 += f1_score(&document_triples(&predicted), &document_triples(expected));
+    }
+
+    Ok(-(total_f1 / gold.len() as f64))
+}
+
+/// Precision/recall F1 over two triple sets
+fn f1_score(predicted: &HashSet<Triple>, expected: &HashSet<Triple>) -> f64 {
+    if predicted.is_empty() && expected.is_empty() {
+        return 1.0;
+    }
+
+    let true_positives = predicted.intersection(expected).count() as f64;
+    let precision = if predicted.is_empty() {
+        0.0
+    } else {
+        true_positives / predicted.len() as f64
+    };
+    let recall = if expected.is_empty() {
+        0.0
+    } else {
+        true_positives / expected.len() as f64
+    };
+
+    if precision + recall == 0.0 {
+        0.0
+    } else {
+        2.0 * precision * recall / (precision + recall)
+    }
+}
+
+/// Flatten an `RdfDocument`'s JSON-LD into subject-predicate-object triples,
+/// using each entity's `name` as its subject
+pub(crate) fn document_triples(doc: &RdfDocument) -> HashSet<Triple> {
+    let mut triples = HashSet::new();
+    collect_triples(&doc.data, &mut triples);
+    triples
+}
+
+fn collect_triples(value: &Value, triples: &mut HashSet<Triple>) {
+    match value {
+        Value::Object(map) => {
+            if let Some(subject) = map.get("name").and_then(Value::as_str) {
+                for (key, val) in map {
+                    if matches!(key.as_str(), "@context" | "@type" | "@id" | "name") {
+                        continue;
+                    }
+                    collect_object_triples(subject, key, val, triples);
+                }
+            }
+            for val in map.values() {
+                collect_triples(val, triples);
+            }
+        }
+        Value::Array(arr) => {
+            for item in arr {
+                collect_triples(item, triples);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn collect_object_triples(subject: &str, predicate: &str, value: &Value, triples: &mut HashSet<Triple>) {
+    match value {
+        Value::String(s) => {
+            triples.insert(Triple::new(subject, predicate, s.clone()));
+        }
+        Value::Object(obj) => {
+            if let Some(object_name) = obj.get("name").and_then(Value::as_str) {
+                triples.insert(Triple::new(subject, predicate, object_name));
+            }
+        }
+        Value::Array(arr) => {
+            for item in arr {
+                collect_object_triples(subject, predicate, item, triples);
+            }
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_f1_score_perfect_match() {
+        let a: HashSet<Triple> = [Triple::new("Ada", "birthPlace", "London")].into();
+        assert!((f1_score(&a, &a) - 1.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_f1_score_no_overlap() {
+        let predicted: HashSet<Triple> = [Triple::new("Ada", "birthPlace", "London")].into();
+        let expected: HashSet<Triple> = [Triple::new("Ada", "birthPlace", "Paris")].into();
+        assert!((f1_score(&predicted, &expected)).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_f1_score_empty_sets_is_perfect() {
+        let empty: HashSet<Triple> = HashSet::new();
+        assert!((f1_score(&empty, &empty) - 1.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_document_triples_flattens_nested_entity() {
+        let doc = RdfDocument {
+            context: serde_json::json!("https://schema.org/"),
+            data: serde_json::json!({
+                "@type": "Person",
+                "name": "Alan Bean",
+                "alumniOf": {"@type": "EducationalOrganization", "name": "UT Austin"}
+            }),
+            provenance: None,
+            prov: None,
+            validity: None,
+        };
+
+        let triples = document_triples(&doc);
+        assert!(triples.contains(&Triple::new("Alan Bean", "alumniOf", "UT Austin")));
+    }
+
+    #[test]
+    fn test_vertex_to_config_clamps_and_rounds() {
+        let template = ExtractionConfig::default();
+        let config = vertex_to_config(&template, [1.5, -0.2, 2.6]);
+        assert!((config.temperature.unwrap() - 1.0).abs() < f32::EPSILON);
+        assert!((config.entity_linker.confidence_threshold - 0.0).abs() < f64::EPSILON);
+        assert_eq!(config.max_retries, 3);
+    }
+
+    #[test]
+    fn test_centroid_excludes_last_vertex() {
+        let simplex = vec![[0.0, 0.0, 0.0], [2.0, 0.0, 0.0], [100.0, 100.0, 100.0]];
+        let centroid = centroid_excluding_last(&simplex);
+        assert!((centroid[0] - 1.0).abs() < f64::EPSILON);
+    }
+}
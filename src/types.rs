@@ -2,8 +2,12 @@
 
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use sha2::{Digest, Sha256};
 use std::collections::HashMap;
+use unicode_normalization::UnicodeNormalization;
 
+use crate::prov::ProvenanceGraph;
+use crate::temporal::ValidityInterval;
 use crate::{Error, Result};
 
 /// Hardcoded JSON-LD @context to ensure correct URIs
@@ -40,8 +44,11 @@ pub struct RdfEntity {
     #[serde(rename = "@id", skip_serializing_if = "Option::is_none")]
     pub id: Option<String>,
 
+    /// The entity's name, either a plain string or a JSON-LD language-tagged
+    /// value (or array of values) for entities with names in several
+    /// languages - see [`Self::with_localized_name`]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub name: Option<String>,
+    pub name: Option<Value>,
 
     #[serde(flatten)]
     pub properties: HashMap<String, Value>,
@@ -69,7 +76,29 @@ impl RdfEntity {
     /// Set the entity name
     #[must_use]
     pub fn with_name(mut self, name: impl Into<String>) -> Self {
-        self.name = Some(name.into());
+        self.name = Some(Value::String(name.into()));
+        self
+    }
+
+    /// Add a language-tagged name (JSON-LD `{"@value": ..., "@language": ...}`)
+    /// for `lang`, a BCP-47 language tag (e.g. `"fr"`, `"ja"`)
+    ///
+    /// Distinct-language labels are never collapsed into one: a plain
+    /// [`Self::with_name`] value is kept as the first array entry, and
+    /// repeated calls append one entry per language rather than overwriting.
+    #[must_use]
+    pub fn with_localized_name(mut self, lang: impl Into<String>, value: impl Into<String>) -> Self {
+        let entry = serde_json::json!({ "@value": value.into(), "@language": lang.into() });
+
+        self.name = Some(match self.name.take() {
+            None => Value::Array(vec![entry]),
+            Some(Value::Array(mut entries)) => {
+                entries.push(entry);
+                Value::Array(entries)
+            }
+            Some(existing) => Value::Array(vec![existing, entry]),
+        });
+
         self
     }
 
@@ -85,6 +114,83 @@ impl RdfEntity {
     pub fn get_property(&self, key: &str) -> Option<&Value> {
         self.properties.get(key)
     }
+
+    /// Decompose `full_name` (see [`crate::person_name::parse_person_name`])
+    /// and populate the Schema.org `givenName`/`familyName`/
+    /// `honorificPrefix`/`honorificSuffix` properties from it
+    ///
+    /// Opt-in: the plain `name` field set by [`Self::with_name`] is
+    /// unaffected, so existing single-field behavior stays the default for
+    /// callers that don't need the structured parts.
+    #[must_use]
+    pub fn with_decomposed_name(mut self, full_name: &str) -> Self {
+        let form = crate::person_name::parse_person_name(full_name);
+
+        if let Some(prefix) = form.honorific_prefix() {
+            self.properties
+                .insert("honorificPrefix".to_string(), Value::String(prefix));
+        }
+        if let Some(given) = form.given_name() {
+            self.properties
+                .insert("givenName".to_string(), Value::String(given));
+        }
+        if let Some(family) = form.family_name() {
+            self.properties
+                .insert("familyName".to_string(), Value::String(family));
+        }
+        if let Some(suffix) = form.honorific_suffix() {
+            self.properties
+                .insert("honorificSuffix".to_string(), Value::String(suffix));
+        }
+
+        self
+    }
+
+    /// Expand this entity's `@type` into a full IRI if it's a
+    /// namespace-qualified `Custom("prefix:Local")` CURIE, using `namespaces`
+    ///
+    /// Returns `None` for the built-in [`EntityType`] variants (already
+    /// resolved through the hardcoded Schema.org context) or for an
+    /// unregistered prefix.
+    #[must_use]
+    pub fn expand_type(&self, namespaces: &crate::namespace::NamespaceRegistry) -> Option<String> {
+        match &self.entity_type {
+            EntityType::Custom(curie) => namespaces.expand(curie),
+            _ => None,
+        }
+    }
+}
+
+/// A character-offset span in the source document where a fact was found,
+/// plus the sentence index it falls in (counted by sentence-ending
+/// punctuation before the span start)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Span {
+    /// Start character offset in the source document
+    pub start: usize,
+    /// End character offset in the source document
+    pub end: usize,
+    /// Index of the sentence containing `start`, counted by sentence-ending
+    /// punctuation (`.`, `!`, `?`) seen before it in the source document
+    pub sentence_id: usize,
+}
+
+impl Span {
+    /// Locate `needle` in `haystack` via substring search, anchored at
+    /// `base_offset` (e.g. a chunk's absolute start offset in the document),
+    /// deriving `sentence_id` by counting sentence-ending punctuation before
+    /// the match. Returns `None` if `needle` doesn't appear in `haystack`.
+    #[must_use]
+    pub fn find(haystack: &str, needle: &str, base_offset: usize) -> Option<Self> {
+        let local_start = haystack.find(needle)?;
+        let sentence_id = haystack[..local_start].matches(['.', '!', '?']).count();
+
+        Some(Self {
+            start: base_offset + local_start,
+            end: base_offset + local_start + needle.len(),
+            sentence_id,
+        })
+    }
 }
 
 /// Provenance metadata for tracking extraction source and confidence
@@ -109,6 +215,12 @@ pub struct Provenance {
     /// Source text that supports this extraction
     #[serde(skip_serializing_if = "Option::is_none")]
     pub source_text: Option<String>,
+
+    /// Per-property evidence spans, keyed by Schema.org property name (e.g.
+    /// `"birthPlace"`), located by substring-searching the extracted value
+    /// in `source_text`
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub property_spans: HashMap<String, Span>,
 }
 
 impl Provenance {
@@ -121,6 +233,7 @@ impl Provenance {
             chunk_id: None,
             method: None,
             source_text: None,
+            property_spans: HashMap::new(),
         }
     }
 
@@ -158,6 +271,13 @@ impl Provenance {
         self.source_text = Some(text.into());
         self
     }
+
+    /// Record the evidence span for a single extracted property
+    #[must_use]
+    pub fn with_property_span(mut self, property: impl Into<String>, span: Span) -> Self {
+        self.property_spans.insert(property.into(), span);
+        self
+    }
 }
 
 impl Default for Provenance {
@@ -178,6 +298,19 @@ pub struct RdfDocument {
     /// Optional provenance metadata (not serialized to JSON-LD by default)
     #[serde(skip)]
     pub provenance: Option<Provenance>,
+
+    /// Optional W3C PROV-O record of the extraction run(s) that produced this
+    /// document (not serialized to JSON-LD by default; see
+    /// [`Self::to_jsonld_with_prov`])
+    #[serde(skip)]
+    pub prov: Option<ProvenanceGraph>,
+
+    /// Optional `[validFrom, validTo)` windows per property, for time-travel
+    /// queries and overlap checking via
+    /// [`TemporalValidator`](crate::temporal::TemporalValidator) (not
+    /// serialized to JSON-LD by default)
+    #[serde(skip)]
+    pub validity: Option<HashMap<String, Vec<ValidityInterval>>>,
 }
 
 impl RdfDocument {
@@ -221,6 +354,8 @@ impl RdfDocument {
             context,
             data: value,
             provenance: None,
+            prov: None,
+            validity: None,
         })
     }
 
@@ -327,6 +462,12 @@ impl RdfDocument {
             if let Some(source) = &prov.source_text {
                 prov_obj.insert("sourceText".to_string(), serde_json::json!(source));
             }
+            if !prov.property_spans.is_empty() {
+                prov_obj.insert(
+                    "propertySpans".to_string(),
+                    serde_json::json!(prov.property_spans),
+                );
+            }
 
             if !prov_obj.is_empty() {
                 output.insert("_provenance".to_string(), serde_json::Value::Object(prov_obj));
@@ -338,6 +479,69 @@ impl RdfDocument {
         }
     }
 
+    /// Set the PROV-O provenance record for this document
+    pub fn set_prov(&mut self, prov: ProvenanceGraph) {
+        self.prov = Some(prov);
+    }
+
+    /// Record an additional `[validFrom, validTo)` window asserted for
+    /// `property`, alongside any already recorded for it
+    pub fn add_validity_interval(&mut self, property: impl Into<String>, interval: ValidityInterval) {
+        self.validity.get_or_insert_with(HashMap::new).entry(property.into()).or_default().push(interval);
+    }
+
+    /// Get the validity intervals recorded for `property`, if any
+    #[must_use]
+    pub fn get_validity(&self, property: &str) -> Option<&[ValidityInterval]> {
+        self.validity.as_ref()?.get(property).map(Vec::as_slice)
+    }
+
+    /// Get the PROV-O provenance record
+    #[must_use]
+    pub fn get_prov(&self) -> Option<&ProvenanceGraph> {
+        self.prov.as_ref()
+    }
+
+    /// Convert to a single JSON-LD document whose `@graph` contains the data
+    /// nodes plus, when [`Self::prov`](Self::get_prov) is set, the run's
+    /// PROV-O activities and entities, under a combined `@context` that
+    /// merges the document's own context with [`crate::prov::PROV_CONTEXT`]
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if serialization fails
+    pub fn to_jsonld_with_prov(&self) -> Result<String> {
+        let Some(prov) = &self.prov else {
+            return self.to_json();
+        };
+        if prov.is_empty() {
+            return self.to_json();
+        }
+
+        let mut data_nodes = match &self.data {
+            Value::Object(obj) if obj.contains_key("@graph") => obj
+                .get("@graph")
+                .and_then(Value::as_array)
+                .cloned()
+                .unwrap_or_default(),
+            Value::Object(obj) if obj.is_empty() => Vec::new(),
+            other => vec![other.clone()],
+        };
+        data_nodes.extend(prov.to_jsonld_nodes());
+
+        let context = serde_json::json!([
+            self.context,
+            { "prov": crate::prov::PROV_CONTEXT },
+        ]);
+
+        let doc = serde_json::json!({
+            "@context": context,
+            "@graph": data_nodes,
+        });
+
+        serde_json::to_string_pretty(&doc).map_err(Error::from)
+    }
+
     /// Inject hardcoded JSON-LD @context to ensure correct URIs
     ///
     /// This replaces whatever @context the LLM generated with our hardcoded
@@ -363,6 +567,53 @@ impl RdfDocument {
         Ok(())
     }
 
+    /// Like [`Self::inject_hardcoded_context`], but merges `namespaces`'
+    /// prefix -> IRI bindings into the resulting `@context` prefix map
+    /// instead of leaving namespace-qualified terms (e.g. `"wd:Q42"`,
+    /// `"dbo:birthPlace"`) unresolved
+    ///
+    /// Hardcoded Schema.org terms always win on a prefix collision - this
+    /// merges registered namespaces in, it never overwrites what
+    /// [`Self::inject_hardcoded_context`] already set, so URI hallucination
+    /// protection for known terms is unaffected.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the hardcoded context cannot be parsed, or if it
+    /// isn't a JSON object (so prefixes have nowhere to merge into)
+    pub fn inject_hardcoded_context_with_namespaces(
+        &mut self,
+        namespaces: &crate::namespace::NamespaceRegistry,
+    ) -> Result<()> {
+        self.inject_hardcoded_context()?;
+
+        let context_map = self.context.as_object_mut().ok_or_else(|| {
+            Error::Config("Hardcoded @context is not a JSON object - cannot merge namespace prefixes".to_string())
+        })?;
+
+        for (prefix, iri) in namespaces.bindings() {
+            context_map.entry(prefix.clone()).or_insert_with(|| Value::String(iri.clone()));
+        }
+
+        if let Some(obj) = self.data.as_object_mut() {
+            obj.insert("@context".to_string(), self.context.clone());
+        }
+
+        Ok(())
+    }
+
+    /// Expand a namespace-qualified (`prefix:localName`) predicate key from
+    /// this document's data into a full IRI using `namespaces`
+    ///
+    /// Returns `None` if `key` isn't present in the document, or if
+    /// [`NamespaceRegistry::expand`](crate::namespace::NamespaceRegistry::expand)
+    /// can't resolve it (no `prefix:` part, or an unregistered prefix).
+    #[must_use]
+    pub fn expand_predicate(&self, key: &str, namespaces: &crate::namespace::NamespaceRegistry) -> Option<String> {
+        self.data.get(key)?;
+        namespaces.expand(key)
+    }
+
     /// Create a new RDF document with hardcoded context injection
     ///
     /// This is the recommended way to create RDF documents from LLM output,
@@ -378,10 +629,141 @@ impl RdfDocument {
     }
 
     /// Get the entity name
+    ///
+    /// When `name` is a language-tagged value or array of values (see
+    /// [`RdfEntity::with_localized_name`]), this returns `None` - use
+    /// [`Self::get_name_lang`] to pick a specific language in that case.
     #[must_use]
     pub fn get_name(&self) -> Option<&str> {
         self.data.get("name")?.as_str()
     }
+
+    /// Get the entity name tagged with BCP-47 language `lang` (e.g. `"fr"`,
+    /// `"ja"`), as set by [`RdfEntity::with_localized_name`]
+    ///
+    /// Matches a single language-tagged `{"@value": ..., "@language": ...}`
+    /// object or an array containing one. Returns `None` for a plain string
+    /// `name` or if no entry tagged with `lang` is present.
+    #[must_use]
+    pub fn get_name_lang(&self, lang: &str) -> Option<&str> {
+        let name = self.data.get("name")?;
+
+        let matches_lang = |entry: &Value| entry.get("@language").and_then(Value::as_str) == Some(lang);
+
+        let entry = match name {
+            Value::Object(_) if matches_lang(name) => Some(name),
+            Value::Array(entries) => entries.iter().find(|entry| matches_lang(entry)),
+            _ => None,
+        }?;
+
+        entry.get("@value")?.as_str()
+    }
+
+    /// Deterministic canonical JSON serialization of `data`, for hashing
+    ///
+    /// Excludes `@context` (so injecting or swapping the context never
+    /// changes the result), sorts object keys lexicographically, drops
+    /// `null`-valued keys, and applies Unicode NFKD normalization to every
+    /// string scalar. Arrays keep their original order - they are data, not
+    /// a set. See [`Self::canonical_digest`] for the hashed form.
+    #[must_use]
+    pub fn canonicalize(&self) -> String {
+        let mut canonical = String::new();
+        write_canonical_json(&self.data, &mut canonical);
+        canonical
+    }
+
+    /// Content-addressed identifier for this document's facts: the SHA-256
+    /// digest of [`Self::canonicalize`], as a lowercase hex string prefixed
+    /// with the hash name (e.g. `"sha256:1f3d...":`)
+    ///
+    /// Two documents carrying the same facts in different key orders, or
+    /// under a differently-injected `@context`, produce the same digest -
+    /// useful for deduplicating chunks and for [`Self::mint_canonical_id`].
+    #[must_use]
+    pub fn canonical_digest(&self) -> String {
+        let mut hasher = Sha256::new();
+        hasher.update(self.canonicalize().as_bytes());
+        let hex: String = hasher
+            .finalize()
+            .iter()
+            .map(|byte| format!("{byte:02x}"))
+            .collect();
+        format!("sha256:{hex}")
+    }
+
+    /// Set `@id` to a content-addressed `urn:sha256:<digest>` URI derived
+    /// from [`Self::canonical_digest`], if `@id` isn't already set
+    ///
+    /// A fallback for [`Self::enrich_with_uri`] when entity linking finds no
+    /// external URI: every extraction still ends up with a stable,
+    /// deterministic identifier rather than none at all.
+    pub fn mint_canonical_id(&mut self) {
+        if self.get_id().is_some() {
+            return;
+        }
+        let digest = self.canonical_digest();
+        let (_, hex) = digest.split_once(':').unwrap_or(("", digest.as_str()));
+        self.enrich_with_uri(format!("urn:sha256:{hex}"));
+    }
+
+    /// Wrap this document as a W3C Verifiable Credential: the document
+    /// becomes the `credentialSubject`, `issuer` names the extraction
+    /// pipeline, and any attached [`Provenance`] is mapped into the
+    /// credential's `evidence` array
+    ///
+    /// See [`crate::credential::VerifiableCredential`] for attaching a signed
+    /// Data-Integrity proof to the result.
+    #[must_use]
+    pub fn to_verifiable_credential(
+        &self,
+        issuer: impl Into<String>,
+        issued_at: impl Into<String>,
+    ) -> crate::credential::VerifiableCredential {
+        crate::credential::VerifiableCredential::from_document(self, issuer, issued_at)
+    }
+}
+
+/// Write `value` to `out` as compact canonical JSON: object keys sorted
+/// lexicographically with `null` values and `@context` dropped, array order
+/// preserved, and every string scalar NFKD-normalized
+pub(crate) fn write_canonical_json(value: &Value, out: &mut String) {
+    match value {
+        Value::Null | Value::Bool(_) | Value::Number(_) => {
+            out.push_str(&value.to_string());
+        }
+        Value::String(s) => {
+            let normalized: String = s.nfkd().collect();
+            out.push_str(&serde_json::to_string(&normalized).unwrap_or_default());
+        }
+        Value::Array(items) => {
+            out.push('[');
+            for (i, item) in items.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_canonical_json(item, out);
+            }
+            out.push(']');
+        }
+        Value::Object(map) => {
+            out.push('{');
+            let mut keys: Vec<&String> = map
+                .keys()
+                .filter(|k| *k != "@context" && !map[*k].is_null())
+                .collect();
+            keys.sort();
+            for (i, key) in keys.into_iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                out.push_str(&serde_json::to_string(key).unwrap_or_default());
+                out.push(':');
+                write_canonical_json(&map[key], out);
+            }
+            out.push('}');
+        }
+    }
 }
 
 #[cfg(test)]
@@ -400,7 +782,136 @@ mod tests {
             entity.id,
             Some("https://example.org/person/test".to_string())
         );
-        assert_eq!(entity.name, Some("Test Person".to_string()));
+        assert_eq!(entity.name, Some(json!("Test Person")));
+    }
+
+    #[test]
+    fn test_rdf_entity_with_decomposed_name() {
+        let entity = RdfEntity::new(EntityType::Person)
+            .with_name("Dr. José García Jr.")
+            .with_decomposed_name("Dr. José García Jr.");
+
+        assert_eq!(entity.name, Some(json!("Dr. José García Jr.")));
+        assert_eq!(
+            entity.get_property("givenName"),
+            Some(&json!("José"))
+        );
+        assert_eq!(
+            entity.get_property("familyName"),
+            Some(&json!("García"))
+        );
+        assert_eq!(
+            entity.get_property("honorificPrefix"),
+            Some(&json!("Dr."))
+        );
+        assert_eq!(
+            entity.get_property("honorificSuffix"),
+            Some(&json!("Jr."))
+        );
+    }
+
+    #[test]
+    fn test_rdf_entity_with_localized_name_appends_entries() {
+        let entity = RdfEntity::new(EntityType::Person)
+            .with_localized_name("fr", "Jean Dupont")
+            .with_localized_name("ja", "ジャン・デュポン");
+
+        assert_eq!(
+            entity.name,
+            Some(json!([
+                { "@value": "Jean Dupont", "@language": "fr" },
+                { "@value": "ジャン・デュポン", "@language": "ja" },
+            ]))
+        );
+    }
+
+    #[test]
+    fn test_rdf_entity_with_localized_name_preserves_plain_name() {
+        let entity = RdfEntity::new(EntityType::Person)
+            .with_name("Jean Dupont")
+            .with_localized_name("ja", "ジャン・デュポン");
+
+        assert_eq!(
+            entity.name,
+            Some(json!([
+                "Jean Dupont",
+                { "@value": "ジャン・デュポン", "@language": "ja" },
+            ]))
+        );
+    }
+
+    #[test]
+    fn test_get_name_lang_matches_array_entry() {
+        let doc = RdfDocument::from_value(json!({
+            "@context": "https://schema.org/",
+            "@type": "Person",
+            "name": [
+                { "@value": "Jean Dupont", "@language": "fr" },
+                { "@value": "ジャン・デュポン", "@language": "ja" },
+            ],
+        }))
+        .unwrap();
+
+        // Latin-script languages still get slugged like a plain `name`;
+        // non-Latin scripts are preserved rather than mangled into nothing.
+        assert_eq!(doc.get_name_lang("fr"), Some("jean_dupont"));
+        assert_eq!(doc.get_name_lang("ja"), Some("ジャン・デュポン"));
+        assert_eq!(doc.get_name_lang("de"), None);
+        assert_eq!(doc.get_name(), None);
+    }
+
+    #[test]
+    fn test_get_name_lang_preserves_non_latin_script() {
+        let doc = RdfDocument::from_value(json!({
+            "@context": "https://schema.org/",
+            "@type": "Person",
+            "name": { "@value": "東京", "@language": "ja" },
+        }))
+        .unwrap();
+
+        assert_eq!(doc.get_name_lang("ja"), Some("東京"));
+    }
+
+    #[test]
+    fn test_expand_type_resolves_custom_curie() {
+        use crate::namespace::NamespaceRegistry;
+
+        let entity = RdfEntity::new(EntityType::Custom("wd:Q42".to_string()));
+        let namespaces = NamespaceRegistry::new().with_prefix("wd", "https://www.wikidata.org/entity/");
+
+        assert_eq!(
+            entity.expand_type(&namespaces),
+            Some("https://www.wikidata.org/entity/Q42".to_string())
+        );
+    }
+
+    #[test]
+    fn test_expand_type_returns_none_for_builtin_variant() {
+        use crate::namespace::NamespaceRegistry;
+
+        let entity = RdfEntity::new(EntityType::Person);
+        let namespaces = NamespaceRegistry::new().with_prefix("wd", "https://www.wikidata.org/entity/");
+
+        assert_eq!(entity.expand_type(&namespaces), None);
+    }
+
+    #[test]
+    fn test_expand_predicate_resolves_registered_prefix() {
+        use crate::namespace::NamespaceRegistry;
+
+        let doc = RdfDocument::from_value(json!({
+            "@context": "https://schema.org/",
+            "@type": "Person",
+            "dbo:birthPlace": "Wheeler",
+        }))
+        .unwrap();
+        let namespaces = NamespaceRegistry::new().with_prefix("dbo", "https://dbpedia.org/ontology/");
+
+        assert_eq!(
+            doc.expand_predicate("dbo:birthPlace", &namespaces),
+            Some("https://dbpedia.org/ontology/birthPlace".to_string())
+        );
+        assert_eq!(doc.expand_predicate("missingKey", &namespaces), None);
     }
 
     #[test]
@@ -434,4 +945,147 @@ mod tests {
         let doc = RdfDocument::from_value(invalid).unwrap();
         assert!(doc.validate().is_err());
     }
+
+    #[test]
+    fn test_span_find_locates_substring_and_sentence() {
+        let text = "Alan Bean was born in Wheeler. He later joined NASA.";
+        let span = Span::find(text, "Wheeler", 0).unwrap();
+
+        assert_eq!(span.start, text.find("Wheeler").unwrap());
+        assert_eq!(span.end, span.start + "Wheeler".len());
+        assert_eq!(span.sentence_id, 0);
+
+        let span = Span::find(text, "NASA", 0).unwrap();
+        assert_eq!(span.sentence_id, 1);
+    }
+
+    #[test]
+    fn test_span_find_missing_substring_returns_none() {
+        assert!(Span::find("Alan Bean was born in Wheeler.", "Houston", 0).is_none());
+    }
+
+    #[test]
+    fn test_provenance_with_property_span() {
+        let span = Span::find("Alan Bean was born in Wheeler.", "Wheeler", 0).unwrap();
+        let provenance = Provenance::new().with_property_span("birthPlace", span);
+
+        assert_eq!(provenance.property_spans.get("birthPlace"), Some(&span));
+    }
+
+    #[test]
+    fn test_canonicalize_ignores_key_order_and_context() {
+        let a = RdfDocument::from_value(json!({
+            "@context": "https://schema.org/",
+            "@type": "Person",
+            "name": "alan_bean",
+            "birthPlace": "Wheeler",
+        }))
+        .unwrap();
+        let b = RdfDocument::from_value(json!({
+            "@context": "https://example.org/different-context",
+            "birthPlace": "Wheeler",
+            "name": "alan_bean",
+            "@type": "Person",
+        }))
+        .unwrap();
+
+        assert_eq!(a.canonicalize(), b.canonicalize());
+        assert_eq!(a.canonical_digest(), b.canonical_digest());
+    }
+
+    #[test]
+    fn test_canonicalize_drops_nulls_keeps_array_order() {
+        let doc = RdfDocument::from_value(json!({
+            "@context": "https://schema.org/",
+            "@type": "Person",
+            "name": "alan_bean",
+            "nickname": null,
+            "knows": ["b", "a"],
+        }))
+        .unwrap();
+
+        let canonical = doc.canonicalize();
+        assert!(!canonical.contains("nickname"));
+        assert!(canonical.contains(r#"["b","a"]"#));
+    }
+
+    #[test]
+    fn test_canonical_digest_is_hex_sha256_prefixed() {
+        let doc = RdfDocument::from_value(json!({
+            "@context": "https://schema.org/",
+            "@type": "Person",
+            "name": "alan_bean",
+        }))
+        .unwrap();
+
+        let digest = doc.canonical_digest();
+        let hex = digest.strip_prefix("sha256:").unwrap();
+        assert_eq!(hex.len(), 64);
+        assert!(hex.chars().all(|c| c.is_ascii_hexdigit()));
+    }
+
+    #[test]
+    fn test_mint_canonical_id_only_applies_when_id_missing() {
+        let mut doc = RdfDocument::from_value(json!({
+            "@context": "https://schema.org/",
+            "@type": "Person",
+            "name": "alan_bean",
+        }))
+        .unwrap();
+
+        doc.mint_canonical_id();
+        let minted = doc.get_id().unwrap().to_string();
+        assert!(minted.starts_with("urn:sha256:"));
+
+        doc.enrich_with_uri("https://example.org/already-linked");
+        doc.mint_canonical_id();
+        assert_eq!(doc.get_id(), Some("https://example.org/already-linked"));
+    }
+
+    #[test]
+    fn test_to_jsonld_with_prov_falls_back_to_plain_json_when_unset() {
+        let doc = RdfDocument::from_value(json!({
+            "@context": "https://schema.org/",
+            "@type": "Person",
+            "name": "alan_bean",
+        }))
+        .unwrap();
+
+        let plain = doc.to_json().unwrap();
+        let with_prov = doc.to_jsonld_with_prov().unwrap();
+        assert_eq!(plain, with_prov);
+    }
+
+    #[test]
+    fn test_to_jsonld_with_prov_merges_data_and_prov_nodes_under_combined_context() {
+        let mut doc = RdfDocument::from_value(json!({
+            "@context": "https://schema.org/",
+            "@type": "Person",
+            "name": "Alan Bean",
+        }))
+        .unwrap();
+
+        let mut prov = crate::prov::ProvenanceGraph::new();
+        prov.record_activity(crate::prov::ProvActivity::new(
+            "urn:run:1",
+            "2026-07-31T00:00:00Z",
+            "2026-07-31T00:00:01Z",
+            "claude-3-5-sonnet",
+            0,
+        ));
+        doc.set_prov(prov);
+        assert!(doc.get_prov().is_some());
+
+        let rendered = doc.to_jsonld_with_prov().unwrap();
+        let value: Value = serde_json::from_str(&rendered).unwrap();
+
+        let context = value.get("@context").and_then(Value::as_array).expect("array context");
+        assert_eq!(context[0], json!("https://schema.org/"));
+        assert_eq!(context[1]["prov"], json!(crate::prov::PROV_CONTEXT));
+
+        let graph = value.get("@graph").and_then(Value::as_array).expect("@graph array");
+        assert_eq!(graph.len(), 2);
+        assert!(graph.iter().any(|node| node.get("name").and_then(Value::as_str) == Some("alan_bean")));
+        assert!(graph.iter().any(|node| node.get("@type").and_then(Value::as_str) == Some("prov:Activity")));
+    }
 }
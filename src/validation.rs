@@ -7,13 +7,20 @@
 //!
 //! - **Rule-Based Validation**: Check required properties for Schema.org types
 //! - **SPARQL ASK Validation**: Run custom SPARQL queries via Oxigraph
-//! - **Confidence Scoring**: Assign confidence scores to validation results
+//! - **Confidence Scoring**: Multiply the document's extraction-time
+//!   confidence by a `(1 + confidence_impact)` factor per violation, so
+//!   independent weak signals compound instead of summing past a threshold
+//!   unpredictably - see [`RdfValidator::validate`] and
+//!   [`ValidationResult::explain`]
 //! - **Type Checking**: Validate property datatypes (dates, URLs, etc.)
 //! - **Cardinality Constraints**: Ensure properties have correct number of values
 
+use crate::datatype::DatatypeValidator;
 use crate::types::RdfDocument;
 use oxigraph::sparql::QueryResults;
 use oxigraph::store::Store;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use std::sync::Arc;
 
@@ -26,6 +33,173 @@ pub struct ValidationRule {
     pub entity_type: Option<String>,
     /// Optional SPARQL ASK query for custom validation
     pub sparql_ask: Option<String>,
+    /// Declarative value-level checks run against the document's properties,
+    /// in addition to `required_properties`/`sparql_ask` (default: none)
+    #[doc(alias = "value_constraints")]
+    pub constraints: Vec<ValueConstraint>,
+    /// SHACL-style min/max value-count bounds per property (default: none),
+    /// as compiled from `sh:minCount`/`sh:maxCount` by
+    /// [`ShaclLoader`](crate::shacl::ShaclLoader)
+    pub cardinality: Vec<CardinalityConstraint>,
+}
+
+/// A `sh:minCount`/`sh:maxCount`-style bound on how many values `property` may have
+#[derive(Debug, Clone)]
+pub struct CardinalityConstraint {
+    pub property: String,
+    pub min_count: Option<usize>,
+    pub max_count: Option<usize>,
+}
+
+/// A [`Constraint`] paired with the confidence penalty it applies when violated
+#[derive(Debug, Clone)]
+pub struct ValueConstraint {
+    pub constraint: Constraint,
+    /// Confidence impact (-1.0 to 0.0) applied when this constraint fails
+    pub confidence_impact: f64,
+}
+
+/// A declarative value-level check against an `RdfDocument`'s properties.
+/// Checked only when every property it references is present and of the
+/// expected type - a missing or mistyped property is `required_properties`'s
+/// job to flag, not a `Constraint`'s.
+#[derive(Debug, Clone)]
+pub enum Constraint {
+    /// `property`'s string value must match `pattern`, compiled once when
+    /// the constraint is built (see [`Constraint::regex`]) so repeated
+    /// `validate` calls never recompile it
+    Regex { property: String, pattern: Regex },
+    /// `property`'s numeric value must fall within `[min, max]`
+    Range { property: String, min: f64, max: f64 },
+    /// `property`'s string value must be one of `allowed`
+    OneOf { property: String, allowed: Vec<String> },
+    /// `lhs op rhs`, comparing two properties of the same document (e.g.
+    /// `foundingDate <= dissolutionDate`)
+    Compare { lhs: String, op: CompareOp, rhs: String },
+}
+
+impl Constraint {
+    /// Build a [`Constraint::Regex`], compiling `pattern` once up front
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `pattern` is not a valid regular expression
+    pub fn regex(property: impl Into<String>, pattern: &str) -> std::result::Result<Self, regex::Error> {
+        Ok(Self::Regex { property: property.into(), pattern: Regex::new(pattern)? })
+    }
+
+    /// Evaluate against `document`, returning a human-readable failure
+    /// message if the constraint is violated, or `None` if it holds or
+    /// doesn't apply (a referenced property is missing or the wrong type)
+    fn check(&self, document: &RdfDocument) -> Option<String> {
+        match self {
+            Self::Regex { property, pattern } => {
+                let value = document.get(property)?.as_str()?;
+                (!pattern.is_match(value)).then(|| {
+                    format!("'{property}' value '{value}' does not match pattern '{}'", pattern.as_str())
+                })
+            }
+            Self::Range { property, min, max } => {
+                let value = document.get(property)?.as_f64()?;
+                (value < *min || value > *max)
+                    .then(|| format!("'{property}' value {value} is outside the range [{min}, {max}]"))
+            }
+            Self::OneOf { property, allowed } => {
+                let value = document.get(property)?.as_str()?;
+                (!allowed.iter().any(|a| a == value))
+                    .then(|| format!("'{property}' value '{value}' is not one of {allowed:?}"))
+            }
+            Self::Compare { lhs, op, rhs } => {
+                let lhs_value = ComparableValue::from_json(document.get(lhs)?)?;
+                let rhs_value = ComparableValue::from_json(document.get(rhs)?)?;
+                let holds = op.evaluate(&lhs_value, &rhs_value)?;
+                (!holds).then(|| {
+                    format!("constraint '{lhs} {op} {rhs}' failed: {lhs_value:?} vs {rhs_value:?}")
+                })
+            }
+        }
+    }
+
+    /// The property whose triple drove this constraint - the left-hand side
+    /// for [`Self::Compare`], since that's the property the rule is really
+    /// about (`foundingDate <= dissolutionDate` is a claim about `foundingDate`)
+    fn primary_property(&self) -> &str {
+        match self {
+            Self::Regex { property, .. } | Self::Range { property, .. } | Self::OneOf { property, .. } => property,
+            Self::Compare { lhs, .. } => lhs,
+        }
+    }
+}
+
+/// Comparison operator for [`Constraint::Compare`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompareOp {
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    Eq,
+    Ne,
+}
+
+impl std::fmt::Display for CompareOp {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Lt => "<",
+            Self::Le => "<=",
+            Self::Gt => ">",
+            Self::Ge => ">=",
+            Self::Eq => "==",
+            Self::Ne => "!=",
+        })
+    }
+}
+
+impl CompareOp {
+    /// Evaluate `lhs self rhs`, or `None` if the two values aren't
+    /// comparable (different kinds - one numeric, one text)
+    fn evaluate(self, lhs: &ComparableValue, rhs: &ComparableValue) -> Option<bool> {
+        if matches!(self, Self::Eq | Self::Ne) {
+            let equal = lhs == rhs;
+            return Some(if self == Self::Eq { equal } else { !equal });
+        }
+
+        let ordering = lhs.partial_cmp_value(rhs)?;
+        Some(match self {
+            Self::Lt => ordering == std::cmp::Ordering::Less,
+            Self::Le => ordering != std::cmp::Ordering::Greater,
+            Self::Gt => ordering == std::cmp::Ordering::Greater,
+            Self::Ge => ordering != std::cmp::Ordering::Less,
+            Self::Eq | Self::Ne => unreachable!("handled above"),
+        })
+    }
+}
+
+/// A property value reduced to something [`Constraint::Compare`] can order:
+/// numbers compare numerically, strings (including ISO 8601 dates, which
+/// sort lexicographically) compare lexicographically. Values of different
+/// kinds are never comparable.
+#[derive(Debug, Clone, PartialEq)]
+enum ComparableValue {
+    Number(f64),
+    Text(String),
+}
+
+impl ComparableValue {
+    fn from_json(value: &Value) -> Option<Self> {
+        value
+            .as_f64()
+            .map(Self::Number)
+            .or_else(|| value.as_str().map(|s| Self::Text(s.to_string())))
+    }
+
+    fn partial_cmp_value(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        match (self, other) {
+            (Self::Number(a), Self::Number(b)) => a.partial_cmp(b),
+            (Self::Text(a), Self::Text(b)) => a.partial_cmp(b),
+            (Self::Number(_) | Self::Text(_), _) => None,
+        }
+    }
 }
 
 /// Configuration for RDF validation
@@ -50,16 +224,34 @@ impl Default for ValidationConfig {
 }
 
 /// Validation result with detailed feedback
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ValidationResult {
     pub valid: bool,
     pub violations: Vec<Violation>,
-    /// Overall confidence score (0.0-1.0)
+    /// Overall confidence score (0.0-1.0), see [`Self::explain`] for how
+    /// this was derived from `baseline_confidence` and `violations`
     pub confidence: f64,
+    /// The document's extraction-time confidence (from
+    /// [`Provenance::confidence`](crate::types::Provenance::confidence)),
+    /// before any violation penalties were applied - 1.0 if the document
+    /// carries no provenance confidence
+    pub baseline_confidence: f64,
+}
+
+/// One labeled factor behind a [`ValidationResult::confidence`] score, as
+/// returned by [`ValidationResult::explain`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConfidenceFactor {
+    /// What moved the score - "extraction confidence" for the baseline, or
+    /// a violation's rule (and property, if known) otherwise
+    pub label: String,
+    /// The multiplicative factor this contributed (1.0 = no effect, 0.0 =
+    /// zeroed the score out entirely)
+    pub factor: f64,
 }
 
 /// A validation violation
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Violation {
     pub rule: String,
     pub message: String,
@@ -68,9 +260,16 @@ pub struct Violation {
     pub property: Option<String>,
     /// Confidence impact (-1.0 to 0.0, how much this reduces overall confidence)
     pub confidence_impact: f64,
+    /// The originating file or chunk id this violation was found in, filled
+    /// in by [`ValidationReport::add`](crate::validation_report::ValidationReport::add)
+    /// when aggregating results across a batch - `None` on a freshly
+    /// returned [`RdfValidator::validate`] result
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub source: Option<String>,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum Severity {
     Error,
     Warning,
@@ -82,6 +281,9 @@ pub struct RdfValidator {
     config: ValidationConfig,
     /// Optional Oxigraph store for SPARQL ASK validation
     store: Option<Arc<Store>>,
+    /// Property->datatype checks (see [`DatatypeValidator`]), e.g. rejecting
+    /// a calendar-nonsense `birthDate` like `2024-13-45`
+    datatypes: DatatypeValidator,
 }
 
 impl Default for RdfValidator {
@@ -93,7 +295,7 @@ impl Default for RdfValidator {
 impl RdfValidator {
     /// Create a new validator with no rules
     #[must_use]
-    pub const fn new() -> Self {
+    pub fn new() -> Self {
         Self {
             rules: Vec::new(),
             config: ValidationConfig {
@@ -102,16 +304,18 @@ impl RdfValidator {
                 enable_sparql_validation: false,
             },
             store: None,
+            datatypes: DatatypeValidator::new(),
         }
     }
 
     /// Create a validator with custom configuration
     #[must_use]
-    pub const fn with_config(config: ValidationConfig) -> Self {
+    pub fn with_config(config: ValidationConfig) -> Self {
         Self {
             rules: Vec::new(),
             config,
             store: None,
+            datatypes: DatatypeValidator::new(),
         }
     }
 
@@ -127,6 +331,8 @@ impl RdfValidator {
             required_properties: vec!["name".to_string()],
             entity_type: Some("Person".to_string()),
             sparql_ask: None,
+            constraints: Vec::new(),
+            cardinality: Vec::new(),
         });
 
         // Rule: Organization must have a name
@@ -136,6 +342,8 @@ impl RdfValidator {
             required_properties: vec!["name".to_string()],
             entity_type: Some("Organization".to_string()),
             sparql_ask: None,
+            constraints: Vec::new(),
+            cardinality: Vec::new(),
         });
 
         // Rule: Place must have a name
@@ -145,6 +353,8 @@ impl RdfValidator {
             required_properties: vec!["name".to_string()],
             entity_type: Some("Place".to_string()),
             sparql_ask: None,
+            constraints: Vec::new(),
+            cardinality: Vec::new(),
         });
 
         // Rule: Event must have a name
@@ -154,8 +364,11 @@ impl RdfValidator {
             required_properties: vec!["name".to_string()],
             entity_type: Some("Event".to_string()),
             sparql_ask: None,
+            constraints: Vec::new(),
+            cardinality: Vec::new(),
         });
 
+        validator.datatypes = DatatypeValidator::with_schema_org_defaults();
         validator
     }
 
@@ -166,6 +379,13 @@ impl RdfValidator {
         self
     }
 
+    /// Replace the property->datatype checks run by [`Self::validate`]
+    #[must_use]
+    pub fn with_datatypes(mut self, datatypes: DatatypeValidator) -> Self {
+        self.datatypes = datatypes;
+        self
+    }
+
     /// Add a validation rule
     pub fn add_rule(&mut self, rule: ValidationRule) {
         self.rules.push(rule);
@@ -182,7 +402,7 @@ impl RdfValidator {
     #[must_use]
     pub fn validate(&self, document: &RdfDocument) -> ValidationResult {
         let mut violations = Vec::new();
-        let mut confidence = 1.0; // Start with perfect confidence
+        let baseline_confidence = document.provenance.as_ref().and_then(|p| p.confidence).unwrap_or(1.0);
 
         // Basic structure validation
         if let Err(e) = document.validate() {
@@ -192,12 +412,10 @@ impl RdfValidator {
                 severity: Severity::Error,
                 property: None,
                 confidence_impact: -0.5, // Major impact
+                source: None,
             });
-            return ValidationResult {
-                valid: false,
-                violations,
-                confidence: 0.5,
-            };
+            let confidence = Self::aggregate_confidence(baseline_confidence, &violations);
+            return ValidationResult { valid: false, violations, confidence, baseline_confidence };
         }
 
         let entity_type = document.get_type();
@@ -214,8 +432,6 @@ impl RdfValidator {
             // Check required properties
             for required_prop in &rule.required_properties {
                 if !Self::has_property(document, required_prop) {
-                    let impact = -0.2; // Missing required property is significant
-                    confidence += impact;
                     violations.push(Violation {
                         rule: rule.name.clone(),
                         message: format!(
@@ -224,19 +440,65 @@ impl RdfValidator {
                         ),
                         severity: Severity::Error,
                         property: Some(required_prop.clone()),
-                        confidence_impact: impact,
+                        confidence_impact: -0.2, // Missing required property is significant
+                        source: None,
+                    });
+                }
+            }
+
+            // Check declarative value constraints
+            for value_constraint in &rule.constraints {
+                if let Some(message) = value_constraint.constraint.check(document) {
+                    violations.push(Violation {
+                        rule: rule.name.clone(),
+                        message,
+                        severity: Severity::Error,
+                        property: Some(value_constraint.constraint.primary_property().to_string()),
+                        confidence_impact: value_constraint.confidence_impact,
+                        source: None,
                     });
                 }
             }
 
+            // Check SHACL-style cardinality bounds
+            for card in &rule.cardinality {
+                let count = match document.get(&card.property) {
+                    Some(Value::Array(values)) => values.len(),
+                    Some(Value::Null) | None => 0,
+                    Some(_) => 1,
+                };
+                if let Some(min_count) = card.min_count {
+                    if count < min_count {
+                        violations.push(Violation {
+                            rule: rule.name.clone(),
+                            message: format!("'{}' has {count} value(s), fewer than sh:minCount {min_count}", card.property),
+                            severity: Severity::Error,
+                            property: Some(card.property.clone()),
+                            confidence_impact: -0.2,
+                            source: None,
+                        });
+                    }
+                }
+                if let Some(max_count) = card.max_count {
+                    if count > max_count {
+                        violations.push(Violation {
+                            rule: rule.name.clone(),
+                            message: format!("'{}' has {count} value(s), more than sh:maxCount {max_count}", card.property),
+                            severity: Severity::Error,
+                            property: Some(card.property.clone()),
+                            confidence_impact: -0.1,
+                            source: None,
+                        });
+                    }
+                }
+            }
+
             // Run SPARQL ASK query if configured
             if self.config.enable_sparql_validation {
                 if let Some(sparql) = &rule.sparql_ask {
                     if let Some(store) = &self.store {
                         if let Ok(result) = Self::execute_sparql_ask(store, sparql, document) {
                             if !result {
-                                let impact = -0.15;
-                                confidence += impact;
                                 violations.push(Violation {
                                     rule: rule.name.clone(),
                                     message: format!(
@@ -245,7 +507,8 @@ impl RdfValidator {
                                     ),
                                     severity: Severity::Warning,
                                     property: None,
-                                    confidence_impact: impact,
+                                    confidence_impact: -0.15,
+                                    source: None,
                                 });
                             }
                         }
@@ -254,49 +517,54 @@ impl RdfValidator {
             }
         }
 
-        // Validate dates if present
-        for date_prop in &["birthDate", "deathDate", "datePublished", "dateCreated"] {
-            if let Some(date_value) = document.get(date_prop) {
-                if !Self::is_valid_date(date_value) {
-                    let impact = -0.05; // Minor impact for date format
-                    confidence += impact;
-                    violations.push(Violation {
-                        rule: "valid_date_format".to_string(),
-                        message: format!("{date_prop} must be in ISO 8601 format (YYYY-MM-DD)"),
-                        severity: Severity::Warning,
-                        property: Some((*date_prop).to_string()),
-                        confidence_impact: impact,
-                    });
-                }
-            }
-        }
+        // Validate property datatypes (dates, durations, numeric ranges, etc.)
+        violations.extend(self.datatypes.validate(document));
 
         // Validate URLs if present
         if let Some(id) = document.get_id() {
             if !Self::is_valid_url(id) {
-                let impact = -0.1;
-                confidence += impact;
                 violations.push(Violation {
                     rule: "valid_uri".to_string(),
                     message: "@id must be a valid URI".to_string(),
                     severity: Severity::Warning,
                     property: Some("@id".to_string()),
-                    confidence_impact: impact,
+                    confidence_impact: -0.1,
+                    source: None,
                 });
             }
         }
 
-        // Ensure confidence stays in valid range
-        confidence = confidence.clamp(0.0, 1.0);
+        let confidence = Self::aggregate_confidence(baseline_confidence, &violations);
 
         ValidationResult {
             valid: violations.iter().all(|v| v.severity != Severity::Error)
                 && confidence >= self.config.min_confidence,
             violations,
             confidence,
+            baseline_confidence,
         }
     }
 
+    /// Combine `baseline_confidence` (the document's provenance-reported
+    /// extraction-time confidence, standing in for the mean per-triple
+    /// confidence across the document's source triples) with every
+    /// violation's `(1 + confidence_impact)` factor.
+    ///
+    /// Earlier versions summed `confidence_impact` directly onto a starting
+    /// 1.0, which double-counted: five unrelated `-0.1` warnings and one
+    /// `-0.5` error both just subtracted, so a pile of weak signals could
+    /// swamp a single strong one. Multiplying the factors together instead
+    /// means independent weak signals compound correctly (five `-0.1`
+    /// warnings leave `0.9^5 ≈ 0.59`, not `0.5`) while one dominant `Error`
+    /// still dominates the product.
+    fn aggregate_confidence(baseline_confidence: f64, violations: &[Violation]) -> f64 {
+        let penalty_product: f64 = violations
+            .iter()
+            .map(|v| (1.0 + v.confidence_impact).max(0.0))
+            .product();
+        (baseline_confidence * penalty_product).clamp(0.0, 1.0)
+    }
+
     /// Execute a SPARQL ASK query against the document
     ///
     /// Returns true if the query returns true, false otherwise
@@ -323,15 +591,6 @@ impl RdfValidator {
         document.get(property).is_some_and(|v| !v.is_null())
     }
 
-    fn is_valid_date(value: &Value) -> bool {
-        value.as_str().is_some_and(|date_str| {
-            // Simple ISO 8601 date validation (YYYY-MM-DD)
-            date_str.len() == 10
-                && date_str.chars().nth(4) == Some('-')
-                && date_str.chars().nth(7) == Some('-')
-        })
-    }
-
     fn is_valid_url(url: &str) -> bool {
         url.starts_with("http://") || url.starts_with("https://")
     }
@@ -379,6 +638,27 @@ impl ValidationResult {
     pub fn total_confidence_impact(&self) -> f64 {
         self.violations.iter().map(|v| v.confidence_impact).sum()
     }
+
+    /// A ranked breakdown of every factor that went into `confidence`, worst
+    /// first, so a caller can see *why* an entity landed below
+    /// `min_confidence`: the baseline extraction confidence, then one entry
+    /// per violation with its `(1 + confidence_impact)` factor
+    #[must_use]
+    pub fn explain(&self) -> Vec<ConfidenceFactor> {
+        let mut factors = vec![ConfidenceFactor {
+            label: "extraction confidence".to_string(),
+            factor: self.baseline_confidence,
+        }];
+        factors.extend(self.violations.iter().map(|v| ConfidenceFactor {
+            label: match &v.property {
+                Some(property) => format!("{} ({property})", v.rule),
+                None => v.rule.clone(),
+            },
+            factor: (1.0 + v.confidence_impact).max(0.0),
+        }));
+        factors.sort_by(|a, b| a.factor.partial_cmp(&b.factor).unwrap_or(std::cmp::Ordering::Equal));
+        factors
+    }
 }
 
 #[cfg(test)]
@@ -462,6 +742,8 @@ mod tests {
             required_properties: vec!["foo".to_string()],
             entity_type: None,
             sparql_ask: None,
+            constraints: Vec::new(),
+            cardinality: Vec::new(),
         });
 
         let doc = RdfDocument::from_value(json!({
@@ -473,4 +755,247 @@ mod tests {
         let result = validator.validate(&doc);
         assert!(!result.is_valid());
     }
+
+    #[test]
+    fn test_regex_constraint_rejects_non_matching_value() {
+        let mut validator = RdfValidator::new();
+        validator.add_rule(ValidationRule {
+            name: "zip_format".to_string(),
+            description: "postalCode must be 5 digits".to_string(),
+            required_properties: Vec::new(),
+            entity_type: None,
+            sparql_ask: None,
+            constraints: vec![ValueConstraint {
+                constraint: Constraint::regex("postalCode", r"^\d{5}$").unwrap(),
+                confidence_impact: -0.1,
+            }],
+            cardinality: Vec::new(),
+        });
+
+        let doc = RdfDocument::from_value(json!({
+            "@context": "https://schema.org/",
+            "@type": "Place",
+            "postalCode": "abc"
+        }))
+        .unwrap();
+
+        let result = validator.validate(&doc);
+        assert!(!result.is_valid());
+        assert_eq!(result.errors().len(), 1);
+        assert!(result.errors()[0].message.contains("postalCode"));
+    }
+
+    #[test]
+    fn test_range_constraint_accepts_value_in_bounds() {
+        let mut validator = RdfValidator::new();
+        validator.add_rule(ValidationRule {
+            name: "rating_range".to_string(),
+            description: "ratingValue must be between 1 and 5".to_string(),
+            required_properties: Vec::new(),
+            entity_type: None,
+            sparql_ask: None,
+            constraints: vec![ValueConstraint {
+                constraint: Constraint::Range { property: "ratingValue".to_string(), min: 1.0, max: 5.0 },
+                confidence_impact: -0.1,
+            }],
+            cardinality: Vec::new(),
+        });
+
+        let doc = RdfDocument::from_value(json!({
+            "@context": "https://schema.org/",
+            "@type": "Review",
+            "ratingValue": 9
+        }))
+        .unwrap();
+
+        let result = validator.validate(&doc);
+        assert!(!result.is_valid());
+        assert_eq!(result.errors().len(), 1);
+        assert!(result.errors()[0].message.contains("outside the range"));
+    }
+
+    #[test]
+    fn test_one_of_constraint_rejects_value_outside_allowed_set() {
+        let mut validator = RdfValidator::new();
+        validator.add_rule(ValidationRule {
+            name: "status_enum".to_string(),
+            description: "eventStatus must be a known value".to_string(),
+            required_properties: Vec::new(),
+            entity_type: None,
+            sparql_ask: None,
+            constraints: vec![ValueConstraint {
+                constraint: Constraint::OneOf {
+                    property: "eventStatus".to_string(),
+                    allowed: vec!["EventScheduled".to_string(), "EventCancelled".to_string()],
+                },
+                confidence_impact: -0.1,
+            }],
+            cardinality: Vec::new(),
+        });
+
+        let doc = RdfDocument::from_value(json!({
+            "@context": "https://schema.org/",
+            "@type": "Event",
+            "eventStatus": "EventPostponed"
+        }))
+        .unwrap();
+
+        let result = validator.validate(&doc);
+        assert!(!result.is_valid());
+        assert_eq!(result.errors().len(), 1);
+    }
+
+    #[test]
+    fn test_compare_constraint_checks_two_properties() {
+        let mut validator = RdfValidator::new();
+        validator.add_rule(ValidationRule {
+            name: "founding_before_dissolution".to_string(),
+            description: "foundingDate must not be after dissolutionDate".to_string(),
+            required_properties: Vec::new(),
+            entity_type: None,
+            sparql_ask: None,
+            constraints: vec![ValueConstraint {
+                constraint: Constraint::Compare {
+                    lhs: "foundingDate".to_string(),
+                    op: CompareOp::Le,
+                    rhs: "dissolutionDate".to_string(),
+                },
+                confidence_impact: -0.2,
+            }],
+            cardinality: Vec::new(),
+        });
+
+        let valid_doc = RdfDocument::from_value(json!({
+            "@context": "https://schema.org/",
+            "@type": "Organization",
+            "foundingDate": "1990-01-01",
+            "dissolutionDate": "2020-01-01"
+        }))
+        .unwrap();
+        assert!(validator.validate(&valid_doc).is_valid());
+
+        let invalid_doc = RdfDocument::from_value(json!({
+            "@context": "https://schema.org/",
+            "@type": "Organization",
+            "foundingDate": "2020-01-01",
+            "dissolutionDate": "1990-01-01"
+        }))
+        .unwrap();
+        let result = validator.validate(&invalid_doc);
+        assert!(!result.is_valid());
+        assert_eq!(result.errors().len(), 1);
+        assert!(result.errors()[0].message.contains("foundingDate <= dissolutionDate"));
+    }
+
+    #[test]
+    fn test_constraint_is_skipped_when_property_missing() {
+        let mut validator = RdfValidator::new();
+        validator.add_rule(ValidationRule {
+            name: "zip_format".to_string(),
+            description: "postalCode must be 5 digits".to_string(),
+            required_properties: Vec::new(),
+            entity_type: None,
+            sparql_ask: None,
+            constraints: vec![ValueConstraint {
+                constraint: Constraint::regex("postalCode", r"^\d{5}$").unwrap(),
+                confidence_impact: -0.1,
+            }],
+            cardinality: Vec::new(),
+        });
+
+        let doc = RdfDocument::from_value(json!({
+            "@context": "https://schema.org/",
+            "@type": "Place"
+        }))
+        .unwrap();
+
+        assert!(validator.validate(&doc).is_valid());
+    }
+
+    #[test]
+    fn test_multiple_weak_violations_compound_multiplicatively_not_additively() {
+        let mut validator = RdfValidator::new();
+        for i in 0..5 {
+            validator.add_rule(ValidationRule {
+                name: format!("warn_{i}"),
+                description: "always fails".to_string(),
+                required_properties: Vec::new(),
+                entity_type: None,
+                sparql_ask: None,
+                constraints: vec![ValueConstraint {
+                    constraint: Constraint::OneOf {
+                        property: format!("field{i}"),
+                        allowed: vec!["expected".to_string()],
+                    },
+                    confidence_impact: -0.1,
+                }],
+                cardinality: Vec::new(),
+            });
+        }
+
+        let doc = RdfDocument::from_value(json!({
+            "@context": "https://schema.org/",
+            "@type": "Thing",
+            "field0": "other", "field1": "other", "field2": "other",
+            "field3": "other", "field4": "other"
+        }))
+        .unwrap();
+
+        let result = validator.validate(&doc);
+        assert_eq!(result.violations.len(), 5);
+        // Multiplicative: 0.9^5, not the additive 1.0 - 5*0.1 = 0.5
+        assert!((result.confidence() - 0.9_f64.powi(5)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_single_missing_name_error_scales_confidence_by_its_factor() {
+        let doc = RdfDocument::from_value(json!({
+            "@context": "https://schema.org/",
+            "@type": "Person",
+            "birthDate": "1932-03-15"
+        }))
+        .unwrap();
+
+        let validator = RdfValidator::with_schema_org_rules();
+        let result = validator.validate(&doc);
+
+        // Missing required 'name' is the only violation: a single 0.8 factor
+        assert!((result.confidence() - 0.8).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_explain_ranks_factors_worst_first_and_includes_baseline() {
+        let doc = RdfDocument::from_value(json!({
+            "@context": "https://schema.org/",
+            "@type": "Organization"
+        }))
+        .unwrap();
+
+        let validator = RdfValidator::with_schema_org_rules();
+        let result = validator.validate(&doc);
+
+        let factors = result.explain();
+        assert!(factors.iter().any(|f| f.label == "extraction confidence" && f.factor == 1.0));
+        assert_eq!(factors[0].label, "organization_requires_name (name)");
+        assert!(factors.windows(2).all(|w| w[0].factor <= w[1].factor));
+    }
+
+    #[test]
+    fn test_extraction_confidence_scales_the_final_score() {
+        let mut doc = RdfDocument::from_value(json!({
+            "@context": "https://schema.org/",
+            "@type": "Person",
+            "name": "Ada Lovelace",
+            "birthDate": "1815-12-10"
+        }))
+        .unwrap();
+        doc.provenance = Some(crate::types::Provenance::new().with_confidence(0.6));
+
+        let validator = RdfValidator::with_schema_org_rules();
+        let result = validator.validate(&doc);
+
+        assert_eq!(result.violations.len(), 0);
+        assert!((result.confidence() - 0.6).abs() < 1e-9);
+        assert!((result.baseline_confidence - 0.6).abs() < 1e-9);
+    }
 }
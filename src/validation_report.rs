@@ -0,0 +1,239 @@
+//! Aggregate, multi-document validation reporting
+//!
+//! [`RdfValidator::validate`](crate::validation::RdfValidator::validate)
+//! returns one [`ValidationResult`](crate::validation::ValidationResult) per
+//! document, with no way to see how a whole batch fared or hand the results
+//! to a CI tool. [`ValidationReport`] collects `(source_id, RdfDocument,
+//! ValidationResult)` triples as they come in, stamping each violation with
+//! the source it came from, and can render the aggregate as either plain
+//! JSON (for custom tooling) or a SARIF 2.1.0 log - the format most CI
+//! annotation tools and policy linters already consume - with each
+//! violation's stable rule name as the SARIF diagnostic code.
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::error::{Error, Result};
+use crate::types::RdfDocument;
+use crate::validation::{Severity, ValidationResult};
+
+/// One validated document's result, tagged with the source it came from
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ValidationReportEntry {
+    /// The originating file or chunk id, as passed to [`ValidationReport::add`]
+    pub source_id: String,
+    /// The document that was validated
+    pub document: RdfDocument,
+    /// The validation outcome, with every violation's `source` set to `source_id`
+    pub result: ValidationResult,
+}
+
+/// Per-source error/warning counts, as returned by [`ValidationReport::summaries`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SourceSummary {
+    pub source_id: String,
+    pub valid: bool,
+    pub error_count: usize,
+    pub warning_count: usize,
+}
+
+/// Aggregate validation results across a batch of extracted documents
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ValidationReport {
+    pub entries: Vec<ValidationReportEntry>,
+}
+
+impl ValidationReport {
+    /// Build an empty report
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record `document`'s `result` under `source_id`, stamping every
+    /// violation's `source` field so it survives independently of the
+    /// entry it's nested in
+    pub fn add(&mut self, source_id: impl Into<String>, document: RdfDocument, mut result: ValidationResult) {
+        let source_id = source_id.into();
+        for violation in &mut result.violations {
+            violation.source = Some(source_id.clone());
+        }
+        self.entries.push(ValidationReportEntry { source_id, document, result });
+    }
+
+    /// Overall pass/fail: `true` only if every entry's result was valid
+    #[must_use]
+    pub fn passed(&self) -> bool {
+        self.entries.iter().all(|entry| entry.result.valid)
+    }
+
+    /// Per-source error/warning counts and pass/fail, in insertion order
+    #[must_use]
+    pub fn summaries(&self) -> Vec<SourceSummary> {
+        self.entries
+            .iter()
+            .map(|entry| SourceSummary {
+                source_id: entry.source_id.clone(),
+                valid: entry.result.valid,
+                error_count: entry.result.errors().len(),
+                warning_count: entry.result.warnings().len(),
+            })
+            .collect()
+    }
+
+    /// Total error-severity violations across every source
+    #[must_use]
+    pub fn total_errors(&self) -> usize {
+        self.entries.iter().map(|entry| entry.result.errors().len()).sum()
+    }
+
+    /// Total warning-severity violations across every source
+    #[must_use]
+    pub fn total_warnings(&self) -> usize {
+        self.entries.iter().map(|entry| entry.result.warnings().len()).sum()
+    }
+
+    /// Render the report as pretty-printed JSON
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if serialization fails
+    pub fn to_json(&self) -> Result<String> {
+        serde_json::to_string_pretty(self).map_err(Error::from)
+    }
+
+    /// Render the report as a SARIF 2.1.0 log: one SARIF `result` per
+    /// violation, with the violation's `rule` as the SARIF `ruleId`
+    /// (registered once in the tool driver's `rules` catalog) and
+    /// `source_id` as the result's artifact location
+    #[must_use]
+    pub fn to_sarif(&self) -> Value {
+        let mut rule_ids: Vec<String> = Vec::new();
+        let mut results = Vec::new();
+
+        for entry in &self.entries {
+            for violation in &entry.result.violations {
+                if !rule_ids.contains(&violation.rule) {
+                    rule_ids.push(violation.rule.clone());
+                }
+
+                results.push(json!({
+                    "ruleId": violation.rule,
+                    "level": sarif_level(&violation.severity),
+                    "message": { "text": violation.message },
+                    "locations": [{
+                        "physicalLocation": {
+                            "artifactLocation": { "uri": entry.source_id }
+                        }
+                    }]
+                }));
+            }
+        }
+
+        let rules: Vec<Value> = rule_ids
+            .iter()
+            .map(|rule_id| json!({ "id": rule_id, "shortDescription": { "text": rule_id } }))
+            .collect();
+
+        json!({
+            "$schema": "https://raw.githubusercontent.com/oasis-tcs/sarif-spec/master/Schemata/sarif-schema-2.1.0.json",
+            "version": "2.1.0",
+            "runs": [{
+                "tool": {
+                    "driver": {
+                        "name": "text-to-rdf",
+                        "rules": rules
+                    }
+                },
+                "results": results
+            }]
+        })
+    }
+}
+
+/// Map a [`Severity`] to its SARIF `level` string
+fn sarif_level(severity: &Severity) -> &'static str {
+    match severity {
+        Severity::Error => "error",
+        Severity::Warning => "warning",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::validation::{RdfValidator, Violation};
+
+    fn doc(json_value: serde_json::Value) -> RdfDocument {
+        RdfDocument::from_value(json_value).unwrap()
+    }
+
+    #[test]
+    fn test_add_stamps_violation_source() {
+        let mut report = ValidationReport::new();
+        let document = doc(json!({"@context": "https://schema.org/", "@type": "Organization"}));
+        let result = RdfValidator::with_schema_org_rules().validate(&document);
+
+        report.add("chunk-0", document, result);
+
+        assert_eq!(report.entries.len(), 1);
+        let violation = &report.entries[0].result.violations[0];
+        assert_eq!(violation.source.as_deref(), Some("chunk-0"));
+    }
+
+    #[test]
+    fn test_passed_is_false_when_any_source_is_invalid() {
+        let mut report = ValidationReport::new();
+        let valid_doc = doc(json!({
+            "@context": "https://schema.org/",
+            "@type": "Person",
+            "name": "Ada Lovelace"
+        }));
+        let invalid_doc = doc(json!({"@context": "https://schema.org/", "@type": "Organization"}));
+        let validator = RdfValidator::with_schema_org_rules();
+
+        report.add("a", valid_doc.clone(), validator.validate(&valid_doc));
+        report.add("b", invalid_doc.clone(), validator.validate(&invalid_doc));
+
+        assert!(!report.passed());
+        assert_eq!(report.summaries().len(), 2);
+        assert!(report.total_errors() >= 1);
+    }
+
+    #[test]
+    fn test_to_sarif_registers_one_rule_per_distinct_violation_rule() {
+        let mut report = ValidationReport::new();
+        let mut result = ValidationResult { valid: false, violations: Vec::new(), confidence: 0.5, baseline_confidence: 1.0 };
+        result.violations.push(Violation {
+            rule: "required_property".to_string(),
+            message: "Missing name".to_string(),
+            severity: Severity::Error,
+            property: Some("name".to_string()),
+            confidence_impact: -0.2,
+            source: None,
+        });
+        report.add("doc-1", doc(json!({"@context": "https://schema.org/"})), result);
+
+        let sarif = report.to_sarif();
+        let rules = sarif["runs"][0]["tool"]["driver"]["rules"].as_array().unwrap();
+        assert_eq!(rules.len(), 1);
+        assert_eq!(rules[0]["id"], "required_property");
+
+        let results = sarif["runs"][0]["results"].as_array().unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0]["level"], "error");
+        assert_eq!(results[0]["locations"][0]["physicalLocation"]["artifactLocation"]["uri"], "doc-1");
+    }
+
+    #[test]
+    fn test_to_json_roundtrips() {
+        let mut report = ValidationReport::new();
+        let document = doc(json!({"@context": "https://schema.org/", "@type": "Person", "name": "Ada"}));
+        let result = RdfValidator::with_schema_org_rules().validate(&document);
+        report.add("doc-1", document, result);
+
+        let json_str = report.to_json().unwrap();
+        let parsed: ValidationReport = serde_json::from_str(&json_str).unwrap();
+        assert_eq!(parsed.entries.len(), 1);
+    }
+}
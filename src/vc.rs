@@ -0,0 +1,558 @@
+//! Sign extracted JSON-LD as a W3C Verifiable Credential JWT
+//!
+//! [`VerifiableCredential`](crate::credential::VerifiableCredential) already
+//! wraps an [`RdfDocument`](crate::types::RdfDocument) as a credential and can
+//! attach an Ed25519 Data-Integrity proof (see [`crate::credential`]), but
+//! that proof format is JSON-LD-native and awkward for consumers who just
+//! want a bearer token to pass around. This module wraps the same credential
+//! in a compact JWS instead: an RS256- or EdDSA-signed JWT whose payload
+//! carries the registered claims (`iss`, `nbf`, `iat`, `jti`) plus a `vc`
+//! claim holding the credential, so any standard JWT library can verify who
+//! extracted which triples and when without understanding JSON-LD proofs at
+//! all. [`sign_credential`]/[`verify_credential`] work directly from PEM
+//! keys; [`issue_credential`]/[`verify_credential_jwk`] accept a [`Jwk`]
+//! instead, for callers that already manage keys in JSON Web Key form.
+
+use crate::credential::VerifiableCredential;
+use crate::types::RdfDocument;
+use crate::{Error, Result};
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine as _};
+use ed25519_dalek::{Signature as Ed25519Signature, Signer as _, SigningKey as Ed25519SigningKey, Verifier as _, VerifyingKey as Ed25519VerifyingKey};
+use rsa::pkcs1v15::{Signature as RsaSignature, SigningKey as RsaSigningKey, VerifyingKey as RsaVerifyingKey};
+use rsa::pkcs8::{DecodePrivateKey, DecodePublicKey};
+use rsa::signature::{Signer as _, Verifier as _};
+use rsa::{BigUint, RsaPrivateKey, RsaPublicKey};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use sha2::Sha256;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// A Verifiable Credential signed as a compact JWS (`header.payload.signature`,
+/// base64url with no padding), ready to hand to any JWT-aware consumer
+#[cfg(feature = "vc-jwt")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SignedCredential {
+    compact: String,
+}
+
+#[cfg(feature = "vc-jwt")]
+impl SignedCredential {
+    /// The compact JWS serialization (`header.payload.signature`)
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.compact
+    }
+}
+
+#[cfg(feature = "vc-jwt")]
+impl std::fmt::Display for SignedCredential {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.compact)
+    }
+}
+
+/// Parse a PEM-encoded RSA private key (PKCS#8), converting it to DER for
+/// the `rsa` crate's signer
+#[cfg(feature = "vc-jwt")]
+fn private_key_from_pem(pem_str: &str) -> Result<RsaPrivateKey> {
+    let der = pem::parse(pem_str).map_err(|e| Error::Signing(format!("Invalid PEM private key: {e}")))?;
+    RsaPrivateKey::from_pkcs8_der(der.contents())
+        .map_err(|e| Error::Signing(format!("Invalid RSA private key: {e}")))
+}
+
+/// Parse a PEM-encoded RSA public key (SubjectPublicKeyInfo), converting it
+/// to DER for the `rsa` crate's verifier
+#[cfg(feature = "vc-jwt")]
+fn public_key_from_pem(pem_str: &str) -> Result<RsaPublicKey> {
+    let der = pem::parse(pem_str).map_err(|e| Error::Signing(format!("Invalid PEM public key: {e}")))?;
+    RsaPublicKey::from_public_key_der(der.contents())
+        .map_err(|e| Error::Signing(format!("Invalid RSA public key: {e}")))
+}
+
+/// Unix epoch seconds, used for the JWT `nbf`/`iat` registered claims
+#[cfg(feature = "vc-jwt")]
+fn now_secs() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |duration| duration.as_secs())
+}
+
+/// RFC 3339 UTC timestamp for the current time, for `issuanceDate`
+///
+/// Computed from [`now_secs`] via the civil calendar conversion in
+/// [`civil_from_days`] rather than pulling in a date/time crate just for
+/// this - the same no-new-dependency tradeoff as
+/// [`VerifiableCredential::canonical_digest`]'s `"sha256:"` prefix.
+#[cfg(feature = "vc-jwt")]
+fn now_rfc3339() -> String {
+    let secs = now_secs();
+    let (year, month, day) = civil_from_days((secs / 86_400) as i64);
+    let time_of_day = secs % 86_400;
+    let (hour, minute, second) = (time_of_day / 3_600, (time_of_day / 60) % 60, time_of_day % 60);
+    format!("{year:04}-{month:02}-{day:02}T{hour:02}:{minute:02}:{second:02}Z")
+}
+
+/// Days since the Unix epoch -> (year, month, day), per Howard Hinnant's
+/// `civil_from_days` algorithm (proleptic Gregorian calendar)
+#[cfg(feature = "vc-jwt")]
+fn civil_from_days(days_since_epoch: i64) -> (i64, u32, u32) {
+    let z = days_since_epoch + 719_468;
+    let era = if z >= 0 { z } else { z - 146_096 } / 146_097;
+    let doe = (z - era * 146_097) as u64;
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let y = yoe as i64 + era * 400;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = if month <= 2 { y + 1 } else { y };
+    (year, month, day)
+}
+
+/// An RSA or Ed25519 key in JSON Web Key form (RFC 7517): a private key as
+/// accepted by [`issue_credential`], or a public key as accepted by
+/// [`verify_credential_jwk`]. Models only the members needed to reconstruct
+/// either - `kty` selects the algorithm (`"RSA"` or `"OKP"` with
+/// `crv: "Ed25519"`), and unrecognized members are simply ignored.
+#[cfg(feature = "vc-jwt")]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Jwk {
+    pub kty: String,
+    #[serde(default)]
+    pub crv: Option<String>,
+    #[serde(default)]
+    pub kid: Option<String>,
+    /// Ed25519 public key, base64url (`kty: "OKP"`)
+    #[serde(default)]
+    pub x: Option<String>,
+    /// RSA modulus, base64url (`kty: "RSA"`)
+    #[serde(default)]
+    pub n: Option<String>,
+    /// RSA public exponent, base64url (`kty: "RSA"`)
+    #[serde(default)]
+    pub e: Option<String>,
+    /// RSA private exponent, or Ed25519 private key seed, base64url
+    #[serde(default)]
+    pub d: Option<String>,
+    /// RSA first prime factor, base64url
+    #[serde(default)]
+    pub p: Option<String>,
+    /// RSA second prime factor, base64url
+    #[serde(default)]
+    pub q: Option<String>,
+}
+
+#[cfg(feature = "vc-jwt")]
+impl Jwk {
+    fn decode_field(&self, field: &str, value: &Option<String>) -> Result<Vec<u8>> {
+        let encoded = value
+            .as_deref()
+            .ok_or_else(|| Error::Signing(format!("JWK is missing required field \"{field}\"")))?;
+        URL_SAFE_NO_PAD
+            .decode(encoded)
+            .map_err(|e| Error::Signing(format!("JWK field \"{field}\" is not valid base64url: {e}")))
+    }
+}
+
+/// Reconstruct an [`RsaPrivateKey`] from a `kty: "RSA"` [`Jwk`]'s `n`, `e`,
+/// `d`, `p`, and `q` members
+#[cfg(feature = "vc-jwt")]
+fn rsa_private_key_from_jwk(jwk: &Jwk) -> Result<RsaPrivateKey> {
+    let n = BigUint::from_bytes_be(&jwk.decode_field("n", &jwk.n)?);
+    let e = BigUint::from_bytes_be(&jwk.decode_field("e", &jwk.e)?);
+    let d = BigUint::from_bytes_be(&jwk.decode_field("d", &jwk.d)?);
+    let p = BigUint::from_bytes_be(&jwk.decode_field("p", &jwk.p)?);
+    let q = BigUint::from_bytes_be(&jwk.decode_field("q", &jwk.q)?);
+
+    RsaPrivateKey::from_components(n, e, d, vec![p, q])
+        .map_err(|e| Error::Signing(format!("Invalid RSA JWK: {e}")))
+}
+
+/// Reconstruct an Ed25519 [`Ed25519SigningKey`] from a `kty: "OKP"`,
+/// `crv: "Ed25519"` [`Jwk`]'s `d` member (the 32-byte private key seed)
+#[cfg(feature = "vc-jwt")]
+fn ed25519_signing_key_from_jwk(jwk: &Jwk) -> Result<Ed25519SigningKey> {
+    let seed = jwk.decode_field("d", &jwk.d)?;
+    let seed: [u8; 32] = seed
+        .try_into()
+        .map_err(|_| Error::Signing("Ed25519 JWK \"d\" must decode to 32 bytes".to_string()))?;
+    Ok(Ed25519SigningKey::from_bytes(&seed))
+}
+
+/// Sign `vc` as a compact RS256 JWS
+///
+/// `private_key_pem` is a PKCS#8 PEM-encoded RSA private key. `issuer`
+/// becomes the JWT `iss` claim (a DID or URI identifying who signed it) and
+/// `kid` is carried in the protected header so a verifier can select the
+/// right public key. `nbf`/`iat` are set to the current time, and `jti` is
+/// [`VerifiableCredential::canonical_digest`] - deterministic and unique per
+/// credential content, without pulling in a UUID dependency just for this.
+///
+/// # Errors
+///
+/// Returns [`Error::Signing`] if the private key cannot be parsed or
+/// signing fails
+#[cfg(feature = "vc-jwt")]
+pub fn sign_credential(
+    vc: &VerifiableCredential,
+    private_key_pem: &str,
+    issuer: impl Into<String>,
+    kid: impl Into<String>,
+) -> Result<SignedCredential> {
+    let private_key = private_key_from_pem(private_key_pem)?;
+    let signing_key = RsaSigningKey::<Sha256>::new(private_key);
+
+    let header = json!({
+        "alg": "RS256",
+        "typ": "JWT",
+        "kid": kid.into(),
+    });
+
+    let now = now_secs();
+    let payload = json!({
+        "iss": issuer.into(),
+        "nbf": now,
+        "iat": now,
+        "jti": vc.canonical_digest(),
+        "vc": vc.as_value(),
+    });
+
+    let header_b64 = URL_SAFE_NO_PAD.encode(serde_json::to_vec(&header)?);
+    let payload_b64 = URL_SAFE_NO_PAD.encode(serde_json::to_vec(&payload)?);
+    let signing_input = format!("{header_b64}.{payload_b64}");
+
+    let signature: RsaSignature = signing_key
+        .try_sign(signing_input.as_bytes())
+        .map_err(|e| Error::Signing(format!("RSA signing failed: {e}")))?;
+    let signature_b64 = URL_SAFE_NO_PAD.encode(signature.to_bytes());
+
+    Ok(SignedCredential { compact: format!("{signing_input}.{signature_b64}") })
+}
+
+/// Verify a compact RS256 JWS produced by [`sign_credential`] against
+/// `public_key_pem` (a PEM-encoded RSA `SubjectPublicKeyInfo`), returning the
+/// embedded `vc` claim on success
+///
+/// # Errors
+///
+/// Returns [`Error::Signing`] if the token is malformed, the public key
+/// cannot be parsed, or the signature does not verify
+#[cfg(feature = "vc-jwt")]
+pub fn verify_credential(jwt: &str, public_key_pem: &str) -> Result<Value> {
+    let mut parts = jwt.split('.');
+    let (Some(header_b64), Some(payload_b64), Some(signature_b64), None) =
+        (parts.next(), parts.next(), parts.next(), parts.next())
+    else {
+        return Err(Error::Signing(
+            "Malformed JWT: expected exactly three dot-separated segments".to_string(),
+        ));
+    };
+
+    let public_key = public_key_from_pem(public_key_pem)?;
+    let verifying_key = RsaVerifyingKey::<Sha256>::new(public_key);
+
+    let signature_bytes = URL_SAFE_NO_PAD
+        .decode(signature_b64)
+        .map_err(|e| Error::Signing(format!("Invalid base64 signature: {e}")))?;
+    let signature = RsaSignature::try_from(signature_bytes.as_slice())
+        .map_err(|e| Error::Signing(format!("Invalid signature encoding: {e}")))?;
+
+    let signing_input = format!("{header_b64}.{payload_b64}");
+    verifying_key
+        .verify(signing_input.as_bytes(), &signature)
+        .map_err(|_| Error::Signing("JWT signature verification failed".to_string()))?;
+
+    let payload_bytes = URL_SAFE_NO_PAD
+        .decode(payload_b64)
+        .map_err(|e| Error::Signing(format!("Invalid base64 payload: {e}")))?;
+    let payload: Value = serde_json::from_slice(&payload_bytes)?;
+
+    payload
+        .get("vc")
+        .cloned()
+        .ok_or_else(|| Error::Signing("JWT payload is missing the \"vc\" claim".to_string()))
+}
+
+/// Wrap `doc` in a [`VerifiableCredential`] attributed to `issuer` (with the
+/// current time as `issuanceDate`) and sign it as a compact JWS, choosing
+/// RS256 or EdDSA from `key.kty`
+///
+/// # Errors
+///
+/// Returns [`Error::Signing`] if `key` is missing the fields its `kty`
+/// requires, or if signing fails
+#[cfg(feature = "vc-jwt")]
+pub fn issue_credential(doc: &RdfDocument, issuer: &str, key: &Jwk) -> Result<String> {
+    let vc = doc.to_verifiable_credential(issuer, now_rfc3339());
+
+    let now = now_secs();
+    let payload = json!({
+        "iss": issuer,
+        "nbf": now,
+        "iat": now,
+        "jti": vc.canonical_digest(),
+        "vc": vc.as_value(),
+    });
+    let payload_b64 = URL_SAFE_NO_PAD.encode(serde_json::to_vec(&payload)?);
+
+    match key.kty.as_str() {
+        "RSA" => {
+            let header_b64 = URL_SAFE_NO_PAD.encode(serde_json::to_vec(&json!({ "alg": "RS256", "typ": "JWT" }))?);
+            let signing_input = format!("{header_b64}.{payload_b64}");
+
+            let signing_key = RsaSigningKey::<Sha256>::new(rsa_private_key_from_jwk(key)?);
+            let signature: RsaSignature = signing_key
+                .try_sign(signing_input.as_bytes())
+                .map_err(|e| Error::Signing(format!("RSA signing failed: {e}")))?;
+            let signature_b64 = URL_SAFE_NO_PAD.encode(signature.to_bytes());
+
+            Ok(format!("{signing_input}.{signature_b64}"))
+        }
+        "OKP" if key.crv.as_deref() == Some("Ed25519") => {
+            let header_b64 = URL_SAFE_NO_PAD.encode(serde_json::to_vec(&json!({ "alg": "EdDSA", "typ": "JWT" }))?);
+            let signing_input = format!("{header_b64}.{payload_b64}");
+
+            let signing_key = ed25519_signing_key_from_jwk(key)?;
+            let signature = signing_key.sign(signing_input.as_bytes());
+            let signature_b64 = URL_SAFE_NO_PAD.encode(signature.to_bytes());
+
+            Ok(format!("{signing_input}.{signature_b64}"))
+        }
+        other => Err(Error::Signing(format!("Unsupported JWK kty \"{other}\" (expected \"RSA\" or \"OKP\")"))),
+    }
+}
+
+/// Verify a compact JWS produced by [`issue_credential`] against `key` (the
+/// *public* counterpart of the [`Jwk`] it was signed with - `n`/`e` for RSA,
+/// or `x` for Ed25519), returning the embedded `vc` claim on success
+///
+/// # Errors
+///
+/// Returns [`Error::Signing`] if the token is malformed, `key` is missing
+/// the fields its `kty` requires, or the signature does not verify
+#[cfg(feature = "vc-jwt")]
+pub fn verify_credential_jwk(jwt: &str, key: &Jwk) -> Result<Value> {
+    let mut parts = jwt.split('.');
+    let (Some(header_b64), Some(payload_b64), Some(signature_b64), None) =
+        (parts.next(), parts.next(), parts.next(), parts.next())
+    else {
+        return Err(Error::Signing(
+            "Malformed JWT: expected exactly three dot-separated segments".to_string(),
+        ));
+    };
+    let signing_input = format!("{header_b64}.{payload_b64}");
+    let signature_bytes = URL_SAFE_NO_PAD
+        .decode(signature_b64)
+        .map_err(|e| Error::Signing(format!("Invalid base64 signature: {e}")))?;
+
+    match key.kty.as_str() {
+        "RSA" => {
+            let n = BigUint::from_bytes_be(&key.decode_field("n", &key.n)?);
+            let e = BigUint::from_bytes_be(&key.decode_field("e", &key.e)?);
+            let public_key =
+                RsaPublicKey::new(n, e).map_err(|e| Error::Signing(format!("Invalid RSA JWK: {e}")))?;
+            let verifying_key = RsaVerifyingKey::<Sha256>::new(public_key);
+            let signature = RsaSignature::try_from(signature_bytes.as_slice())
+                .map_err(|e| Error::Signing(format!("Invalid signature encoding: {e}")))?;
+            verifying_key
+                .verify(signing_input.as_bytes(), &signature)
+                .map_err(|_| Error::Signing("JWT signature verification failed".to_string()))?;
+        }
+        "OKP" if key.crv.as_deref() == Some("Ed25519") => {
+            let x = key.decode_field("x", &key.x)?;
+            let x: [u8; 32] = x
+                .try_into()
+                .map_err(|_| Error::Signing("Ed25519 JWK \"x\" must decode to 32 bytes".to_string()))?;
+            let verifying_key = Ed25519VerifyingKey::from_bytes(&x)
+                .map_err(|e| Error::Signing(format!("Invalid Ed25519 JWK: {e}")))?;
+            let signature_bytes: [u8; 64] = signature_bytes
+                .try_into()
+                .map_err(|_| Error::Signing("Invalid Ed25519 signature length".to_string()))?;
+            verifying_key
+                .verify(signing_input.as_bytes(), &Ed25519Signature::from_bytes(&signature_bytes))
+                .map_err(|_| Error::Signing("JWT signature verification failed".to_string()))?;
+        }
+        other => return Err(Error::Signing(format!("Unsupported JWK kty \"{other}\" (expected \"RSA\" or \"OKP\")"))),
+    }
+
+    let payload_bytes = URL_SAFE_NO_PAD
+        .decode(payload_b64)
+        .map_err(|e| Error::Signing(format!("Invalid base64 payload: {e}")))?;
+    let payload: Value = serde_json::from_slice(&payload_bytes)?;
+
+    payload
+        .get("vc")
+        .cloned()
+        .ok_or_else(|| Error::Signing("JWT payload is missing the \"vc\" claim".to_string()))
+}
+
+#[cfg(all(test, feature = "vc-jwt"))]
+mod tests {
+    use super::*;
+    use crate::types::RdfDocument;
+    use rsa::pkcs8::{EncodePrivateKey, EncodePublicKey, LineEnding};
+    use serde_json::json;
+
+    fn sample_credential() -> VerifiableCredential {
+        let doc = RdfDocument::from_value(json!({
+            "@context": "https://schema.org/",
+            "@type": "Person",
+            "name": "alan_bean",
+        }))
+        .unwrap();
+        doc.to_verifiable_credential("https://example.org/pipeline", "2026-07-31T00:00:00Z")
+    }
+
+    fn keypair() -> (String, String) {
+        let mut rng = rand::thread_rng();
+        let private_key = RsaPrivateKey::new(&mut rng, 2048).expect("key generation should succeed");
+        let public_key = RsaPublicKey::from(&private_key);
+
+        let private_pem = private_key.to_pkcs8_pem(LineEnding::LF).unwrap().to_string();
+        let public_pem = public_key.to_public_key_pem(LineEnding::LF).unwrap();
+        (private_pem, public_pem)
+    }
+
+    #[test]
+    fn test_sign_credential_produces_three_segment_jws() {
+        let (private_pem, _) = keypair();
+        let vc = sample_credential();
+
+        let signed = sign_credential(&vc, &private_pem, "https://example.org/pipeline", "key-1").unwrap();
+        assert_eq!(signed.as_str().split('.').count(), 3);
+    }
+
+    #[test]
+    fn test_verify_credential_recovers_embedded_vc() {
+        let (private_pem, public_pem) = keypair();
+        let vc = sample_credential();
+
+        let signed = sign_credential(&vc, &private_pem, "https://example.org/pipeline", "key-1").unwrap();
+        let recovered = verify_credential(signed.as_str(), &public_pem).unwrap();
+
+        assert_eq!(recovered["credentialSubject"]["name"], "alan_bean");
+    }
+
+    #[test]
+    fn test_verify_credential_rejects_wrong_key() {
+        let (private_pem, _) = keypair();
+        let (_, other_public_pem) = keypair();
+        let vc = sample_credential();
+
+        let signed = sign_credential(&vc, &private_pem, "https://example.org/pipeline", "key-1").unwrap();
+        assert!(verify_credential(signed.as_str(), &other_public_pem).is_err());
+    }
+
+    #[test]
+    fn test_verify_credential_rejects_malformed_jwt() {
+        let (_, public_pem) = keypair();
+        assert!(verify_credential("not-a-jwt", &public_pem).is_err());
+    }
+
+    fn sample_document() -> RdfDocument {
+        RdfDocument::from_value(json!({
+            "@context": "https://schema.org/",
+            "@type": "Person",
+            "name": "alan_bean",
+        }))
+        .unwrap()
+    }
+
+    fn rsa_jwk_pair() -> (Jwk, Jwk) {
+        let mut rng = rand::thread_rng();
+        let private_key = RsaPrivateKey::new(&mut rng, 2048).expect("key generation should succeed");
+        let public_key = RsaPublicKey::from(&private_key);
+
+        let n = URL_SAFE_NO_PAD.encode(public_key.n().to_bytes_be());
+        let e = URL_SAFE_NO_PAD.encode(public_key.e().to_bytes_be());
+        let d = URL_SAFE_NO_PAD.encode(private_key.d().to_bytes_be());
+        let primes = private_key.primes();
+        let p = URL_SAFE_NO_PAD.encode(primes[0].to_bytes_be());
+        let q = URL_SAFE_NO_PAD.encode(primes[1].to_bytes_be());
+
+        let private_jwk = Jwk {
+            kty: "RSA".to_string(),
+            crv: None,
+            kid: None,
+            x: None,
+            n: Some(n.clone()),
+            e: Some(e.clone()),
+            d: Some(d),
+            p: Some(p),
+            q: Some(q),
+        };
+        let public_jwk =
+            Jwk { kty: "RSA".to_string(), crv: None, kid: None, x: None, n: Some(n), e: Some(e), d: None, p: None, q: None };
+        (private_jwk, public_jwk)
+    }
+
+    fn ed25519_jwk_pair(seed: u8) -> (Jwk, Jwk) {
+        let signing_key = Ed25519SigningKey::from_bytes(&[seed; 32]);
+        let verifying_key = signing_key.verifying_key();
+
+        let d = URL_SAFE_NO_PAD.encode(signing_key.to_bytes());
+        let x = URL_SAFE_NO_PAD.encode(verifying_key.to_bytes());
+
+        let private_jwk = Jwk {
+            kty: "OKP".to_string(),
+            crv: Some("Ed25519".to_string()),
+            kid: None,
+            x: None,
+            n: None,
+            e: None,
+            d: Some(d),
+            p: None,
+            q: None,
+        };
+        let public_jwk = Jwk {
+            kty: "OKP".to_string(),
+            crv: Some("Ed25519".to_string()),
+            kid: None,
+            x: Some(x),
+            n: None,
+            e: None,
+            d: None,
+            p: None,
+            q: None,
+        };
+        (private_jwk, public_jwk)
+    }
+
+    #[test]
+    fn test_issue_credential_rsa_round_trips_through_verify_credential_jwk() {
+        let (private_jwk, public_jwk) = rsa_jwk_pair();
+        let doc = sample_document();
+
+        let jwt = issue_credential(&doc, "https://example.org/pipeline", &private_jwk).unwrap();
+        let recovered = verify_credential_jwk(&jwt, &public_jwk).unwrap();
+
+        assert_eq!(recovered["credentialSubject"]["name"], "alan_bean");
+        assert_eq!(recovered["issuer"], "https://example.org/pipeline");
+    }
+
+    #[test]
+    fn test_issue_credential_ed25519_round_trips_through_verify_credential_jwk() {
+        let (private_jwk, public_jwk) = ed25519_jwk_pair(9);
+        let doc = sample_document();
+
+        let jwt = issue_credential(&doc, "https://example.org/pipeline", &private_jwk).unwrap();
+        let recovered = verify_credential_jwk(&jwt, &public_jwk).unwrap();
+
+        assert_eq!(recovered["credentialSubject"]["name"], "alan_bean");
+    }
+
+    #[test]
+    fn test_verify_credential_jwk_rejects_wrong_key() {
+        let (private_jwk, _) = ed25519_jwk_pair(9);
+        let (_, other_public_jwk) = ed25519_jwk_pair(42);
+        let doc = sample_document();
+
+        let jwt = issue_credential(&doc, "https://example.org/pipeline", &private_jwk).unwrap();
+        assert!(verify_credential_jwk(&jwt, &other_public_jwk).is_err());
+    }
+
+    #[test]
+    fn test_issue_credential_rejects_unsupported_kty() {
+        let doc = sample_document();
+        let key = Jwk { kty: "EC".to_string(), crv: None, kid: None, x: None, n: None, e: None, d: None, p: None, q: None };
+        assert!(issue_credential(&doc, "https://example.org/pipeline", &key).is_err());
+    }
+}